@@ -0,0 +1,6 @@
+--liquibase formatted sql
+
+--changeset core-api:001-users-password-argon2
+--comment: Argon2id PHC strings do not fit in CHAR(60); the cast also drops the padding of legacy rows
+ALTER TABLE users ALTER COLUMN password TYPE VARCHAR(255);
+--rollback ALTER TABLE users ALTER COLUMN password TYPE CHAR(60);
@@ -0,0 +1,5 @@
+mod query;
+pub use query::get_password_hash_query;
+
+mod view;
+pub use view::GetPasswordHashQueryView;
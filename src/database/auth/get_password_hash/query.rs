@@ -0,0 +1,17 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+use crate::database::auth::get_password_hash::GetPasswordHashQueryView;
+
+pub async fn get_password_hash_query(
+    view: GetPasswordHashQueryView,
+    pool: PgPool,
+) -> Result<Option<String>, DatabaseError> {
+    let result = sqlx::query_scalar::<_, String>(&view.get_request())
+        .bind(view.get_user_id() as i32)
+        .fetch_optional(&pool)
+        .await?;
+
+    Ok(result)
+}
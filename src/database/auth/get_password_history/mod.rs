@@ -0,0 +1,5 @@
+mod query;
+pub use query::get_password_history_query;
+
+mod view;
+pub use view::GetPasswordHistoryQueryView;
@@ -0,0 +1,18 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+use crate::database::auth::get_password_history::GetPasswordHistoryQueryView;
+
+pub async fn get_password_history_query(
+    view: GetPasswordHistoryQueryView,
+    pool: PgPool,
+) -> Result<Vec<String>, DatabaseError> {
+    let result = sqlx::query_scalar::<_, String>(&view.get_request())
+        .bind(view.get_user_id() as i32)
+        .bind(view.get_limit() as i64)
+        .fetch_all(&pool)
+        .await?;
+
+    Ok(result)
+}
@@ -0,0 +1,41 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct GetPasswordHistoryQueryView {
+    user_id: u64,
+    limit: u64,
+}
+
+impl GetPasswordHistoryQueryView {
+    pub fn new(user_id: u64, limit: u64) -> Self {
+        Self { user_id, limit }
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+
+    pub fn get_limit(&self) -> u64 {
+        self.limit
+    }
+}
+
+impl DatabaseQueryView for GetPasswordHistoryQueryView {
+    fn get_request(&self) -> String {
+        "SELECT password_hash FROM password_history
+        WHERE user_id = $1
+        ORDER BY created_at DESC, id DESC
+        LIMIT $2"
+            .to_string()
+    }
+}
+
+impl Display for GetPasswordHistoryQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "GetPasswordHistoryQueryView: user_id = {}, limit = {}",
+            self.user_id, self.limit
+        )
+    }
+}
@@ -0,0 +1,5 @@
+mod query;
+pub use query::is_password_expired_query;
+
+mod view;
+pub use view::IsPasswordExpiredQueryView;
@@ -0,0 +1,18 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+use crate::database::auth::is_password_expired::IsPasswordExpiredQueryView;
+
+pub async fn is_password_expired_query(
+    view: IsPasswordExpiredQueryView,
+    pool: PgPool,
+) -> Result<bool, DatabaseError> {
+    let result = sqlx::query_scalar::<_, bool>(&view.get_request())
+        .bind(view.get_user_id() as i32)
+        .bind(view.get_max_age_days() as i32)
+        .fetch_optional(&pool)
+        .await?;
+
+    Ok(result.unwrap_or(false))
+}
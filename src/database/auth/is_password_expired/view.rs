@@ -0,0 +1,43 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct IsPasswordExpiredQueryView {
+    user_id: u64,
+    max_age_days: u64,
+}
+
+impl IsPasswordExpiredQueryView {
+    pub fn new(user_id: u64, max_age_days: u64) -> Self {
+        Self {
+            user_id,
+            max_age_days,
+        }
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+
+    pub fn get_max_age_days(&self) -> u64 {
+        self.max_age_days
+    }
+}
+
+impl DatabaseQueryView for IsPasswordExpiredQueryView {
+    fn get_request(&self) -> String {
+        // Sans date de changement connue (comptes antérieurs), le mot de passe n'expire pas
+        "SELECT COALESCE(password_changed_at < now() - make_interval(days => $2), false)
+        FROM users WHERE id = $1"
+            .to_string()
+    }
+}
+
+impl Display for IsPasswordExpiredQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IsPasswordExpiredQueryView: user_id = {}, max_age_days = {}",
+            self.user_id, self.max_age_days
+        )
+    }
+}
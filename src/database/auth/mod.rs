@@ -1,5 +1,10 @@
 pub mod change_password;
+pub mod get_password_hash;
+pub mod get_password_history;
 pub mod is_first_time;
+pub mod is_password_expired;
 pub mod login;
+pub mod record_password_change;
 pub mod register;
 pub mod unset_first_connection;
+pub mod verify_email;
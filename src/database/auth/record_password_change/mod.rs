@@ -0,0 +1,5 @@
+mod query;
+pub use query::record_password_change_query;
+
+mod view;
+pub use view::RecordPasswordChangeQueryView;
@@ -0,0 +1,19 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+use crate::database::auth::record_password_change::RecordPasswordChangeQueryView;
+
+pub async fn record_password_change_query(
+    view: RecordPasswordChangeQueryView,
+    pool: PgPool,
+) -> Result<(), DatabaseError> {
+    sqlx::query(&view.get_request())
+        .bind(view.get_user_id() as i32)
+        .bind(view.get_password_hash())
+        .bind(view.get_history_size() as i64)
+        .execute(&pool)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+/**
+ * Stamps `password_changed_at`, appends the new hash to the history and drops the entries
+ * older than the `history_size` most recent ones.
+ */
+pub struct RecordPasswordChangeQueryView {
+    user_id: u64,
+    password_hash: String,
+    history_size: u64,
+}
+
+impl RecordPasswordChangeQueryView {
+    pub fn new(user_id: u64, password_hash: &str, history_size: u64) -> Self {
+        Self {
+            user_id,
+            password_hash: password_hash.to_string(),
+            history_size,
+        }
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+
+    pub fn get_password_hash(&self) -> &str {
+        &self.password_hash
+    }
+
+    pub fn get_history_size(&self) -> u64 {
+        self.history_size
+    }
+}
+
+impl DatabaseQueryView for RecordPasswordChangeQueryView {
+    fn get_request(&self) -> String {
+        // Le DELETE ne voit pas la ligne insérée par la même requête : on garde donc $3 - 1 anciennes
+        "WITH touched AS (
+            UPDATE users SET password_changed_at = now() WHERE id = $1
+        ), inserted AS (
+            INSERT INTO password_history (user_id, password_hash) VALUES ($1, $2)
+        )
+        DELETE FROM password_history
+        WHERE user_id = $1
+        AND id NOT IN (
+            SELECT id FROM password_history
+            WHERE user_id = $1
+            ORDER BY created_at DESC, id DESC
+            LIMIT GREATEST($3 - 1, 0)
+        )"
+        .to_string()
+    }
+}
+
+impl Display for RecordPasswordChangeQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "RecordPasswordChangeQueryView: user_id = {}, password_hash = [PROTECTED]",
+            self.user_id
+        )
+    }
+}
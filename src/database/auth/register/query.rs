@@ -7,14 +7,17 @@ pub async fn register_query(
     view: RegisterUserQueryView,
     pool: PgPool,
 ) -> Result<bool, DatabaseError> {
-    let result = sqlx::query_scalar::<_, bool>(&view.get_request())
+    let request = view.get_request();
+    let mut query = sqlx::query_scalar::<_, bool>(&request)
         .bind(view.get_first_name())
         .bind(view.get_last_name())
         .bind(view.get_email())
         .bind(view.get_password())
-        .bind(view.get_phone_number())
-        .fetch_one(&pool)
-        .await?;
+        .bind(view.get_phone_number());
+    if let Some(status) = view.get_status() {
+        query = query.bind(status);
+    }
+    let result = query.fetch_one(&pool).await?;
 
     Ok(result)
 }
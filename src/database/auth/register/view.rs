@@ -7,6 +7,7 @@ pub struct RegisterUserQueryView {
     email: String,
     password: String,
     phone_number: Option<String>,
+    status: Option<String>,
 }
 
 impl RegisterUserQueryView {
@@ -23,9 +24,18 @@ impl RegisterUserQueryView {
             email: email.to_string(),
             password: password.to_string(),
             phone_number: phone_number.map(|s| s.to_string()),
+            status: None,
         }
     }
 
+    /**
+     * Overrides the column default, e.g. to create a self-registered account as pending.
+     */
+    pub fn with_status(mut self, status: &str) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+
     pub fn get_first_name(&self) -> &str {
         &self.first_name
     }
@@ -41,14 +51,24 @@ impl RegisterUserQueryView {
     pub fn get_phone_number(&self) -> Option<&str> {
         self.phone_number.as_deref()
     }
+    pub fn get_status(&self) -> Option<&str> {
+        self.status.as_deref()
+    }
 }
 
 impl DatabaseQueryView for RegisterUserQueryView {
     fn get_request(&self) -> String {
         // Une seule requête gère les deux cas. Postgres acceptera $5 comme NULL.
-        "INSERT INTO users (first_name, last_name, email, password, phone_number) \
-         VALUES ($1, $2, $3, $4, $5) RETURNING true"
-            .to_string()
+        match self.status {
+            Some(_) => {
+                "INSERT INTO users (first_name, last_name, email, password, phone_number, status) \
+                 VALUES ($1, $2, $3, $4, $5, $6) RETURNING true"
+                    .to_string()
+            }
+            None => "INSERT INTO users (first_name, last_name, email, password, phone_number) \
+                 VALUES ($1, $2, $3, $4, $5) RETURNING true"
+                .to_string(),
+        }
     }
 }
 
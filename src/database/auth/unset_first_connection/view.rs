@@ -25,7 +25,7 @@ impl UnsetFirstConnectionQueryView {
 
 impl DatabaseQueryView for UnsetFirstConnectionQueryView {
     fn get_request(&self) -> String {
-        "UPDATE users SET first_connect = false AND password = $1 WHERE id = $2".to_string()
+        "UPDATE users SET first_connect = false, password = $1 WHERE id = $2".to_string()
     }
 }
 
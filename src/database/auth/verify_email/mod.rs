@@ -0,0 +1,5 @@
+mod query;
+pub use query::verify_email_query;
+
+mod view;
+pub use view::VerifyEmailQueryView;
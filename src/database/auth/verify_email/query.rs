@@ -0,0 +1,23 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+use crate::database::auth::verify_email::VerifyEmailQueryView;
+use crate::database::users::{ACTIVE_USER_STATUS, PENDING_USER_STATUS};
+
+/**
+ * Activates a pending account. Returns false when the account was not pending.
+ */
+pub async fn verify_email_query(
+    view: VerifyEmailQueryView,
+    pool: PgPool,
+) -> Result<bool, DatabaseError> {
+    let result = sqlx::query_scalar::<_, bool>(&view.get_request())
+        .bind(view.get_user_id() as i32)
+        .bind(ACTIVE_USER_STATUS)
+        .bind(PENDING_USER_STATUS)
+        .fetch_optional(&pool)
+        .await?;
+
+    Ok(result.unwrap_or(false))
+}
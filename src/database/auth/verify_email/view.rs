@@ -0,0 +1,28 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct VerifyEmailQueryView {
+    user_id: u64,
+}
+
+impl VerifyEmailQueryView {
+    pub fn new(user_id: u64) -> Self {
+        Self { user_id }
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+}
+
+impl DatabaseQueryView for VerifyEmailQueryView {
+    fn get_request(&self) -> String {
+        "UPDATE users SET status = $2 WHERE id = $1 AND status = $3 RETURNING true".to_string()
+    }
+}
+
+impl Display for VerifyEmailQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "VerifyEmailQueryView: user_id = {}", self.user_id)
+    }
+}
@@ -0,0 +1,23 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+use crate::database::email_changes::confirm_email_change::ConfirmEmailChangeQueryView;
+
+/**
+ * Applies the pending change in a single statement. Returns false when no matching
+ * change is pending; fails with a constraint violation when the address was claimed
+ * by another account in the meantime.
+ */
+pub async fn confirm_email_change_query(
+    view: ConfirmEmailChangeQueryView,
+    pool: PgPool,
+) -> Result<bool, DatabaseError> {
+    let result = sqlx::query_scalar::<_, bool>(&view.get_request())
+        .bind(view.get_user_id() as i32)
+        .bind(view.get_new_email())
+        .fetch_optional(&pool)
+        .await?;
+
+    Ok(result.unwrap_or(false))
+}
@@ -0,0 +1,49 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct ConfirmEmailChangeQueryView {
+    user_id: u64,
+    new_email: String,
+}
+
+impl ConfirmEmailChangeQueryView {
+    pub fn new(user_id: u64, new_email: &str) -> Self {
+        Self {
+            user_id,
+            new_email: new_email.to_string(),
+        }
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+
+    pub fn get_new_email(&self) -> &str {
+        &self.new_email
+    }
+}
+
+impl DatabaseQueryView for ConfirmEmailChangeQueryView {
+    fn get_request(&self) -> String {
+        "WITH change AS ( \
+            DELETE FROM email_changes \
+            WHERE user_id = $1 AND new_email = $2 AND expires_at > NOW() \
+            RETURNING new_email \
+         ) \
+         UPDATE users SET email = change.new_email \
+         FROM change \
+         WHERE users.id = $1 \
+         RETURNING true"
+            .to_string()
+    }
+}
+
+impl Display for ConfirmEmailChangeQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ConfirmEmailChangeQueryView: user_id = {}, new_email = {}",
+            self.user_id, self.new_email
+        )
+    }
+}
@@ -0,0 +1,23 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+use crate::database::email_changes::create_email_change::CreateEmailChangeQueryView;
+
+/**
+ * Stores (or replaces) the pending change of the user.
+ * Returns false when the new address already belongs to an account.
+ */
+pub async fn create_email_change_query(
+    view: CreateEmailChangeQueryView,
+    pool: PgPool,
+) -> Result<bool, DatabaseError> {
+    let result = sqlx::query_scalar::<_, bool>(&view.get_request())
+        .bind(view.get_user_id() as i32)
+        .bind(view.get_new_email())
+        .bind(view.get_ttl_seconds() as f64)
+        .fetch_optional(&pool)
+        .await?;
+
+    Ok(result.unwrap_or(false))
+}
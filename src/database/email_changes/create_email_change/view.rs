@@ -0,0 +1,52 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct CreateEmailChangeQueryView {
+    user_id: u64,
+    new_email: String,
+    ttl_seconds: u64,
+}
+
+impl CreateEmailChangeQueryView {
+    pub fn new(user_id: u64, new_email: &str, ttl_seconds: u64) -> Self {
+        Self {
+            user_id,
+            new_email: new_email.to_string(),
+            ttl_seconds,
+        }
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+
+    pub fn get_new_email(&self) -> &str {
+        &self.new_email
+    }
+
+    pub fn get_ttl_seconds(&self) -> u64 {
+        self.ttl_seconds
+    }
+}
+
+impl DatabaseQueryView for CreateEmailChangeQueryView {
+    fn get_request(&self) -> String {
+        "INSERT INTO email_changes (user_id, new_email, expires_at) \
+         SELECT $1, $2, NOW() + make_interval(secs => $3) \
+         WHERE NOT EXISTS (SELECT 1 FROM users WHERE LOWER(email) = LOWER($2)) \
+         ON CONFLICT (user_id) DO UPDATE \
+         SET new_email = EXCLUDED.new_email, expires_at = EXCLUDED.expires_at, created_at = NOW() \
+         RETURNING true"
+            .to_string()
+    }
+}
+
+impl Display for CreateEmailChangeQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CreateEmailChangeQueryView: user_id = {}, new_email = {}, ttl_seconds = {}",
+            self.user_id, self.new_email, self.ttl_seconds
+        )
+    }
+}
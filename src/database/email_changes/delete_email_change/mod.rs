@@ -0,0 +1,5 @@
+mod query;
+pub use query::delete_email_change_query;
+
+mod view;
+pub use view::DeleteEmailChangeQueryView;
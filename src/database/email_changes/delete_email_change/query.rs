@@ -0,0 +1,20 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+use crate::database::email_changes::delete_email_change::DeleteEmailChangeQueryView;
+
+/**
+ * Drops the pending change of the user. Returns false when there was none.
+ */
+pub async fn delete_email_change_query(
+    view: DeleteEmailChangeQueryView,
+    pool: PgPool,
+) -> Result<bool, DatabaseError> {
+    let result = sqlx::query(&view.get_request())
+        .bind(view.get_user_id() as i32)
+        .execute(&pool)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
@@ -0,0 +1,20 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+use crate::database::email_changes::get_email_change::GetEmailChangeQueryView;
+
+/**
+ * Returns the address waiting for confirmation, if the change has not expired.
+ */
+pub async fn get_email_change_query(
+    view: GetEmailChangeQueryView,
+    pool: PgPool,
+) -> Result<Option<String>, DatabaseError> {
+    let result = sqlx::query_scalar::<_, String>(&view.get_request())
+        .bind(view.get_user_id() as i32)
+        .fetch_optional(&pool)
+        .await?;
+
+    Ok(result)
+}
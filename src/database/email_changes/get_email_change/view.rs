@@ -0,0 +1,28 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct GetEmailChangeQueryView {
+    user_id: u64,
+}
+
+impl GetEmailChangeQueryView {
+    pub fn new(user_id: u64) -> Self {
+        Self { user_id }
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+}
+
+impl DatabaseQueryView for GetEmailChangeQueryView {
+    fn get_request(&self) -> String {
+        "SELECT new_email FROM email_changes WHERE user_id = $1 AND expires_at > NOW()".to_string()
+    }
+}
+
+impl Display for GetEmailChangeQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GetEmailChangeQueryView: user_id = {}", self.user_id)
+    }
+}
@@ -0,0 +1,4 @@
+pub mod confirm_email_change;
+pub mod create_email_change;
+pub mod delete_email_change;
+pub mod get_email_change;
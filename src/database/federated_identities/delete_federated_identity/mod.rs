@@ -0,0 +1,5 @@
+mod query;
+pub use query::delete_federated_identity_query;
+
+mod view;
+pub use view::DeleteFederatedIdentityQueryView;
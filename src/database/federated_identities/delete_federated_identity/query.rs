@@ -0,0 +1,21 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+use crate::database::federated_identities::delete_federated_identity::DeleteFederatedIdentityQueryView;
+
+/**
+ * Returns false when the user had no account linked at this provider.
+ */
+pub async fn delete_federated_identity_query(
+    view: DeleteFederatedIdentityQueryView,
+    pool: PgPool,
+) -> Result<bool, DatabaseError> {
+    let result = sqlx::query(&view.get_request())
+        .bind(view.get_user_id() as i32)
+        .bind(view.get_issuer())
+        .execute(&pool)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
@@ -0,0 +1,40 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct DeleteFederatedIdentityQueryView {
+    user_id: u64,
+    issuer: String,
+}
+
+impl DeleteFederatedIdentityQueryView {
+    pub fn new(user_id: u64, issuer: &str) -> Self {
+        Self {
+            user_id,
+            issuer: issuer.to_string(),
+        }
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+
+    pub fn get_issuer(&self) -> &str {
+        &self.issuer
+    }
+}
+
+impl DatabaseQueryView for DeleteFederatedIdentityQueryView {
+    fn get_request(&self) -> String {
+        "DELETE FROM federated_identities WHERE user_id = $1 AND issuer = $2".to_string()
+    }
+}
+
+impl Display for DeleteFederatedIdentityQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "DeleteFederatedIdentityQueryView: user_id = {}, issuer = {}",
+            self.user_id, self.issuer
+        )
+    }
+}
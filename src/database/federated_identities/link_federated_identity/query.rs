@@ -0,0 +1,23 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+use crate::database::federated_identities::link_federated_identity::LinkFederatedIdentityQueryView;
+
+/**
+ * Fails with a constraint violation when the external account or the user is already linked.
+ */
+pub async fn link_federated_identity_query(
+    view: LinkFederatedIdentityQueryView,
+    pool: PgPool,
+) -> Result<(), DatabaseError> {
+    sqlx::query(&view.get_request())
+        .bind(view.get_issuer())
+        .bind(view.get_subject())
+        .bind(view.get_user_id() as i32)
+        .bind(view.get_email())
+        .execute(&pool)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct LinkFederatedIdentityQueryView {
+    issuer: String,
+    subject: String,
+    user_id: u64,
+    email: Option<String>,
+}
+
+impl LinkFederatedIdentityQueryView {
+    pub fn new(issuer: &str, subject: &str, user_id: u64, email: Option<&str>) -> Self {
+        Self {
+            issuer: issuer.to_string(),
+            subject: subject.to_string(),
+            user_id,
+            email: email.map(str::to_string),
+        }
+    }
+
+    pub fn get_issuer(&self) -> &str {
+        &self.issuer
+    }
+
+    pub fn get_subject(&self) -> &str {
+        &self.subject
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+
+    pub fn get_email(&self) -> Option<&str> {
+        self.email.as_deref()
+    }
+}
+
+impl DatabaseQueryView for LinkFederatedIdentityQueryView {
+    fn get_request(&self) -> String {
+        // Les contraintes d'unicité refusent un compte externe ou un utilisateur déjà lié
+        "INSERT INTO federated_identities (issuer, subject, user_id, email, last_login_at) \
+         VALUES ($1, $2, $3, $4, NOW())"
+            .to_string()
+    }
+}
+
+impl Display for LinkFederatedIdentityQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "LinkFederatedIdentityQueryView: issuer = {}, subject = {}, user_id = {}, email = {:?}",
+            self.issuer, self.subject, self.user_id, self.email
+        )
+    }
+}
@@ -0,0 +1,3 @@
+pub mod delete_federated_identity;
+pub mod link_federated_identity;
+pub mod use_federated_identity;
@@ -0,0 +1,5 @@
+mod query;
+pub use query::use_federated_identity_query;
+
+mod view;
+pub use view::UseFederatedIdentityQueryView;
@@ -0,0 +1,22 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+use crate::database::federated_identities::use_federated_identity::UseFederatedIdentityQueryView;
+
+/**
+ * Returns the user linked to the external account and stamps `last_login_at`.
+ * Returns None when the account is not linked or its user is archived.
+ */
+pub async fn use_federated_identity_query(
+    view: UseFederatedIdentityQueryView,
+    pool: PgPool,
+) -> Result<Option<u64>, DatabaseError> {
+    let result = sqlx::query_scalar::<_, i32>(&view.get_request())
+        .bind(view.get_issuer())
+        .bind(view.get_subject())
+        .fetch_optional(&pool)
+        .await?;
+
+    Ok(result.map(|user_id| user_id as u64))
+}
@@ -0,0 +1,45 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct UseFederatedIdentityQueryView {
+    issuer: String,
+    subject: String,
+}
+
+impl UseFederatedIdentityQueryView {
+    pub fn new(issuer: &str, subject: &str) -> Self {
+        Self {
+            issuer: issuer.to_string(),
+            subject: subject.to_string(),
+        }
+    }
+
+    pub fn get_issuer(&self) -> &str {
+        &self.issuer
+    }
+
+    pub fn get_subject(&self) -> &str {
+        &self.subject
+    }
+}
+
+impl DatabaseQueryView for UseFederatedIdentityQueryView {
+    fn get_request(&self) -> String {
+        "UPDATE federated_identities f SET last_login_at = NOW() \
+         FROM users u \
+         WHERE f.issuer = $1 AND f.subject = $2 \
+         AND u.id = f.user_id AND NOT u.is_archived \
+         RETURNING f.user_id"
+            .to_string()
+    }
+}
+
+impl Display for UseFederatedIdentityQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "UseFederatedIdentityQueryView: issuer = {}, subject = {}",
+            self.issuer, self.subject
+        )
+    }
+}
@@ -0,0 +1,5 @@
+mod query;
+pub use query::create_impersonation_query;
+
+mod view;
+pub use view::CreateImpersonationQueryView;
@@ -0,0 +1,21 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+use crate::database::impersonations::create_impersonation::CreateImpersonationQueryView;
+
+pub async fn create_impersonation_query(
+    view: CreateImpersonationQueryView,
+    pool: PgPool,
+) -> Result<(), DatabaseError> {
+    sqlx::query(&view.get_request())
+        .bind(view.get_id())
+        .bind(view.get_admin_id() as i32)
+        .bind(view.get_user_id() as i32)
+        .bind(view.get_reason())
+        .bind(view.get_expires_at())
+        .execute(&pool)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use chrono::{DateTime, Utc};
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+use uuid::Uuid;
+
+pub struct CreateImpersonationQueryView {
+    id: Uuid,
+    admin_id: u64,
+    user_id: u64,
+    reason: String,
+    expires_at: DateTime<Utc>,
+}
+
+impl CreateImpersonationQueryView {
+    pub fn new(
+        id: Uuid,
+        admin_id: u64,
+        user_id: u64,
+        reason: &str,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            admin_id,
+            user_id,
+            reason: reason.to_string(),
+            expires_at,
+        }
+    }
+
+    pub fn get_id(&self) -> &Uuid {
+        &self.id
+    }
+
+    pub fn get_admin_id(&self) -> u64 {
+        self.admin_id
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+
+    pub fn get_reason(&self) -> &str {
+        &self.reason
+    }
+
+    pub fn get_expires_at(&self) -> &DateTime<Utc> {
+        &self.expires_at
+    }
+}
+
+impl DatabaseQueryView for CreateImpersonationQueryView {
+    fn get_request(&self) -> String {
+        "INSERT INTO impersonations (id, admin_id, user_id, reason, expires_at) \
+         VALUES ($1, $2, $3, $4, $5)"
+            .to_string()
+    }
+}
+
+impl Display for CreateImpersonationQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CreateImpersonationQueryView: id = {}, admin_id = {}, user_id = {}, reason = {}, expires_at = {}",
+            self.id, self.admin_id, self.user_id, self.reason, self.expires_at
+        )
+    }
+}
@@ -0,0 +1,5 @@
+mod query;
+pub use query::end_impersonation_query;
+
+mod view;
+pub use view::EndImpersonationQueryView;
@@ -0,0 +1,20 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+use crate::database::impersonations::end_impersonation::EndImpersonationQueryView;
+
+/**
+ * Returns false when the impersonation does not exist or is already over.
+ */
+pub async fn end_impersonation_query(
+    view: EndImpersonationQueryView,
+    pool: PgPool,
+) -> Result<bool, DatabaseError> {
+    let result = sqlx::query(&view.get_request())
+        .bind(view.get_id())
+        .execute(&pool)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
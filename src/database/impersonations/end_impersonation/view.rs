@@ -0,0 +1,31 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+use uuid::Uuid;
+
+pub struct EndImpersonationQueryView {
+    id: Uuid,
+}
+
+impl EndImpersonationQueryView {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+
+    pub fn get_id(&self) -> &Uuid {
+        &self.id
+    }
+}
+
+impl DatabaseQueryView for EndImpersonationQueryView {
+    fn get_request(&self) -> String {
+        "UPDATE impersonations SET ended_at = NOW() \
+         WHERE id = $1 AND ended_at IS NULL AND expires_at > NOW()"
+            .to_string()
+    }
+}
+
+impl Display for EndImpersonationQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EndImpersonationQueryView: id = {}", self.id)
+    }
+}
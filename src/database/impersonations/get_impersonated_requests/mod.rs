@@ -0,0 +1,5 @@
+mod query;
+pub use query::get_impersonated_requests_query;
+
+mod view;
+pub use view::GetImpersonatedRequestsQueryView;
@@ -0,0 +1,19 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+use crate::database::impersonations::get_impersonated_requests::GetImpersonatedRequestsQueryView;
+use crate::database::impersonations::ImpersonatedRequest;
+
+pub async fn get_impersonated_requests_query(
+    view: GetImpersonatedRequestsQueryView,
+    pool: PgPool,
+) -> Result<Vec<ImpersonatedRequest>, DatabaseError> {
+    let result: Vec<ImpersonatedRequest> =
+        sqlx::query_as::<_, ImpersonatedRequest>(&view.get_request())
+            .bind(view.get_impersonation_id())
+            .fetch_all(&pool)
+            .await?;
+
+    Ok(result)
+}
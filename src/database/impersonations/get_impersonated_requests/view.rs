@@ -0,0 +1,37 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+use uuid::Uuid;
+
+pub struct GetImpersonatedRequestsQueryView {
+    impersonation_id: Uuid,
+}
+
+impl GetImpersonatedRequestsQueryView {
+    pub fn new(impersonation_id: Uuid) -> Self {
+        Self { impersonation_id }
+    }
+
+    pub fn get_impersonation_id(&self) -> &Uuid {
+        &self.impersonation_id
+    }
+}
+
+impl DatabaseQueryView for GetImpersonatedRequestsQueryView {
+    fn get_request(&self) -> String {
+        "SELECT id, impersonation_id, admin_id, user_id, method, path, created_at
+        FROM impersonated_requests
+        WHERE impersonation_id = $1
+        ORDER BY created_at ASC, id ASC"
+            .to_string()
+    }
+}
+
+impl Display for GetImpersonatedRequestsQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "GetImpersonatedRequestsQueryView: impersonation_id = {}",
+            self.impersonation_id
+        )
+    }
+}
@@ -0,0 +1,7 @@
+pub mod create_impersonation;
+pub mod end_impersonation;
+pub mod get_impersonated_requests;
+pub mod record_impersonated_request;
+
+mod view;
+pub use view::ImpersonatedRequest;
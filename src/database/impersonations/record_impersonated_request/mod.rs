@@ -0,0 +1,5 @@
+mod query;
+pub use query::record_impersonated_request_query;
+
+mod view;
+pub use view::RecordImpersonatedRequestQueryView;
@@ -0,0 +1,22 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+use crate::database::impersonations::record_impersonated_request::RecordImpersonatedRequestQueryView;
+
+/**
+ * Returns false, and records nothing, once the impersonation is ended or expired.
+ */
+pub async fn record_impersonated_request_query(
+    view: RecordImpersonatedRequestQueryView,
+    pool: PgPool,
+) -> Result<bool, DatabaseError> {
+    let result = sqlx::query(&view.get_request())
+        .bind(view.get_impersonation_id())
+        .bind(view.get_method())
+        .bind(view.get_path())
+        .execute(&pool)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
@@ -0,0 +1,51 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+use uuid::Uuid;
+
+pub struct RecordImpersonatedRequestQueryView {
+    impersonation_id: Uuid,
+    method: String,
+    path: String,
+}
+
+impl RecordImpersonatedRequestQueryView {
+    pub fn new(impersonation_id: Uuid, method: &str, path: &str) -> Self {
+        Self {
+            impersonation_id,
+            method: method.to_string(),
+            path: path.to_string(),
+        }
+    }
+
+    pub fn get_impersonation_id(&self) -> &Uuid {
+        &self.impersonation_id
+    }
+
+    pub fn get_method(&self) -> &str {
+        &self.method
+    }
+
+    pub fn get_path(&self) -> &str {
+        &self.path
+    }
+}
+
+impl DatabaseQueryView for RecordImpersonatedRequestQueryView {
+    fn get_request(&self) -> String {
+        // Les identifiants viennent de l'impersonation elle-même, pas des claims du jeton
+        "INSERT INTO impersonated_requests (impersonation_id, admin_id, user_id, method, path) \
+         SELECT id, admin_id, user_id, $2, $3 FROM impersonations \
+         WHERE id = $1 AND ended_at IS NULL AND expires_at > NOW()"
+            .to_string()
+    }
+}
+
+impl Display for RecordImpersonatedRequestQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "RecordImpersonatedRequestQueryView: impersonation_id = {}, method = {}, path = {}",
+            self.impersonation_id, self.method, self.path
+        )
+    }
+}
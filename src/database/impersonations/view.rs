@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize, Eq, PartialEq, Serialize, sqlx::FromRow)]
+pub struct ImpersonatedRequest {
+    id: i32,
+    impersonation_id: Uuid,
+    admin_id: i32,
+    user_id: i32,
+    method: String,
+    path: String,
+    created_at: DateTime<Utc>,
+}
+
+impl ImpersonatedRequest {
+    pub fn id(&self) -> i32 {
+        self.id
+    }
+
+    pub fn impersonation_id(&self) -> &Uuid {
+        &self.impersonation_id
+    }
+
+    pub fn admin_id(&self) -> i32 {
+        self.admin_id
+    }
+
+    pub fn user_id(&self) -> i32 {
+        self.user_id
+    }
+
+    pub fn method(&self) -> &str {
+        &self.method
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+}
@@ -0,0 +1,5 @@
+mod query;
+pub use query::accept_invitation_query;
+
+mod view;
+pub use view::AcceptInvitationQueryView;
@@ -0,0 +1,24 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+use crate::database::invitations::accept_invitation::AcceptInvitationQueryView;
+use crate::database::users::ACTIVE_USER_STATUS;
+
+/**
+ * Consumes the invitation and activates the account with its first password in one
+ * statement, so a link can only be used once. Returns the id of the activated user.
+ */
+pub async fn accept_invitation_query(
+    view: AcceptInvitationQueryView,
+    pool: PgPool,
+) -> Result<Option<u64>, DatabaseError> {
+    let result = sqlx::query_scalar::<_, i32>(&view.get_request())
+        .bind(view.get_token_hash())
+        .bind(view.get_password())
+        .bind(ACTIVE_USER_STATUS)
+        .fetch_optional(&pool)
+        .await?;
+
+    Ok(result.map(|user_id| user_id as u64))
+}
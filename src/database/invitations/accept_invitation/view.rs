@@ -0,0 +1,48 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct AcceptInvitationQueryView {
+    token_hash: String,
+    password: String,
+}
+
+impl AcceptInvitationQueryView {
+    pub fn new(token_hash: &str, password: &str) -> Self {
+        Self {
+            token_hash: token_hash.to_string(),
+            password: password.to_string(),
+        }
+    }
+
+    pub fn get_token_hash(&self) -> &str {
+        &self.token_hash
+    }
+
+    pub fn get_password(&self) -> &str {
+        &self.password
+    }
+}
+
+impl DatabaseQueryView for AcceptInvitationQueryView {
+    fn get_request(&self) -> String {
+        "WITH invitation AS ( \
+            DELETE FROM invitations \
+            WHERE token_hash = $1 AND expires_at > NOW() \
+            RETURNING user_id \
+         ) \
+         UPDATE users SET password = $2, first_connect = false, status = $3 \
+         FROM invitation \
+         WHERE users.id = invitation.user_id \
+         RETURNING users.id"
+            .to_string()
+    }
+}
+
+impl Display for AcceptInvitationQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "AcceptInvitationQueryView: token_hash = [PROTECTED], password = [PROTECTED]"
+        )
+    }
+}
@@ -0,0 +1,5 @@
+mod query;
+pub use query::create_invitation_query;
+
+mod view;
+pub use view::CreateInvitationQueryView;
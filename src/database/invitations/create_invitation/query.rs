@@ -0,0 +1,20 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+use crate::database::invitations::create_invitation::CreateInvitationQueryView;
+
+pub async fn create_invitation_query(
+    view: CreateInvitationQueryView,
+    pool: PgPool,
+) -> Result<(), DatabaseError> {
+    sqlx::query(&view.get_request())
+        .bind(view.get_user_id() as i32)
+        .bind(view.get_token_hash())
+        .bind(view.get_invited_by() as i32)
+        .bind(view.get_ttl_seconds() as f64)
+        .execute(&pool)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct CreateInvitationQueryView {
+    user_id: u64,
+    token_hash: String,
+    invited_by: u64,
+    ttl_seconds: u64,
+}
+
+impl CreateInvitationQueryView {
+    pub fn new(user_id: u64, token_hash: &str, invited_by: u64, ttl_seconds: u64) -> Self {
+        Self {
+            user_id,
+            token_hash: token_hash.to_string(),
+            invited_by,
+            ttl_seconds,
+        }
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+
+    pub fn get_token_hash(&self) -> &str {
+        &self.token_hash
+    }
+
+    pub fn get_invited_by(&self) -> u64 {
+        self.invited_by
+    }
+
+    pub fn get_ttl_seconds(&self) -> u64 {
+        self.ttl_seconds
+    }
+}
+
+impl DatabaseQueryView for CreateInvitationQueryView {
+    fn get_request(&self) -> String {
+        "INSERT INTO invitations (user_id, token_hash, invited_by, expires_at) \
+         VALUES ($1, $2, $3, NOW() + make_interval(secs => $4))"
+            .to_string()
+    }
+}
+
+impl Display for CreateInvitationQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CreateInvitationQueryView: user_id = {}, token_hash = [PROTECTED], invited_by = {}, ttl_seconds = {}",
+            self.user_id, self.invited_by, self.ttl_seconds
+        )
+    }
+}
@@ -0,0 +1,20 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+use crate::database::invitations::delete_invitation::DeleteInvitationQueryView;
+
+/**
+ * Returns false when the user had no pending invitation.
+ */
+pub async fn delete_invitation_query(
+    view: DeleteInvitationQueryView,
+    pool: PgPool,
+) -> Result<bool, DatabaseError> {
+    let result = sqlx::query(&view.get_request())
+        .bind(view.get_user_id() as i32)
+        .execute(&pool)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
@@ -0,0 +1,28 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct DeleteInvitationQueryView {
+    user_id: u64,
+}
+
+impl DeleteInvitationQueryView {
+    pub fn new(user_id: u64) -> Self {
+        Self { user_id }
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+}
+
+impl DatabaseQueryView for DeleteInvitationQueryView {
+    fn get_request(&self) -> String {
+        "DELETE FROM invitations WHERE user_id = $1".to_string()
+    }
+}
+
+impl Display for DeleteInvitationQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DeleteInvitationQueryView: user_id = {}", self.user_id)
+    }
+}
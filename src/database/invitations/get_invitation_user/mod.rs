@@ -0,0 +1,5 @@
+mod query;
+pub use query::get_invitation_user_query;
+
+mod view;
+pub use view::GetInvitationUserQueryView;
@@ -0,0 +1,20 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+use crate::database::invitations::get_invitation_user::GetInvitationUserQueryView;
+
+/**
+ * Returns the invitee of a pending, unexpired invitation without consuming it.
+ */
+pub async fn get_invitation_user_query(
+    view: GetInvitationUserQueryView,
+    pool: PgPool,
+) -> Result<Option<u64>, DatabaseError> {
+    let result = sqlx::query_scalar::<_, i32>(&view.get_request())
+        .bind(view.get_token_hash())
+        .fetch_optional(&pool)
+        .await?;
+
+    Ok(result.map(|user_id| user_id as u64))
+}
@@ -0,0 +1,30 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct GetInvitationUserQueryView {
+    token_hash: String,
+}
+
+impl GetInvitationUserQueryView {
+    pub fn new(token_hash: &str) -> Self {
+        Self {
+            token_hash: token_hash.to_string(),
+        }
+    }
+
+    pub fn get_token_hash(&self) -> &str {
+        &self.token_hash
+    }
+}
+
+impl DatabaseQueryView for GetInvitationUserQueryView {
+    fn get_request(&self) -> String {
+        "SELECT user_id FROM invitations WHERE token_hash = $1 AND expires_at > NOW()".to_string()
+    }
+}
+
+impl Display for GetInvitationUserQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GetInvitationUserQueryView: token_hash = [PROTECTED]")
+    }
+}
@@ -0,0 +1,5 @@
+mod query;
+pub use query::get_invitations_query;
+
+mod view;
+pub use view::GetInvitationsQueryView;
@@ -0,0 +1,16 @@
+use crate::database::invitations::get_invitations::GetInvitationsQueryView;
+use crate::database::invitations::Invitation;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+pub async fn get_invitations_query(
+    view: GetInvitationsQueryView,
+    pool: PgPool,
+) -> Result<Vec<Invitation>, DatabaseError> {
+    let result: Vec<Invitation> = sqlx::query_as::<_, Invitation>(&view.get_request())
+        .fetch_all(&pool)
+        .await?;
+
+    Ok(result)
+}
@@ -0,0 +1,20 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct GetInvitationsQueryView {}
+
+impl DatabaseQueryView for GetInvitationsQueryView {
+    fn get_request(&self) -> String {
+        "SELECT i.user_id, u.email, u.first_name, u.last_name, i.invited_by, i.created_at, i.expires_at
+        FROM invitations i
+        JOIN users u ON u.id = i.user_id
+        ORDER BY i.created_at DESC"
+            .to_string()
+    }
+}
+
+impl Display for GetInvitationsQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GetInvitationsQueryView")
+    }
+}
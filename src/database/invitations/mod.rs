@@ -0,0 +1,9 @@
+pub mod accept_invitation;
+pub mod create_invitation;
+pub mod delete_invitation;
+pub mod get_invitation_user;
+pub mod get_invitations;
+pub mod renew_invitation;
+
+mod view;
+pub use view::Invitation;
@@ -0,0 +1,5 @@
+mod query;
+pub use query::renew_invitation_query;
+
+mod view;
+pub use view::RenewInvitationQueryView;
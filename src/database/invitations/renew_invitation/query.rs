@@ -0,0 +1,23 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+use crate::database::invitations::renew_invitation::RenewInvitationQueryView;
+
+/**
+ * Replaces the token of a pending invitation, which kills the previous link.
+ * Returns the email of the invitee, or None when no invitation is pending.
+ */
+pub async fn renew_invitation_query(
+    view: RenewInvitationQueryView,
+    pool: PgPool,
+) -> Result<Option<String>, DatabaseError> {
+    let result = sqlx::query_scalar::<_, String>(&view.get_request())
+        .bind(view.get_user_id() as i32)
+        .bind(view.get_token_hash())
+        .bind(view.get_ttl_seconds() as f64)
+        .fetch_optional(&pool)
+        .await?;
+
+    Ok(result)
+}
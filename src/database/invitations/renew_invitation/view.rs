@@ -0,0 +1,51 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct RenewInvitationQueryView {
+    user_id: u64,
+    token_hash: String,
+    ttl_seconds: u64,
+}
+
+impl RenewInvitationQueryView {
+    pub fn new(user_id: u64, token_hash: &str, ttl_seconds: u64) -> Self {
+        Self {
+            user_id,
+            token_hash: token_hash.to_string(),
+            ttl_seconds,
+        }
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+
+    pub fn get_token_hash(&self) -> &str {
+        &self.token_hash
+    }
+
+    pub fn get_ttl_seconds(&self) -> u64 {
+        self.ttl_seconds
+    }
+}
+
+impl DatabaseQueryView for RenewInvitationQueryView {
+    fn get_request(&self) -> String {
+        "UPDATE invitations i \
+         SET token_hash = $2, expires_at = NOW() + make_interval(secs => $3), created_at = NOW() \
+         FROM users u \
+         WHERE i.user_id = $1 AND u.id = i.user_id \
+         RETURNING u.email"
+            .to_string()
+    }
+}
+
+impl Display for RenewInvitationQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "RenewInvitationQueryView: user_id = {}, token_hash = [PROTECTED], ttl_seconds = {}",
+            self.user_id, self.ttl_seconds
+        )
+    }
+}
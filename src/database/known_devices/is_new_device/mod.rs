@@ -0,0 +1,5 @@
+mod query;
+pub use query::is_new_device_query;
+
+mod view;
+pub use view::IsNewDeviceQueryView;
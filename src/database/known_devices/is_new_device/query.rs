@@ -0,0 +1,22 @@
+use crate::database::known_devices::is_new_device::IsNewDeviceQueryView;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+/**
+ * True when the user already logged in from other devices, never from this IP and device.
+ * The very first login of an account has nothing to compare to and is never reported.
+ */
+pub async fn is_new_device_query(
+    view: IsNewDeviceQueryView,
+    pool: PgPool,
+) -> Result<bool, DatabaseError> {
+    let result: bool = sqlx::query_scalar(&view.get_request())
+        .bind(view.get_user_id() as i64)
+        .bind(view.get_ip())
+        .bind(view.get_device_info())
+        .fetch_one(&pool)
+        .await?;
+
+    Ok(result)
+}
@@ -0,0 +1,51 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct IsNewDeviceQueryView {
+    user_id: u64,
+    ip_address: std::net::IpAddr,
+    device_info: String,
+}
+
+impl IsNewDeviceQueryView {
+    pub fn new(user_id: u64, ip_address: std::net::IpAddr, device_info: &str) -> Self {
+        Self {
+            user_id,
+            ip_address,
+            device_info: device_info.to_string(),
+        }
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+
+    pub fn get_ip(&self) -> &std::net::IpAddr {
+        &self.ip_address
+    }
+
+    pub fn get_device_info(&self) -> &str {
+        &self.device_info
+    }
+}
+
+impl DatabaseQueryView for IsNewDeviceQueryView {
+    fn get_request(&self) -> String {
+        "SELECT EXISTS (SELECT 1 FROM known_devices WHERE user_id = $1)
+            AND NOT EXISTS (
+                SELECT 1 FROM known_devices
+                WHERE user_id = $1 AND ip_address = $2 AND device_info = $3
+            )"
+        .to_string()
+    }
+}
+
+impl Display for IsNewDeviceQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IsNewDeviceQueryView: user_id = {}, ip = {}, device_info = {}",
+            self.user_id, self.ip_address, self.device_info,
+        )
+    }
+}
@@ -0,0 +1,2 @@
+pub mod is_new_device;
+pub mod record_known_device;
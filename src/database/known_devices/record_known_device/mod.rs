@@ -0,0 +1,5 @@
+mod query;
+pub use query::record_known_device_query;
+
+mod view;
+pub use view::RecordKnownDeviceQueryView;
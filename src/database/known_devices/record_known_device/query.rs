@@ -0,0 +1,18 @@
+use crate::database::known_devices::record_known_device::RecordKnownDeviceQueryView;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+pub async fn record_known_device_query(
+    view: RecordKnownDeviceQueryView,
+    pool: PgPool,
+) -> Result<(), DatabaseError> {
+    sqlx::query(&view.get_request())
+        .bind(view.get_user_id() as i64)
+        .bind(view.get_ip())
+        .bind(view.get_device_info())
+        .execute(&pool)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct RecordKnownDeviceQueryView {
+    user_id: u64,
+    ip_address: std::net::IpAddr,
+    device_info: String,
+}
+
+impl RecordKnownDeviceQueryView {
+    pub fn new(user_id: u64, ip_address: std::net::IpAddr, device_info: &str) -> Self {
+        Self {
+            user_id,
+            ip_address,
+            device_info: device_info.to_string(),
+        }
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+
+    pub fn get_ip(&self) -> &std::net::IpAddr {
+        &self.ip_address
+    }
+
+    pub fn get_device_info(&self) -> &str {
+        &self.device_info
+    }
+}
+
+impl DatabaseQueryView for RecordKnownDeviceQueryView {
+    fn get_request(&self) -> String {
+        "INSERT INTO known_devices (user_id, ip_address, device_info)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (user_id, ip_address, device_info) DO UPDATE SET last_seen_at = NOW()"
+            .to_string()
+    }
+}
+
+impl Display for RecordKnownDeviceQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "RecordKnownDeviceQueryView: user_id = {}, ip = {}, device_info = {}",
+            self.user_id, self.ip_address, self.device_info,
+        )
+    }
+}
@@ -0,0 +1,5 @@
+mod query;
+pub use query::consume_recovery_code_query;
+
+mod view;
+pub use view::ConsumeRecoveryCodeQueryView;
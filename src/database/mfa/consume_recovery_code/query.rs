@@ -0,0 +1,21 @@
+use crate::database::mfa::consume_recovery_code::ConsumeRecoveryCodeQueryView;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+/**
+ * Marks a recovery code as used. Returns false when the code is unknown or already used,
+ * the `used_at IS NULL` guard making concurrent redemptions of the same code impossible.
+ */
+pub async fn consume_recovery_code_query(
+    view: ConsumeRecoveryCodeQueryView,
+    pool: PgPool,
+) -> Result<bool, DatabaseError> {
+    let result = sqlx::query(&view.get_request())
+        .bind(view.get_user_id() as i32)
+        .bind(view.get_code_hash())
+        .execute(&pool)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
@@ -0,0 +1,42 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct ConsumeRecoveryCodeQueryView {
+    user_id: u64,
+    code_hash: String,
+}
+
+impl ConsumeRecoveryCodeQueryView {
+    pub fn new(user_id: u64, code_hash: &str) -> Self {
+        Self {
+            user_id,
+            code_hash: code_hash.to_string(),
+        }
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+
+    pub fn get_code_hash(&self) -> &str {
+        &self.code_hash
+    }
+}
+
+impl DatabaseQueryView for ConsumeRecoveryCodeQueryView {
+    fn get_request(&self) -> String {
+        "UPDATE user_recovery_codes SET used_at = NOW()
+        WHERE user_id = $1 AND code_hash = $2 AND used_at IS NULL"
+            .to_string()
+    }
+}
+
+impl Display for ConsumeRecoveryCodeQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ConsumeRecoveryCodeQueryView: user_id = {}, code_hash = [PROTECTED]",
+            self.user_id
+        )
+    }
+}
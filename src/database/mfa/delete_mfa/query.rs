@@ -0,0 +1,20 @@
+use crate::database::mfa::delete_mfa::DeleteMfaQueryView;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+/**
+ * Removes the TOTP secret and every recovery code of the user.
+ * Returns false when the user had no MFA configured.
+ */
+pub async fn delete_mfa_query(
+    view: DeleteMfaQueryView,
+    pool: PgPool,
+) -> Result<bool, DatabaseError> {
+    let result = sqlx::query(&view.get_request())
+        .bind(view.get_user_id() as i32)
+        .execute(&pool)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
@@ -0,0 +1,30 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct DeleteMfaQueryView {
+    user_id: u64,
+}
+
+impl DeleteMfaQueryView {
+    pub fn new(user_id: u64) -> Self {
+        Self { user_id }
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+}
+
+impl DatabaseQueryView for DeleteMfaQueryView {
+    fn get_request(&self) -> String {
+        "WITH deleted_codes AS (DELETE FROM user_recovery_codes WHERE user_id = $1)
+        DELETE FROM user_mfa WHERE user_id = $1"
+            .to_string()
+    }
+}
+
+impl Display for DeleteMfaQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DeleteMfaQueryView: user_id = {}", self.user_id)
+    }
+}
@@ -0,0 +1,5 @@
+mod query;
+pub use query::enable_mfa_query;
+
+mod view;
+pub use view::EnableMfaQueryView;
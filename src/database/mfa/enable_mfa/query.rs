@@ -0,0 +1,19 @@
+use crate::database::mfa::enable_mfa::EnableMfaQueryView;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+/**
+ * Returns false when there was no pending enrollment to confirm.
+ */
+pub async fn enable_mfa_query(
+    view: EnableMfaQueryView,
+    pool: PgPool,
+) -> Result<bool, DatabaseError> {
+    let result = sqlx::query(&view.get_request())
+        .bind(view.get_user_id() as i32)
+        .execute(&pool)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
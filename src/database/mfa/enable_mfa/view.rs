@@ -0,0 +1,29 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct EnableMfaQueryView {
+    user_id: u64,
+}
+
+impl EnableMfaQueryView {
+    pub fn new(user_id: u64) -> Self {
+        Self { user_id }
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+}
+
+impl DatabaseQueryView for EnableMfaQueryView {
+    fn get_request(&self) -> String {
+        "UPDATE user_mfa SET enabled = true, confirmed_at = NOW() WHERE user_id = $1 AND enabled = false"
+            .to_string()
+    }
+}
+
+impl Display for EnableMfaQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EnableMfaQueryView: user_id = {}", self.user_id)
+    }
+}
@@ -0,0 +1,5 @@
+mod query;
+pub use query::get_user_mfa_query;
+
+mod view;
+pub use view::{GetUserMfaQueryResultView, GetUserMfaQueryView};
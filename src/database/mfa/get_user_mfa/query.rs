@@ -0,0 +1,16 @@
+use crate::database::mfa::get_user_mfa::{GetUserMfaQueryResultView, GetUserMfaQueryView};
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+pub async fn get_user_mfa_query(
+    view: GetUserMfaQueryView,
+    pool: PgPool,
+) -> Result<Option<GetUserMfaQueryResultView>, DatabaseError> {
+    let result = sqlx::query_as::<_, GetUserMfaQueryResultView>(&view.get_request())
+        .bind(view.get_user_id() as i32)
+        .fetch_optional(&pool)
+        .await?;
+
+    Ok(result)
+}
@@ -0,0 +1,51 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct GetUserMfaQueryView {
+    user_id: u64,
+}
+
+impl GetUserMfaQueryView {
+    pub fn new(user_id: u64) -> Self {
+        Self { user_id }
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+}
+
+impl DatabaseQueryView for GetUserMfaQueryView {
+    fn get_request(&self) -> String {
+        "SELECT totp_secret, enabled FROM user_mfa WHERE user_id = $1".to_string()
+    }
+}
+
+impl Display for GetUserMfaQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GetUserMfaQueryView: user_id = {}", self.user_id)
+    }
+}
+
+#[derive(Debug, sqlx::FromRow, PartialEq, Eq)]
+pub struct GetUserMfaQueryResultView {
+    totp_secret: String,
+    enabled: bool,
+}
+
+impl GetUserMfaQueryResultView {
+    pub fn new(totp_secret: &str, enabled: bool) -> Self {
+        Self {
+            totp_secret: totp_secret.to_string(),
+            enabled,
+        }
+    }
+
+    pub fn totp_secret(&self) -> &str {
+        &self.totp_secret
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+}
@@ -0,0 +1,6 @@
+pub mod consume_recovery_code;
+pub mod delete_mfa;
+pub mod enable_mfa;
+pub mod get_user_mfa;
+pub mod replace_recovery_codes;
+pub mod set_mfa_secret;
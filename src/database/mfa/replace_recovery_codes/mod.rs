@@ -0,0 +1,5 @@
+mod query;
+pub use query::replace_recovery_codes_query;
+
+mod view;
+pub use view::ReplaceRecoveryCodesQueryView;
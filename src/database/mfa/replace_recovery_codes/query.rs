@@ -0,0 +1,17 @@
+use crate::database::mfa::replace_recovery_codes::ReplaceRecoveryCodesQueryView;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+pub async fn replace_recovery_codes_query(
+    view: ReplaceRecoveryCodesQueryView,
+    pool: PgPool,
+) -> Result<(), DatabaseError> {
+    sqlx::query(&view.get_request())
+        .bind(view.get_user_id() as i32)
+        .bind(view.get_code_hashes())
+        .execute(&pool)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct ReplaceRecoveryCodesQueryView {
+    user_id: u64,
+    code_hashes: Vec<String>,
+}
+
+impl ReplaceRecoveryCodesQueryView {
+    pub fn new(user_id: u64, code_hashes: Vec<String>) -> Self {
+        Self {
+            user_id,
+            code_hashes,
+        }
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+
+    pub fn get_code_hashes(&self) -> &Vec<String> {
+        &self.code_hashes
+    }
+}
+
+impl DatabaseQueryView for ReplaceRecoveryCodesQueryView {
+    fn get_request(&self) -> String {
+        "WITH deleted_codes AS (DELETE FROM user_recovery_codes WHERE user_id = $1)
+        INSERT INTO user_recovery_codes (user_id, code_hash) SELECT $1, UNNEST($2::text[])"
+            .to_string()
+    }
+}
+
+impl Display for ReplaceRecoveryCodesQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ReplaceRecoveryCodesQueryView: user_id = {}, code_hashes = [PROTECTED]",
+            self.user_id
+        )
+    }
+}
@@ -0,0 +1,5 @@
+mod query;
+pub use query::set_mfa_secret_query;
+
+mod view;
+pub use view::SetMfaSecretQueryView;
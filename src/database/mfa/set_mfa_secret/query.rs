@@ -0,0 +1,20 @@
+use crate::database::mfa::set_mfa_secret::SetMfaSecretQueryView;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+/**
+ * Returns false when MFA is already enabled: a confirmed secret is never overwritten.
+ */
+pub async fn set_mfa_secret_query(
+    view: SetMfaSecretQueryView,
+    pool: PgPool,
+) -> Result<bool, DatabaseError> {
+    let result = sqlx::query(&view.get_request())
+        .bind(view.get_user_id() as i32)
+        .bind(view.get_totp_secret())
+        .execute(&pool)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
@@ -0,0 +1,43 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct SetMfaSecretQueryView {
+    user_id: u64,
+    totp_secret: String,
+}
+
+impl SetMfaSecretQueryView {
+    pub fn new(user_id: u64, totp_secret: &str) -> Self {
+        Self {
+            user_id,
+            totp_secret: totp_secret.to_string(),
+        }
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+
+    pub fn get_totp_secret(&self) -> &str {
+        &self.totp_secret
+    }
+}
+
+impl DatabaseQueryView for SetMfaSecretQueryView {
+    fn get_request(&self) -> String {
+        "INSERT INTO user_mfa (user_id, totp_secret, enabled) VALUES ($1, $2, false)
+        ON CONFLICT (user_id) DO UPDATE SET totp_secret = EXCLUDED.totp_secret, created_at = NOW()
+        WHERE user_mfa.enabled = false"
+            .to_string()
+    }
+}
+
+impl Display for SetMfaSecretQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SetMfaSecretQueryView: user_id = {}, totp_secret = [PROTECTED]",
+            self.user_id
+        )
+    }
+}
@@ -1,9 +1,21 @@
 pub mod admin;
 pub mod auth;
+pub mod email_changes;
+pub mod federated_identities;
 pub mod get_user_id;
 pub mod groups;
+pub mod impersonations;
+pub mod invitations;
+pub mod known_devices;
+pub mod mfa;
+pub mod oauth_clients;
+pub mod oidc;
+pub mod personal_access_tokens;
 pub mod ressources;
 pub mod rights;
 pub mod roles;
+pub mod security_events;
+pub mod session_policies;
 pub mod sessions;
 pub mod users;
+pub mod webauthn;
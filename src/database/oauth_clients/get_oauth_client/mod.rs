@@ -0,0 +1,5 @@
+mod query;
+pub use query::get_oauth_client_query;
+
+mod view;
+pub use view::{GetOAuthClientQueryResultView, GetOAuthClientQueryView};
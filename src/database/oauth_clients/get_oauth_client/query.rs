@@ -0,0 +1,18 @@
+use crate::database::oauth_clients::get_oauth_client::{
+    GetOAuthClientQueryResultView, GetOAuthClientQueryView,
+};
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+pub async fn get_oauth_client_query(
+    view: GetOAuthClientQueryView,
+    pool: PgPool,
+) -> Result<Option<GetOAuthClientQueryResultView>, DatabaseError> {
+    let result = sqlx::query_as::<_, GetOAuthClientQueryResultView>(&view.get_request())
+        .bind(view.get_client_id())
+        .fetch_optional(&pool)
+        .await?;
+
+    Ok(result)
+}
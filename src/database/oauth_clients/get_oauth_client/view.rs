@@ -0,0 +1,82 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct GetOAuthClientQueryView {
+    client_id: String,
+}
+
+impl GetOAuthClientQueryView {
+    pub fn new(client_id: &str) -> Self {
+        Self {
+            client_id: client_id.to_string(),
+        }
+    }
+
+    pub fn get_client_id(&self) -> &str {
+        &self.client_id
+    }
+}
+
+impl DatabaseQueryView for GetOAuthClientQueryView {
+    fn get_request(&self) -> String {
+        "SELECT client_id, client_secret_hash, name, module_id, scopes FROM oauth_clients
+        WHERE client_id = $1 AND revoked_at IS NULL"
+            .to_string()
+    }
+}
+
+impl Display for GetOAuthClientQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GetOAuthClientQueryView: client_id = {}", self.client_id)
+    }
+}
+
+#[derive(Debug, sqlx::FromRow, PartialEq, Eq)]
+pub struct GetOAuthClientQueryResultView {
+    client_id: String,
+    client_secret_hash: String,
+    name: String,
+    module_id: Option<i32>,
+    scopes: Vec<String>,
+}
+
+impl GetOAuthClientQueryResultView {
+    pub fn new(
+        client_id: &str,
+        client_secret_hash: &str,
+        name: &str,
+        module_id: Option<i32>,
+        scopes: Vec<String>,
+    ) -> Self {
+        Self {
+            client_id: client_id.to_string(),
+            client_secret_hash: client_secret_hash.to_string(),
+            name: name.to_string(),
+            module_id,
+            scopes,
+        }
+    }
+
+    pub fn get_client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    pub fn get_client_secret_hash(&self) -> &str {
+        &self.client_secret_hash
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /**
+     * Module the client acts for. Clients without one can only introspect and revoke.
+     */
+    pub fn get_module_id(&self) -> Option<i32> {
+        self.module_id
+    }
+
+    pub fn get_scopes(&self) -> &[String] {
+        &self.scopes
+    }
+}
@@ -0,0 +1,17 @@
+use crate::database::oidc::consume_authorization_code::ConsumeAuthorizationCodeQueryView;
+use crate::database::oidc::AuthorizationCode;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+pub async fn consume_authorization_code_query(
+    view: ConsumeAuthorizationCodeQueryView,
+    pool: PgPool,
+) -> Result<Option<AuthorizationCode>, DatabaseError> {
+    let result = sqlx::query_as::<_, AuthorizationCode>(&view.get_request())
+        .bind(view.get_code_hash())
+        .fetch_optional(&pool)
+        .await?;
+
+    Ok(result)
+}
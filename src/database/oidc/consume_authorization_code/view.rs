@@ -0,0 +1,36 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct ConsumeAuthorizationCodeQueryView {
+    code_hash: String,
+}
+
+impl ConsumeAuthorizationCodeQueryView {
+    pub fn new(code_hash: &str) -> Self {
+        Self {
+            code_hash: code_hash.to_string(),
+        }
+    }
+
+    pub fn get_code_hash(&self) -> &str {
+        &self.code_hash
+    }
+}
+
+impl DatabaseQueryView for ConsumeAuthorizationCodeQueryView {
+    fn get_request(&self) -> String {
+        // Supprimé même expiré ou mal utilisé : un code ne sert qu'une fois
+        "DELETE FROM oidc_authorization_codes WHERE code_hash = $1
+        RETURNING client_id, user_id, session_id, redirect_uri, scopes, nonce, code_challenge, expires_at"
+            .to_string()
+    }
+}
+
+impl Display for ConsumeAuthorizationCodeQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ConsumeAuthorizationCodeQueryView: code_hash = [PROTECTED]"
+        )
+    }
+}
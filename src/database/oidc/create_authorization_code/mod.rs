@@ -0,0 +1,5 @@
+mod query;
+pub use query::create_authorization_code_query;
+
+mod view;
+pub use view::CreateAuthorizationCodeQueryView;
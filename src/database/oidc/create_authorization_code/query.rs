@@ -0,0 +1,24 @@
+use crate::database::oidc::create_authorization_code::CreateAuthorizationCodeQueryView;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+pub async fn create_authorization_code_query(
+    view: CreateAuthorizationCodeQueryView,
+    pool: PgPool,
+) -> Result<(), DatabaseError> {
+    sqlx::query(&view.get_request())
+        .bind(view.get_code_hash())
+        .bind(view.get_client_id())
+        .bind(view.get_user_id() as i32)
+        .bind(view.get_session_id())
+        .bind(view.get_redirect_uri())
+        .bind(view.get_scopes())
+        .bind(view.get_nonce())
+        .bind(view.get_code_challenge())
+        .bind(view.get_ttl() as f64)
+        .execute(&pool)
+        .await?;
+
+    Ok(())
+}
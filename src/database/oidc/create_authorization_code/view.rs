@@ -0,0 +1,97 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+use uuid::Uuid;
+
+pub struct CreateAuthorizationCodeQueryView {
+    code_hash: String,
+    client_id: String,
+    user_id: u64,
+    session_id: Option<Uuid>,
+    redirect_uri: String,
+    scopes: Vec<String>,
+    nonce: Option<String>,
+    code_challenge: String,
+    ttl: u64,
+}
+
+impl CreateAuthorizationCodeQueryView {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        code_hash: &str,
+        client_id: &str,
+        user_id: u64,
+        session_id: Option<Uuid>,
+        redirect_uri: &str,
+        scopes: Vec<String>,
+        nonce: Option<&str>,
+        code_challenge: &str,
+        ttl: u64,
+    ) -> Self {
+        Self {
+            code_hash: code_hash.to_string(),
+            client_id: client_id.to_string(),
+            user_id,
+            session_id,
+            redirect_uri: redirect_uri.to_string(),
+            scopes,
+            nonce: nonce.map(str::to_string),
+            code_challenge: code_challenge.to_string(),
+            ttl,
+        }
+    }
+
+    pub fn get_code_hash(&self) -> &str {
+        &self.code_hash
+    }
+
+    pub fn get_client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+
+    pub fn get_session_id(&self) -> Option<Uuid> {
+        self.session_id
+    }
+
+    pub fn get_redirect_uri(&self) -> &str {
+        &self.redirect_uri
+    }
+
+    pub fn get_scopes(&self) -> &Vec<String> {
+        &self.scopes
+    }
+
+    pub fn get_nonce(&self) -> Option<&str> {
+        self.nonce.as_deref()
+    }
+
+    pub fn get_code_challenge(&self) -> &str {
+        &self.code_challenge
+    }
+
+    pub fn get_ttl(&self) -> u64 {
+        self.ttl
+    }
+}
+
+impl DatabaseQueryView for CreateAuthorizationCodeQueryView {
+    fn get_request(&self) -> String {
+        "INSERT INTO oidc_authorization_codes
+        (code_hash, client_id, user_id, session_id, redirect_uri, scopes, nonce, code_challenge, expires_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, NOW() + make_interval(secs => $9))"
+            .to_string()
+    }
+}
+
+impl Display for CreateAuthorizationCodeQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CreateAuthorizationCodeQueryView: code_hash = [PROTECTED], client_id = {}, user_id = {}, redirect_uri = {}, scopes = {:?}",
+            self.client_id, self.user_id, self.redirect_uri, self.scopes
+        )
+    }
+}
@@ -0,0 +1,5 @@
+mod query;
+pub use query::get_oidc_client_query;
+
+mod view;
+pub use view::GetOidcClientQueryView;
@@ -0,0 +1,17 @@
+use crate::database::oidc::get_oidc_client::GetOidcClientQueryView;
+use crate::database::oidc::OidcClient;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+pub async fn get_oidc_client_query(
+    view: GetOidcClientQueryView,
+    pool: PgPool,
+) -> Result<Option<OidcClient>, DatabaseError> {
+    let result = sqlx::query_as::<_, OidcClient>(&view.get_request())
+        .bind(view.get_client_id())
+        .fetch_optional(&pool)
+        .await?;
+
+    Ok(result)
+}
@@ -0,0 +1,32 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct GetOidcClientQueryView {
+    client_id: String,
+}
+
+impl GetOidcClientQueryView {
+    pub fn new(client_id: &str) -> Self {
+        Self {
+            client_id: client_id.to_string(),
+        }
+    }
+
+    pub fn get_client_id(&self) -> &str {
+        &self.client_id
+    }
+}
+
+impl DatabaseQueryView for GetOidcClientQueryView {
+    fn get_request(&self) -> String {
+        "SELECT client_id, name, client_secret_hash, redirect_uris, first_party FROM oidc_clients
+        WHERE client_id = $1 AND revoked_at IS NULL"
+            .to_string()
+    }
+}
+
+impl Display for GetOidcClientQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GetOidcClientQueryView: client_id = {}", self.client_id)
+    }
+}
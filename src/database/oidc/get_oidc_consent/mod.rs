@@ -0,0 +1,5 @@
+mod query;
+pub use query::get_oidc_consent_query;
+
+mod view;
+pub use view::GetOidcConsentQueryView;
@@ -0,0 +1,20 @@
+use crate::database::oidc::get_oidc_consent::GetOidcConsentQueryView;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+/**
+ * Scopes the user already granted to the client, empty when it never consented.
+ */
+pub async fn get_oidc_consent_query(
+    view: GetOidcConsentQueryView,
+    pool: PgPool,
+) -> Result<Vec<String>, DatabaseError> {
+    let result: Option<Vec<String>> = sqlx::query_scalar(&view.get_request())
+        .bind(view.get_user_id() as i32)
+        .bind(view.get_client_id())
+        .fetch_optional(&pool)
+        .await?;
+
+    Ok(result.unwrap_or_default())
+}
@@ -0,0 +1,40 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct GetOidcConsentQueryView {
+    user_id: u64,
+    client_id: String,
+}
+
+impl GetOidcConsentQueryView {
+    pub fn new(user_id: u64, client_id: &str) -> Self {
+        Self {
+            user_id,
+            client_id: client_id.to_string(),
+        }
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+
+    pub fn get_client_id(&self) -> &str {
+        &self.client_id
+    }
+}
+
+impl DatabaseQueryView for GetOidcConsentQueryView {
+    fn get_request(&self) -> String {
+        "SELECT scopes FROM oidc_consents WHERE user_id = $1 AND client_id = $2".to_string()
+    }
+}
+
+impl Display for GetOidcConsentQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "GetOidcConsentQueryView: user_id = {}, client_id = {}",
+            self.user_id, self.client_id
+        )
+    }
+}
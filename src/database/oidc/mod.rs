@@ -0,0 +1,8 @@
+pub mod consume_authorization_code;
+pub mod create_authorization_code;
+pub mod get_oidc_client;
+pub mod get_oidc_consent;
+pub mod save_oidc_consent;
+
+mod view;
+pub use view::{AuthorizationCode, OidcClient};
@@ -0,0 +1,5 @@
+mod query;
+pub use query::save_oidc_consent_query;
+
+mod view;
+pub use view::SaveOidcConsentQueryView;
@@ -0,0 +1,18 @@
+use crate::database::oidc::save_oidc_consent::SaveOidcConsentQueryView;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+pub async fn save_oidc_consent_query(
+    view: SaveOidcConsentQueryView,
+    pool: PgPool,
+) -> Result<(), DatabaseError> {
+    sqlx::query(&view.get_request())
+        .bind(view.get_user_id() as i32)
+        .bind(view.get_client_id())
+        .bind(view.get_scopes())
+        .execute(&pool)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct SaveOidcConsentQueryView {
+    user_id: u64,
+    client_id: String,
+    scopes: Vec<String>,
+}
+
+impl SaveOidcConsentQueryView {
+    pub fn new(user_id: u64, client_id: &str, scopes: Vec<String>) -> Self {
+        Self {
+            user_id,
+            client_id: client_id.to_string(),
+            scopes,
+        }
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+
+    pub fn get_client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    pub fn get_scopes(&self) -> &Vec<String> {
+        &self.scopes
+    }
+}
+
+impl DatabaseQueryView for SaveOidcConsentQueryView {
+    fn get_request(&self) -> String {
+        // Les scopes déjà accordés restent acquis
+        "INSERT INTO oidc_consents (user_id, client_id, scopes) VALUES ($1, $2, $3)
+        ON CONFLICT (user_id, client_id) DO UPDATE
+        SET scopes = ARRAY(SELECT DISTINCT unnest(oidc_consents.scopes || EXCLUDED.scopes)),
+            granted_at = now()"
+            .to_string()
+    }
+}
+
+impl Display for SaveOidcConsentQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SaveOidcConsentQueryView: user_id = {}, client_id = {}, scopes = {:?}",
+            self.user_id, self.client_id, self.scopes
+        )
+    }
+}
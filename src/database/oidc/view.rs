@@ -0,0 +1,89 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/**
+ * A front end registered for single sign-on. Public clients (single page apps) have no
+ * secret and rely on PKCE alone.
+ */
+#[derive(Debug, Eq, PartialEq, sqlx::FromRow)]
+pub struct OidcClient {
+    client_id: String,
+    name: String,
+    client_secret_hash: Option<String>,
+    redirect_uris: Vec<String>,
+    first_party: bool,
+}
+
+impl OidcClient {
+    pub fn client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn client_secret_hash(&self) -> Option<&str> {
+        self.client_secret_hash.as_deref()
+    }
+
+    pub fn redirect_uris(&self) -> &[String] {
+        &self.redirect_uris
+    }
+
+    /**
+     * mairie360's own front ends: users are not asked to consent to them.
+     */
+    pub fn is_first_party(&self) -> bool {
+        self.first_party
+    }
+}
+
+/**
+ * A consumed authorization code; the code itself is never stored.
+ */
+#[derive(Debug, Eq, PartialEq, sqlx::FromRow)]
+pub struct AuthorizationCode {
+    client_id: String,
+    user_id: i32,
+    session_id: Option<Uuid>,
+    redirect_uri: String,
+    scopes: Vec<String>,
+    nonce: Option<String>,
+    code_challenge: String,
+    expires_at: DateTime<Utc>,
+}
+
+impl AuthorizationCode {
+    pub fn client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    pub fn user_id(&self) -> i32 {
+        self.user_id
+    }
+
+    pub fn session_id(&self) -> Option<&Uuid> {
+        self.session_id.as_ref()
+    }
+
+    pub fn redirect_uri(&self) -> &str {
+        &self.redirect_uri
+    }
+
+    pub fn scopes(&self) -> &[String] {
+        &self.scopes
+    }
+
+    pub fn nonce(&self) -> Option<&str> {
+        self.nonce.as_deref()
+    }
+
+    pub fn code_challenge(&self) -> &str {
+        &self.code_challenge
+    }
+
+    pub fn expires_at(&self) -> &DateTime<Utc> {
+        &self.expires_at
+    }
+}
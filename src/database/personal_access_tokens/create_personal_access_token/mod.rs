@@ -0,0 +1,5 @@
+mod query;
+pub use query::create_personal_access_token_query;
+
+mod view;
+pub use view::CreatePersonalAccessTokenQueryView;
@@ -0,0 +1,22 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+use crate::database::personal_access_tokens::create_personal_access_token::CreatePersonalAccessTokenQueryView;
+use crate::database::personal_access_tokens::StoredPersonalAccessToken;
+
+pub async fn create_personal_access_token_query(
+    view: CreatePersonalAccessTokenQueryView,
+    pool: PgPool,
+) -> Result<StoredPersonalAccessToken, DatabaseError> {
+    let result = sqlx::query_as::<_, StoredPersonalAccessToken>(&view.get_request())
+        .bind(view.get_user_id() as i32)
+        .bind(view.get_name())
+        .bind(view.get_token_hash())
+        .bind(view.get_scopes())
+        .bind(view.get_expires_in_days().map(|days| days as i32))
+        .fetch_one(&pool)
+        .await?;
+
+    Ok(result)
+}
@@ -0,0 +1,68 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct CreatePersonalAccessTokenQueryView {
+    user_id: u64,
+    name: String,
+    token_hash: String,
+    scopes: Vec<String>,
+    expires_in_days: Option<u32>,
+}
+
+impl CreatePersonalAccessTokenQueryView {
+    pub fn new(
+        user_id: u64,
+        name: &str,
+        token_hash: &str,
+        scopes: Vec<String>,
+        expires_in_days: Option<u32>,
+    ) -> Self {
+        Self {
+            user_id,
+            name: name.to_string(),
+            token_hash: token_hash.to_string(),
+            scopes,
+            expires_in_days,
+        }
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_token_hash(&self) -> &str {
+        &self.token_hash
+    }
+
+    pub fn get_scopes(&self) -> &Vec<String> {
+        &self.scopes
+    }
+
+    pub fn get_expires_in_days(&self) -> Option<u32> {
+        self.expires_in_days
+    }
+}
+
+impl DatabaseQueryView for CreatePersonalAccessTokenQueryView {
+    fn get_request(&self) -> String {
+        // make_interval est strict : sans durée, expires_at reste NULL
+        "INSERT INTO personal_access_tokens (user_id, name, token_hash, scopes, expires_at) \
+         VALUES ($1, $2, $3, $4, NOW() + make_interval(days => $5)) \
+         RETURNING id, name, scopes, created_at, expires_at, last_used_at"
+            .to_string()
+    }
+}
+
+impl Display for CreatePersonalAccessTokenQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CreatePersonalAccessTokenQueryView: user_id = {}, name = {}, token_hash = [PROTECTED], scopes = {:?}, expires_in_days = {:?}",
+            self.user_id, self.name, self.scopes, self.expires_in_days
+        )
+    }
+}
@@ -0,0 +1,5 @@
+mod query;
+pub use query::get_personal_access_tokens_by_user_query;
+
+mod view;
+pub use view::GetPersonalAccessTokensByUserQueryView;
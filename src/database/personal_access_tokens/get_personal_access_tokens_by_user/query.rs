@@ -0,0 +1,18 @@
+use crate::database::personal_access_tokens::get_personal_access_tokens_by_user::GetPersonalAccessTokensByUserQueryView;
+use crate::database::personal_access_tokens::StoredPersonalAccessToken;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+pub async fn get_personal_access_tokens_by_user_query(
+    view: GetPersonalAccessTokensByUserQueryView,
+    pool: PgPool,
+) -> Result<Vec<StoredPersonalAccessToken>, DatabaseError> {
+    let result: Vec<StoredPersonalAccessToken> =
+        sqlx::query_as::<_, StoredPersonalAccessToken>(&view.get_request())
+            .bind(view.get_user_id() as i32)
+            .fetch_all(&pool)
+            .await?;
+
+    Ok(result)
+}
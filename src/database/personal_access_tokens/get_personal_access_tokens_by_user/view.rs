@@ -0,0 +1,36 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct GetPersonalAccessTokensByUserQueryView {
+    user_id: u64,
+}
+
+impl GetPersonalAccessTokensByUserQueryView {
+    pub fn new(user_id: u64) -> Self {
+        Self { user_id }
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+}
+
+impl DatabaseQueryView for GetPersonalAccessTokensByUserQueryView {
+    fn get_request(&self) -> String {
+        "SELECT id, name, scopes, created_at, expires_at, last_used_at
+        FROM personal_access_tokens
+        WHERE user_id = $1 AND revoked_at IS NULL
+        ORDER BY created_at DESC"
+            .to_string()
+    }
+}
+
+impl Display for GetPersonalAccessTokensByUserQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "GetPersonalAccessTokensByUserQueryView: user_id = {}",
+            self.user_id
+        )
+    }
+}
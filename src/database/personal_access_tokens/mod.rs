@@ -0,0 +1,7 @@
+pub mod create_personal_access_token;
+pub mod get_personal_access_tokens_by_user;
+pub mod revoke_personal_access_token;
+pub mod use_personal_access_token;
+
+mod view;
+pub use view::StoredPersonalAccessToken;
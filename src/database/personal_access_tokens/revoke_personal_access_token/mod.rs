@@ -0,0 +1,5 @@
+mod query;
+pub use query::revoke_personal_access_token_query;
+
+mod view;
+pub use view::RevokePersonalAccessTokenQueryView;
@@ -0,0 +1,21 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+use crate::database::personal_access_tokens::revoke_personal_access_token::RevokePersonalAccessTokenQueryView;
+
+/**
+ * Returns false when the token does not exist, belongs to someone else or is already revoked.
+ */
+pub async fn revoke_personal_access_token_query(
+    view: RevokePersonalAccessTokenQueryView,
+    pool: PgPool,
+) -> Result<bool, DatabaseError> {
+    let result = sqlx::query(&view.get_request())
+        .bind(view.get_token_id() as i32)
+        .bind(view.get_user_id() as i32)
+        .execute(&pool)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
@@ -0,0 +1,39 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct RevokePersonalAccessTokenQueryView {
+    user_id: u64,
+    token_id: u64,
+}
+
+impl RevokePersonalAccessTokenQueryView {
+    pub fn new(user_id: u64, token_id: u64) -> Self {
+        Self { user_id, token_id }
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+
+    pub fn get_token_id(&self) -> u64 {
+        self.token_id
+    }
+}
+
+impl DatabaseQueryView for RevokePersonalAccessTokenQueryView {
+    fn get_request(&self) -> String {
+        "UPDATE personal_access_tokens SET revoked_at = NOW() \
+         WHERE id = $1 AND user_id = $2 AND revoked_at IS NULL"
+            .to_string()
+    }
+}
+
+impl Display for RevokePersonalAccessTokenQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "RevokePersonalAccessTokenQueryView: user_id = {}, token_id = {}",
+            self.user_id, self.token_id
+        )
+    }
+}
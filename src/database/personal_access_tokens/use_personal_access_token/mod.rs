@@ -0,0 +1,5 @@
+mod query;
+pub use query::use_personal_access_token_query;
+
+mod view;
+pub use view::UsePersonalAccessTokenQueryView;
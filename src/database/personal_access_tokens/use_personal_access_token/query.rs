@@ -0,0 +1,28 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+use crate::database::personal_access_tokens::use_personal_access_token::UsePersonalAccessTokenQueryView;
+use crate::security::personal_access_token::{PersonalAccessToken, PersonalAccessTokenScope};
+
+/**
+ * Looks up a live token and stamps `last_used_at` in the same statement.
+ * Returns None for unknown, expired or revoked tokens and for archived owners.
+ */
+pub async fn use_personal_access_token_query(
+    view: UsePersonalAccessTokenQueryView,
+    pool: PgPool,
+) -> Result<Option<PersonalAccessToken>, DatabaseError> {
+    let result = sqlx::query_as::<_, (i32, i32, Vec<String>)>(&view.get_request())
+        .bind(view.get_token_hash())
+        .fetch_optional(&pool)
+        .await?;
+
+    Ok(result.map(|(id, user_id, scopes)| {
+        PersonalAccessToken::new(
+            id,
+            user_id as u64,
+            PersonalAccessTokenScope::parse_all(&scopes),
+        )
+    }))
+}
@@ -0,0 +1,40 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct UsePersonalAccessTokenQueryView {
+    token_hash: String,
+}
+
+impl UsePersonalAccessTokenQueryView {
+    pub fn new(token_hash: &str) -> Self {
+        Self {
+            token_hash: token_hash.to_string(),
+        }
+    }
+
+    pub fn get_token_hash(&self) -> &str {
+        &self.token_hash
+    }
+}
+
+impl DatabaseQueryView for UsePersonalAccessTokenQueryView {
+    fn get_request(&self) -> String {
+        "UPDATE personal_access_tokens t SET last_used_at = NOW() \
+         FROM users u \
+         WHERE t.token_hash = $1 \
+         AND t.revoked_at IS NULL \
+         AND (t.expires_at IS NULL OR t.expires_at > NOW()) \
+         AND u.id = t.user_id AND NOT u.is_archived \
+         RETURNING t.id, t.user_id, t.scopes"
+            .to_string()
+    }
+}
+
+impl Display for UsePersonalAccessTokenQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "UsePersonalAccessTokenQueryView: token_hash = [PROTECTED]"
+        )
+    }
+}
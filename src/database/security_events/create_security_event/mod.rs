@@ -0,0 +1,5 @@
+mod query;
+pub use query::create_security_event_query;
+
+mod view;
+pub use view::CreateSecurityEventQueryView;
@@ -0,0 +1,19 @@
+use crate::database::security_events::create_security_event::CreateSecurityEventQueryView;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+pub async fn create_security_event_query(
+    view: CreateSecurityEventQueryView,
+    pool: PgPool,
+) -> Result<(), DatabaseError> {
+    sqlx::query(&view.get_request())
+        .bind(view.get_user_id().map(|user_id| user_id as i32))
+        .bind(view.get_event_type())
+        .bind(view.get_ip_address())
+        .bind(view.get_details())
+        .execute(&pool)
+        .await?;
+
+    Ok(())
+}
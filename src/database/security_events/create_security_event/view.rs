@@ -0,0 +1,59 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct CreateSecurityEventQueryView {
+    user_id: Option<u64>,
+    event_type: String,
+    ip_address: Option<std::net::IpAddr>,
+    details: Option<String>,
+}
+
+impl CreateSecurityEventQueryView {
+    pub fn new(
+        user_id: Option<u64>,
+        event_type: &str,
+        ip_address: Option<std::net::IpAddr>,
+        details: Option<String>,
+    ) -> Self {
+        Self {
+            user_id,
+            event_type: event_type.to_string(),
+            ip_address,
+            details,
+        }
+    }
+
+    pub fn get_user_id(&self) -> Option<u64> {
+        self.user_id
+    }
+
+    pub fn get_event_type(&self) -> &str {
+        &self.event_type
+    }
+
+    pub fn get_ip_address(&self) -> Option<std::net::IpAddr> {
+        self.ip_address
+    }
+
+    pub fn get_details(&self) -> Option<&str> {
+        self.details.as_deref()
+    }
+}
+
+impl DatabaseQueryView for CreateSecurityEventQueryView {
+    fn get_request(&self) -> String {
+        "INSERT INTO security_events (user_id, event_type, ip_address, details)
+        VALUES ($1, $2, $3, $4)"
+            .to_string()
+    }
+}
+
+impl Display for CreateSecurityEventQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CreateSecurityEventQueryView: user_id = {:?}, event_type = {}",
+            self.user_id, self.event_type
+        )
+    }
+}
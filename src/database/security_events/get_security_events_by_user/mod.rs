@@ -0,0 +1,5 @@
+mod query;
+pub use query::get_security_events_by_user_query;
+
+pub mod view;
+pub use view::GetSecurityEventsByUserQueryView;
@@ -0,0 +1,17 @@
+use crate::database::security_events::get_security_events_by_user::GetSecurityEventsByUserQueryView;
+use crate::database::security_events::SecurityEvent;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+pub async fn get_security_events_by_user_query(
+    view: GetSecurityEventsByUserQueryView,
+    pool: PgPool,
+) -> Result<Vec<SecurityEvent>, DatabaseError> {
+    let result: Vec<SecurityEvent> = sqlx::query_as::<_, SecurityEvent>(&view.get_request())
+        .bind(view.get_user_id() as i64)
+        .fetch_all(&pool)
+        .await?;
+
+    Ok(result)
+}
@@ -0,0 +1,36 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct GetSecurityEventsByUserQueryView {
+    user_id: u64,
+}
+
+impl GetSecurityEventsByUserQueryView {
+    pub fn new(user_id: u64) -> Self {
+        Self { user_id }
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+}
+
+impl DatabaseQueryView for GetSecurityEventsByUserQueryView {
+    fn get_request(&self) -> String {
+        "SELECT id, user_id, event_type, ip_address, details, created_at
+        FROM security_events
+        WHERE user_id = $1
+        ORDER BY created_at DESC"
+            .to_string()
+    }
+}
+
+impl Display for GetSecurityEventsByUserQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "GetSecurityEventsByUserQueryView: user_id = {}",
+            self.user_id
+        )
+    }
+}
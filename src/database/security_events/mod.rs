@@ -0,0 +1,5 @@
+pub mod create_security_event;
+pub mod get_security_events_by_user;
+
+mod view;
+pub use view::SecurityEvent;
@@ -0,0 +1,38 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Eq, PartialEq, Serialize, sqlx::FromRow)]
+pub struct SecurityEvent {
+    id: i32,
+    user_id: Option<i32>,
+    event_type: String,
+    ip_address: Option<std::net::IpAddr>,
+    details: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+impl SecurityEvent {
+    pub fn id(&self) -> i32 {
+        self.id
+    }
+
+    pub fn user_id(&self) -> Option<i32> {
+        self.user_id
+    }
+
+    pub fn event_type(&self) -> &str {
+        &self.event_type
+    }
+
+    pub fn ip_address(&self) -> Option<&std::net::IpAddr> {
+        self.ip_address.as_ref()
+    }
+
+    pub fn details(&self) -> Option<&str> {
+        self.details.as_deref()
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+}
@@ -0,0 +1,5 @@
+mod query;
+pub use query::delete_role_session_policy_query;
+
+pub mod view;
+pub use view::DeleteRoleSessionPolicyQueryView;
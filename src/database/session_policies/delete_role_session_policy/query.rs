@@ -0,0 +1,19 @@
+use crate::database::session_policies::delete_role_session_policy::DeleteRoleSessionPolicyQueryView;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+/**
+ * Returns false when the role had no policy.
+ */
+pub async fn delete_role_session_policy_query(
+    view: DeleteRoleSessionPolicyQueryView,
+    pool: PgPool,
+) -> Result<bool, DatabaseError> {
+    let result = sqlx::query(&view.get_request())
+        .bind(view.get_role_id() as i32)
+        .execute(&pool)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
@@ -0,0 +1,32 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct DeleteRoleSessionPolicyQueryView {
+    role_id: u64,
+}
+
+impl DeleteRoleSessionPolicyQueryView {
+    pub fn new(role_id: u64) -> Self {
+        Self { role_id }
+    }
+
+    pub fn get_role_id(&self) -> u64 {
+        self.role_id
+    }
+}
+
+impl DatabaseQueryView for DeleteRoleSessionPolicyQueryView {
+    fn get_request(&self) -> String {
+        "DELETE FROM role_session_policies WHERE role_id = $1".to_string()
+    }
+}
+
+impl Display for DeleteRoleSessionPolicyQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "DeleteRoleSessionPolicyQueryView: role_id = {}",
+            self.role_id
+        )
+    }
+}
@@ -0,0 +1,17 @@
+use crate::database::session_policies::get_role_session_policy::GetRoleSessionPolicyQueryView;
+use crate::database::session_policies::RoleSessionPolicy;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+pub async fn get_role_session_policy_query(
+    view: GetRoleSessionPolicyQueryView,
+    pool: PgPool,
+) -> Result<Option<RoleSessionPolicy>, DatabaseError> {
+    let result = sqlx::query_as::<_, RoleSessionPolicy>(&view.get_request())
+        .bind(view.get_role_id() as i32)
+        .fetch_optional(&pool)
+        .await?;
+
+    Ok(result)
+}
@@ -0,0 +1,34 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct GetRoleSessionPolicyQueryView {
+    role_id: u64,
+}
+
+impl GetRoleSessionPolicyQueryView {
+    pub fn new(role_id: u64) -> Self {
+        Self { role_id }
+    }
+
+    pub fn get_role_id(&self) -> u64 {
+        self.role_id
+    }
+}
+
+impl DatabaseQueryView for GetRoleSessionPolicyQueryView {
+    fn get_request(&self) -> String {
+        "SELECT role_id, max_sessions, limit_action, idle_timeout_minutes, absolute_lifetime_minutes
+        FROM role_session_policies WHERE role_id = $1"
+            .to_string()
+    }
+}
+
+impl Display for GetRoleSessionPolicyQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "GetRoleSessionPolicyQueryView: role_id = {}",
+            self.role_id
+        )
+    }
+}
@@ -0,0 +1,5 @@
+mod query;
+pub use query::get_user_session_policies_query;
+
+pub mod view;
+pub use view::GetUserSessionPoliciesQueryView;
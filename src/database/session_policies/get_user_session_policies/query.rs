@@ -0,0 +1,20 @@
+use crate::database::session_policies::get_user_session_policies::GetUserSessionPoliciesQueryView;
+use crate::database::session_policies::RoleSessionPolicy;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+/**
+ * Session policies of the roles held by a user, empty when none of them has one.
+ */
+pub async fn get_user_session_policies_query(
+    view: GetUserSessionPoliciesQueryView,
+    pool: PgPool,
+) -> Result<Vec<RoleSessionPolicy>, DatabaseError> {
+    let result = sqlx::query_as::<_, RoleSessionPolicy>(&view.get_request())
+        .bind(view.get_user_id() as i32)
+        .fetch_all(&pool)
+        .await?;
+
+    Ok(result)
+}
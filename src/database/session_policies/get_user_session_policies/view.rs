@@ -0,0 +1,36 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct GetUserSessionPoliciesQueryView {
+    user_id: u64,
+}
+
+impl GetUserSessionPoliciesQueryView {
+    pub fn new(user_id: u64) -> Self {
+        Self { user_id }
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+}
+
+impl DatabaseQueryView for GetUserSessionPoliciesQueryView {
+    fn get_request(&self) -> String {
+        "SELECT p.role_id, p.max_sessions, p.limit_action, p.idle_timeout_minutes, p.absolute_lifetime_minutes
+        FROM role_session_policies p
+        JOIN user_roles ur ON ur.role_id = p.role_id
+        WHERE ur.user_id = $1"
+            .to_string()
+    }
+}
+
+impl Display for GetUserSessionPoliciesQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "GetUserSessionPoliciesQueryView: user_id = {}",
+            self.user_id
+        )
+    }
+}
@@ -0,0 +1,7 @@
+pub mod delete_role_session_policy;
+pub mod get_role_session_policy;
+pub mod get_user_session_policies;
+pub mod put_role_session_policy;
+
+mod view;
+pub use view::RoleSessionPolicy;
@@ -0,0 +1,21 @@
+use crate::database::session_policies::put_role_session_policy::PutRoleSessionPolicyQueryView;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+pub async fn put_role_session_policy_query(
+    view: PutRoleSessionPolicyQueryView,
+    pool: PgPool,
+) -> Result<(), DatabaseError> {
+    let policy = view.get_policy();
+    sqlx::query(&view.get_request())
+        .bind(view.get_role_id() as i32)
+        .bind(policy.max_sessions().map(|value| value as i32))
+        .bind(policy.limit_action().as_str())
+        .bind(policy.idle_timeout_minutes().map(|value| value as i32))
+        .bind(policy.absolute_lifetime_minutes().map(|value| value as i32))
+        .execute(&pool)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use crate::security::session_policy::SessionPolicy;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct PutRoleSessionPolicyQueryView {
+    role_id: u64,
+    policy: SessionPolicy,
+}
+
+impl PutRoleSessionPolicyQueryView {
+    pub fn new(role_id: u64, policy: SessionPolicy) -> Self {
+        Self { role_id, policy }
+    }
+
+    pub fn get_role_id(&self) -> u64 {
+        self.role_id
+    }
+
+    pub fn get_policy(&self) -> &SessionPolicy {
+        &self.policy
+    }
+}
+
+impl DatabaseQueryView for PutRoleSessionPolicyQueryView {
+    fn get_request(&self) -> String {
+        "INSERT INTO role_session_policies
+            (role_id, max_sessions, limit_action, idle_timeout_minutes, absolute_lifetime_minutes)
+        VALUES ($1, $2, $3, $4, $5)
+        ON CONFLICT (role_id) DO UPDATE SET
+            max_sessions = EXCLUDED.max_sessions,
+            limit_action = EXCLUDED.limit_action,
+            idle_timeout_minutes = EXCLUDED.idle_timeout_minutes,
+            absolute_lifetime_minutes = EXCLUDED.absolute_lifetime_minutes"
+            .to_string()
+    }
+}
+
+impl Display for PutRoleSessionPolicyQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "PutRoleSessionPolicyQueryView: role_id = {}, policy = {:?}",
+            self.role_id, self.policy
+        )
+    }
+}
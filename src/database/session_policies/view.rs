@@ -0,0 +1,25 @@
+use crate::security::session_policy::{SessionLimitAction, SessionPolicy};
+
+#[derive(Debug, sqlx::FromRow, PartialEq, Eq)]
+pub struct RoleSessionPolicy {
+    role_id: i32,
+    max_sessions: Option<i32>,
+    limit_action: String,
+    idle_timeout_minutes: Option<i32>,
+    absolute_lifetime_minutes: Option<i32>,
+}
+
+impl RoleSessionPolicy {
+    pub fn get_role_id(&self) -> u64 {
+        self.role_id as u64
+    }
+
+    pub fn to_policy(&self) -> SessionPolicy {
+        SessionPolicy::new(
+            self.max_sessions.map(|value| value as u32),
+            SessionLimitAction::parse(&self.limit_action).unwrap_or_default(),
+            self.idle_timeout_minutes.map(|value| value as u32),
+            self.absolute_lifetime_minutes.map(|value| value as u32),
+        )
+    }
+}
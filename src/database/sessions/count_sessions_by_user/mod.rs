@@ -0,0 +1,5 @@
+mod query;
+pub use query::count_sessions_by_user_query;
+
+pub mod view;
+pub use view::CountSessionsByUserQueryView;
@@ -0,0 +1,19 @@
+use crate::database::sessions::count_sessions_by_user::CountSessionsByUserQueryView;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+pub async fn count_sessions_by_user_query(
+    view: CountSessionsByUserQueryView,
+    pool: PgPool,
+) -> Result<u64, DatabaseError> {
+    let count: i64 = sqlx::query_scalar(&view.get_request())
+        .bind(view.get_user_id() as i64)
+        .bind(view.get_active_only())
+        .bind(view.get_created_after())
+        .bind(view.get_created_before())
+        .fetch_one(&pool)
+        .await?;
+
+    Ok(count as u64)
+}
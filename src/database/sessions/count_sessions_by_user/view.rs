@@ -0,0 +1,63 @@
+use chrono::{DateTime, Utc};
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+use crate::database::sessions::get_sessions_by_user::view::SESSIONS_BY_USER_FILTER;
+
+pub struct CountSessionsByUserQueryView {
+    user_id: u64,
+    active_only: bool,
+    created_after: Option<DateTime<Utc>>,
+    created_before: Option<DateTime<Utc>>,
+}
+
+impl CountSessionsByUserQueryView {
+    pub fn new(
+        user_id: u64,
+        active_only: bool,
+        created_after: Option<DateTime<Utc>>,
+        created_before: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            user_id,
+            active_only,
+            created_after,
+            created_before,
+        }
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+
+    pub fn get_active_only(&self) -> bool {
+        self.active_only
+    }
+
+    pub fn get_created_after(&self) -> Option<&DateTime<Utc>> {
+        self.created_after.as_ref()
+    }
+
+    pub fn get_created_before(&self) -> Option<&DateTime<Utc>> {
+        self.created_before.as_ref()
+    }
+}
+
+impl DatabaseQueryView for CountSessionsByUserQueryView {
+    fn get_request(&self) -> String {
+        format!(
+            "SELECT COUNT(*) FROM sessions WHERE {}",
+            SESSIONS_BY_USER_FILTER
+        )
+    }
+}
+
+impl Display for CountSessionsByUserQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CountSessionsByUserQueryView: user_id = {}, active_only = {}, created_after = {:?}, created_before = {:?}",
+            self.user_id, self.active_only, self.created_after, self.created_before
+        )
+    }
+}
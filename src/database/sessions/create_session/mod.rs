@@ -3,3 +3,4 @@ pub use query::create_session_query;
 
 mod view;
 pub use view::CreateSessionQueryView;
+pub(crate) use view::CAP_SESSION_EXPIRY;
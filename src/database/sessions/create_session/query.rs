@@ -1,4 +1,4 @@
-use crate::database::sessions::create_session::CreateSessionQueryView;
+use crate::database::sessions::create_session::{CreateSessionQueryView, CAP_SESSION_EXPIRY};
 use mairie360_api_lib::database::db_interface::DatabaseQueryView;
 use mairie360_api_lib::database::errors::DatabaseError;
 use sqlx::PgPool;
@@ -7,13 +7,29 @@ pub async fn create_session_query(
     view: CreateSessionQueryView,
     pool: PgPool,
 ) -> Result<(), DatabaseError> {
+    let mut transaction = pool.begin().await?;
     sqlx::query(&view.get_request())
+        .bind(view.get_id())
         .bind(view.get_user_id() as i64)
         .bind(view.get_token_hash())
         .bind(view.get_device_info())
         .bind(view.get_ip_address())
-        .execute(&pool)
+        .bind(
+            view.get_idle_timeout_minutes()
+                .map(|minutes| minutes as i32),
+        )
+        .bind(view.get_absolute_expires_at())
+        .bind(view.get_device().browser())
+        .bind(view.get_device().os())
+        .bind(view.get_device().device_type().as_str())
+        .bind(view.get_device().app_version())
+        .execute(&mut *transaction)
         .await?;
+    sqlx::query(CAP_SESSION_EXPIRY)
+        .bind(view.get_id())
+        .execute(&mut *transaction)
+        .await?;
+    transaction.commit().await?;
 
     Ok(())
 }
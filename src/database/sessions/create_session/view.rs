@@ -1,11 +1,30 @@
+use crate::security::device::DeviceDetails;
+use crate::security::session_policy::SessionPolicy;
+use chrono::{DateTime, Utc};
 use mairie360_api_lib::database::db_interface::DatabaseQueryView;
 use std::fmt::Display;
 
+/**
+ * Brings `expires_at` of the session $1 down to its idle timeout and absolute lifetime;
+ * sessions opened without a policy keep the default of the table.
+ */
+pub(crate) const CAP_SESSION_EXPIRY: &str = "UPDATE sessions
+    SET expires_at = LEAST(
+        expires_at,
+        NOW() + make_interval(mins => idle_timeout_minutes),
+        absolute_expires_at
+    )
+    WHERE id = $1";
+
 pub struct CreateSessionQueryView {
+    id: uuid::Uuid,
     user_id: u64,
     token_hash: String,
     device_info: String,
     ip_address: std::net::IpAddr,
+    idle_timeout_minutes: Option<u32>,
+    absolute_expires_at: Option<DateTime<Utc>>,
+    device: DeviceDetails,
 }
 
 impl CreateSessionQueryView {
@@ -16,13 +35,39 @@ impl CreateSessionQueryView {
         ip_address: std::net::IpAddr,
     ) -> Self {
         Self {
+            id: uuid::Uuid::new_v4(),
             user_id,
             token_hash: token_hash.to_string(),
             device_info: device_info.to_string(),
             ip_address,
+            idle_timeout_minutes: None,
+            absolute_expires_at: None,
+            device: DeviceDetails::default(),
         }
     }
 
+    pub fn with_device(mut self, device: DeviceDetails) -> Self {
+        self.device = device;
+        self
+    }
+
+    /**
+     * Applies the idle timeout and absolute lifetime of the policy to the session,
+     * and to every session refreshed from it.
+     */
+    pub fn with_policy(mut self, policy: &SessionPolicy) -> Self {
+        self.idle_timeout_minutes = policy.idle_timeout_minutes();
+        self.absolute_expires_at = policy.absolute_expires_at(Utc::now());
+        self
+    }
+
+    /**
+     * Id of the session about to be created, known up front so it can go into the JWT.
+     */
+    pub fn get_id(&self) -> &uuid::Uuid {
+        &self.id
+    }
+
     pub fn get_user_id(&self) -> u64 {
         self.user_id
     }
@@ -38,11 +83,26 @@ impl CreateSessionQueryView {
     pub fn get_ip_address(&self) -> &std::net::IpAddr {
         &self.ip_address
     }
+
+    pub fn get_idle_timeout_minutes(&self) -> Option<u32> {
+        self.idle_timeout_minutes
+    }
+
+    pub fn get_absolute_expires_at(&self) -> Option<&DateTime<Utc>> {
+        self.absolute_expires_at.as_ref()
+    }
+
+    pub fn get_device(&self) -> &DeviceDetails {
+        &self.device
+    }
 }
 
 impl DatabaseQueryView for CreateSessionQueryView {
     fn get_request(&self) -> String {
-        "INSERT INTO sessions (user_id, token_hash, device_info, ip_address) VALUES ($1, $2, $3, $4)".to_string()
+        "INSERT INTO sessions (id, user_id, token_hash, device_info, ip_address, idle_timeout_minutes, absolute_expires_at,
+            browser, os, device_type, app_version)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)"
+            .to_string()
     }
 }
 
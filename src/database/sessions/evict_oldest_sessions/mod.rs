@@ -0,0 +1,5 @@
+mod query;
+pub use query::evict_oldest_sessions_query;
+
+pub mod view;
+pub use view::EvictOldestSessionsQueryView;
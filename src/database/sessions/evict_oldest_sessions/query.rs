@@ -0,0 +1,21 @@
+use crate::database::sessions::evict_oldest_sessions::EvictOldestSessionsQueryView;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/**
+ * Revokes the oldest active sessions of a user. Returns the ids of the revoked sessions.
+ */
+pub async fn evict_oldest_sessions_query(
+    view: EvictOldestSessionsQueryView,
+    pool: PgPool,
+) -> Result<Vec<Uuid>, DatabaseError> {
+    let result: Vec<Uuid> = sqlx::query_scalar(&view.get_request())
+        .bind(view.get_user_id() as i64)
+        .bind(view.get_count() as i64)
+        .fetch_all(&pool)
+        .await?;
+
+    Ok(result)
+}
@@ -0,0 +1,45 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct EvictOldestSessionsQueryView {
+    user_id: u64,
+    count: u64,
+}
+
+impl EvictOldestSessionsQueryView {
+    pub fn new(user_id: u64, count: u64) -> Self {
+        Self { user_id, count }
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+
+    pub fn get_count(&self) -> u64 {
+        self.count
+    }
+}
+
+impl DatabaseQueryView for EvictOldestSessionsQueryView {
+    fn get_request(&self) -> String {
+        "UPDATE sessions SET revoked_at = NOW()
+        WHERE id IN (
+            SELECT id FROM sessions
+            WHERE user_id = $1 AND revoked_at IS NULL AND expires_at > NOW()
+            ORDER BY created_at ASC
+            LIMIT $2
+        )
+        RETURNING id"
+            .to_string()
+    }
+}
+
+impl Display for EvictOldestSessionsQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "EvictOldestSessionsQueryView: user_id = {}, count = {}",
+            self.user_id, self.count
+        )
+    }
+}
@@ -0,0 +1,16 @@
+use crate::database::sessions::expire_sessions::ExpireSessionsQueryView;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+/**
+ * Returns the number of sessions marked as expired.
+ */
+pub async fn expire_sessions_query(
+    view: ExpireSessionsQueryView,
+    pool: PgPool,
+) -> Result<u64, DatabaseError> {
+    let result = sqlx::query(&view.get_request()).execute(&pool).await?;
+
+    Ok(result.rows_affected())
+}
@@ -0,0 +1,34 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct ExpireSessionsQueryView {}
+
+impl ExpireSessionsQueryView {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for ExpireSessionsQueryView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DatabaseQueryView for ExpireSessionsQueryView {
+    fn get_request(&self) -> String {
+        // Les sessions révoquées ou remplacées par un refresh ont déjà leur date de fin
+        "UPDATE sessions SET expired_at = expires_at
+        WHERE expired_at IS NULL
+        AND revoked_at IS NULL
+        AND rotated_at IS NULL
+        AND expires_at <= NOW()"
+            .to_string()
+    }
+}
+
+impl Display for ExpireSessionsQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ExpireSessionsQueryView")
+    }
+}
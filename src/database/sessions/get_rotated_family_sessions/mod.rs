@@ -0,0 +1,5 @@
+mod query;
+pub use query::get_rotated_family_sessions_query;
+
+mod view;
+pub use view::GetRotatedFamilySessionsQueryView;
@@ -0,0 +1,22 @@
+use crate::database::sessions::get_rotated_family_sessions::GetRotatedFamilySessionsQueryView;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/**
+ * Sessions of the same families as `session_ids` that were refreshed less than
+ * `max_age_seconds` ago. The access tokens issued before those refreshes may still be valid.
+ */
+pub async fn get_rotated_family_sessions_query(
+    view: GetRotatedFamilySessionsQueryView,
+    pool: PgPool,
+) -> Result<Vec<Uuid>, DatabaseError> {
+    let result: Vec<Uuid> = sqlx::query_scalar(&view.get_request())
+        .bind(view.get_session_ids())
+        .bind(view.get_max_age_seconds() as i64)
+        .fetch_all(&pool)
+        .await?;
+
+    Ok(result)
+}
@@ -0,0 +1,44 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+use uuid::Uuid;
+
+pub struct GetRotatedFamilySessionsQueryView {
+    session_ids: Vec<Uuid>,
+    max_age_seconds: u64,
+}
+
+impl GetRotatedFamilySessionsQueryView {
+    pub fn new(session_ids: &[Uuid], max_age_seconds: u64) -> Self {
+        Self {
+            session_ids: session_ids.to_vec(),
+            max_age_seconds,
+        }
+    }
+
+    pub fn get_session_ids(&self) -> &[Uuid] {
+        &self.session_ids
+    }
+
+    pub fn get_max_age_seconds(&self) -> u64 {
+        self.max_age_seconds
+    }
+}
+
+impl DatabaseQueryView for GetRotatedFamilySessionsQueryView {
+    fn get_request(&self) -> String {
+        "SELECT id FROM sessions
+        WHERE family_id IN (SELECT family_id FROM sessions WHERE id = ANY($1))
+        AND rotated_at > NOW() - $2 * INTERVAL '1 second'"
+            .to_string()
+    }
+}
+
+impl Display for GetRotatedFamilySessionsQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "GetRotatedFamilySessionsQueryView: session_ids = {:?}, max_age_seconds = {}",
+            self.session_ids, self.max_age_seconds
+        )
+    }
+}
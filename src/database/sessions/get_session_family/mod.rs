@@ -0,0 +1,5 @@
+mod query;
+pub use query::get_session_family_query;
+
+mod view;
+pub use view::{GetSessionFamilyQueryResultView, GetSessionFamilyQueryView};
@@ -0,0 +1,18 @@
+use crate::database::sessions::get_session_family::{
+    GetSessionFamilyQueryResultView, GetSessionFamilyQueryView,
+};
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+pub async fn get_session_family_query(
+    view: GetSessionFamilyQueryView,
+    pool: PgPool,
+) -> Result<Option<GetSessionFamilyQueryResultView>, DatabaseError> {
+    let result = sqlx::query_as::<_, GetSessionFamilyQueryResultView>(&view.get_request())
+        .bind(view.get_token_hash())
+        .fetch_optional(&pool)
+        .await?;
+
+    Ok(result)
+}
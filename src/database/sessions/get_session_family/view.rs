@@ -0,0 +1,69 @@
+use chrono::{DateTime, Duration, Utc};
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+use uuid::Uuid;
+
+pub struct GetSessionFamilyQueryView {
+    token_hash: String,
+}
+
+impl GetSessionFamilyQueryView {
+    pub fn new(token_hash: &str) -> Self {
+        Self {
+            token_hash: token_hash.to_string(),
+        }
+    }
+
+    pub fn get_token_hash(&self) -> &str {
+        &self.token_hash
+    }
+}
+
+impl DatabaseQueryView for GetSessionFamilyQueryView {
+    fn get_request(&self) -> String {
+        "SELECT user_id, family_id, rotated_at FROM sessions WHERE token_hash = $1".to_string()
+    }
+}
+
+impl Display for GetSessionFamilyQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GetSessionFamilyQueryView: token_hash = [PROTECTED]")
+    }
+}
+
+#[derive(Debug, sqlx::FromRow, PartialEq, Eq)]
+pub struct GetSessionFamilyQueryResultView {
+    user_id: i32,
+    family_id: Uuid,
+    rotated_at: Option<DateTime<Utc>>,
+}
+
+impl GetSessionFamilyQueryResultView {
+    pub fn new(user_id: i32, family_id: Uuid, rotated_at: Option<DateTime<Utc>>) -> Self {
+        Self {
+            user_id,
+            family_id,
+            rotated_at,
+        }
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id as u64
+    }
+
+    pub fn get_family_id(&self) -> &Uuid {
+        &self.family_id
+    }
+
+    pub fn is_rotated(&self) -> bool {
+        self.rotated_at.is_some()
+    }
+
+    /**
+     * True when the session was rotated less than `seconds` ago.
+     */
+    pub fn is_rotated_within(&self, seconds: u64) -> bool {
+        self.rotated_at
+            .is_some_and(|rotated_at| Utc::now() - rotated_at < Duration::seconds(seconds as i64))
+    }
+}
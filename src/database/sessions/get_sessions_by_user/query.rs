@@ -4,12 +4,23 @@ use mairie360_api_lib::database::db_interface::DatabaseQueryView;
 use mairie360_api_lib::database::errors::DatabaseError;
 use sqlx::PgPool;
 
+/**
+ * Most recent sessions first.
+ */
 pub async fn get_sessions_by_user_query(
     view: GetSessionsByUserQueryView,
     pool: PgPool,
 ) -> Result<Vec<Session>, DatabaseError> {
     let result: Vec<Session> = sqlx::query_as::<_, Session>(&view.get_request())
         .bind(view.get_user_id() as i64)
+        .bind(view.get_active_only())
+        .bind(view.get_created_after())
+        .bind(view.get_created_before())
+        .bind(
+            view.get_limit()
+                .map(|limit| i64::try_from(limit).unwrap_or(i64::MAX)),
+        )
+        .bind(i64::try_from(view.get_offset()).unwrap_or(i64::MAX))
         .fetch_all(&pool)
         .await?;
 
@@ -1,28 +1,112 @@
+use chrono::{DateTime, Utc};
 use mairie360_api_lib::database::db_interface::DatabaseQueryView;
 use std::fmt::Display;
 
+/**
+ * Conditions shared with `CountSessionsByUserQueryView`, for parameters `$1` to `$4`.
+ */
+pub(crate) const SESSIONS_BY_USER_FILTER: &str = "user_id = $1
+        AND (NOT $2 OR (revoked_at IS NULL AND expires_at > NOW()))
+        AND ($3::timestamptz IS NULL OR created_at >= $3)
+        AND ($4::timestamptz IS NULL OR created_at < $4)";
+
 pub struct GetSessionsByUserQueryView {
     user_id: u64,
+    active_only: bool,
+    created_after: Option<DateTime<Utc>>,
+    created_before: Option<DateTime<Utc>>,
+    limit: Option<u64>,
+    offset: u64,
 }
 
 impl GetSessionsByUserQueryView {
+    /**
+     * Every session of the user, revoked and expired ones included.
+     */
     pub fn new(user_id: u64) -> Self {
-        Self { user_id }
+        Self {
+            user_id,
+            active_only: false,
+            created_after: None,
+            created_before: None,
+            limit: None,
+            offset: 0,
+        }
+    }
+
+    pub fn active_only(mut self, active_only: bool) -> Self {
+        self.active_only = active_only;
+        self
+    }
+
+    /**
+     * Sessions created from `after` included to `before` excluded; either bound may be left open.
+     */
+    pub fn created_between(
+        mut self,
+        after: Option<DateTime<Utc>>,
+        before: Option<DateTime<Utc>>,
+    ) -> Self {
+        self.created_after = after;
+        self.created_before = before;
+        self
+    }
+
+    pub fn paginate(mut self, limit: u64, offset: u64) -> Self {
+        self.limit = Some(limit);
+        self.offset = offset;
+        self
     }
 
     pub fn get_user_id(&self) -> u64 {
         self.user_id
     }
+
+    pub fn get_active_only(&self) -> bool {
+        self.active_only
+    }
+
+    pub fn get_created_after(&self) -> Option<&DateTime<Utc>> {
+        self.created_after.as_ref()
+    }
+
+    pub fn get_created_before(&self) -> Option<&DateTime<Utc>> {
+        self.created_before.as_ref()
+    }
+
+    pub fn get_limit(&self) -> Option<u64> {
+        self.limit
+    }
+
+    pub fn get_offset(&self) -> u64 {
+        self.offset
+    }
 }
 
 impl DatabaseQueryView for GetSessionsByUserQueryView {
     fn get_request(&self) -> String {
-        "SELECT * FROM sessions WHERE user_id = $1".to_string()
+        // LIMIT NULL ne limite rien
+        format!(
+            "SELECT * FROM sessions
+        WHERE {}
+        ORDER BY created_at DESC, id
+        LIMIT $5 OFFSET $6",
+            SESSIONS_BY_USER_FILTER
+        )
     }
 }
 
 impl Display for GetSessionsByUserQueryView {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "GetSessionsByUserQueryView: user_id = {}", self.user_id)
+        write!(
+            f,
+            "GetSessionsByUserQueryView: user_id = {}, active_only = {}, created_after = {:?}, created_before = {:?}, limit = {:?}, offset = {}",
+            self.user_id,
+            self.active_only,
+            self.created_after,
+            self.created_before,
+            self.limit,
+            self.offset
+        )
     }
 }
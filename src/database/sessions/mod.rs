@@ -1,13 +1,23 @@
+pub mod count_sessions_by_user;
 pub mod create_session;
+pub mod evict_oldest_sessions;
+pub mod expire_sessions;
 pub mod get_active_session;
 pub mod get_active_sessions;
+pub mod get_rotated_family_sessions;
 pub mod get_session_by_token;
+pub mod get_session_family;
 pub mod get_sessions;
 pub mod get_sessions_by_user;
+pub mod purge_sessions;
 pub mod revoke_previous_session;
 pub mod revoke_session;
 pub mod revoke_session_by_id;
 pub mod revoke_session_by_token;
+pub mod revoke_session_family;
+pub mod revoke_session_family_by_id;
+pub mod revoke_user_sessions;
+pub mod rotate_session;
 
 mod view;
 pub use view::Session;
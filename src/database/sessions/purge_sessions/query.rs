@@ -0,0 +1,19 @@
+use crate::database::sessions::purge_sessions::PurgeSessionsQueryView;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+/**
+ * Returns the number of sessions deleted.
+ */
+pub async fn purge_sessions_query(
+    view: PurgeSessionsQueryView,
+    pool: PgPool,
+) -> Result<u64, DatabaseError> {
+    let result = sqlx::query(&view.get_request())
+        .bind(view.get_retention_days() as i32)
+        .execute(&pool)
+        .await?;
+
+    Ok(result.rows_affected())
+}
@@ -0,0 +1,35 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct PurgeSessionsQueryView {
+    retention_days: u32,
+}
+
+impl PurgeSessionsQueryView {
+    pub fn new(retention_days: u32) -> Self {
+        Self { retention_days }
+    }
+
+    pub fn get_retention_days(&self) -> u32 {
+        self.retention_days
+    }
+}
+
+impl DatabaseQueryView for PurgeSessionsQueryView {
+    fn get_request(&self) -> String {
+        // Une session révoquée ou remplacée n'a jamais pu servir au-delà de expires_at
+        "DELETE FROM sessions
+        WHERE expires_at < NOW() - make_interval(days => $1)"
+            .to_string()
+    }
+}
+
+impl Display for PurgeSessionsQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "PurgeSessionsQueryView: retention_days = {}",
+            self.retention_days
+        )
+    }
+}
@@ -2,18 +2,19 @@ use crate::database::sessions::revoke_previous_session::RevokePreviousSessionQue
 use mairie360_api_lib::database::db_interface::DatabaseQueryView;
 use mairie360_api_lib::database::errors::DatabaseError;
 use sqlx::PgPool;
+use uuid::Uuid;
 
 pub async fn revoke_previous_session_query(
     view: RevokePreviousSessionQueryView,
     pool: PgPool,
-) -> Result<(), DatabaseError> {
-    sqlx::query(&view.get_request())
+) -> Result<Vec<Uuid>, DatabaseError> {
+    let result: Vec<Uuid> = sqlx::query_scalar(&view.get_request())
         .bind(view.get_revoked_at())
         .bind(view.get_user_id() as i64)
         .bind(view.get_ip())
         .bind(view.get_device_info())
-        .execute(&pool)
+        .fetch_all(&pool)
         .await?;
 
-    Ok(())
+    Ok(result)
 }
@@ -37,7 +37,7 @@ impl RevokePreviousSessionQueryView {
 
 impl DatabaseQueryView for RevokePreviousSessionQueryView {
     fn get_request(&self) -> String {
-        "UPDATE sessions SET revoked_at = $1 WHERE user_id = $2 AND ip_address = $3 AND device_info = $4".to_string()
+        "UPDATE sessions SET revoked_at = $1 WHERE user_id = $2 AND ip_address = $3 AND device_info = $4 AND revoked_at IS NULL RETURNING id".to_string()
     }
 }
 
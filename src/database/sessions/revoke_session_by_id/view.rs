@@ -36,7 +36,8 @@ impl DatabaseQueryView for RevokeSessionByIdQueryView {
         "UPDATE sessions
          SET revoked_at = $1
          WHERE user_id = $2
-         AND id = $3"
+         AND id = $3
+         AND revoked_at IS NULL"
             .to_string()
     }
 }
@@ -0,0 +1,5 @@
+mod query;
+pub use query::revoke_session_family_query;
+
+mod view;
+pub use view::RevokeSessionFamilyQueryView;
@@ -0,0 +1,21 @@
+use crate::database::sessions::revoke_session_family::RevokeSessionFamilyQueryView;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/**
+ * Revokes every session descending from the same login. Returns the ids of the sessions
+ * that were still active.
+ */
+pub async fn revoke_session_family_query(
+    view: RevokeSessionFamilyQueryView,
+    pool: PgPool,
+) -> Result<Vec<Uuid>, DatabaseError> {
+    let result: Vec<Uuid> = sqlx::query_scalar(&view.get_request())
+        .bind(view.get_family_id())
+        .fetch_all(&pool)
+        .await?;
+
+    Ok(result)
+}
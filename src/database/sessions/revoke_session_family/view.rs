@@ -0,0 +1,35 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+use uuid::Uuid;
+
+pub struct RevokeSessionFamilyQueryView {
+    family_id: Uuid,
+}
+
+impl RevokeSessionFamilyQueryView {
+    pub fn new(family_id: Uuid) -> Self {
+        Self { family_id }
+    }
+
+    pub fn get_family_id(&self) -> &Uuid {
+        &self.family_id
+    }
+}
+
+impl DatabaseQueryView for RevokeSessionFamilyQueryView {
+    fn get_request(&self) -> String {
+        "UPDATE sessions SET revoked_at = NOW() WHERE family_id = $1 AND revoked_at IS NULL
+        RETURNING id"
+            .to_string()
+    }
+}
+
+impl Display for RevokeSessionFamilyQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "RevokeSessionFamilyQueryView: family_id = {}",
+            self.family_id
+        )
+    }
+}
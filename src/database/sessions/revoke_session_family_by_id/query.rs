@@ -0,0 +1,22 @@
+use crate::database::sessions::revoke_session_family_by_id::RevokeSessionFamilyByIdQueryView;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/**
+ * Revokes a session of the user and every session refreshed from the same login since.
+ * Returns the ids of the sessions that were still active.
+ */
+pub async fn revoke_session_family_by_id_query(
+    view: RevokeSessionFamilyByIdQueryView,
+    pool: PgPool,
+) -> Result<Vec<Uuid>, DatabaseError> {
+    let result: Vec<Uuid> = sqlx::query_scalar(&view.get_request())
+        .bind(view.get_user_id() as i64)
+        .bind(view.get_id())
+        .fetch_all(&pool)
+        .await?;
+
+    Ok(result)
+}
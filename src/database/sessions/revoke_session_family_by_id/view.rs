@@ -0,0 +1,43 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+use uuid::Uuid;
+
+pub struct RevokeSessionFamilyByIdQueryView {
+    user_id: u64,
+    id: Uuid,
+}
+
+impl RevokeSessionFamilyByIdQueryView {
+    pub fn new(user_id: u64, id: Uuid) -> Self {
+        Self { user_id, id }
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+
+    pub fn get_id(&self) -> &Uuid {
+        &self.id
+    }
+}
+
+impl DatabaseQueryView for RevokeSessionFamilyByIdQueryView {
+    fn get_request(&self) -> String {
+        "UPDATE sessions SET revoked_at = NOW()
+        WHERE user_id = $1
+        AND family_id = (SELECT family_id FROM sessions WHERE user_id = $1 AND id = $2)
+        AND revoked_at IS NULL
+        RETURNING id"
+            .to_string()
+    }
+}
+
+impl Display for RevokeSessionFamilyByIdQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "RevokeSessionFamilyByIdQueryView: user_id = {}, id = {}",
+            self.user_id, self.id,
+        )
+    }
+}
@@ -0,0 +1,5 @@
+mod query;
+pub use query::revoke_user_sessions_query;
+
+mod view;
+pub use view::RevokeUserSessionsQueryView;
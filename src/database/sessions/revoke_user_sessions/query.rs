@@ -0,0 +1,21 @@
+use crate::database::sessions::revoke_user_sessions::RevokeUserSessionsQueryView;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/**
+ * Revokes every active session of a user but the kept one. Returns the ids of the revoked sessions.
+ */
+pub async fn revoke_user_sessions_query(
+    view: RevokeUserSessionsQueryView,
+    pool: PgPool,
+) -> Result<Vec<Uuid>, DatabaseError> {
+    let result: Vec<Uuid> = sqlx::query_scalar(&view.get_request())
+        .bind(view.get_user_id() as i32)
+        .bind(view.get_kept_session_id())
+        .fetch_all(&pool)
+        .await?;
+
+    Ok(result)
+}
@@ -0,0 +1,53 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+use uuid::Uuid;
+
+pub struct RevokeUserSessionsQueryView {
+    user_id: u64,
+    kept_session_id: Option<Uuid>,
+}
+
+impl RevokeUserSessionsQueryView {
+    pub fn new(user_id: u64) -> Self {
+        Self {
+            user_id,
+            kept_session_id: None,
+        }
+    }
+
+    /**
+     * Leaves one session untouched, usually the one making the request.
+     */
+    pub fn keeping(mut self, session_id: Uuid) -> Self {
+        self.kept_session_id = Some(session_id);
+        self
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+
+    pub fn get_kept_session_id(&self) -> Option<Uuid> {
+        self.kept_session_id
+    }
+}
+
+impl DatabaseQueryView for RevokeUserSessionsQueryView {
+    fn get_request(&self) -> String {
+        "UPDATE sessions SET revoked_at = NOW()
+        WHERE user_id = $1 AND revoked_at IS NULL
+        AND ($2::uuid IS NULL OR id <> $2)
+        RETURNING id"
+            .to_string()
+    }
+}
+
+impl Display for RevokeUserSessionsQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "RevokeUserSessionsQueryView: user_id = {}, kept_session_id = {:?}",
+            self.user_id, self.kept_session_id
+        )
+    }
+}
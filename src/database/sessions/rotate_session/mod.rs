@@ -0,0 +1,5 @@
+mod query;
+pub use query::rotate_session_query;
+
+mod view;
+pub use view::RotateSessionQueryView;
@@ -0,0 +1,35 @@
+use crate::database::sessions::create_session::CAP_SESSION_EXPIRY;
+use crate::database::sessions::rotate_session::RotateSessionQueryView;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+/**
+ * Retires the session holding the presented refresh token and opens its successor in the
+ * same token family, which keeps the idle timeout and absolute lifetime of the login. The
+ * successor records the IP it was refreshed from: clients change networks between refreshes.
+ * Returns the session owner, or None when the token is unknown, expired, revoked or was
+ * already rotated.
+ */
+pub async fn rotate_session_query(
+    view: RotateSessionQueryView,
+    pool: PgPool,
+) -> Result<Option<u64>, DatabaseError> {
+    let mut transaction = pool.begin().await?;
+    let result: Option<i32> = sqlx::query_scalar(&view.get_request())
+        .bind(view.get_token_hash())
+        .bind(view.get_new_token_hash())
+        .bind(view.get_ip_address())
+        .bind(view.get_new_session_id())
+        .fetch_optional(&mut *transaction)
+        .await?;
+    if result.is_some() {
+        sqlx::query(CAP_SESSION_EXPIRY)
+            .bind(view.get_new_session_id())
+            .execute(&mut *transaction)
+            .await?;
+    }
+    transaction.commit().await?;
+
+    Ok(result.map(|user_id| user_id as u64))
+}
@@ -0,0 +1,65 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct RotateSessionQueryView {
+    new_session_id: uuid::Uuid,
+    token_hash: String,
+    new_token_hash: String,
+    ip_address: std::net::IpAddr,
+}
+
+impl RotateSessionQueryView {
+    pub fn new(token_hash: &str, new_token_hash: &str, ip_address: std::net::IpAddr) -> Self {
+        Self {
+            new_session_id: uuid::Uuid::new_v4(),
+            token_hash: token_hash.to_string(),
+            new_token_hash: new_token_hash.to_string(),
+            ip_address,
+        }
+    }
+
+    pub fn get_new_session_id(&self) -> &uuid::Uuid {
+        &self.new_session_id
+    }
+
+    pub fn get_token_hash(&self) -> &str {
+        &self.token_hash
+    }
+
+    pub fn get_new_token_hash(&self) -> &str {
+        &self.new_token_hash
+    }
+
+    pub fn get_ip_address(&self) -> &std::net::IpAddr {
+        &self.ip_address
+    }
+}
+
+impl DatabaseQueryView for RotateSessionQueryView {
+    fn get_request(&self) -> String {
+        "WITH rotated AS (
+            UPDATE sessions SET rotated_at = NOW(), revoked_at = NOW()
+            WHERE token_hash = $1
+                AND rotated_at IS NULL AND revoked_at IS NULL AND expires_at > NOW()
+            RETURNING user_id, device_info, family_id, idle_timeout_minutes, absolute_expires_at,
+                browser, os, device_type, app_version
+        )
+        INSERT INTO sessions (id, user_id, token_hash, device_info, ip_address, family_id, idle_timeout_minutes, absolute_expires_at,
+            browser, os, device_type, app_version)
+        SELECT $4, user_id, $2, device_info, $3, family_id, idle_timeout_minutes, absolute_expires_at,
+            browser, os, device_type, app_version
+        FROM rotated
+        RETURNING user_id"
+            .to_string()
+    }
+}
+
+impl Display for RotateSessionQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "RotateSessionQueryView: token_hash = [PROTECTED], new_token_hash = [PROTECTED], ip_address = {}",
+            self.ip_address
+        )
+    }
+}
@@ -1,3 +1,4 @@
+use crate::security::device::DeviceDetails;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -11,6 +12,15 @@ pub struct Session {
     created_at: DateTime<Utc>,
     expires_at: DateTime<Utc>,
     revoked_at: Option<DateTime<Utc>>,
+    // Absentes de v_sessions et des sessions ouvertes avant leur ajout
+    #[sqlx(default)]
+    browser: Option<String>,
+    #[sqlx(default)]
+    os: Option<String>,
+    #[sqlx(default)]
+    device_type: Option<String>,
+    #[sqlx(default)]
+    app_version: Option<String>,
 }
 
 impl Session {
@@ -31,9 +41,21 @@ impl Session {
             created_at,
             expires_at,
             revoked_at,
+            browser: None,
+            os: None,
+            device_type: None,
+            app_version: None,
         }
     }
 
+    pub fn with_device(mut self, device: &DeviceDetails) -> Self {
+        self.browser = device.browser().map(str::to_string);
+        self.os = device.os().map(str::to_string);
+        self.device_type = Some(device.device_type().as_str().to_string());
+        self.app_version = device.app_version().map(str::to_string);
+        self
+    }
+
     pub fn id(&self) -> &Uuid {
         &self.id
     }
@@ -61,4 +83,20 @@ impl Session {
     pub fn revoked_at(&self) -> Option<&DateTime<Utc>> {
         self.revoked_at.as_ref()
     }
+
+    pub fn browser(&self) -> Option<&str> {
+        self.browser.as_deref()
+    }
+
+    pub fn os(&self) -> Option<&str> {
+        self.os.as_deref()
+    }
+
+    pub fn device_type(&self) -> Option<&str> {
+        self.device_type.as_deref()
+    }
+
+    pub fn app_version(&self) -> Option<&str> {
+        self.app_version.as_deref()
+    }
 }
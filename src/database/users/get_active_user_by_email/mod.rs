@@ -0,0 +1,5 @@
+mod query;
+pub use query::get_active_user_by_email_query;
+
+mod view;
+pub use view::GetActiveUserByEmailQueryView;
@@ -0,0 +1,17 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+use crate::database::users::get_active_user_by_email::GetActiveUserByEmailQueryView;
+
+pub async fn get_active_user_by_email_query(
+    view: GetActiveUserByEmailQueryView,
+    pool: PgPool,
+) -> Result<Option<u64>, DatabaseError> {
+    let result = sqlx::query_scalar::<_, i32>(&view.get_request())
+        .bind(view.get_email())
+        .fetch_optional(&pool)
+        .await?;
+
+    Ok(result.map(|id| id as u64))
+}
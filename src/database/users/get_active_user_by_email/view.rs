@@ -0,0 +1,35 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+use crate::database::users::ACTIVE_USER_STATUS;
+
+pub struct GetActiveUserByEmailQueryView {
+    email: String,
+}
+
+impl GetActiveUserByEmailQueryView {
+    pub fn new(email: &str) -> Self {
+        Self {
+            email: email.to_string(),
+        }
+    }
+
+    pub fn get_email(&self) -> &str {
+        &self.email
+    }
+}
+
+impl DatabaseQueryView for GetActiveUserByEmailQueryView {
+    fn get_request(&self) -> String {
+        format!(
+            "SELECT id FROM users WHERE LOWER(email) = LOWER($1) AND status = '{}' AND NOT is_archived",
+            ACTIVE_USER_STATUS
+        )
+    }
+}
+
+impl Display for GetActiveUserByEmailQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GetActiveUserByEmailQueryView: email = {}", self.email)
+    }
+}
@@ -0,0 +1,5 @@
+mod query;
+pub use query::get_first_connection_users_query;
+
+pub mod view;
+pub use view::GetFirstConnectionUsersQueryView;
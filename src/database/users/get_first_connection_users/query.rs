@@ -0,0 +1,20 @@
+use crate::database::users::get_first_connection_users::GetFirstConnectionUsersQueryView;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+/**
+ * Returns the given users that still have to choose their first password.
+ */
+pub async fn get_first_connection_users_query(
+    view: GetFirstConnectionUsersQueryView,
+    pool: PgPool,
+) -> Result<Vec<u64>, DatabaseError> {
+    let ids: Vec<i32> = view.get_user_ids().iter().map(|id| *id as i32).collect();
+    let result: Vec<i32> = sqlx::query_scalar(&view.get_request())
+        .bind(ids)
+        .fetch_all(&pool)
+        .await?;
+
+    Ok(result.into_iter().map(|id| id as u64).collect())
+}
@@ -0,0 +1,32 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct GetFirstConnectionUsersQueryView {
+    user_ids: Vec<u64>,
+}
+
+impl GetFirstConnectionUsersQueryView {
+    pub fn new(user_ids: Vec<u64>) -> Self {
+        Self { user_ids }
+    }
+
+    pub fn get_user_ids(&self) -> &[u64] {
+        &self.user_ids
+    }
+}
+
+impl DatabaseQueryView for GetFirstConnectionUsersQueryView {
+    fn get_request(&self) -> String {
+        "SELECT id FROM users WHERE id = ANY($1) AND first_connect".to_string()
+    }
+}
+
+impl Display for GetFirstConnectionUsersQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "GetFirstConnectionUsersQueryView: user_ids = {:?}",
+            self.user_ids
+        )
+    }
+}
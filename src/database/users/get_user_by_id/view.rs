@@ -18,7 +18,7 @@ impl GetUserByIdQueryView {
 
 impl DatabaseQueryView for GetUserByIdQueryView {
     fn get_request(&self) -> String {
-        "SELECT first_name, last_name, email, phone_number, status, is_archived FROM users WHERE id = $1"
+        "SELECT first_name, last_name, email, phone_number, status, is_archived, new_sign_in_alerts FROM users WHERE id = $1"
             .to_string()
     }
 }
@@ -37,6 +37,7 @@ pub struct GetUserByIdQueryResultView {
     phone_number: Option<String>,
     status: String,
     is_archived: bool,
+    new_sign_in_alerts: bool,
 }
 
 impl GetUserByIdQueryResultView {
@@ -47,6 +48,7 @@ impl GetUserByIdQueryResultView {
         phone_number: Option<&str>,
         status: &str,
         is_archived: bool,
+        new_sign_in_alerts: bool,
     ) -> Self {
         Self {
             first_name: first_name.to_string(),
@@ -55,6 +57,7 @@ impl GetUserByIdQueryResultView {
             phone_number: phone_number.map(|p| p.to_string()),
             status: status.to_string(),
             is_archived,
+            new_sign_in_alerts,
         }
     }
 
@@ -85,4 +88,8 @@ impl GetUserByIdQueryResultView {
     pub fn is_archived(&self) -> bool {
         self.is_archived
     }
+
+    pub fn new_sign_in_alerts(&self) -> bool {
+        self.new_sign_in_alerts
+    }
 }
@@ -0,0 +1,5 @@
+mod query;
+pub use query::get_users_by_ids_query;
+
+mod view;
+pub use view::{GetUsersByIdsQueryView, UserSummary};
@@ -0,0 +1,17 @@
+use crate::database::users::get_users_by_ids::{GetUsersByIdsQueryView, UserSummary};
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+pub async fn get_users_by_ids_query(
+    view: GetUsersByIdsQueryView,
+    pool: PgPool,
+) -> Result<Vec<UserSummary>, DatabaseError> {
+    let ids: Vec<i32> = view.get_ids().iter().map(|id| *id as i32).collect();
+    let result: Vec<UserSummary> = sqlx::query_as::<_, UserSummary>(&view.get_request())
+        .bind(ids)
+        .fetch_all(&pool)
+        .await?;
+
+    Ok(result)
+}
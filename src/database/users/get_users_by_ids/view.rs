@@ -0,0 +1,62 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct GetUsersByIdsQueryView {
+    ids: Vec<u64>,
+}
+
+impl GetUsersByIdsQueryView {
+    pub fn new(ids: Vec<u64>) -> Self {
+        Self { ids }
+    }
+
+    pub fn get_ids(&self) -> &[u64] {
+        &self.ids
+    }
+}
+
+impl DatabaseQueryView for GetUsersByIdsQueryView {
+    fn get_request(&self) -> String {
+        "SELECT id, first_name, last_name, email, status FROM users
+        WHERE id = ANY($1) AND NOT is_archived
+        ORDER BY id"
+            .to_string()
+    }
+}
+
+impl Display for GetUsersByIdsQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GetUsersByIdsQueryView: ids = {:?}", self.ids)
+    }
+}
+
+#[derive(Debug, sqlx::FromRow, PartialEq, Eq)]
+pub struct UserSummary {
+    id: i32,
+    first_name: String,
+    last_name: String,
+    email: String,
+    status: String,
+}
+
+impl UserSummary {
+    pub fn id(&self) -> i32 {
+        self.id
+    }
+
+    pub fn first_name(&self) -> &str {
+        &self.first_name
+    }
+
+    pub fn last_name(&self) -> &str {
+        &self.last_name
+    }
+
+    pub fn email(&self) -> &str {
+        &self.email
+    }
+
+    pub fn status(&self) -> &str {
+        &self.status
+    }
+}
@@ -1,6 +1,14 @@
 pub mod add_role;
 pub mod delete_user;
+pub mod get_active_user_by_email;
+pub mod get_first_connection_users;
 pub mod get_roles;
 pub mod get_user_by_id;
+pub mod get_users_by_ids;
 pub mod patch_user;
 pub mod remove_role;
+
+// Valeurs de users.status posées par Core ; les autres statuts restent gérés ailleurs
+pub const PENDING_USER_STATUS: &str = "pending";
+pub const ACTIVE_USER_STATUS: &str = "active";
+pub const INVITED_USER_STATUS: &str = "invited";
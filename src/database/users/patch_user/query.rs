@@ -36,6 +36,9 @@ pub async fn patch_user_query(
     if let Some(phone_number) = view.phone_number() {
         add_field!("phone_number", phone_number);
     }
+    if let Some(new_sign_in_alerts) = view.new_sign_in_alerts() {
+        add_field!("new_sign_in_alerts", new_sign_in_alerts);
+    }
 
     // Si aucun champ n'a été ajouté, on arrête tout
     if first {
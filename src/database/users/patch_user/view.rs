@@ -8,6 +8,7 @@ pub struct PatchUserQueryView {
     email: Option<String>,
     phone_number: Option<String>,
     password: Option<String>,
+    new_sign_in_alerts: Option<bool>,
 }
 
 impl PatchUserQueryView {
@@ -26,9 +27,15 @@ impl PatchUserQueryView {
             email: email.map(|s| s.to_string()),
             phone_number: phone_number.map(|s| s.to_string()),
             password: password.map(|s| s.to_string()),
+            new_sign_in_alerts: None,
         }
     }
 
+    pub fn with_new_sign_in_alerts(mut self, new_sign_in_alerts: Option<bool>) -> Self {
+        self.new_sign_in_alerts = new_sign_in_alerts;
+        self
+    }
+
     pub fn id(&self) -> u64 {
         self.id
     }
@@ -48,6 +55,9 @@ impl PatchUserQueryView {
     pub fn password(&self) -> Option<&str> {
         self.password.as_deref()
     }
+    pub fn new_sign_in_alerts(&self) -> Option<bool> {
+        self.new_sign_in_alerts
+    }
 }
 
 impl DatabaseQueryView for PatchUserQueryView {
@@ -68,6 +78,9 @@ impl DatabaseQueryView for PatchUserQueryView {
         if let Some(password) = &self.password {
             request.push_str(&format!("password = '{}', ", password));
         }
+        if let Some(new_sign_in_alerts) = self.new_sign_in_alerts {
+            request.push_str(&format!("new_sign_in_alerts = {}, ", new_sign_in_alerts));
+        }
         request.push_str(&format!("WHERE id = {}", self.id));
         request.push_str(" RETURNING true");
         request
@@ -0,0 +1,21 @@
+use crate::database::webauthn::create_credential::CreateCredentialQueryView;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+pub async fn create_credential_query(
+    view: CreateCredentialQueryView,
+    pool: PgPool,
+) -> Result<(), DatabaseError> {
+    sqlx::query(&view.get_request())
+        .bind(view.get_user_id() as i32)
+        .bind(view.get_credential_id())
+        .bind(view.get_public_key())
+        .bind(view.get_algorithm() as i32)
+        .bind(view.get_sign_count() as i64)
+        .bind(view.get_name())
+        .execute(&pool)
+        .await?;
+
+    Ok(())
+}
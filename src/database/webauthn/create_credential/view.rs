@@ -0,0 +1,73 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct CreateCredentialQueryView {
+    user_id: u64,
+    credential_id: String,
+    public_key: Vec<u8>,
+    algorithm: i64,
+    sign_count: u32,
+    name: String,
+}
+
+impl CreateCredentialQueryView {
+    pub fn new(
+        user_id: u64,
+        credential_id: &str,
+        public_key: &[u8],
+        algorithm: i64,
+        sign_count: u32,
+        name: &str,
+    ) -> Self {
+        Self {
+            user_id,
+            credential_id: credential_id.to_string(),
+            public_key: public_key.to_vec(),
+            algorithm,
+            sign_count,
+            name: name.to_string(),
+        }
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+
+    pub fn get_credential_id(&self) -> &str {
+        &self.credential_id
+    }
+
+    pub fn get_public_key(&self) -> &[u8] {
+        &self.public_key
+    }
+
+    pub fn get_algorithm(&self) -> i64 {
+        self.algorithm
+    }
+
+    pub fn get_sign_count(&self) -> u32 {
+        self.sign_count
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl DatabaseQueryView for CreateCredentialQueryView {
+    fn get_request(&self) -> String {
+        "INSERT INTO webauthn_credentials (user_id, credential_id, public_key, algorithm, sign_count, name)
+        VALUES ($1, $2, $3, $4, $5, $6)"
+            .to_string()
+    }
+}
+
+impl Display for CreateCredentialQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CreateCredentialQueryView: user_id = {}, credential_id = {}, name = {}",
+            self.user_id, self.credential_id, self.name
+        )
+    }
+}
@@ -0,0 +1,5 @@
+mod query;
+pub use query::delete_credential_query;
+
+mod view;
+pub use view::DeleteCredentialQueryView;
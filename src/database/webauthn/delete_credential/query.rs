@@ -0,0 +1,20 @@
+use crate::database::webauthn::delete_credential::DeleteCredentialQueryView;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+/**
+ * Returns false when the passkey does not exist or belongs to another user.
+ */
+pub async fn delete_credential_query(
+    view: DeleteCredentialQueryView,
+    pool: PgPool,
+) -> Result<bool, DatabaseError> {
+    let result = sqlx::query(&view.get_request())
+        .bind(view.get_id() as i32)
+        .bind(view.get_user_id() as i32)
+        .execute(&pool)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
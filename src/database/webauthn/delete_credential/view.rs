@@ -0,0 +1,37 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct DeleteCredentialQueryView {
+    id: u64,
+    user_id: u64,
+}
+
+impl DeleteCredentialQueryView {
+    pub fn new(id: u64, user_id: u64) -> Self {
+        Self { id, user_id }
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+}
+
+impl DatabaseQueryView for DeleteCredentialQueryView {
+    fn get_request(&self) -> String {
+        "DELETE FROM webauthn_credentials WHERE id = $1 AND user_id = $2".to_string()
+    }
+}
+
+impl Display for DeleteCredentialQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "DeleteCredentialQueryView: id = {}, user_id = {}",
+            self.id, self.user_id
+        )
+    }
+}
@@ -0,0 +1,5 @@
+mod query;
+pub use query::get_credential_by_credential_id_query;
+
+mod view;
+pub use view::GetCredentialByCredentialIdQueryView;
@@ -0,0 +1,17 @@
+use crate::database::webauthn::get_credential_by_credential_id::GetCredentialByCredentialIdQueryView;
+use crate::database::webauthn::WebauthnCredential;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+pub async fn get_credential_by_credential_id_query(
+    view: GetCredentialByCredentialIdQueryView,
+    pool: PgPool,
+) -> Result<Option<WebauthnCredential>, DatabaseError> {
+    let result = sqlx::query_as::<_, WebauthnCredential>(&view.get_request())
+        .bind(view.get_credential_id())
+        .fetch_optional(&pool)
+        .await?;
+
+    Ok(result)
+}
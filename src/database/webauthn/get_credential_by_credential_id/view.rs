@@ -0,0 +1,34 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct GetCredentialByCredentialIdQueryView {
+    credential_id: String,
+}
+
+impl GetCredentialByCredentialIdQueryView {
+    pub fn new(credential_id: &str) -> Self {
+        Self {
+            credential_id: credential_id.to_string(),
+        }
+    }
+
+    pub fn get_credential_id(&self) -> &str {
+        &self.credential_id
+    }
+}
+
+impl DatabaseQueryView for GetCredentialByCredentialIdQueryView {
+    fn get_request(&self) -> String {
+        "SELECT * FROM webauthn_credentials WHERE credential_id = $1".to_string()
+    }
+}
+
+impl Display for GetCredentialByCredentialIdQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "GetCredentialByCredentialIdQueryView: credential_id = {}",
+            self.credential_id
+        )
+    }
+}
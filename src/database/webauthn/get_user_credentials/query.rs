@@ -0,0 +1,17 @@
+use crate::database::webauthn::get_user_credentials::GetUserCredentialsQueryView;
+use crate::database::webauthn::WebauthnCredential;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+pub async fn get_user_credentials_query(
+    view: GetUserCredentialsQueryView,
+    pool: PgPool,
+) -> Result<Vec<WebauthnCredential>, DatabaseError> {
+    let result = sqlx::query_as::<_, WebauthnCredential>(&view.get_request())
+        .bind(view.get_user_id() as i32)
+        .fetch_all(&pool)
+        .await?;
+
+    Ok(result)
+}
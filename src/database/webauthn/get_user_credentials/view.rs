@@ -0,0 +1,28 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct GetUserCredentialsQueryView {
+    user_id: u64,
+}
+
+impl GetUserCredentialsQueryView {
+    pub fn new(user_id: u64) -> Self {
+        Self { user_id }
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+}
+
+impl DatabaseQueryView for GetUserCredentialsQueryView {
+    fn get_request(&self) -> String {
+        "SELECT * FROM webauthn_credentials WHERE user_id = $1 ORDER BY created_at".to_string()
+    }
+}
+
+impl Display for GetUserCredentialsQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GetUserCredentialsQueryView: user_id = {}", self.user_id)
+    }
+}
@@ -0,0 +1,9 @@
+pub mod create_credential;
+pub mod delete_credential;
+pub mod get_credential_by_credential_id;
+pub mod get_user_credentials;
+pub mod rename_credential;
+pub mod update_credential_usage;
+
+mod view;
+pub use view::WebauthnCredential;
@@ -0,0 +1,5 @@
+mod query;
+pub use query::rename_credential_query;
+
+mod view;
+pub use view::RenameCredentialQueryView;
@@ -0,0 +1,21 @@
+use crate::database::webauthn::rename_credential::RenameCredentialQueryView;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+/**
+ * Returns false when the passkey does not exist or belongs to another user.
+ */
+pub async fn rename_credential_query(
+    view: RenameCredentialQueryView,
+    pool: PgPool,
+) -> Result<bool, DatabaseError> {
+    let result = sqlx::query(&view.get_request())
+        .bind(view.get_name())
+        .bind(view.get_id() as i32)
+        .bind(view.get_user_id() as i32)
+        .execute(&pool)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
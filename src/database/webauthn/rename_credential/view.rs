@@ -0,0 +1,46 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct RenameCredentialQueryView {
+    id: u64,
+    user_id: u64,
+    name: String,
+}
+
+impl RenameCredentialQueryView {
+    pub fn new(id: u64, user_id: u64, name: &str) -> Self {
+        Self {
+            id,
+            user_id,
+            name: name.to_string(),
+        }
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn get_user_id(&self) -> u64 {
+        self.user_id
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl DatabaseQueryView for RenameCredentialQueryView {
+    fn get_request(&self) -> String {
+        "UPDATE webauthn_credentials SET name = $1 WHERE id = $2 AND user_id = $3".to_string()
+    }
+}
+
+impl Display for RenameCredentialQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "RenameCredentialQueryView: id = {}, user_id = {}, name = {}",
+            self.id, self.user_id, self.name
+        )
+    }
+}
@@ -0,0 +1,5 @@
+mod query;
+pub use query::update_credential_usage_query;
+
+mod view;
+pub use view::UpdateCredentialUsageQueryView;
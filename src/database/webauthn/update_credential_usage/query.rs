@@ -0,0 +1,20 @@
+use crate::database::webauthn::update_credential_usage::UpdateCredentialUsageQueryView;
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+/**
+ * Returns false when the signature counter went backwards, which hints at a cloned authenticator.
+ */
+pub async fn update_credential_usage_query(
+    view: UpdateCredentialUsageQueryView,
+    pool: PgPool,
+) -> Result<bool, DatabaseError> {
+    let result = sqlx::query(&view.get_request())
+        .bind(view.get_id() as i32)
+        .bind(view.get_sign_count() as i64)
+        .execute(&pool)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
@@ -0,0 +1,40 @@
+use mairie360_api_lib::database::db_interface::DatabaseQueryView;
+use std::fmt::Display;
+
+pub struct UpdateCredentialUsageQueryView {
+    id: u64,
+    sign_count: u32,
+}
+
+impl UpdateCredentialUsageQueryView {
+    pub fn new(id: u64, sign_count: u32) -> Self {
+        Self { id, sign_count }
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn get_sign_count(&self) -> u32 {
+        self.sign_count
+    }
+}
+
+impl DatabaseQueryView for UpdateCredentialUsageQueryView {
+    fn get_request(&self) -> String {
+        // Les authentificateurs sans compteur renvoient toujours 0 (WebAuthn §6.1.1)
+        "UPDATE webauthn_credentials SET sign_count = $2, last_used_at = NOW()
+        WHERE id = $1 AND (sign_count < $2 OR (sign_count = 0 AND $2 = 0))"
+            .to_string()
+    }
+}
+
+impl Display for UpdateCredentialUsageQueryView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "UpdateCredentialUsageQueryView: id = {}, sign_count = {}",
+            self.id, self.sign_count
+        )
+    }
+}
@@ -0,0 +1,52 @@
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Eq, PartialEq, sqlx::FromRow)]
+pub struct WebauthnCredential {
+    id: i32,
+    user_id: i32,
+    credential_id: String,
+    public_key: Vec<u8>,
+    algorithm: i32,
+    sign_count: i64,
+    name: String,
+    created_at: DateTime<Utc>,
+    last_used_at: Option<DateTime<Utc>>,
+}
+
+impl WebauthnCredential {
+    pub fn id(&self) -> i32 {
+        self.id
+    }
+
+    pub fn user_id(&self) -> i32 {
+        self.user_id
+    }
+
+    pub fn credential_id(&self) -> &str {
+        &self.credential_id
+    }
+
+    pub fn public_key(&self) -> &[u8] {
+        &self.public_key
+    }
+
+    pub fn algorithm(&self) -> i32 {
+        self.algorithm
+    }
+
+    pub fn sign_count(&self) -> i64 {
+        self.sign_count
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn last_used_at(&self) -> Option<&DateTime<Utc>> {
+        self.last_used_at.as_ref()
+    }
+}
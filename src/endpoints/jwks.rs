@@ -0,0 +1,34 @@
+use actix_web::{get, HttpResponse, Responder};
+use chrono::Utc;
+use utoipa::OpenApi;
+
+use crate::security::jwt::key_store;
+
+/**
+ * Publishes the public keys access tokens are signed with, so other mairie360 modules
+ * can verify them without sharing any secret. Tokens name their key in the `kid` header.
+ */
+#[utoipa::path(
+    get,
+    path = ".well-known/jwks.json",
+    responses(
+        (status = 200, description = "JSON Web Key Set of the token signing keys", content_type = "application/json"),
+        (status = 500, description = "Signing keys are not configured")
+    )
+)]
+#[get("/.well-known/jwks.json")]
+pub async fn jwks() -> impl Responder {
+    match key_store() {
+        Ok(store) => HttpResponse::Ok()
+            .insert_header(("Cache-Control", "public, max-age=300"))
+            .json(store.jwks(Utc::now())),
+        Err(e) => {
+            eprintln!("JWT Key Error: {}", e);
+            HttpResponse::InternalServerError().body("Signing keys are not configured.")
+        }
+    }
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(jwks))]
+pub struct JwksDoc;
@@ -0,0 +1,24 @@
+use crate::janitor::metrics::render;
+use actix_web::{get, HttpResponse, Responder};
+use utoipa::OpenApi;
+
+/** * Handles a GET request to the /metrics endpoint.
+ * Exposes the counters of this instance in the Prometheus text format.
+ */
+#[utoipa::path(
+    get,
+    path = "metrics",
+    responses(
+        (status = 200, description = "Prometheus metrics of this instance", body = String, content_type = "text/plain")
+    )
+)]
+#[get("/metrics")]
+pub async fn metrics() -> impl Responder {
+    HttpResponse::Ok()
+        .content_type("text/plain; version=0.0.4")
+        .body(render())
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(metrics,))]
+pub struct MetricsDoc;
@@ -1,5 +1,8 @@
 pub mod health;
 pub mod hello;
+pub mod jwks;
+pub mod metrics;
+pub mod openid_configuration;
 pub mod swagger;
 pub mod v1;
 
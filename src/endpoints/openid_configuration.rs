@@ -0,0 +1,85 @@
+use actix_web::{get, HttpResponse, Responder};
+use serde::Serialize;
+use utoipa::OpenApi;
+
+use crate::endpoints::v1::oidc::oidc_issuer;
+use crate::security::oidc::{PKCE_METHOD, SUPPORTED_SCOPES};
+
+/**
+ * OpenID Connect discovery metadata, enough for standard client libraries to configure
+ * themselves from `OIDC_ISSUER`.
+ */
+#[derive(Serialize)]
+struct OpenIdConfiguration {
+    issuer: String,
+    authorization_endpoint: String,
+    token_endpoint: String,
+    userinfo_endpoint: String,
+    jwks_uri: String,
+    response_types_supported: Vec<&'static str>,
+    grant_types_supported: Vec<&'static str>,
+    subject_types_supported: Vec<&'static str>,
+    id_token_signing_alg_values_supported: Vec<&'static str>,
+    scopes_supported: Vec<&'static str>,
+    token_endpoint_auth_methods_supported: Vec<&'static str>,
+    code_challenge_methods_supported: Vec<&'static str>,
+    claims_supported: Vec<&'static str>,
+}
+
+impl OpenIdConfiguration {
+    fn new(issuer: String) -> Self {
+        Self {
+            authorization_endpoint: format!("{}/api/v1/oidc/authorize", issuer),
+            token_endpoint: format!("{}/api/v1/oauth/token", issuer),
+            userinfo_endpoint: format!("{}/api/v1/oidc/userinfo", issuer),
+            jwks_uri: format!("{}/.well-known/jwks.json", issuer),
+            issuer,
+            response_types_supported: vec!["code"],
+            grant_types_supported: vec!["authorization_code", "client_credentials"],
+            subject_types_supported: vec!["public"],
+            id_token_signing_alg_values_supported: vec!["EdDSA", "RS256"],
+            scopes_supported: SUPPORTED_SCOPES.to_vec(),
+            token_endpoint_auth_methods_supported: vec![
+                "none",
+                "client_secret_basic",
+                "client_secret_post",
+            ],
+            code_challenge_methods_supported: vec![PKCE_METHOD],
+            claims_supported: vec![
+                "sub",
+                "name",
+                "given_name",
+                "family_name",
+                "email",
+                "phone_number",
+                "nonce",
+                "sid",
+            ],
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = ".well-known/openid-configuration",
+    responses(
+        (status = 200, description = "OpenID Connect discovery document", content_type = "application/json"),
+        (status = 500, description = "`OIDC_ISSUER` is not configured")
+    )
+)]
+#[get("/.well-known/openid-configuration")]
+pub async fn openid_configuration() -> impl Responder {
+    match oidc_issuer() {
+        Some(issuer) => HttpResponse::Ok()
+            .insert_header(("Cache-Control", "public, max-age=300"))
+            .json(OpenIdConfiguration::new(issuer)),
+        None => {
+            eprintln!("OIDC_ISSUER is missing");
+            HttpResponse::InternalServerError().body("OpenID Connect is not configured.")
+        }
+    }
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(openid_configuration))]
+pub struct OpenIdConfigurationDoc;
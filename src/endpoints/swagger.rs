@@ -1,5 +1,8 @@
 use crate::endpoints::health::HealthDoc;
 use crate::endpoints::hello::HelloDoc;
+use crate::endpoints::jwks::JwksDoc;
+use crate::endpoints::metrics::MetricsDoc;
+use crate::endpoints::openid_configuration::OpenIdConfigurationDoc;
 use crate::endpoints::v1::doc::V1Doc;
 use utoipa::openapi::security::{Http, HttpAuthScheme, SecurityScheme};
 use utoipa::{Modify, OpenApi};
@@ -10,6 +13,9 @@ use utoipa::{Modify, OpenApi};
         (path = "/api/v1", api = V1Doc),
         (path = "/", api = HealthDoc),
         (path = "/", api = HelloDoc),
+        (path = "/", api = JwksDoc),
+        (path = "/", api = MetricsDoc),
+        (path = "/", api = OpenIdConfigurationDoc),
     ),
     modifiers(&SecurityAddon) // On ajoute le modifier ici
 )]
@@ -23,6 +29,10 @@ impl Modify for SecurityAddon {
         components.add_security_scheme(
             "jwt",
             SecurityScheme::Http(Http::new(HttpAuthScheme::Bearer)),
+        );
+        components.add_security_scheme(
+            "client_credentials",
+            SecurityScheme::Http(Http::new(HttpAuthScheme::Basic)),
         )
     }
 }
@@ -1,12 +1,16 @@
+use crate::endpoints::v1::admin::impersonations::doc::ImpersonationsDoc;
+use crate::endpoints::v1::admin::invitations::doc::InvitationsDoc;
 use crate::endpoints::v1::admin::roles::doc::RolesDoc;
-// use crate::endpoints::v1::admin::sessions::doc::SessionsDoc;
+use crate::endpoints::v1::admin::sessions::doc::SessionsDoc;
 use crate::endpoints::v1::admin::users::doc::UsersDoc;
 use utoipa::OpenApi;
 
 #[derive(OpenApi)]
 #[openapi(nest(
+    (path = "/impersonations", api = ImpersonationsDoc, tags = ["Admin - Impersonations"]),
+    (path = "/invitations", api = InvitationsDoc, tags = ["Admin - Invitations"]),
     (path = "/roles", api = RolesDoc, tags = ["Admin - Roles"]),
-    // (path = "/sessions", api = SessionsDoc, tags = ["Admin - Sessions"]),
+    (path = "/sessions", api = SessionsDoc, tags = ["Admin - Sessions"]),
     (path = "/users", api = UsersDoc, tags = ["Admin - Users"]),
 ))]
 pub struct AdminDoc;
@@ -0,0 +1,15 @@
+use crate::endpoints::v1::admin::impersonations::endpoint;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        endpoint::admin_end_impersonation,
+        endpoint::admin_get_impersonated_requests
+    ),
+    components(schemas(
+        super::response_view::ImpersonatedRequestsResponseView,
+        super::response_view::ImpersonatedRequestSchema
+    ))
+)]
+pub struct ImpersonationsDoc;
@@ -0,0 +1,149 @@
+use actix_web::{
+    delete, error::ResponseError, get, http::StatusCode, web, HttpResponse, Responder,
+};
+use mairie360_api_lib::pool::AppState;
+use mairie360_api_lib::security::AuthenticatedUser;
+use uuid::Uuid;
+
+use crate::database::impersonations::end_impersonation::{
+    end_impersonation_query, EndImpersonationQueryView,
+};
+use crate::database::impersonations::get_impersonated_requests::{
+    get_impersonated_requests_query, GetImpersonatedRequestsQueryView,
+};
+use crate::database::security_events::create_security_event::{
+    create_security_event_query, CreateSecurityEventQueryView,
+};
+use crate::endpoints::v1::admin::impersonations::response_view::ImpersonatedRequestsResponseView;
+use crate::endpoints::v1::admin::impersonations::IMPERSONATION_ENDED_EVENT;
+
+#[derive(Debug, Clone, PartialEq)]
+enum ImpersonationError {
+    DatabaseError,
+    NotFound,
+}
+
+impl std::fmt::Display for ImpersonationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImpersonationError::DatabaseError => write!(f, "Database error occurred"),
+            ImpersonationError::NotFound => write!(f, "No ongoing impersonation with this id"),
+        }
+    }
+}
+
+impl ResponseError for ImpersonationError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            ImpersonationError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            ImpersonationError::NotFound => StatusCode::NOT_FOUND,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+async fn end_impersonation(
+    state: web::Data<AppState>,
+    admin_id: u64,
+    impersonation_id: Uuid,
+) -> Result<(), ImpersonationError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(ImpersonationError::DatabaseError),
+    };
+
+    // Le middleware refuse le jeton dès que l'impersonation est terminée
+    let ended = end_impersonation_query(
+        EndImpersonationQueryView::new(impersonation_id),
+        pool.clone(),
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("Error: {}", e);
+        ImpersonationError::DatabaseError
+    })?;
+    if !ended {
+        return Err(ImpersonationError::NotFound);
+    }
+
+    let event = CreateSecurityEventQueryView::new(
+        Some(admin_id),
+        IMPERSONATION_ENDED_EVENT,
+        None,
+        Some(format!("impersonation_id = {}", impersonation_id)),
+    );
+    create_security_event_query(event, pool).await.map_err(|e| {
+        eprintln!("Error: {}", e);
+        ImpersonationError::DatabaseError
+    })
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{impersonationId}",
+    params(
+        ("impersonationId" = String, Path, description = "ID de l'impersonation")
+    ),
+    responses(
+        (status = 204, description = "Impersonation ended, its access token no longer works"),
+        (status = 404, description = "No ongoing impersonation with this id"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Admin - Impersonations",
+    security(
+        ("jwt" = [])
+    )
+)]
+#[delete("/{impersonationId}")]
+pub async fn admin_end_impersonation(
+    admin: AuthenticatedUser,
+    state: web::Data<AppState>,
+    path: web::Path<Uuid>,
+) -> Result<impl Responder, ImpersonationError> {
+    end_impersonation(state, admin.id, path.into_inner()).await?;
+
+    Ok(HttpResponse::NoContent())
+}
+
+#[utoipa::path(
+    get,
+    path = "/{impersonationId}/requests",
+    params(
+        ("impersonationId" = String, Path, description = "ID de l'impersonation")
+    ),
+    responses(
+        (status = 200, description = "Requests made during the impersonation, oldest first", body = ImpersonatedRequestsResponseView),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Admin - Impersonations",
+    security(
+        ("jwt" = [])
+    )
+)]
+#[get("/{impersonationId}/requests")]
+pub async fn admin_get_impersonated_requests(
+    state: web::Data<AppState>,
+    path: web::Path<Uuid>,
+) -> Result<impl Responder, ImpersonationError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(ImpersonationError::DatabaseError),
+    };
+
+    let view = GetImpersonatedRequestsQueryView::new(path.into_inner());
+    let requests = get_impersonated_requests_query(view, pool)
+        .await
+        .map_err(|e| {
+            eprintln!("Error: {}", e);
+            ImpersonationError::DatabaseError
+        })?;
+
+    Ok(
+        HttpResponse::Ok().json(ImpersonatedRequestsResponseView::new(
+            requests.into_iter().map(|r| r.into()).collect(),
+        )),
+    )
+}
@@ -0,0 +1,28 @@
+pub mod doc;
+pub mod endpoint;
+pub mod response_view;
+
+use actix_web::web;
+use mairie360_api_lib::env_manager::get_env_var;
+
+pub const IMPERSONATION_STARTED_EVENT: &str = "impersonation_started";
+pub const IMPERSONATION_ENDED_EVENT: &str = "impersonation_ended";
+
+const DEFAULT_IMPERSONATION_TTL: u64 = 900;
+
+pub fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/impersonations")
+            .service(endpoint::admin_end_impersonation)
+            .service(endpoint::admin_get_impersonated_requests),
+    );
+}
+
+/**
+ * Lifetime in seconds of an impersonation and of its access token, which is never refreshed.
+ */
+pub fn impersonation_ttl() -> u64 {
+    get_env_var("IMPERSONATION_TTL")
+        .and_then(|value| value.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_IMPERSONATION_TTL)
+}
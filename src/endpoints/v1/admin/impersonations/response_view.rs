@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use utoipa::ToSchema;
+
+use crate::database::impersonations::ImpersonatedRequest;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ImpersonatedRequestSchema {
+    id: i32,
+    admin_id: i32,
+    user_id: i32,
+    method: String,
+    path: String,
+    created_at: String,
+}
+
+impl From<ImpersonatedRequest> for ImpersonatedRequestSchema {
+    fn from(request: ImpersonatedRequest) -> Self {
+        ImpersonatedRequestSchema {
+            id: request.id(),
+            admin_id: request.admin_id(),
+            user_id: request.user_id(),
+            method: request.method().to_string(),
+            path: request.path().to_string(),
+            created_at: request.created_at().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ImpersonatedRequestsResponseView {
+    requests: Vec<ImpersonatedRequestSchema>,
+}
+
+impl ImpersonatedRequestsResponseView {
+    pub fn new(requests: Vec<ImpersonatedRequestSchema>) -> Self {
+        ImpersonatedRequestsResponseView { requests }
+    }
+}
+
+impl Display for ImpersonatedRequestsResponseView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ImpersonatedRequestsResponseView {{ requests: {:?} }}",
+            self.requests
+        )
+    }
+}
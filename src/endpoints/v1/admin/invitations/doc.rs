@@ -0,0 +1,16 @@
+use crate::endpoints::v1::admin::invitations::endpoint;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        endpoint::admin_get_invitations,
+        endpoint::admin_resend_invitation,
+        endpoint::admin_revoke_invitation
+    ),
+    components(schemas(
+        super::response_view::InvitationsResponseView,
+        super::response_view::InvitationSchema
+    ))
+)]
+pub struct InvitationsDoc;
@@ -0,0 +1,183 @@
+use actix_web::{
+    delete, error::ResponseError, get, http::StatusCode, post, web, HttpResponse, Responder,
+};
+use mairie360_api_lib::pool::AppState;
+
+use crate::database::invitations::delete_invitation::{
+    delete_invitation_query, DeleteInvitationQueryView,
+};
+use crate::database::invitations::get_invitations::{
+    get_invitations_query, GetInvitationsQueryView,
+};
+use crate::database::invitations::renew_invitation::{
+    renew_invitation_query, RenewInvitationQueryView,
+};
+use crate::database::users::delete_user::{delete_user_query, DeleteUserQueryView};
+use crate::endpoints::v1::admin::invitations::response_view::InvitationsResponseView;
+use crate::endpoints::v1::admin::invitations::{invitation_ttl, send_invitation_email};
+use crate::security::token::{generate_token, hash_token};
+
+#[derive(Debug, Clone, PartialEq)]
+enum InvitationError {
+    DatabaseError,
+    NotFound,
+}
+
+impl std::fmt::Display for InvitationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvitationError::DatabaseError => write!(f, "Database error occurred"),
+            InvitationError::NotFound => write!(f, "No pending invitation for this user"),
+        }
+    }
+}
+
+impl ResponseError for InvitationError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            InvitationError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            InvitationError::NotFound => StatusCode::NOT_FOUND,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "",
+    responses(
+        (status = 200, description = "Invitations not accepted yet, expired ones included, most recent first", body = InvitationsResponseView),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Admin - Invitations",
+    security(
+        ("jwt" = [])
+    )
+)]
+#[get("/")]
+pub async fn admin_get_invitations(
+    state: web::Data<AppState>,
+) -> Result<impl Responder, InvitationError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(InvitationError::DatabaseError),
+    };
+
+    let invitations = get_invitations_query(GetInvitationsQueryView {}, pool)
+        .await
+        .map_err(|e| {
+            eprintln!("Error: {}", e);
+            InvitationError::DatabaseError
+        })?;
+
+    Ok(HttpResponse::Ok().json(InvitationsResponseView::new(
+        invitations.into_iter().map(|i| i.into()).collect(),
+    )))
+}
+
+async fn resend_invitation(
+    state: web::Data<AppState>,
+    user_id: u64,
+) -> Result<(), InvitationError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(InvitationError::DatabaseError),
+    };
+
+    // Un nouveau jeton remplace l'ancien : le lien précédent ne fonctionne plus
+    let token = generate_token();
+    let view = RenewInvitationQueryView::new(user_id, &hash_token(&token), invitation_ttl());
+    let email = renew_invitation_query(view, pool)
+        .await
+        .map_err(|e| {
+            eprintln!("Error: {}", e);
+            InvitationError::DatabaseError
+        })?
+        .ok_or(InvitationError::NotFound)?;
+
+    send_invitation_email(&email, &token).await;
+    Ok(())
+}
+
+#[utoipa::path(
+    post,
+    path = "/{userId}/resend",
+    params(
+        ("userId" = u64, Path, description = "ID de l'utilisateur invité")
+    ),
+    responses(
+        (status = 202, description = "A new invitation link is sent and the previous one no longer works"),
+        (status = 404, description = "No pending invitation for this user"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Admin - Invitations",
+    security(
+        ("jwt" = [])
+    )
+)]
+#[post("/{userId}/resend")]
+pub async fn admin_resend_invitation(
+    state: web::Data<AppState>,
+    path: web::Path<u64>,
+) -> Result<impl Responder, InvitationError> {
+    resend_invitation(state, path.into_inner()).await?;
+
+    Ok(HttpResponse::Accepted())
+}
+
+async fn revoke_invitation(
+    state: web::Data<AppState>,
+    user_id: u64,
+) -> Result<(), InvitationError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(InvitationError::DatabaseError),
+    };
+
+    let revoked = delete_invitation_query(DeleteInvitationQueryView::new(user_id), pool.clone())
+        .await
+        .map_err(|e| {
+            eprintln!("Error: {}", e);
+            InvitationError::DatabaseError
+        })?;
+    if !revoked {
+        return Err(InvitationError::NotFound);
+    }
+
+    // Le compte n'a jamais été activé : il disparaît avec son invitation
+    delete_user_query(DeleteUserQueryView::new(user_id), pool)
+        .await
+        .map_err(|e| {
+            eprintln!("Error: {}", e);
+            InvitationError::DatabaseError
+        })
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{userId}",
+    params(
+        ("userId" = u64, Path, description = "ID de l'utilisateur invité")
+    ),
+    responses(
+        (status = 204, description = "Invitation revoked and the account that was never activated deleted"),
+        (status = 404, description = "No pending invitation for this user"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Admin - Invitations",
+    security(
+        ("jwt" = [])
+    )
+)]
+#[delete("/{userId}")]
+pub async fn admin_revoke_invitation(
+    state: web::Data<AppState>,
+    path: web::Path<u64>,
+) -> Result<impl Responder, InvitationError> {
+    revoke_invitation(state, path.into_inner()).await?;
+
+    Ok(HttpResponse::NoContent())
+}
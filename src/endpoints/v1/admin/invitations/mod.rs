@@ -0,0 +1,70 @@
+pub mod doc;
+pub mod endpoint;
+pub mod response_view;
+
+use actix_web::web;
+use mairie360_api_lib::env_manager::get_env_var;
+
+use crate::{build_email, get_email_link_url, get_email_sender, send_email, EmailDestination};
+
+const DEFAULT_INVITATION_TTL: u64 = 604800;
+
+pub fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/invitations")
+            .service(endpoint::admin_get_invitations)
+            .service(endpoint::admin_resend_invitation)
+            .service(endpoint::admin_revoke_invitation),
+    );
+}
+
+pub fn invitation_ttl() -> u64 {
+    get_env_var("INVITATION_TTL")
+        .and_then(|value| value.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_INVITATION_TTL)
+}
+
+/**
+ * Emails a link to the front-end page (`INVITATION_URL`) that posts the token, with the
+ * chosen password, to `/auth/accept_invitation`. Failures are only logged: an admin can resend it.
+ */
+pub async fn send_invitation_email(email: &str, token: &str) -> bool {
+    let url = match get_email_link_url("INVITATION_URL") {
+        Some(url) => url,
+        None => return false,
+    };
+    let destination = EmailDestination {
+        from: match get_email_sender() {
+            Ok(sender) => sender,
+            Err(e) => {
+                eprintln!("Email Sender Error: {}", e);
+                return false;
+            }
+        },
+        to: email.to_string(),
+    };
+    let subject = "Invitation à rejoindre Mairie360";
+    let body = format!(
+        "Bonjour, un compte Mairie360 a été créé pour vous. \
+        Choisissez votre mot de passe en ouvrant ce lien : {}?token={} \
+        Ce lien n'est valable qu'une fois et expire dans {} heures.",
+        url,
+        token,
+        invitation_ttl() / 3600
+    );
+
+    let message = match build_email(&destination, subject, &body) {
+        Ok(message) => message,
+        Err(e) => {
+            eprintln!("Email Build Error: {}", e);
+            return false;
+        }
+    };
+    match send_email(message).await {
+        Ok(_) => true,
+        Err(e) => {
+            eprintln!("Mail Error: {}", e);
+            false
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use utoipa::ToSchema;
+
+use crate::database::invitations::Invitation;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct InvitationSchema {
+    user_id: i32,
+    email: String,
+    first_name: String,
+    last_name: String,
+    invited_by: Option<i32>,
+    created_at: String,
+    expires_at: String,
+}
+
+impl From<Invitation> for InvitationSchema {
+    fn from(invitation: Invitation) -> Self {
+        InvitationSchema {
+            user_id: invitation.user_id(),
+            email: invitation.email().to_string(),
+            first_name: invitation.first_name().to_string(),
+            last_name: invitation.last_name().to_string(),
+            invited_by: invitation.invited_by(),
+            created_at: invitation.created_at().to_string(),
+            expires_at: invitation.expires_at().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct InvitationsResponseView {
+    invitations: Vec<InvitationSchema>,
+}
+
+impl InvitationsResponseView {
+    pub fn new(invitations: Vec<InvitationSchema>) -> Self {
+        InvitationsResponseView { invitations }
+    }
+}
+
+impl Display for InvitationsResponseView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "InvitationsResponseView {{ invitations: {:?} }}",
+            self.invitations
+        )
+    }
+}
@@ -1,6 +1,8 @@
 pub mod doc;
+pub mod impersonations;
+pub mod invitations;
 pub mod roles;
-// pub mod sessions;
+pub mod sessions;
 pub mod users;
 
 use actix_web::web;
@@ -10,8 +12,10 @@ pub fn config(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/admin")
             // .wrap(AdminMiddleware)
+            .configure(impersonations::config)
+            .configure(invitations::config)
             .configure(roles::config)
-            // .configure(sessions::config)
+            .configure(sessions::config)
             .configure(users::config),
     );
 }
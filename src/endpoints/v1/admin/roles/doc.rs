@@ -5,6 +5,10 @@ use crate::endpoints::v1::admin::roles::get::endpoint::__path_admin_get_role;
 use crate::endpoints::v1::admin::roles::patch::endpoint::__path_admin_patch_role;
 use crate::endpoints::v1::admin::roles::post::endpoint::__path_admin_post_role;
 use crate::endpoints::v1::admin::roles::put::endpoint::__path_admin_put_role;
+use crate::endpoints::v1::admin::roles::session_policy::endpoint::{
+    __path_admin_delete_role_session_policy, __path_admin_get_role_session_policy,
+    __path_admin_put_role_session_policy,
+};
 
 #[derive(OpenApi)]
 #[openapi(
@@ -13,12 +17,16 @@ use crate::endpoints::v1::admin::roles::put::endpoint::__path_admin_put_role;
         admin_get_role,
         admin_patch_role,
         admin_post_role,
-        admin_put_role
+        admin_put_role,
+        admin_delete_role_session_policy,
+        admin_get_role_session_policy,
+        admin_put_role_session_policy
     ),
     components(schemas(
         super::view::RoleWriteView,
         super::get::view::GetResponseView,
         super::patch::view::PatchView,
+        super::session_policy::view::SessionPolicyView,
     ))
 )]
 pub struct RolesDoc;
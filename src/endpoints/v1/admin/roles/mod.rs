@@ -4,6 +4,7 @@ mod get;
 mod patch;
 mod post;
 mod put;
+mod session_policy;
 pub mod view;
 
 use actix_web::web;
@@ -15,6 +16,9 @@ pub fn config(cfg: &mut web::ServiceConfig) {
             .service(get::endpoint::admin_get_role)
             .service(patch::endpoint::admin_patch_role)
             .service(post::endpoint::admin_post_role)
-            .service(put::endpoint::admin_put_role),
+            .service(put::endpoint::admin_put_role)
+            .service(session_policy::endpoint::admin_delete_role_session_policy)
+            .service(session_policy::endpoint::admin_get_role_session_policy)
+            .service(session_policy::endpoint::admin_put_role_session_policy),
     );
 }
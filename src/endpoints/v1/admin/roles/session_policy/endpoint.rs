@@ -0,0 +1,184 @@
+use crate::database::roles::does_role_exist::{does_role_exist_query, DoesRoleExistQueryView};
+use crate::database::session_policies::delete_role_session_policy::{
+    delete_role_session_policy_query, DeleteRoleSessionPolicyQueryView,
+};
+use crate::database::session_policies::get_role_session_policy::{
+    get_role_session_policy_query, GetRoleSessionPolicyQueryView,
+};
+use crate::database::session_policies::put_role_session_policy::{
+    put_role_session_policy_query, PutRoleSessionPolicyQueryView,
+};
+use crate::endpoints::v1::admin::roles::session_policy::view::SessionPolicyView;
+
+use actix_web::http::StatusCode;
+use actix_web::{delete, get, put, web, HttpResponse, Responder, ResponseError};
+use mairie360_api_lib::pool::AppState;
+use sqlx::PgPool;
+
+#[derive(Debug, Clone, PartialEq)]
+enum SessionPolicyError {
+    BadRequest,
+    DatabaseError,
+    NotFound,
+}
+
+impl std::fmt::Display for SessionPolicyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SessionPolicyError::BadRequest => {
+                write!(f, "limit_action must be evict_oldest or refuse.")
+            }
+            SessionPolicyError::DatabaseError => {
+                write!(f, "An error occurred while accessing the database.")
+            }
+            SessionPolicyError::NotFound => {
+                write!(f, "The requested resource was not found.")
+            }
+        }
+    }
+}
+
+impl ResponseError for SessionPolicyError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            SessionPolicyError::BadRequest => StatusCode::BAD_REQUEST,
+            SessionPolicyError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            SessionPolicyError::NotFound => StatusCode::NOT_FOUND,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+fn get_pool(state: &web::Data<AppState>) -> Result<PgPool, SessionPolicyError> {
+    match state.db_pool.clone() {
+        Some(pool) => Ok(pool),
+        None => Err(SessionPolicyError::DatabaseError),
+    }
+}
+
+async fn ensure_role_exists(role_id: u64, pool: PgPool) -> Result<(), SessionPolicyError> {
+    let exists = does_role_exist_query(DoesRoleExistQueryView::new(role_id), pool)
+        .await
+        .map_err(|e| {
+            eprintln!("Database Error: {}", e);
+            SessionPolicyError::DatabaseError
+        })?;
+    if !exists {
+        return Err(SessionPolicyError::NotFound);
+    }
+    Ok(())
+}
+
+#[utoipa::path(
+    get,
+    path = "/{id}/session_policy",
+    params(
+        ("id" = i32, Path, description = "Role database id")
+    ),
+    responses(
+        (status = 200, description = "Session policy of the role", body = SessionPolicyView),
+        (status = 404, description = "Unknown role, or the role has no session policy"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    ),
+    tag = "Admin - Roles"
+)]
+#[get("/{id}/session_policy")]
+pub async fn admin_get_role_session_policy(
+    id: web::Path<u64>,
+    state: web::Data<AppState>,
+) -> Result<impl Responder, SessionPolicyError> {
+    let pool = get_pool(&state)?;
+    let policy =
+        get_role_session_policy_query(GetRoleSessionPolicyQueryView::new(id.into_inner()), pool)
+            .await
+            .map_err(|e| {
+                eprintln!("Database Error: {}", e);
+                SessionPolicyError::DatabaseError
+            })?
+            .ok_or(SessionPolicyError::NotFound)?;
+
+    Ok(HttpResponse::Ok().json(SessionPolicyView::from(policy.to_policy())))
+}
+
+#[utoipa::path(
+    put,
+    path = "/{id}/session_policy",
+    request_body = SessionPolicyView,
+    params(
+        ("id" = i32, Path, description = "Role database id")
+    ),
+    responses(
+        (status = 204, description = "Session policy saved, it applies to the next logins"),
+        (status = 400, description = "Unknown limit_action"),
+        (status = 404, description = "Unknown role"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    ),
+    tag = "Admin - Roles"
+)]
+#[put("/{id}/session_policy")]
+pub async fn admin_put_role_session_policy(
+    id: web::Path<u64>,
+    payload: web::Json<SessionPolicyView>,
+    state: web::Data<AppState>,
+) -> Result<impl Responder, SessionPolicyError> {
+    let pool = get_pool(&state)?;
+    let role_id = id.into_inner();
+    let policy = payload.to_policy().ok_or(SessionPolicyError::BadRequest)?;
+    ensure_role_exists(role_id, pool.clone()).await?;
+
+    put_role_session_policy_query(PutRoleSessionPolicyQueryView::new(role_id, policy), pool)
+        .await
+        .map_err(|e| {
+            eprintln!("Database Error: {}", e);
+            SessionPolicyError::DatabaseError
+        })?;
+
+    Ok(HttpResponse::NoContent())
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{id}/session_policy",
+    params(
+        ("id" = i32, Path, description = "Role database id")
+    ),
+    responses(
+        (status = 204, description = "Session policy removed, the role falls back to the default"),
+        (status = 404, description = "Unknown role, or the role has no session policy"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    ),
+    tag = "Admin - Roles"
+)]
+#[delete("/{id}/session_policy")]
+pub async fn admin_delete_role_session_policy(
+    id: web::Path<u64>,
+    state: web::Data<AppState>,
+) -> Result<impl Responder, SessionPolicyError> {
+    let pool = get_pool(&state)?;
+    let deleted = delete_role_session_policy_query(
+        DeleteRoleSessionPolicyQueryView::new(id.into_inner()),
+        pool,
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("Database Error: {}", e);
+        SessionPolicyError::DatabaseError
+    })?;
+    if !deleted {
+        return Err(SessionPolicyError::NotFound);
+    }
+
+    Ok(HttpResponse::NoContent())
+}
@@ -0,0 +1,44 @@
+use crate::security::session_policy::{SessionLimitAction, SessionPolicy};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/**
+ * Session limits of a role; a missing or 0 value means no limit.
+ * `limit_action` is `evict_oldest` (default) or `refuse`.
+ */
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SessionPolicyView {
+    max_sessions: Option<u32>,
+    limit_action: Option<String>,
+    idle_timeout_minutes: Option<u32>,
+    absolute_lifetime_minutes: Option<u32>,
+}
+
+impl SessionPolicyView {
+    /**
+     * None when `limit_action` is not a known action.
+     */
+    pub fn to_policy(&self) -> Option<SessionPolicy> {
+        let limit_action = match &self.limit_action {
+            Some(action) => SessionLimitAction::parse(action)?,
+            None => SessionLimitAction::default(),
+        };
+        Some(SessionPolicy::new(
+            self.max_sessions,
+            limit_action,
+            self.idle_timeout_minutes,
+            self.absolute_lifetime_minutes,
+        ))
+    }
+}
+
+impl From<SessionPolicy> for SessionPolicyView {
+    fn from(policy: SessionPolicy) -> Self {
+        Self {
+            max_sessions: policy.max_sessions(),
+            limit_action: Some(policy.limit_action().as_str().to_string()),
+            idle_timeout_minutes: policy.idle_timeout_minutes(),
+            absolute_lifetime_minutes: policy.absolute_lifetime_minutes(),
+        }
+    }
+}
@@ -4,6 +4,9 @@ use utoipa::OpenApi;
 #[derive(OpenApi)]
 #[openapi(
     paths(endpoint::audit),
-    components(schemas(super::response_view::AuditResponseView),)
+    components(schemas(
+        super::response_view::AuditResponseView,
+        super::response_view::AuditSessionSchema
+    ))
 )]
 pub struct AuditDoc;
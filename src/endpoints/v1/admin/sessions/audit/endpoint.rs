@@ -1,33 +1,38 @@
-use crate::endpoints::v1::admin::sessions::audit::request_view::AuditPathParamRequestView;
+use crate::database::sessions::count_sessions_by_user::{
+    count_sessions_by_user_query, CountSessionsByUserQueryView,
+};
+use crate::database::sessions::get_sessions_by_user::{
+    get_sessions_by_user_query, GetSessionsByUserQueryView,
+};
+use crate::endpoints::v1::admin::sessions::audit::request_view::AuditQueryRequestView;
 use crate::endpoints::v1::admin::sessions::audit::response_view::AuditResponseView;
 
 use actix_web::http::StatusCode;
 use actix_web::{get, web, HttpResponse, Responder, ResponseError};
 use mairie360_api_lib::pool::AppState;
-// use serde_json;
 
 #[derive(Debug, Clone, PartialEq)]
-enum AboutError {
-    UserNotFound,
+enum AuditError {
     DatabaseError,
+    InvalidRange,
 }
 
-impl std::fmt::Display for AboutError {
+impl std::fmt::Display for AuditError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            AboutError::UserNotFound => write!(f, "User not found."),
-            AboutError::DatabaseError => {
+            AuditError::DatabaseError => {
                 write!(f, "An error occurred while accessing the database.")
             }
+            AuditError::InvalidRange => write!(f, "`from` must be before `to`."),
         }
     }
 }
 
-impl ResponseError for AboutError {
+impl ResponseError for AuditError {
     fn status_code(&self) -> StatusCode {
         match self {
-            AboutError::UserNotFound => StatusCode::UNAUTHORIZED, // On garde 401 selon tes specs
-            AboutError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            AuditError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            AuditError::InvalidRange => StatusCode::BAD_REQUEST,
         }
     }
 
@@ -36,28 +41,72 @@ impl ResponseError for AboutError {
     }
 }
 
+async fn audit_sessions(
+    state: web::Data<AppState>,
+    user_id: u64,
+    query: AuditQueryRequestView,
+) -> Result<AuditResponseView, AuditError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(AuditError::DatabaseError),
+    };
+    if let (Some(from), Some(to)) = (query.from(), query.to()) {
+        if from >= to {
+            return Err(AuditError::InvalidRange);
+        }
+    }
+
+    let view = GetSessionsByUserQueryView::new(user_id)
+        .active_only(query.active_only())
+        .created_between(query.from(), query.to())
+        .paginate(query.limit(), query.offset());
+    let sessions = get_sessions_by_user_query(view, pool.clone())
+        .await
+        .map_err(|e| {
+            eprintln!("Database Error: {}", e);
+            AuditError::DatabaseError
+        })?;
+    let count_view =
+        CountSessionsByUserQueryView::new(user_id, query.active_only(), query.from(), query.to());
+    let total = count_sessions_by_user_query(count_view, pool)
+        .await
+        .map_err(|e| {
+            eprintln!("Database Error: {}", e);
+            AuditError::DatabaseError
+        })?;
+
+    Ok(AuditResponseView::new(
+        sessions.into_iter().map(|s| s.into()).collect(),
+        total,
+        query.limit(),
+        query.offset(),
+    ))
+}
+
 #[utoipa::path(
     get,
-    path = "{user_id}/audit",
+    path = "{userId}/audit",
     responses(
-        (status = 200, description = "User info retrieved successfully", body = AuditResponseView),
-        (status = 401, description = "Invalid user ID"),
+        (status = 200, description = "Sessions of the user, revoked and expired ones included unless `active_only` is set, most recent first", body = AuditResponseView),
+        (status = 400, description = "Invalid filters"),
         (status = 500, description = "Internal server error")
     ),
     params(
-        ("user_id" = u64, Path, description = "The ID of the user"),
+        ("userId" = u64, Path, description = "ID de l'utilisateur"),
+        AuditQueryRequestView
     ),
     security(
         ("jwt" = [])
     ),
     tags = ["Admin - Sessions"]
 )]
-#[get("/{user_id}/audit")]
+#[get("/{userId}/audit")]
 pub async fn audit(
-    path: web::Path<AuditPathParamRequestView>,
+    path: web::Path<u64>,
+    query: web::Query<AuditQueryRequestView>,
     state: web::Data<AppState>,
-) -> Result<impl Responder, AboutError> {
-    let view = path.into_inner();
+) -> Result<impl Responder, AuditError> {
+    let response = audit_sessions(state, path.into_inner(), query.into_inner()).await?;
 
-    Ok(HttpResponse::Ok())
+    Ok(HttpResponse::Ok().json(response))
 }
@@ -1,24 +1,61 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
-use utoipa::ToSchema;
+use utoipa::IntoParams;
 
-#[derive(Serialize, Deserialize, ToSchema)]
-pub struct AuditPathParamRequestView {
-    pub user_id: u64,
+const DEFAULT_AUDIT_LIMIT: u64 = 50;
+const MAX_AUDIT_LIMIT: u64 = 200;
+
+#[derive(Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct AuditQueryRequestView {
+    /// Only sessions neither revoked nor expired
+    active_only: Option<bool>,
+    /// Sessions created at or after this date (RFC 3339)
+    #[param(value_type = Option<String>, format = DateTime)]
+    from: Option<DateTime<Utc>>,
+    /// Sessions created before this date (RFC 3339)
+    #[param(value_type = Option<String>, format = DateTime)]
+    to: Option<DateTime<Utc>>,
+    /// Page size, 50 by default and 200 at most
+    limit: Option<u64>,
+    /// Sessions to skip, most recent first
+    offset: Option<u64>,
 }
 
-impl AuditPathParamRequestView {
-    pub fn user_id(&self) -> u64 {
-        self.user_id
+impl AuditQueryRequestView {
+    pub fn active_only(&self) -> bool {
+        self.active_only.unwrap_or(false)
+    }
+
+    pub fn from(&self) -> Option<DateTime<Utc>> {
+        self.from
+    }
+
+    pub fn to(&self) -> Option<DateTime<Utc>> {
+        self.to
+    }
+
+    pub fn limit(&self) -> u64 {
+        self.limit
+            .unwrap_or(DEFAULT_AUDIT_LIMIT)
+            .clamp(1, MAX_AUDIT_LIMIT)
+    }
+
+    /**
+     * Capped to what Postgres accepts as an OFFSET.
+     */
+    pub fn offset(&self) -> u64 {
+        self.offset.unwrap_or(0).min(i64::MAX as u64)
     }
 }
 
-impl Display for AuditPathParamRequestView {
+impl Display for AuditQueryRequestView {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "AuditPathParamRequestView {{ user_id: {} }}",
-            self.user_id
+            "AuditQueryRequestView {{ active_only: {:?}, from: {:?}, to: {:?}, limit: {:?}, offset: {:?} }}",
+            self.active_only, self.from, self.to, self.limit, self.offset
         )
     }
 }
@@ -5,10 +5,14 @@ use utoipa::ToSchema;
 use crate::database::sessions::Session;
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
-struct AuditSessionSchema {
+pub struct AuditSessionSchema {
     id: String,
     user_id: i32,
     device_info: String,
+    browser: Option<String>,
+    os: Option<String>,
+    device_type: Option<String>,
+    app_version: Option<String>,
     ip_address: String,
     created_at: String,
     expires_at: String,
@@ -21,6 +25,10 @@ impl From<Session> for AuditSessionSchema {
             id: session.id().to_string(),
             user_id: session.user_id(),
             device_info: session.device_info().to_string(),
+            browser: session.browser().map(str::to_string),
+            os: session.os().map(str::to_string),
+            device_type: session.device_type().map(str::to_string),
+            app_version: session.app_version().map(str::to_string),
             ip_address: session.ip_address().to_string(),
             created_at: session.created_at().to_string(),
             expires_at: session.expires_at().to_string(),
@@ -32,26 +40,36 @@ impl From<Session> for AuditSessionSchema {
 #[derive(Serialize, Deserialize, ToSchema)]
 pub struct AuditResponseView {
     sessions: Vec<AuditSessionSchema>,
+    total: u64,
+    limit: u64,
+    offset: u64,
 }
 
 impl AuditResponseView {
-    pub fn new(sessions: Vec<AuditSessionSchema>) -> Self {
-        AuditResponseView { sessions }
+    pub fn new(sessions: Vec<AuditSessionSchema>, total: u64, limit: u64, offset: u64) -> Self {
+        AuditResponseView {
+            sessions,
+            total,
+            limit,
+            offset,
+        }
     }
 
     pub fn sessions(&self) -> &[AuditSessionSchema] {
         &self.sessions
     }
-}
 
-impl Display for AuditResponseView {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "AuditResponseView {{ sessions: {:?} }}", self.sessions)
+    pub fn total(&self) -> u64 {
+        self.total
     }
 }
 
-impl From<Vec<AuditSessionSchema>> for AuditResponseView {
-    fn from(sessions: Vec<AuditSessionSchema>) -> Self {
-        AuditResponseView { sessions }
+impl Display for AuditResponseView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "AuditResponseView {{ sessions: {:?}, total: {}, limit: {}, offset: {} }}",
+            self.sessions, self.total, self.limit, self.offset
+        )
     }
 }
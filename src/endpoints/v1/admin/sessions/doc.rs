@@ -1,6 +1,7 @@
 use crate::endpoints::v1::admin::sessions::audit::doc::AuditDoc;
 // use crate::endpoints::v1::admin::sessions::get::doc::GetDoc;
 use crate::endpoints::v1::admin::sessions::revoke::doc::RevokeDoc;
+use crate::endpoints::v1::admin::sessions::revoke_all::doc::RevokeAllDoc;
 use utoipa::OpenApi;
 
 #[derive(OpenApi)]
@@ -8,5 +9,6 @@ use utoipa::OpenApi;
     (path = "/", api = AuditDoc),
     // (path = "/", api = GetDoc),
     (path = "/", api = RevokeDoc),
+    (path = "/", api = RevokeAllDoc),
 ))]
 pub struct SessionsDoc;
@@ -2,14 +2,19 @@ pub mod audit;
 pub mod doc;
 // pub mod get;
 pub mod revoke;
+pub mod revoke_all;
 
 use actix_web::web;
 
+pub const SESSION_REVOKED_BY_ADMIN_EVENT: &str = "session_revoked_by_admin";
+pub const SESSIONS_REVOKED_BY_ADMIN_EVENT: &str = "sessions_revoked_by_admin";
+
 pub fn config(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/sessions")
             .service(audit::endpoint::audit)
             // .service(get::endpoint::get)
-            .service(revoke::endpoint::revoke),
+            .service(revoke::endpoint::revoke)
+            .service(revoke_all::endpoint::revoke_all),
     );
 }
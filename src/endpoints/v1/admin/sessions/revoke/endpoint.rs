@@ -1,32 +1,47 @@
+use crate::database::security_events::create_security_event::{
+    create_security_event_query, CreateSecurityEventQueryView,
+};
+use crate::database::sessions::revoke_session_by_id::{
+    revoke_session_by_id_query, RevokeSessionByIdQueryView,
+};
 use crate::endpoints::v1::admin::sessions::revoke::request_view::RevokeRequestView;
+use crate::endpoints::v1::admin::sessions::SESSION_REVOKED_BY_ADMIN_EVENT;
+use crate::endpoints::v1::sessions::revoke_access_tokens;
 
 use actix_web::http::StatusCode;
 use actix_web::{post, web, HttpResponse, Responder, ResponseError};
 
 use mairie360_api_lib::pool::AppState;
+use mairie360_api_lib::security::AuthenticatedUser;
+use uuid::Uuid;
 
 #[derive(Debug, Clone, PartialEq)]
-enum AboutError {
-    UserNotFound,
+enum RevokeError {
+    BadRequest,
     DatabaseError,
+    SessionNotFound,
 }
 
-impl std::fmt::Display for AboutError {
+impl std::fmt::Display for RevokeError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            AboutError::UserNotFound => write!(f, "User not found."),
-            AboutError::DatabaseError => {
+            RevokeError::BadRequest => write!(f, "Invalid session ID."),
+            RevokeError::DatabaseError => {
                 write!(f, "An error occurred while accessing the database.")
             }
+            RevokeError::SessionNotFound => {
+                write!(f, "No active session with this ID for this user.")
+            }
         }
     }
 }
 
-impl ResponseError for AboutError {
+impl ResponseError for RevokeError {
     fn status_code(&self) -> StatusCode {
         match self {
-            AboutError::UserNotFound => StatusCode::UNAUTHORIZED, // On garde 401 selon tes specs
-            AboutError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            RevokeError::BadRequest => StatusCode::BAD_REQUEST,
+            RevokeError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            RevokeError::SessionNotFound => StatusCode::NOT_FOUND,
         }
     }
 
@@ -35,13 +50,58 @@ impl ResponseError for AboutError {
     }
 }
 
+async fn revoke_session(
+    state: web::Data<AppState>,
+    admin_id: u64,
+    user_id: u64,
+    view: RevokeRequestView,
+) -> Result<(), RevokeError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(RevokeError::DatabaseError),
+    };
+    let session_id = Uuid::parse_str(view.session_id()).map_err(|_| RevokeError::BadRequest)?;
+
+    let revoked = revoke_session_by_id_query(
+        RevokeSessionByIdQueryView::new(user_id, session_id),
+        pool.clone(),
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("Database Error: {}", e);
+        RevokeError::DatabaseError
+    })?;
+    if !revoked {
+        return Err(RevokeError::SessionNotFound);
+    }
+    revoke_access_tokens(&state, &[session_id]).await;
+
+    let event = CreateSecurityEventQueryView::new(
+        Some(user_id),
+        SESSION_REVOKED_BY_ADMIN_EVENT,
+        None,
+        Some(format!(
+            "session_id = {}, revoked_by = {}",
+            session_id, admin_id
+        )),
+    );
+    if let Err(e) = create_security_event_query(event, pool).await {
+        eprintln!("Security Event DB Error: {}", e);
+    }
+    Ok(())
+}
+
 #[utoipa::path(
     post,
-    path = "revoke",
+    path = "{userId}/revoke",
     request_body = RevokeRequestView,
+    params(
+        ("userId" = u64, Path, description = "ID de l'utilisateur"),
+    ),
     responses(
-        (status = 200, description = "Token revoked successfully"),
-        (status = 401, description = "Invalid session ID"),
+        (status = 204, description = "Session revoked, its refresh and access tokens stop working"),
+        (status = 400, description = "Invalid session ID"),
+        (status = 404, description = "No active session with this ID for this user"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -49,12 +109,14 @@ impl ResponseError for AboutError {
     ),
     tags = ["Admin - Sessions"]
 )]
-#[post("/revoke")]
+#[post("/{userId}/revoke")]
 pub async fn revoke(
+    admin: AuthenticatedUser,
+    path: web::Path<u64>,
     body: web::Json<RevokeRequestView>,
     state: web::Data<AppState>,
-) -> Result<impl Responder, AboutError> {
-    let view = body.into_inner();
+) -> Result<impl Responder, RevokeError> {
+    revoke_session(state, admin.id, path.into_inner(), body.into_inner()).await?;
 
-    Ok(HttpResponse::Ok())
+    Ok(HttpResponse::NoContent())
 }
@@ -0,0 +1,6 @@
+use crate::endpoints::v1::admin::sessions::revoke_all::endpoint;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(paths(endpoint::revoke_all))]
+pub struct RevokeAllDoc;
@@ -0,0 +1,105 @@
+use crate::database::security_events::create_security_event::{
+    create_security_event_query, CreateSecurityEventQueryView,
+};
+use crate::database::sessions::revoke_user_sessions::{
+    revoke_user_sessions_query, RevokeUserSessionsQueryView,
+};
+use crate::endpoints::v1::admin::sessions::SESSIONS_REVOKED_BY_ADMIN_EVENT;
+use crate::endpoints::v1::sessions::revoke_access_tokens;
+
+use actix_web::http::StatusCode;
+use actix_web::{post, web, HttpResponse, Responder, ResponseError};
+
+use mairie360_api_lib::pool::AppState;
+use mairie360_api_lib::security::AuthenticatedUser;
+
+#[derive(Debug, Clone, PartialEq)]
+enum RevokeAllError {
+    DatabaseError,
+}
+
+impl std::fmt::Display for RevokeAllError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RevokeAllError::DatabaseError => {
+                write!(f, "An error occurred while accessing the database.")
+            }
+        }
+    }
+}
+
+impl ResponseError for RevokeAllError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            RevokeAllError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+async fn revoke_user_sessions(
+    state: web::Data<AppState>,
+    admin_id: u64,
+    user_id: u64,
+) -> Result<(), RevokeAllError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(RevokeAllError::DatabaseError),
+    };
+
+    let session_ids =
+        revoke_user_sessions_query(RevokeUserSessionsQueryView::new(user_id), pool.clone())
+            .await
+            .map_err(|e| {
+                eprintln!("Database Error: {}", e);
+                RevokeAllError::DatabaseError
+            })?;
+    revoke_access_tokens(&state, &session_ids).await;
+    if session_ids.is_empty() {
+        return Ok(());
+    }
+
+    let event = CreateSecurityEventQueryView::new(
+        Some(user_id),
+        SESSIONS_REVOKED_BY_ADMIN_EVENT,
+        None,
+        Some(format!(
+            "revoked_sessions = {}, revoked_by = {}",
+            session_ids.len(),
+            admin_id
+        )),
+    );
+    if let Err(e) = create_security_event_query(event, pool).await {
+        eprintln!("Security Event DB Error: {}", e);
+    }
+    Ok(())
+}
+
+#[utoipa::path(
+    post,
+    path = "{userId}/revoke_all",
+    params(
+        ("userId" = u64, Path, description = "ID de l'utilisateur"),
+    ),
+    responses(
+        (status = 204, description = "Every active session of the user revoked, the user has to log in again"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("jwt" = [])
+    ),
+    tags = ["Admin - Sessions"]
+)]
+#[post("/{userId}/revoke_all")]
+pub async fn revoke_all(
+    admin: AuthenticatedUser,
+    path: web::Path<u64>,
+    state: web::Data<AppState>,
+) -> Result<impl Responder, RevokeAllError> {
+    revoke_user_sessions(state, admin.id, path.into_inner()).await?;
+
+    Ok(HttpResponse::NoContent())
+}
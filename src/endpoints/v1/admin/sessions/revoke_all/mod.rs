@@ -1,3 +1,2 @@
 pub mod doc;
 pub mod endpoint;
-pub mod request_view;
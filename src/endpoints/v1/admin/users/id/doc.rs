@@ -1,12 +1,20 @@
 use crate::endpoints::v1::admin::users::id::delete::doc::DeleteUserDoc;
 use crate::endpoints::v1::admin::users::id::get::doc::GetUserDoc;
+use crate::endpoints::v1::admin::users::id::impersonate::doc::ImpersonateUserDoc;
+use crate::endpoints::v1::admin::users::id::mfa::doc::ResetUserMfaDoc;
 use crate::endpoints::v1::admin::users::id::patch::doc::PatchUserDoc;
 use crate::endpoints::v1::admin::users::id::roles::doc::RolesDoc;
+use crate::endpoints::v1::admin::users::id::unlock::doc::UnlockUserDoc;
+use crate::endpoints::v1::admin::users::id::verify_email::doc::VerifyUserEmailDoc;
 use utoipa::OpenApi;
 
 #[derive(OpenApi)]
 #[openapi(nest(
     (path = "/roles", api = RolesDoc),
+    (path = "/impersonate", api = ImpersonateUserDoc),
+    (path = "/mfa", api = ResetUserMfaDoc),
+    (path = "/unlock", api = UnlockUserDoc),
+    (path = "/verify_email", api = VerifyUserEmailDoc),
     (path = "/", api = DeleteUserDoc),
     (path = "/", api = GetUserDoc),
     (path = "/", api = PatchUserDoc),
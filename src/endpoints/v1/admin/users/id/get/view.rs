@@ -11,6 +11,10 @@ use utoipa::ToSchema;
 struct SessionResultView {
     id: String,
     device_info: String,
+    browser: Option<String>,
+    os: Option<String>,
+    device_type: Option<String>,
+    app_version: Option<String>,
     ip_address: String,
     created_at: String,
     expires_at: String,
@@ -22,6 +26,10 @@ impl From<&Session> for SessionResultView {
         Self {
             id: value.id().to_string(),
             device_info: value.device_info().to_string(),
+            browser: value.browser().map(str::to_string),
+            os: value.os().map(str::to_string),
+            device_type: value.device_type().map(str::to_string),
+            app_version: value.app_version().map(str::to_string),
             ip_address: value.ip_address().to_string(),
             created_at: value.created_at().to_string(),
             expires_at: value.expires_at().to_string(),
@@ -0,0 +1,12 @@
+use crate::endpoints::v1::admin::users::id::impersonate::endpoint;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(endpoint::admin_impersonate_user),
+    components(schemas(
+        super::view::ImpersonateUserView,
+        super::view::ImpersonateUserResponseView
+    ))
+)]
+pub struct ImpersonateUserDoc;
@@ -0,0 +1,196 @@
+use actix_web::{
+    dev::ConnectionInfo, error::ResponseError, http::StatusCode, post, web, HttpResponse, Responder,
+};
+use chrono::{Duration, Utc};
+use mairie360_api_lib::database::errors::DatabaseError;
+use mairie360_api_lib::database::queries::{is_admin_query, QueryError};
+use mairie360_api_lib::database::query_views::IsAdminQueryView;
+use mairie360_api_lib::pool::AppState;
+use mairie360_api_lib::security::AuthenticatedUser;
+use uuid::Uuid;
+
+use crate::database::impersonations::create_impersonation::{
+    create_impersonation_query, CreateImpersonationQueryView,
+};
+use crate::database::security_events::create_security_event::{
+    create_security_event_query, CreateSecurityEventQueryView,
+};
+use crate::database::users::get_user_by_id::{get_user_by_id_query, GetUserByIdQueryView};
+use crate::database::users::ACTIVE_USER_STATUS;
+use crate::endpoints::v1::admin::impersonations::{impersonation_ttl, IMPERSONATION_STARTED_EVENT};
+use crate::endpoints::v1::admin::users::id::impersonate::view::{
+    ImpersonateUserResponseView, ImpersonateUserView,
+};
+use crate::security::jwt::generate_impersonation_jwt;
+
+const MAX_REASON_LENGTH: usize = 500;
+
+#[derive(Debug, Clone, PartialEq)]
+enum ImpersonateUserError {
+    DatabaseError,
+    Forbidden,
+    MissingReason,
+    NotFound,
+    TokenGenerationError,
+}
+
+impl std::fmt::Display for ImpersonateUserError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImpersonateUserError::DatabaseError => write!(f, "Database error occurred"),
+            ImpersonateUserError::Forbidden => {
+                write!(
+                    f,
+                    "Administrators and inactive accounts cannot be impersonated"
+                )
+            }
+            ImpersonateUserError::MissingReason => {
+                write!(f, "A reason of at most 500 characters is required")
+            }
+            ImpersonateUserError::NotFound => write!(f, "User not found"),
+            ImpersonateUserError::TokenGenerationError => {
+                write!(f, "Failed to generate JWT token")
+            }
+        }
+    }
+}
+
+impl ResponseError for ImpersonateUserError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            ImpersonateUserError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            ImpersonateUserError::Forbidden => StatusCode::FORBIDDEN,
+            ImpersonateUserError::MissingReason => StatusCode::BAD_REQUEST,
+            ImpersonateUserError::NotFound => StatusCode::NOT_FOUND,
+            ImpersonateUserError::TokenGenerationError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+async fn impersonate(
+    state: web::Data<AppState>,
+    admin_id: u64,
+    user_id: u64,
+    reason: &str,
+    ip_address: Option<std::net::IpAddr>,
+) -> Result<(String, ImpersonateUserResponseView), ImpersonateUserError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(ImpersonateUserError::DatabaseError),
+    };
+    let reason = reason.trim();
+    if reason.is_empty() || reason.chars().count() > MAX_REASON_LENGTH {
+        return Err(ImpersonateUserError::MissingReason);
+    }
+
+    let user = match get_user_by_id_query(GetUserByIdQueryView::new(user_id), pool.clone()).await {
+        Ok(user) => user,
+        Err(DatabaseError::Query(QueryError::InvalidId(_))) => {
+            return Err(ImpersonateUserError::NotFound)
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return Err(ImpersonateUserError::DatabaseError);
+        }
+    };
+    if user_id == admin_id || user.is_archived() || user.status() != ACTIVE_USER_STATUS {
+        return Err(ImpersonateUserError::Forbidden);
+    }
+    // Agir en tant qu'un autre administrateur reviendrait à partager ses droits
+    let is_admin = is_admin_query(IsAdminQueryView::new(user_id), pool.clone())
+        .await
+        .map_err(|e| {
+            eprintln!("Error: {}", e);
+            ImpersonateUserError::DatabaseError
+        })?;
+    if is_admin {
+        return Err(ImpersonateUserError::Forbidden);
+    }
+
+    let impersonation_id = Uuid::new_v4();
+    let ttl = impersonation_ttl();
+    let expires_at = Utc::now() + Duration::seconds(ttl as i64);
+    let view =
+        CreateImpersonationQueryView::new(impersonation_id, admin_id, user_id, reason, expires_at);
+    create_impersonation_query(view, pool.clone())
+        .await
+        .map_err(|e| {
+            eprintln!("Error: {}", e);
+            ImpersonateUserError::DatabaseError
+        })?;
+
+    // Visible par l'utilisateur dans son propre historique de sécurité
+    let event = CreateSecurityEventQueryView::new(
+        Some(user_id),
+        IMPERSONATION_STARTED_EVENT,
+        ip_address,
+        Some(format!(
+            "impersonation_id = {}, admin_id = {}, reason = {}",
+            impersonation_id, admin_id, reason
+        )),
+    );
+    create_security_event_query(event, pool)
+        .await
+        .map_err(|e| {
+            eprintln!("Error: {}", e);
+            ImpersonateUserError::DatabaseError
+        })?;
+
+    let jwt =
+        generate_impersonation_jwt(user_id, admin_id, &impersonation_id, ttl).map_err(|e| {
+            eprintln!("JWT Error: {}", e);
+            ImpersonateUserError::TokenGenerationError
+        })?;
+    Ok((
+        jwt,
+        ImpersonateUserResponseView::new(impersonation_id.to_string(), expires_at.to_string()),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "",
+    params(
+        ("userId" = u64, Path, description = "ID de l'utilisateur")
+    ),
+    request_body = ImpersonateUserView,
+    responses(
+        (status = 201, description = "Impersonation started; the access token acting as the user is in the Authorization header and cannot be refreshed", body = ImpersonateUserResponseView),
+        (status = 400, description = "A reason of at most 500 characters is required"),
+        (status = 403, description = "Administrators, the caller and inactive accounts cannot be impersonated"),
+        (status = 404, description = "User not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Admin - Users",
+    security(
+        ("jwt" = [])
+    )
+)]
+#[post("/impersonate")]
+pub async fn admin_impersonate_user(
+    admin: AuthenticatedUser,
+    state: web::Data<AppState>,
+    path: web::Path<u64>,
+    body: web::Json<ImpersonateUserView>,
+    conn: ConnectionInfo,
+) -> Result<impl Responder, ImpersonateUserError> {
+    let ip_address = conn
+        .realip_remote_addr()
+        .and_then(|ip| ip.parse::<std::net::IpAddr>().ok());
+    let (jwt, response) = impersonate(
+        state,
+        admin.id,
+        path.into_inner(),
+        body.reason(),
+        ip_address,
+    )
+    .await?;
+
+    Ok(HttpResponse::Created()
+        .append_header(("Authorization", format!("Bearer {}", jwt)))
+        .json(response))
+}
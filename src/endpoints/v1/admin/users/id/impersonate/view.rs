@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use utoipa::ToSchema;
+
+#[derive(Deserialize, ToSchema)]
+pub struct ImpersonateUserView {
+    reason: String,
+}
+
+impl ImpersonateUserView {
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl Display for ImpersonateUserView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ImpersonateUserView {{ reason: {} }}", self.reason)
+    }
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ImpersonateUserResponseView {
+    impersonation_id: String,
+    expires_at: String,
+}
+
+impl ImpersonateUserResponseView {
+    pub fn new(impersonation_id: String, expires_at: String) -> Self {
+        Self {
+            impersonation_id,
+            expires_at,
+        }
+    }
+}
+
+impl Display for ImpersonateUserResponseView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ImpersonateUserResponseView {{ impersonation_id: {}, expires_at: {} }}",
+            self.impersonation_id, self.expires_at
+        )
+    }
+}
@@ -0,0 +1,6 @@
+use crate::endpoints::v1::admin::users::id::mfa::endpoint;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(paths(endpoint::admin_reset_user_mfa))]
+pub struct ResetUserMfaDoc;
@@ -0,0 +1,77 @@
+use actix_web::{delete, error::ResponseError, http::StatusCode, web, HttpResponse, Responder};
+use mairie360_api_lib::pool::AppState;
+
+use crate::database::mfa::delete_mfa::{delete_mfa_query, DeleteMfaQueryView};
+
+#[derive(Debug, Clone, PartialEq)]
+enum ResetUserMfaError {
+    DatabaseError,
+    NotConfigured,
+}
+
+impl std::fmt::Display for ResetUserMfaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResetUserMfaError::DatabaseError => write!(f, "Database error occurred"),
+            ResetUserMfaError::NotConfigured => write!(f, "User has no MFA configured"),
+        }
+    }
+}
+
+impl ResponseError for ResetUserMfaError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            ResetUserMfaError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            ResetUserMfaError::NotConfigured => StatusCode::NOT_FOUND,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+async fn reset_mfa(state: web::Data<AppState>, user_id: u64) -> Result<(), ResetUserMfaError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(ResetUserMfaError::DatabaseError),
+    };
+
+    let deleted = delete_mfa_query(DeleteMfaQueryView::new(user_id), pool)
+        .await
+        .map_err(|e| {
+            eprintln!("Error: {}", e);
+            ResetUserMfaError::DatabaseError
+        })?;
+    if !deleted {
+        return Err(ResetUserMfaError::NotConfigured);
+    }
+
+    Ok(())
+}
+
+#[utoipa::path(
+    delete,
+    path = "",
+    params(
+        ("userId" = u64, Path, description = "ID de l'utilisateur")
+    ),
+    responses(
+        (status = 204, description = "MFA and recovery codes removed, the user can enroll again"),
+        (status = 404, description = "User has no MFA configured"),
+        (status = 500, description = "Database error occurred")
+    ),
+    tag = "Admin - Users",
+    security(
+        ("jwt" = [])
+    )
+)]
+#[delete("/mfa")]
+pub async fn admin_reset_user_mfa(
+    state: web::Data<AppState>,
+    path: web::Path<u64>,
+) -> Result<impl Responder, ResetUserMfaError> {
+    reset_mfa(state, path.into_inner()).await?;
+
+    Ok(HttpResponse::NoContent())
+}
@@ -1,14 +1,22 @@
 mod delete;
 pub mod doc;
 mod get;
+mod impersonate;
+mod mfa;
 mod patch;
 mod roles;
+mod unlock;
+mod verify_email;
 
 use actix_web::web;
 pub fn config(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/{userId}")
             .configure(roles::config)
+            .service(impersonate::endpoint::admin_impersonate_user)
+            .service(mfa::endpoint::admin_reset_user_mfa)
+            .service(unlock::endpoint::admin_unlock_user)
+            .service(verify_email::endpoint::admin_verify_user_email)
             .service(delete::endpoint::admin_delete_user)
             .service(patch::endpoint::admin_patch_user)
             .service(get::endpoint::admin_get_user),
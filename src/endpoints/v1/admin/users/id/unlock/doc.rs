@@ -0,0 +1,6 @@
+use crate::endpoints::v1::admin::users::id::unlock::endpoint;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(paths(endpoint::admin_unlock_user))]
+pub struct UnlockUserDoc;
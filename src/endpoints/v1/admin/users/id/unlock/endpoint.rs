@@ -0,0 +1,99 @@
+use actix_web::{error::ResponseError, http::StatusCode, post, web, HttpResponse, Responder};
+use mairie360_api_lib::pool::AppState;
+use mairie360_api_lib::security::AuthenticatedUser;
+
+use crate::database::security_events::create_security_event::{
+    create_security_event_query, CreateSecurityEventQueryView,
+};
+use crate::endpoints::v1::auth::login::throttle::{
+    clear_failures, ThrottleSubject, ACCOUNT_UNLOCKED_EVENT,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+enum UnlockUserError {
+    DatabaseError,
+    RedisError,
+}
+
+impl std::fmt::Display for UnlockUserError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UnlockUserError::DatabaseError => write!(f, "Database error occurred"),
+            UnlockUserError::RedisError => write!(f, "Internal Redis error"),
+        }
+    }
+}
+
+impl ResponseError for UnlockUserError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            UnlockUserError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            UnlockUserError::RedisError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+async fn unlock_user(
+    state: web::Data<AppState>,
+    admin_id: u64,
+    user_id: u64,
+) -> Result<(), UnlockUserError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(UnlockUserError::DatabaseError),
+    };
+
+    let mut was_locked = false;
+    for subject in [
+        ThrottleSubject::Account(user_id),
+        ThrottleSubject::SecondFactor(user_id),
+    ] {
+        was_locked |= clear_failures(&state, &subject)
+            .await
+            .map_err(|_| UnlockUserError::RedisError)?;
+    }
+    if !was_locked {
+        return Ok(());
+    }
+
+    let event = CreateSecurityEventQueryView::new(
+        Some(user_id),
+        ACCOUNT_UNLOCKED_EVENT,
+        None,
+        Some(format!("unlocked_by = {}", admin_id)),
+    );
+    create_security_event_query(event, pool).await.map_err(|e| {
+        eprintln!("Error: {}", e);
+        UnlockUserError::DatabaseError
+    })
+}
+
+#[utoipa::path(
+    post,
+    path = "",
+    params(
+        ("userId" = u64, Path, description = "ID de l'utilisateur")
+    ),
+    responses(
+        (status = 204, description = "Login and second-factor lockouts and failure counters of the user cleared"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Admin - Users",
+    security(
+        ("jwt" = [])
+    )
+)]
+#[post("/unlock")]
+pub async fn admin_unlock_user(
+    admin: AuthenticatedUser,
+    state: web::Data<AppState>,
+    path: web::Path<u64>,
+) -> Result<impl Responder, UnlockUserError> {
+    unlock_user(state, admin.id, path.into_inner()).await?;
+
+    Ok(HttpResponse::NoContent())
+}
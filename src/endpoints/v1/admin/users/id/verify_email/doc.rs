@@ -0,0 +1,6 @@
+use crate::endpoints::v1::admin::users::id::verify_email::endpoint;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(paths(endpoint::admin_verify_user_email))]
+pub struct VerifyUserEmailDoc;
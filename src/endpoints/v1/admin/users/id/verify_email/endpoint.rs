@@ -0,0 +1,82 @@
+use actix_web::{error::ResponseError, http::StatusCode, post, web, HttpResponse, Responder};
+use mairie360_api_lib::pool::AppState;
+
+use crate::database::auth::verify_email::{verify_email_query, VerifyEmailQueryView};
+
+#[derive(Debug, Clone, PartialEq)]
+enum VerifyUserEmailError {
+    DatabaseError,
+    NotPending,
+}
+
+impl std::fmt::Display for VerifyUserEmailError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VerifyUserEmailError::DatabaseError => write!(f, "Database error occurred"),
+            VerifyUserEmailError::NotPending => {
+                write!(f, "User is not waiting for email verification")
+            }
+        }
+    }
+}
+
+impl ResponseError for VerifyUserEmailError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            VerifyUserEmailError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            VerifyUserEmailError::NotPending => StatusCode::NOT_FOUND,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+async fn verify_email(
+    state: web::Data<AppState>,
+    user_id: u64,
+) -> Result<(), VerifyUserEmailError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(VerifyUserEmailError::DatabaseError),
+    };
+
+    let verified = verify_email_query(VerifyEmailQueryView::new(user_id), pool)
+        .await
+        .map_err(|e| {
+            eprintln!("Error: {}", e);
+            VerifyUserEmailError::DatabaseError
+        })?;
+    if !verified {
+        return Err(VerifyUserEmailError::NotPending);
+    }
+
+    Ok(())
+}
+
+#[utoipa::path(
+    post,
+    path = "",
+    params(
+        ("userId" = u64, Path, description = "ID de l'utilisateur")
+    ),
+    responses(
+        (status = 204, description = "Email address marked as verified, the user can log in"),
+        (status = 404, description = "User is not waiting for email verification"),
+        (status = 500, description = "Database error occurred")
+    ),
+    tag = "Admin - Users",
+    security(
+        ("jwt" = [])
+    )
+)]
+#[post("/verify_email")]
+pub async fn admin_verify_user_email(
+    state: web::Data<AppState>,
+    path: web::Path<u64>,
+) -> Result<impl Responder, VerifyUserEmailError> {
+    verify_email(state, path.into_inner()).await?;
+
+    Ok(HttpResponse::NoContent())
+}
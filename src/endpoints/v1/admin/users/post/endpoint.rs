@@ -1,10 +1,19 @@
 use crate::database::auth::register::register_query;
 use crate::database::auth::register::RegisterUserQueryView;
+use crate::database::get_user_id::{get_user_id_query, GetUserIdQueryView};
+use crate::database::invitations::create_invitation::{
+    create_invitation_query, CreateInvitationQueryView,
+};
+use crate::database::users::INVITED_USER_STATUS;
+use crate::endpoints::v1::admin::invitations::{invitation_ttl, send_invitation_email};
 use crate::endpoints::v1::admin::users::post::view::CreateUserView;
+use crate::security::password::{hash_password, PasswordHasherConfig};
+use crate::security::token::{generate_token, hash_token};
 use actix_web::{error::ResponseError, http::StatusCode, post, web, HttpResponse, Responder};
 use mairie360_api_lib::database::queries::does_user_exist_by_email_query;
 use mairie360_api_lib::database::query_views::DoesUserExistByEmailQueryView;
 use mairie360_api_lib::pool::AppState;
+use mairie360_api_lib::security::AuthenticatedUser;
 use sqlx::PgPool;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -12,6 +21,7 @@ enum CreateUserError {
     InvalidData,
     UserAlreadyExists,
     DatabaseError,
+    PasswordHashError,
 }
 
 impl std::fmt::Display for CreateUserError {
@@ -20,6 +30,7 @@ impl std::fmt::Display for CreateUserError {
             CreateUserError::InvalidData => write!(f, "Invalid data provided"),
             CreateUserError::UserAlreadyExists => write!(f, "User already exists"),
             CreateUserError::DatabaseError => write!(f, "Database error occurred"),
+            CreateUserError::PasswordHashError => write!(f, "Failed to secure password"),
         }
     }
 }
@@ -30,6 +41,7 @@ impl ResponseError for CreateUserError {
             CreateUserError::InvalidData => StatusCode::BAD_REQUEST,
             CreateUserError::UserAlreadyExists => StatusCode::CONFLICT,
             CreateUserError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            CreateUserError::PasswordHashError => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
 
@@ -51,11 +63,6 @@ fn is_valid_email(email: &str) -> bool {
     }
 }
 
-fn is_valid_password(password: &str) -> bool {
-    //Need to be more complex and based on requirements
-    password.len() >= 8
-}
-
 fn is_valid_phone_number(phone_number: Option<&str>) -> bool {
     //Need to be more complex and based on requirements
     match phone_number {
@@ -83,41 +90,75 @@ async fn can_be_registered(
         return Err(CreateUserError::UserAlreadyExists);
     }
 
-    if !is_valid_password(register_view.password()) {
-        return Err(CreateUserError::InvalidData);
-    }
     if !is_valid_phone_number(register_view.phone_number()) {
         return Err(CreateUserError::InvalidData);
     }
     Ok(())
 }
 
+async fn invite_user(pool: &PgPool, email: &str, admin_id: u64) -> Result<(), CreateUserError> {
+    let user_id = get_user_id_query(GetUserIdQueryView::new(email), pool.clone())
+        .await
+        .map_err(|e| {
+            eprintln!("Database error: {}", e);
+            CreateUserError::DatabaseError
+        })? as u64;
+
+    let token = generate_token();
+    let view =
+        CreateInvitationQueryView::new(user_id, &hash_token(&token), admin_id, invitation_ttl());
+    create_invitation_query(view, pool.clone())
+        .await
+        .map_err(|e| {
+            eprintln!("Database error: {}", e);
+            CreateUserError::DatabaseError
+        })?;
+
+    send_invitation_email(email, &token).await;
+    Ok(())
+}
+
+/**
+ * Creates the account without a usable password: the invitee chooses it through the
+ * emailed invitation link.
+ */
 async fn register_user(
     register_view: &CreateUserView,
     state: web::Data<AppState>,
+    admin_id: u64,
 ) -> Result<(), CreateUserError> {
-    can_be_registered(register_view, &state.db_pool.clone().unwrap()).await?;
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(CreateUserError::DatabaseError),
+    };
+    can_be_registered(register_view, &pool).await?;
+
+    // Hash d'un secret aléatoire jamais transmis : aucun mot de passe ne peut correspondre
+    let password_hash = hash_password(&generate_token(), PasswordHasherConfig::from_env())
+        .await
+        .map_err(|e| {
+            eprintln!("Password hashing error: {}", e);
+            CreateUserError::PasswordHashError
+        })?;
 
     let view = RegisterUserQueryView::new(
         register_view.first_name(),
         register_view.last_name(),
         register_view.email(),
-        register_view.password(),
-        register_view.phone_number().map(|s| s),
-    );
-
-    let success = register_query(view, state.db_pool.clone().unwrap())
-        .await
-        .map_err(|e| {
-            eprintln!("Database error: {}", e);
-            CreateUserError::DatabaseError
-        })?;
-
-    if success {
-        Ok(())
-    } else {
-        Err(CreateUserError::DatabaseError)
+        &password_hash,
+        register_view.phone_number(),
+    )
+    .with_status(INVITED_USER_STATUS);
+
+    let success = register_query(view, pool.clone()).await.map_err(|e| {
+        eprintln!("Database error: {}", e);
+        CreateUserError::DatabaseError
+    })?;
+    if !success {
+        return Err(CreateUserError::DatabaseError);
     }
+
+    invite_user(&pool, register_view.email(), admin_id).await
 }
 
 #[utoipa::path(
@@ -125,21 +166,25 @@ async fn register_user(
     path = "",
     request_body = CreateUserView,
     responses(
-        (status = 201, description = "User created successfully"),
+        (status = 201, description = "User created and invitation sent"),
         (status = 400, description = "Invalid data provided"),
         (status = 409, description = "User already exists"),
         (status = 500, description = "Database error occurred")
     ),
-    tag = "Admin - Users"
+    tag = "Admin - Users",
+    security(
+        ("jwt" = [])
+    )
 )]
 #[post("/")]
 pub async fn admin_post_user(
+    admin: AuthenticatedUser,
     payload: web::Json<CreateUserView>,
     state: web::Data<AppState>,
 ) -> Result<impl Responder, CreateUserError> {
     let register_view = payload.into_inner();
 
-    register_user(&register_view, state).await?;
+    register_user(&register_view, state, admin.id).await?;
 
-    Ok(HttpResponse::Created().body("User created successfully!"))
+    Ok(HttpResponse::Created().body("User created, invitation sent!"))
 }
@@ -7,7 +7,6 @@ pub struct CreateUserView {
     first_name: String,
     last_name: String,
     email: String,
-    password: String,
     phone_number: Option<String>,
 }
 
@@ -24,10 +23,6 @@ impl CreateUserView {
         &self.email
     }
 
-    pub fn password(&self) -> &str {
-        &self.password
-    }
-
     pub fn phone_number(&self) -> Option<&str> {
         self.phone_number.as_deref()
     }
@@ -37,8 +32,8 @@ impl Display for CreateUserView {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "CreateUserView {{ first_name: {}, last_name: {}, email: {}, password: {}, phone_number: {:?} }}",
-            self.first_name, self.last_name, self.email, self.password, self.phone_number
+            "CreateUserView {{ first_name: {}, last_name: {}, email: {}, phone_number: {:?} }}",
+            self.first_name, self.last_name, self.email, self.phone_number
         )
     }
 }
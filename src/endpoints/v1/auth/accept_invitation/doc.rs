@@ -0,0 +1,12 @@
+use crate::endpoints::v1::auth::accept_invitation::endpoint;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(endpoint::accept_invitation),
+    components(schemas(
+        super::view::AcceptInvitationView,
+        super::view::AcceptInvitationResponseView
+    ))
+)]
+pub struct AcceptInvitationDoc;
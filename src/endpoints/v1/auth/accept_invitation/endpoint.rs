@@ -0,0 +1,198 @@
+use actix_web::dev::ConnectionInfo;
+use actix_web::http::StatusCode;
+use actix_web::{post, web, HttpResponse, Responder, ResponseError};
+use mairie360_api_lib::pool::AppState;
+
+use crate::database::invitations::accept_invitation::{
+    accept_invitation_query, AcceptInvitationQueryView,
+};
+use crate::database::invitations::get_invitation_user::{
+    get_invitation_user_query, GetInvitationUserQueryView,
+};
+use crate::database::security_events::create_security_event::{
+    create_security_event_query, CreateSecurityEventQueryView,
+};
+use crate::endpoints::v1::auth::accept_invitation::view::{
+    AcceptInvitationResponseView, AcceptInvitationView,
+};
+use crate::endpoints::v1::auth::login::endpoint::{generate_session, LoginError};
+use crate::endpoints::v1::auth::password_policy::view::PasswordPolicyErrorView;
+use crate::endpoints::v1::auth::password_policy::{
+    check_user_new_password, record_password_change, PasswordPolicyError,
+};
+use crate::security::device::UserAgent;
+use crate::security::password::{hash_password, PasswordHasherConfig};
+use crate::security::password_policy::{PasswordPolicy, PasswordViolation};
+use crate::security::token::hash_token;
+
+pub const INVITATION_ACCEPTED_EVENT: &str = "invitation_accepted";
+
+#[derive(Debug, Clone, PartialEq)]
+enum AcceptInvitationError {
+    DatabaseError,
+    InvalidToken,
+    PasswordHashError,
+    TokenGenerationError,
+    TooManySessions,
+    WeakPassword(Vec<PasswordViolation>),
+}
+
+impl From<PasswordPolicyError> for AcceptInvitationError {
+    fn from(error: PasswordPolicyError) -> Self {
+        match error {
+            PasswordPolicyError::DatabaseError => AcceptInvitationError::DatabaseError,
+            PasswordPolicyError::PasswordHashError => AcceptInvitationError::PasswordHashError,
+            PasswordPolicyError::Violations(violations) => {
+                AcceptInvitationError::WeakPassword(violations)
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for AcceptInvitationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AcceptInvitationError::DatabaseError => {
+                write!(f, "An error occurred while accessing the database.")
+            }
+            AcceptInvitationError::InvalidToken => {
+                write!(f, "Invalid, expired or already used invitation.")
+            }
+            AcceptInvitationError::PasswordHashError => write!(f, "Failed to secure password."),
+            AcceptInvitationError::TokenGenerationError => {
+                write!(f, "Failed to generate JWT token.")
+            }
+            AcceptInvitationError::TooManySessions => {
+                write!(
+                    f,
+                    "Maximum number of active sessions reached, log out elsewhere first."
+                )
+            }
+            AcceptInvitationError::WeakPassword(_) => {
+                write!(f, "Password does not meet the password policy.")
+            }
+        }
+    }
+}
+
+impl ResponseError for AcceptInvitationError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            AcceptInvitationError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            AcceptInvitationError::InvalidToken => StatusCode::BAD_REQUEST,
+            AcceptInvitationError::PasswordHashError => StatusCode::INTERNAL_SERVER_ERROR,
+            AcceptInvitationError::TokenGenerationError => StatusCode::INTERNAL_SERVER_ERROR,
+            AcceptInvitationError::TooManySessions => StatusCode::CONFLICT,
+            AcceptInvitationError::WeakPassword(_) => StatusCode::BAD_REQUEST,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        if let AcceptInvitationError::WeakPassword(violations) = self {
+            return HttpResponse::build(self.status_code()).json(PasswordPolicyErrorView::new(
+                &self.to_string(),
+                violations.clone(),
+            ));
+        }
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+async fn accept(
+    state: web::Data<AppState>,
+    view: AcceptInvitationView,
+    ip_adress: std::net::IpAddr,
+    user_agent: &UserAgent,
+) -> Result<(String, String), AcceptInvitationError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(AcceptInvitationError::DatabaseError),
+    };
+    let token_hash = hash_token(view.token());
+
+    // Vérifié avant de consommer l'invitation pour que l'utilisateur puisse réessayer
+    let user_id =
+        get_invitation_user_query(GetInvitationUserQueryView::new(&token_hash), pool.clone())
+            .await
+            .map_err(|e| {
+                eprintln!("Database Error: {}", e);
+                AcceptInvitationError::DatabaseError
+            })?
+            .ok_or(AcceptInvitationError::InvalidToken)?;
+    let policy = PasswordPolicy::from_env();
+    check_user_new_password(&pool, &policy, view.password(), user_id).await?;
+
+    let password_hash = hash_password(view.password(), PasswordHasherConfig::from_env())
+        .await
+        .map_err(|e| {
+            eprintln!("Password hashing error: {}", e);
+            AcceptInvitationError::PasswordHashError
+        })?;
+    // Deux requêtes concurrentes avec le même lien : une seule trouve encore l'invitation
+    let user_id = accept_invitation_query(
+        AcceptInvitationQueryView::new(&token_hash, &password_hash),
+        pool.clone(),
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("Database Error: {}", e);
+        AcceptInvitationError::DatabaseError
+    })?
+    .ok_or(AcceptInvitationError::InvalidToken)?;
+
+    record_password_change(&pool, &policy, user_id, &password_hash)
+        .await
+        .map_err(|e| {
+            eprintln!("Database Error: {}", e);
+            AcceptInvitationError::DatabaseError
+        })?;
+    let event = CreateSecurityEventQueryView::new(
+        Some(user_id),
+        INVITATION_ACCEPTED_EVENT,
+        Some(ip_adress),
+        None,
+    );
+    if let Err(e) = create_security_event_query(event, pool).await {
+        eprintln!("Security Event DB Error: {}", e);
+    }
+
+    generate_session(user_id, &view.device_info(), user_agent, ip_adress, state)
+        .await
+        .map_err(|e| {
+            eprintln!("Failed to generate session: {:?}", e);
+            if e == LoginError::TooManySessions {
+                return AcceptInvitationError::TooManySessions;
+            }
+            AcceptInvitationError::TokenGenerationError
+        })
+}
+
+#[utoipa::path(
+    post,
+    path = "/",
+    request_body = AcceptInvitationView,
+    responses(
+        (status = 200, description = "Password set, account activated and session opened", body = AcceptInvitationResponseView),
+        (status = 400, description = "Invalid, expired or already used invitation, or the password breaks the policy", body = PasswordPolicyErrorView),
+        (status = 409, description = "Maximum number of active sessions reached and the session policy refuses new logins"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Auth",
+)]
+#[post("/accept_invitation")]
+pub async fn accept_invitation(
+    state: web::Data<AppState>,
+    body: web::Json<AcceptInvitationView>,
+    conn: ConnectionInfo,
+    user_agent: UserAgent,
+) -> Result<impl Responder, AcceptInvitationError> {
+    let ip_str = conn.realip_remote_addr().unwrap_or("unknown").to_string();
+    let ip_address = ip_str
+        .parse::<std::net::IpAddr>()
+        .unwrap_or(std::net::IpAddr::V4(std::net::Ipv4Addr::new(0, 0, 0, 0)));
+    let (jwt, refresh_token) = accept(state, body.into_inner(), ip_address, &user_agent).await?;
+
+    Ok(HttpResponse::Ok()
+        .append_header(("Authorization", format!("Bearer {}", jwt)))
+        .json(AcceptInvitationResponseView::from(refresh_token)))
+}
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AcceptInvitationView {
+    token: String,
+    password: String,
+    device_info: String,
+}
+
+impl AcceptInvitationView {
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    pub fn password(&self) -> &str {
+        &self.password
+    }
+
+    pub fn device_info(&self) -> String {
+        self.device_info.clone()
+    }
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct AcceptInvitationResponseView {
+    refresh_token: String,
+}
+
+impl AcceptInvitationResponseView {
+    pub fn new(refresh_token: String) -> Self {
+        AcceptInvitationResponseView { refresh_token }
+    }
+
+    pub fn refresh_token(&self) -> &str {
+        &self.refresh_token
+    }
+}
+
+impl Display for AcceptInvitationResponseView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "AcceptInvitationResponseView {{ refresh_token: {} }}",
+            self.refresh_token
+        )
+    }
+}
+
+impl From<String> for AcceptInvitationResponseView {
+    fn from(token: String) -> Self {
+        AcceptInvitationResponseView {
+            refresh_token: token,
+        }
+    }
+}
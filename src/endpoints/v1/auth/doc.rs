@@ -1,16 +1,39 @@
+use crate::endpoints::v1::auth::accept_invitation::doc::AcceptInvitationDoc;
+use crate::endpoints::v1::auth::email_change::doc::EmailChangeDoc;
+use crate::endpoints::v1::auth::federated::doc::FederatedDoc;
 use crate::endpoints::v1::auth::force_change_password::doc::ForceChangePasswordDoc;
 use crate::endpoints::v1::auth::forgot_password::doc::ForgotPasswordDoc;
 use crate::endpoints::v1::auth::login::doc::LoginDoc;
+use crate::endpoints::v1::auth::magic_link::doc::MagicLinkDoc;
+use crate::endpoints::v1::auth::mfa_verify::doc::MfaVerifyDoc;
+use crate::endpoints::v1::auth::password_policy::view::PasswordPolicyErrorView;
+use crate::endpoints::v1::auth::refresh::doc::RefreshDoc;
 use crate::endpoints::v1::auth::register::doc::RegisterDoc;
 use crate::endpoints::v1::auth::reset_password::doc::ResetPasswordDoc;
+use crate::endpoints::v1::auth::sign_in_alert::doc::SignInAlertDoc;
+use crate::endpoints::v1::auth::verify_email::doc::VerifyEmailDoc;
+use crate::endpoints::v1::auth::webauthn::doc::WebauthnDoc;
+use crate::security::password_policy::PasswordViolation;
 use utoipa::OpenApi;
 
 #[derive(OpenApi)]
-#[openapi(nest(
-    (path = "/force_change_password", api = ForceChangePasswordDoc, tags = ["Auth"]),
-    (path = "/forgot_password", api = ForgotPasswordDoc, tags = ["Auth"]),
-    (path = "/register", api = RegisterDoc, tags = ["Auth"]),
-    (path = "/login", api = LoginDoc, tags = ["Auth"]),
-    (path = "/reset_password", api = ResetPasswordDoc, tags = ["Auth"]),
-))]
+#[openapi(
+    nest(
+        (path = "/accept_invitation", api = AcceptInvitationDoc, tags = ["Auth"]),
+        (path = "/federated", api = FederatedDoc, tags = ["Auth"]),
+        (path = "/force_change_password", api = ForceChangePasswordDoc, tags = ["Auth"]),
+        (path = "/forgot_password", api = ForgotPasswordDoc, tags = ["Auth"]),
+        (path = "/register", api = RegisterDoc, tags = ["Auth"]),
+        (path = "/login", api = LoginDoc, tags = ["Auth"]),
+        (path = "/magic_link", api = MagicLinkDoc, tags = ["Auth"]),
+        (path = "/mfa_verify", api = MfaVerifyDoc, tags = ["Auth"]),
+        (path = "/refresh", api = RefreshDoc, tags = ["Auth"]),
+        (path = "/reset_password", api = ResetPasswordDoc, tags = ["Auth"]),
+        (path = "/secure_account", api = SignInAlertDoc, tags = ["Auth"]),
+        (path = "/webauthn", api = WebauthnDoc, tags = ["Auth"]),
+        (path = "/", api = VerifyEmailDoc, tags = ["Auth"]),
+        (path = "/", api = EmailChangeDoc, tags = ["Auth"]),
+    ),
+    components(schemas(PasswordPolicyErrorView, PasswordViolation))
+)]
 pub struct AuthDoc;
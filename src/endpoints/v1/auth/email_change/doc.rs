@@ -0,0 +1,9 @@
+use crate::endpoints::v1::auth::email_change::endpoint;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(endpoint::confirm_email_change, endpoint::cancel_email_change),
+    components(schemas(super::view::EmailChangeTokenView))
+)]
+pub struct EmailChangeDoc;
@@ -0,0 +1,250 @@
+use actix_web::{
+    dev::ConnectionInfo, http::StatusCode, post, web, HttpResponse, Responder, ResponseError,
+};
+use chrono::Utc;
+use mairie360_api_lib::database::errors::DatabaseError;
+use mairie360_api_lib::database::queries::QueryError;
+use mairie360_api_lib::pool::AppState;
+use sqlx::PgPool;
+
+use crate::database::email_changes::confirm_email_change::{
+    confirm_email_change_query, ConfirmEmailChangeQueryView,
+};
+use crate::database::email_changes::delete_email_change::{
+    delete_email_change_query, DeleteEmailChangeQueryView,
+};
+use crate::database::email_changes::get_email_change::{
+    get_email_change_query, GetEmailChangeQueryView,
+};
+use crate::database::security_events::create_security_event::{
+    create_security_event_query, CreateSecurityEventQueryView,
+};
+use crate::database::users::get_user_by_id::{get_user_by_id_query, GetUserByIdQueryView};
+use crate::endpoints::v1::auth::email_change::view::EmailChangeTokenView;
+use crate::endpoints::v1::auth::email_change::{
+    EMAIL_CHANGED_EVENT, EMAIL_CHANGE_CANCELLED_EVENT, EMAIL_CHANGE_CANCEL_PURPOSE,
+    EMAIL_CHANGE_CONFIRM_PURPOSE,
+};
+use crate::security::email_token::EmailTokenSigner;
+
+#[derive(Debug, Clone, PartialEq)]
+enum EmailChangeTokenError {
+    AddressTaken,
+    DatabaseError,
+    InvalidToken,
+    ServerError,
+}
+
+impl std::fmt::Display for EmailChangeTokenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EmailChangeTokenError::AddressTaken => {
+                write!(f, "This email address is already used by another account.")
+            }
+            EmailChangeTokenError::DatabaseError => {
+                write!(f, "An error occurred while accessing the database.")
+            }
+            EmailChangeTokenError::InvalidToken => write!(f, "Invalid or expired link."),
+            EmailChangeTokenError::ServerError => write!(f, "Internal server error."),
+        }
+    }
+}
+
+impl ResponseError for EmailChangeTokenError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            EmailChangeTokenError::AddressTaken => StatusCode::CONFLICT,
+            EmailChangeTokenError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            EmailChangeTokenError::InvalidToken => StatusCode::BAD_REQUEST,
+            EmailChangeTokenError::ServerError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+fn get_signer() -> Result<EmailTokenSigner, EmailChangeTokenError> {
+    EmailTokenSigner::from_env().map_err(|e| {
+        eprintln!("Email Token Error: {}", e);
+        EmailChangeTokenError::ServerError
+    })
+}
+
+fn get_ip_address(conn: &ConnectionInfo) -> Option<std::net::IpAddr> {
+    conn.realip_remote_addr()
+        .and_then(|ip| ip.parse::<std::net::IpAddr>().ok())
+}
+
+async fn record_event(
+    pool: &PgPool,
+    user_id: u64,
+    event_type: &str,
+    ip_address: Option<std::net::IpAddr>,
+    details: Option<String>,
+) {
+    let event = CreateSecurityEventQueryView::new(Some(user_id), event_type, ip_address, details);
+    if let Err(e) = create_security_event_query(event, pool.clone()).await {
+        eprintln!("Security Event DB Error: {}", e);
+    }
+}
+
+async fn confirm(
+    pool: &PgPool,
+    token: &str,
+    ip_address: Option<std::net::IpAddr>,
+) -> Result<(), EmailChangeTokenError> {
+    let signer = get_signer()?;
+    let user_id =
+        EmailTokenSigner::user_id(token).map_err(|_| EmailChangeTokenError::InvalidToken)?;
+    let user = get_user_by_id_query(GetUserByIdQueryView::new(user_id), pool.clone())
+        .await
+        .map_err(|_| EmailChangeTokenError::InvalidToken)?;
+    let new_email = get_email_change_query(GetEmailChangeQueryView::new(user_id), pool.clone())
+        .await
+        .map_err(|e| {
+            eprintln!("Database Error: {}", e);
+            EmailChangeTokenError::DatabaseError
+        })?
+        .ok_or(EmailChangeTokenError::InvalidToken)?;
+
+    // Le jeton est signé pour l'adresse en attente : une demande remplacée invalide l'ancien lien
+    signer
+        .verify(
+            EMAIL_CHANGE_CONFIRM_PURPOSE,
+            token,
+            &new_email,
+            Utc::now().timestamp(),
+        )
+        .map_err(|e| {
+            eprintln!("Email Change Error: {}", e);
+            EmailChangeTokenError::InvalidToken
+        })?;
+
+    // L'unicité de users.email départage deux comptes qui confirment la même adresse
+    let view = ConfirmEmailChangeQueryView::new(user_id, &new_email);
+    match confirm_email_change_query(view, pool.clone()).await {
+        Ok(true) => {}
+        Ok(false) => return Err(EmailChangeTokenError::InvalidToken),
+        Err(DatabaseError::Query(QueryError::ConstraintViolation(_))) => {
+            if let Err(e) =
+                delete_email_change_query(DeleteEmailChangeQueryView::new(user_id), pool.clone())
+                    .await
+            {
+                eprintln!("Database Error: {}", e);
+            }
+            return Err(EmailChangeTokenError::AddressTaken);
+        }
+        Err(e) => {
+            eprintln!("Database Error: {}", e);
+            return Err(EmailChangeTokenError::DatabaseError);
+        }
+    }
+
+    record_event(
+        pool,
+        user_id,
+        EMAIL_CHANGED_EVENT,
+        ip_address,
+        Some(format!("{} -> {}", user.email(), new_email)),
+    )
+    .await;
+    Ok(())
+}
+
+#[utoipa::path(
+    post,
+    path = "confirm_email_change",
+    request_body = EmailChangeTokenView,
+    responses(
+        (status = 204, description = "The new email address replaces the previous one"),
+        (status = 400, description = "Invalid or expired link."),
+        (status = 409, description = "The address was claimed by another account in the meantime"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Auth"
+)]
+#[post("/confirm_email_change")]
+pub async fn confirm_email_change(
+    payload: web::Json<EmailChangeTokenView>,
+    state: web::Data<AppState>,
+    conn: ConnectionInfo,
+) -> Result<impl Responder, EmailChangeTokenError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(EmailChangeTokenError::DatabaseError),
+    };
+    confirm(&pool, payload.token(), get_ip_address(&conn)).await?;
+    Ok(HttpResponse::NoContent())
+}
+
+async fn cancel(
+    pool: &PgPool,
+    token: &str,
+    ip_address: Option<std::net::IpAddr>,
+) -> Result<(), EmailChangeTokenError> {
+    let signer = get_signer()?;
+    let user_id =
+        EmailTokenSigner::user_id(token).map_err(|_| EmailChangeTokenError::InvalidToken)?;
+    let user = get_user_by_id_query(GetUserByIdQueryView::new(user_id), pool.clone())
+        .await
+        .map_err(|_| EmailChangeTokenError::InvalidToken)?;
+
+    // Signé pour l'adresse actuelle : le lien ne sert plus une fois le changement appliqué
+    signer
+        .verify(
+            EMAIL_CHANGE_CANCEL_PURPOSE,
+            token,
+            user.email(),
+            Utc::now().timestamp(),
+        )
+        .map_err(|e| {
+            eprintln!("Email Change Error: {}", e);
+            EmailChangeTokenError::InvalidToken
+        })?;
+
+    let cancelled =
+        delete_email_change_query(DeleteEmailChangeQueryView::new(user_id), pool.clone())
+            .await
+            .map_err(|e| {
+                eprintln!("Database Error: {}", e);
+                EmailChangeTokenError::DatabaseError
+            })?;
+    if cancelled {
+        record_event(
+            pool,
+            user_id,
+            EMAIL_CHANGE_CANCELLED_EVENT,
+            ip_address,
+            None,
+        )
+        .await;
+    }
+    Ok(())
+}
+
+#[utoipa::path(
+    post,
+    path = "cancel_email_change",
+    request_body = EmailChangeTokenView,
+    responses(
+        (status = 204, description = "The pending email change, if any, is dropped"),
+        (status = 400, description = "Invalid or expired link."),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Auth"
+)]
+#[post("/cancel_email_change")]
+pub async fn cancel_email_change(
+    payload: web::Json<EmailChangeTokenView>,
+    state: web::Data<AppState>,
+    conn: ConnectionInfo,
+) -> Result<impl Responder, EmailChangeTokenError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(EmailChangeTokenError::DatabaseError),
+    };
+    cancel(&pool, payload.token(), get_ip_address(&conn)).await?;
+    Ok(HttpResponse::NoContent())
+}
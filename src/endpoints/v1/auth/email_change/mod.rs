@@ -0,0 +1,126 @@
+pub mod doc;
+pub mod endpoint;
+pub mod view;
+
+use chrono::Utc;
+use mairie360_api_lib::env_manager::get_env_var;
+use sqlx::PgPool;
+
+use crate::database::email_changes::create_email_change::{
+    create_email_change_query, CreateEmailChangeQueryView,
+};
+use crate::security::email_token::EmailTokenSigner;
+use crate::{build_email, get_email_link_url, get_email_sender, send_email, EmailDestination};
+
+pub const EMAIL_CHANGE_CONFIRM_PURPOSE: &str = "email_change_confirm";
+pub const EMAIL_CHANGE_CANCEL_PURPOSE: &str = "email_change_cancel";
+
+pub const EMAIL_CHANGE_REQUESTED_EVENT: &str = "email_change_requested";
+pub const EMAIL_CHANGED_EVENT: &str = "email_changed";
+pub const EMAIL_CHANGE_CANCELLED_EVENT: &str = "email_change_cancelled";
+
+const DEFAULT_EMAIL_CHANGE_TTL: u64 = 86400;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EmailChangeError {
+    AddressTaken,
+    DatabaseError,
+    ServerError,
+}
+
+pub fn email_change_ttl() -> u64 {
+    get_env_var("EMAIL_CHANGE_TTL")
+        .and_then(|value| value.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_EMAIL_CHANGE_TTL)
+}
+
+async fn send_link(to: &str, subject: &str, body: &str) {
+    let destination = EmailDestination {
+        from: match get_email_sender() {
+            Ok(sender) => sender,
+            Err(e) => {
+                eprintln!("Email Sender Error: {}", e);
+                return;
+            }
+        },
+        to: to.to_string(),
+    };
+    let message = match build_email(&destination, subject, body) {
+        Ok(message) => message,
+        Err(e) => {
+            eprintln!("Email Build Error: {}", e);
+            return;
+        }
+    };
+    if let Err(e) = send_email(message).await {
+        eprintln!("Mail Error: {}", e);
+    }
+}
+
+/**
+ * Stores the new address as pending, then emails a confirmation link to it
+ * (`EMAIL_CHANGE_URL`) and a cancel link to the current one (`EMAIL_CHANGE_CANCEL_URL`).
+ * The address is only written to `users` once the link is posted to `/auth/confirm_email_change`.
+ * Both variables are required: without them nothing is stored and the request fails.
+ */
+pub async fn request_email_change(
+    pool: &PgPool,
+    user_id: u64,
+    current_email: &str,
+    new_email: &str,
+) -> Result<(), EmailChangeError> {
+    let signer = EmailTokenSigner::from_env().map_err(|e| {
+        eprintln!("Email Token Error: {}", e);
+        EmailChangeError::ServerError
+    })?;
+    // Vérifiées avant d'enregistrer la demande : sans elles aucun lien ne peut partir
+    let confirm_url = get_email_link_url("EMAIL_CHANGE_URL");
+    let cancel_url = get_email_link_url("EMAIL_CHANGE_CANCEL_URL");
+    let (confirm_url, cancel_url) = match (confirm_url, cancel_url) {
+        (Some(confirm_url), Some(cancel_url)) => (confirm_url, cancel_url),
+        _ => return Err(EmailChangeError::ServerError),
+    };
+    let ttl = email_change_ttl();
+    let created = create_email_change_query(
+        CreateEmailChangeQueryView::new(user_id, new_email, ttl),
+        pool.clone(),
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("Database Error: {}", e);
+        EmailChangeError::DatabaseError
+    })?;
+    if !created {
+        return Err(EmailChangeError::AddressTaken);
+    }
+
+    let expires_at = Utc::now().timestamp() + ttl as i64;
+    let confirm_token = signer.sign(EMAIL_CHANGE_CONFIRM_PURPOSE, user_id, new_email, expires_at);
+    let cancel_token = signer.sign(
+        EMAIL_CHANGE_CANCEL_PURPOSE,
+        user_id,
+        current_email,
+        expires_at,
+    );
+
+    send_link(
+        new_email,
+        "Confirmez votre nouvelle adresse email",
+        &format!(
+            "Bonjour, confirmez votre nouvelle adresse email en ouvrant ce lien : {}?token={}",
+            confirm_url, confirm_token
+        ),
+    )
+    .await;
+    send_link(
+        current_email,
+        "Changement d'adresse email demandé",
+        &format!(
+            "Bonjour, un changement d'adresse email vers {} a été demandé pour votre compte. \
+            Si vous n'êtes pas à l'origine de cette demande, annulez-la avec ce lien : {}?token={}",
+            new_email, cancel_url, cancel_token
+        ),
+    )
+    .await;
+    Ok(())
+}
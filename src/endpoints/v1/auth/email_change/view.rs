@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EmailChangeTokenView {
+    token: String,
+}
+
+impl EmailChangeTokenView {
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+}
@@ -0,0 +1,6 @@
+use crate::endpoints::v1::auth::federated::authorize::endpoint;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(paths(endpoint::federated_authorize))]
+pub struct FederatedAuthorizeDoc;
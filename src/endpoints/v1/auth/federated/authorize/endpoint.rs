@@ -0,0 +1,79 @@
+use actix_web::{get, http::StatusCode, web, HttpResponse, Responder, ResponseError};
+use mairie360_api_lib::pool::AppState;
+
+use crate::endpoints::v1::auth::federated::{start_federation, FederationStartError};
+use crate::security::federation::FederationError;
+
+#[derive(Debug, Clone, PartialEq)]
+enum FederatedAuthorizeError {
+    NotConfigured,
+    ProviderError,
+    RedisError,
+}
+
+impl From<FederationStartError> for FederatedAuthorizeError {
+    fn from(error: FederationStartError) -> Self {
+        match error {
+            FederationStartError::Federation(FederationError::Configuration(e)) => {
+                eprintln!("Federation Error: {}", e);
+                FederatedAuthorizeError::NotConfigured
+            }
+            FederationStartError::Federation(e) => {
+                eprintln!("Federation Error: {}", e);
+                FederatedAuthorizeError::ProviderError
+            }
+            FederationStartError::RedisError => FederatedAuthorizeError::RedisError,
+        }
+    }
+}
+
+impl std::fmt::Display for FederatedAuthorizeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FederatedAuthorizeError::NotConfigured => {
+                write!(f, "Login through an identity provider is not configured.")
+            }
+            FederatedAuthorizeError::ProviderError => {
+                write!(f, "The identity provider could not be reached.")
+            }
+            FederatedAuthorizeError::RedisError => write!(f, "Internal Redis error."),
+        }
+    }
+}
+
+impl ResponseError for FederatedAuthorizeError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            FederatedAuthorizeError::NotConfigured => StatusCode::SERVICE_UNAVAILABLE,
+            FederatedAuthorizeError::ProviderError => StatusCode::BAD_GATEWAY,
+            FederatedAuthorizeError::RedisError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "",
+    responses(
+        (status = 302, description = "Redirects the browser to the identity provider, which comes back to FEDERATION_REDIRECT_URI with a code and a state"),
+        (status = 500, description = "Internal server error"),
+        (status = 502, description = "The identity provider could not be reached"),
+        (status = 503, description = "No identity provider is configured")
+    ),
+    tag = "Auth"
+)]
+#[get("/authorize")]
+pub async fn federated_authorize(
+    state: web::Data<AppState>,
+) -> Result<impl Responder, FederatedAuthorizeError> {
+    let url = start_federation(&state, None).await?;
+
+    Ok(HttpResponse::Found()
+        .insert_header(("Location", url))
+        .insert_header(("Cache-Control", "no-store"))
+        .finish())
+}
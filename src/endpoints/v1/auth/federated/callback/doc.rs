@@ -0,0 +1,14 @@
+use crate::endpoints::v1::auth::federated::callback::endpoint;
+use crate::endpoints::v1::auth::login::view::{LoginMfaRequiredResponseView, LoginResponseView};
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(endpoint::federated_callback),
+    components(schemas(
+        super::view::FederatedCallbackView,
+        LoginResponseView,
+        LoginMfaRequiredResponseView
+    ))
+)]
+pub struct FederatedCallbackDoc;
@@ -0,0 +1,375 @@
+use actix_web::{
+    dev::ConnectionInfo, http::StatusCode, post, web, HttpRequest, HttpResponse, Responder,
+    ResponseError,
+};
+use chrono::Utc;
+use mairie360_api_lib::database::errors::DatabaseError;
+use mairie360_api_lib::database::queries::QueryError;
+use mairie360_api_lib::jwt_manager::get_jwt_from_request;
+use mairie360_api_lib::pool::AppState;
+use sqlx::PgPool;
+
+use crate::database::federated_identities::link_federated_identity::{
+    link_federated_identity_query, LinkFederatedIdentityQueryView,
+};
+use crate::database::federated_identities::use_federated_identity::{
+    use_federated_identity_query, UseFederatedIdentityQueryView,
+};
+use crate::database::security_events::create_security_event::{
+    create_security_event_query, CreateSecurityEventQueryView,
+};
+use crate::database::users::get_active_user_by_email::{
+    get_active_user_by_email_query, GetActiveUserByEmailQueryView,
+};
+use crate::endpoints::v1::auth::federated::callback::view::FederatedCallbackView;
+use crate::endpoints::v1::auth::federated::{
+    FederationState, FEDERATED_IDENTITY_LINKED_EVENT, FEDERATED_LOGIN_EVENT,
+};
+use crate::endpoints::v1::auth::login::endpoint::{generate_session, is_mfa_enabled, LoginError};
+use crate::endpoints::v1::auth::login::view::{LoginMfaRequiredResponseView, LoginResponseView};
+use crate::endpoints::v1::mfa::create_mfa_challenge;
+use crate::redis::handle_get_and_delete;
+use crate::security::device::UserAgent;
+use crate::security::federation::{
+    exchange_code, fetch_jwks, fetch_provider_metadata, verify_id_token, FederatedIdentity,
+    FederationConfig, FederationError,
+};
+use crate::security::jwt::check_jwt_validity;
+
+#[derive(Debug, Clone, PartialEq)]
+enum FederatedCallbackError {
+    AlreadyLinked,
+    DatabaseError,
+    InvalidState,
+    LoginRequired,
+    NoLinkedAccount,
+    NotConfigured,
+    ProviderError,
+    Rejected,
+    RedisError,
+    SessionError,
+    TooManySessions,
+}
+
+impl From<FederationError> for FederatedCallbackError {
+    fn from(error: FederationError) -> Self {
+        eprintln!("Federation Error: {}", error);
+        match error {
+            FederationError::Configuration(_) => FederatedCallbackError::NotConfigured,
+            FederationError::Provider(_) => FederatedCallbackError::ProviderError,
+            FederationError::CodeRejected(_)
+            | FederationError::InvalidIdToken(_)
+            | FederationError::UnknownKey(_)
+            | FederationError::ExpiredIdToken
+            | FederationError::NonceMismatch => FederatedCallbackError::Rejected,
+        }
+    }
+}
+
+impl std::fmt::Display for FederatedCallbackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FederatedCallbackError::AlreadyLinked => write!(
+                f,
+                "This external account or this user is already linked to another account."
+            ),
+            FederatedCallbackError::DatabaseError => {
+                write!(f, "An error occurred while accessing the database.")
+            }
+            FederatedCallbackError::InvalidState => {
+                write!(f, "Invalid or expired login attempt, start again.")
+            }
+            FederatedCallbackError::LoginRequired => {
+                write!(f, "Log in with the account to link first.")
+            }
+            FederatedCallbackError::NoLinkedAccount => write!(
+                f,
+                "No account is linked to this external account, log in and link it first."
+            ),
+            FederatedCallbackError::NotConfigured => {
+                write!(f, "Login through an identity provider is not configured.")
+            }
+            FederatedCallbackError::ProviderError => {
+                write!(f, "The identity provider could not be reached.")
+            }
+            FederatedCallbackError::Rejected => {
+                write!(f, "The identity provider response was rejected.")
+            }
+            FederatedCallbackError::RedisError => write!(f, "Internal Redis error."),
+            FederatedCallbackError::SessionError => write!(f, "Failed to create the session."),
+            FederatedCallbackError::TooManySessions => write!(
+                f,
+                "Maximum number of active sessions reached, log out elsewhere first."
+            ),
+        }
+    }
+}
+
+impl ResponseError for FederatedCallbackError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            FederatedCallbackError::AlreadyLinked => StatusCode::CONFLICT,
+            FederatedCallbackError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            FederatedCallbackError::InvalidState => StatusCode::UNAUTHORIZED,
+            FederatedCallbackError::LoginRequired => StatusCode::UNAUTHORIZED,
+            FederatedCallbackError::NoLinkedAccount => StatusCode::FORBIDDEN,
+            FederatedCallbackError::NotConfigured => StatusCode::SERVICE_UNAVAILABLE,
+            FederatedCallbackError::ProviderError => StatusCode::BAD_GATEWAY,
+            FederatedCallbackError::Rejected => StatusCode::UNAUTHORIZED,
+            FederatedCallbackError::RedisError => StatusCode::INTERNAL_SERVER_ERROR,
+            FederatedCallbackError::SessionError => StatusCode::INTERNAL_SERVER_ERROR,
+            FederatedCallbackError::TooManySessions => StatusCode::CONFLICT,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+enum FederatedOutcome {
+    Session(String, String),
+    MfaRequired(String),
+    Linked,
+}
+
+async fn consume_state(
+    state: &web::Data<AppState>,
+    request_state: &str,
+) -> Result<FederationState, FederatedCallbackError> {
+    let conn = state
+        .get_redis_conn()
+        .await
+        .ok_or(FederatedCallbackError::RedisError)?;
+    let value = handle_get_and_delete(conn, &format!("{}/federation_state", request_state))
+        .await
+        .map_err(|e| {
+            eprintln!("Redis Error: {}", e);
+            FederatedCallbackError::RedisError
+        })?
+        .ok_or(FederatedCallbackError::InvalidState)?;
+    serde_json::from_str(&value).map_err(|_| FederatedCallbackError::InvalidState)
+}
+
+async fn authenticate(
+    view: &FederatedCallbackView,
+    config: &FederationConfig,
+    federation_state: &FederationState,
+) -> Result<FederatedIdentity, FederatedCallbackError> {
+    let metadata = fetch_provider_metadata(config.issuer()).await?;
+    let id_token = exchange_code(
+        &metadata,
+        config,
+        view.code(),
+        &federation_state.code_verifier,
+    )
+    .await?;
+    let jwks = fetch_jwks(&metadata).await?;
+    Ok(verify_id_token(
+        &id_token,
+        &jwks,
+        config,
+        &federation_state.nonce,
+        Utc::now(),
+    )?)
+}
+
+async fn record_event(
+    pool: &PgPool,
+    user_id: u64,
+    event_type: &str,
+    ip_address: std::net::IpAddr,
+    details: String,
+) {
+    let event = CreateSecurityEventQueryView::new(
+        Some(user_id),
+        event_type,
+        Some(ip_address),
+        Some(details),
+    );
+    if let Err(e) = create_security_event_query(event, pool.clone()).await {
+        eprintln!("Security Event DB Error: {}", e);
+    }
+}
+
+async fn link(
+    pool: &PgPool,
+    config: &FederationConfig,
+    identity: &FederatedIdentity,
+    user_id: u64,
+    ip_address: std::net::IpAddr,
+) -> Result<(), FederatedCallbackError> {
+    let view = LinkFederatedIdentityQueryView::new(
+        config.issuer(),
+        identity.subject(),
+        user_id,
+        identity.email(),
+    );
+    match link_federated_identity_query(view, pool.clone()).await {
+        Ok(()) => {}
+        Err(DatabaseError::Query(QueryError::ConstraintViolation(_))) => {
+            return Err(FederatedCallbackError::AlreadyLinked)
+        }
+        Err(e) => {
+            eprintln!("Database Error: {}", e);
+            return Err(FederatedCallbackError::DatabaseError);
+        }
+    }
+    record_event(
+        pool,
+        user_id,
+        FEDERATED_IDENTITY_LINKED_EVENT,
+        ip_address,
+        format!(
+            "issuer = {}, subject = {}",
+            config.issuer(),
+            identity.subject()
+        ),
+    )
+    .await;
+    Ok(())
+}
+
+/**
+ * The user already linked to the external account or, on a first login, the active user
+ * with the address the provider verified, who gets linked on the way.
+ */
+async fn resolve_user(
+    pool: &PgPool,
+    config: &FederationConfig,
+    identity: &FederatedIdentity,
+    ip_address: std::net::IpAddr,
+) -> Result<u64, FederatedCallbackError> {
+    let linked = use_federated_identity_query(
+        UseFederatedIdentityQueryView::new(config.issuer(), identity.subject()),
+        pool.clone(),
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("Database Error: {}", e);
+        FederatedCallbackError::DatabaseError
+    })?;
+    if let Some(user_id) = linked {
+        return Ok(user_id);
+    }
+
+    // Une adresse non vérifiée par le fournisseur ne prouve rien
+    let email = identity
+        .verified_email()
+        .ok_or(FederatedCallbackError::NoLinkedAccount)?;
+    let user_id =
+        get_active_user_by_email_query(GetActiveUserByEmailQueryView::new(email), pool.clone())
+            .await
+            .map_err(|e| {
+                eprintln!("Database Error: {}", e);
+                FederatedCallbackError::DatabaseError
+            })?
+            .ok_or(FederatedCallbackError::NoLinkedAccount)?;
+    link(pool, config, identity, user_id, ip_address).await?;
+    Ok(user_id)
+}
+
+async fn callback(
+    view: &FederatedCallbackView,
+    state: web::Data<AppState>,
+    req: &HttpRequest,
+    ip_address: std::net::IpAddr,
+    user_agent: &UserAgent,
+) -> Result<FederatedOutcome, FederatedCallbackError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(FederatedCallbackError::DatabaseError),
+    };
+    // Consommé avant d'appeler le fournisseur : un code et son état ne servent qu'une fois
+    let federation_state = consume_state(&state, view.state()).await?;
+    let config = FederationConfig::from_env()?;
+    let identity = authenticate(view, &config, &federation_state).await?;
+
+    if let Some(link_user_id) = federation_state.link_user_id {
+        // Sans cette vérification, un état volé lierait le compte externe de la victime à l'attaquant
+        let jwt = get_jwt_from_request(req).ok_or(FederatedCallbackError::LoginRequired)?;
+        let user_id = check_jwt_validity(&jwt, &state)
+            .await
+            .map_err(|_| FederatedCallbackError::LoginRequired)?;
+        if user_id != link_user_id {
+            return Err(FederatedCallbackError::LoginRequired);
+        }
+        link(&pool, &config, &identity, user_id, ip_address).await?;
+        return Ok(FederatedOutcome::Linked);
+    }
+
+    let user_id = resolve_user(&pool, &config, &identity, ip_address).await?;
+    record_event(
+        &pool,
+        user_id,
+        FEDERATED_LOGIN_EVENT,
+        ip_address,
+        format!("issuer = {}", config.issuer()),
+    )
+    .await;
+
+    // Le fournisseur remplace le mot de passe, pas le second facteur exigé par Core
+    if is_mfa_enabled(user_id, &state)
+        .await
+        .map_err(|_| FederatedCallbackError::DatabaseError)?
+    {
+        let challenge = create_mfa_challenge(&state, user_id, view.device_info())
+            .await
+            .ok_or(FederatedCallbackError::RedisError)?;
+        return Ok(FederatedOutcome::MfaRequired(challenge));
+    }
+
+    let (jwt, refresh_token) =
+        generate_session(user_id, view.device_info(), user_agent, ip_address, state)
+            .await
+            .map_err(|e| {
+                eprintln!("Session Error: {}", e);
+                if e == LoginError::TooManySessions {
+                    return FederatedCallbackError::TooManySessions;
+                }
+                FederatedCallbackError::SessionError
+            })?;
+    Ok(FederatedOutcome::Session(jwt, refresh_token))
+}
+
+#[utoipa::path(
+    post,
+    path = "",
+    request_body = FederatedCallbackView,
+    responses(
+        (status = 200, description = "External account accepted, session created", body = LoginResponseView),
+        (status = 202, description = "External account accepted, a second factor is required on /auth/mfa_verify", body = LoginMfaRequiredResponseView),
+        (status = 204, description = "External account linked to the logged in user, who started the link from /user/me/federated_identity"),
+        (status = 401, description = "Expired state, rejected code or ID token, or linking without the session that started it"),
+        (status = 403, description = "No active user is linked to the external account or has its verified email"),
+        (status = 409, description = "The external account or the user is already linked, or the maximum number of active sessions is reached"),
+        (status = 500, description = "Internal server error"),
+        (status = 502, description = "The identity provider could not be reached"),
+        (status = 503, description = "No identity provider is configured")
+    ),
+    tag = "Auth"
+)]
+#[post("/callback")]
+pub async fn federated_callback(
+    payload: web::Json<FederatedCallbackView>,
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    conn: ConnectionInfo,
+    user_agent: UserAgent,
+) -> Result<impl Responder, FederatedCallbackError> {
+    let view = payload.into_inner();
+    let ip_str = conn.realip_remote_addr().unwrap_or("unknown").to_string();
+    let ip_address = ip_str
+        .parse::<std::net::IpAddr>()
+        .unwrap_or(std::net::IpAddr::V4(std::net::Ipv4Addr::new(0, 0, 0, 0)));
+
+    match callback(&view, state, &req, ip_address, &user_agent).await? {
+        FederatedOutcome::Session(jwt, refresh_token) => Ok(HttpResponse::Ok()
+            .append_header(("Authorization", format!("Bearer {}", jwt)))
+            .json(LoginResponseView::from(refresh_token))),
+        FederatedOutcome::MfaRequired(challenge) => {
+            Ok(HttpResponse::Accepted().json(LoginMfaRequiredResponseView::new(challenge)))
+        }
+        FederatedOutcome::Linked => Ok(HttpResponse::NoContent().finish()),
+    }
+}
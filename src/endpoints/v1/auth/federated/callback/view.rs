@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use utoipa::ToSchema;
+
+/**
+ * What the identity provider appended to FEDERATION_REDIRECT_URI, posted by that page.
+ */
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct FederatedCallbackView {
+    code: String,
+    state: String,
+    device_info: String,
+}
+
+impl FederatedCallbackView {
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    pub fn state(&self) -> &str {
+        &self.state
+    }
+
+    pub fn device_info(&self) -> &str {
+        &self.device_info
+    }
+}
+
+impl Display for FederatedCallbackView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "FederatedCallbackView {{ code: [PROTECTED], state: [PROTECTED], device_info: {} }}",
+            self.device_info
+        )
+    }
+}
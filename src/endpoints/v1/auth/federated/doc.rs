@@ -0,0 +1,10 @@
+use crate::endpoints::v1::auth::federated::authorize::doc::FederatedAuthorizeDoc;
+use crate::endpoints::v1::auth::federated::callback::doc::FederatedCallbackDoc;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(nest(
+    (path = "/authorize", api = FederatedAuthorizeDoc, tags = ["Auth"]),
+    (path = "/callback", api = FederatedCallbackDoc, tags = ["Auth"]),
+))]
+pub struct FederatedDoc;
@@ -0,0 +1,104 @@
+pub mod authorize;
+pub mod callback;
+pub mod doc;
+
+use actix_web::web;
+use mairie360_api_lib::env_manager::get_env_var;
+use mairie360_api_lib::pool::AppState;
+use serde::{Deserialize, Serialize};
+
+use crate::redis::handle_expiring_post;
+use crate::security::federation::{fetch_provider_metadata, FederationConfig, FederationError};
+use crate::security::oidc::code_challenge;
+use crate::security::token::generate_token;
+
+const DEFAULT_FEDERATION_STATE_TTL: u64 = 600;
+
+pub const FEDERATED_LOGIN_EVENT: &str = "federated_login";
+pub const FEDERATED_IDENTITY_LINKED_EVENT: &str = "federated_identity_linked";
+pub const FEDERATED_IDENTITY_UNLINKED_EVENT: &str = "federated_identity_unlinked";
+
+pub fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/federated")
+            .service(authorize::endpoint::federated_authorize)
+            .service(callback::endpoint::federated_callback),
+    );
+}
+
+/**
+ * Login started at the identity provider, stored in Redis under `{state}/federation_state`.
+ * `link_user_id` is set when a logged in user links their external account instead of logging in.
+ */
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FederationState {
+    pub nonce: String,
+    pub code_verifier: String,
+    pub link_user_id: Option<u64>,
+}
+
+/**
+ * Time in seconds the user has to come back from the identity provider (`FEDERATION_STATE_TTL`).
+ */
+pub fn federation_state_ttl() -> u64 {
+    get_env_var("FEDERATION_STATE_TTL")
+        .and_then(|value| value.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_FEDERATION_STATE_TTL)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FederationStartError {
+    Federation(FederationError),
+    RedisError,
+}
+
+/**
+ * Saves a new state and returns the identity provider URL to send the browser to.
+ */
+pub async fn start_federation(
+    state: &web::Data<AppState>,
+    link_user_id: Option<u64>,
+) -> Result<String, FederationStartError> {
+    let config = FederationConfig::from_env().map_err(FederationStartError::Federation)?;
+    let metadata = fetch_provider_metadata(config.issuer())
+        .await
+        .map_err(FederationStartError::Federation)?;
+
+    let request_state = generate_token();
+    let federation_state = FederationState {
+        nonce: generate_token(),
+        code_verifier: generate_token(),
+        link_user_id,
+    };
+    let url = metadata
+        .authorization_url(
+            &config,
+            &request_state,
+            &federation_state.nonce,
+            &code_challenge(&federation_state.code_verifier),
+        )
+        .ok_or_else(|| {
+            FederationStartError::Federation(FederationError::Provider(
+                "invalid authorization_endpoint".to_string(),
+            ))
+        })?;
+
+    let value =
+        serde_json::to_string(&federation_state).map_err(|_| FederationStartError::RedisError)?;
+    let conn = state
+        .get_redis_conn()
+        .await
+        .ok_or(FederationStartError::RedisError)?;
+    handle_expiring_post(
+        conn,
+        &format!("{}/federation_state", request_state),
+        &value,
+        federation_state_ttl(),
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("Redis Error: {}", e);
+        FederationStartError::RedisError
+    })?;
+    Ok(url)
+}
@@ -3,6 +3,12 @@ use crate::database::auth::unset_first_connection::{
     unset_first_connection_query, UnsetFirstConnectionQueryView,
 };
 use crate::endpoints::v1::auth::force_change_password::view::ForceChangePasswordView;
+use crate::endpoints::v1::auth::password_policy::view::PasswordPolicyErrorView;
+use crate::endpoints::v1::auth::password_policy::{
+    check_user_new_password, is_password_expired, record_password_change, PasswordPolicyError,
+};
+use crate::security::password::{hash_password, PasswordHasherConfig};
+use crate::security::password_policy::{PasswordPolicy, PasswordViolation};
 use actix_web::http::StatusCode;
 use actix_web::{post, web, HttpResponse, Responder, ResponseError};
 use mairie360_api_lib::pool::redis::simple_key::secured::handle_secure_get;
@@ -13,7 +19,21 @@ use sqlx::PgPool;
 enum ForceChanhePasswordError {
     DatabaseError,
     Forbidden,
+    PasswordHashError,
     Unauthorized,
+    WeakPassword(Vec<PasswordViolation>),
+}
+
+impl From<PasswordPolicyError> for ForceChanhePasswordError {
+    fn from(error: PasswordPolicyError) -> Self {
+        match error {
+            PasswordPolicyError::DatabaseError => ForceChanhePasswordError::DatabaseError,
+            PasswordPolicyError::PasswordHashError => ForceChanhePasswordError::PasswordHashError,
+            PasswordPolicyError::Violations(violations) => {
+                ForceChanhePasswordError::WeakPassword(violations)
+            }
+        }
+    }
 }
 
 impl std::fmt::Display for ForceChanhePasswordError {
@@ -25,9 +45,15 @@ impl std::fmt::Display for ForceChanhePasswordError {
             ForceChanhePasswordError::Forbidden => {
                 write!(f, "Unknown user token")
             }
+            ForceChanhePasswordError::PasswordHashError => {
+                write!(f, "Failed to secure password")
+            }
             ForceChanhePasswordError::Unauthorized => {
                 write!(f, "Unauthorized")
             }
+            ForceChanhePasswordError::WeakPassword(_) => {
+                write!(f, "Password does not meet the password policy")
+            }
         }
     }
 }
@@ -37,11 +63,19 @@ impl ResponseError for ForceChanhePasswordError {
         match self {
             ForceChanhePasswordError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
             ForceChanhePasswordError::Forbidden => StatusCode::FORBIDDEN,
+            ForceChanhePasswordError::PasswordHashError => StatusCode::INTERNAL_SERVER_ERROR,
             ForceChanhePasswordError::Unauthorized => StatusCode::UNAUTHORIZED,
+            ForceChanhePasswordError::WeakPassword(_) => StatusCode::BAD_REQUEST,
         }
     }
 
     fn error_response(&self) -> HttpResponse {
+        if let ForceChanhePasswordError::WeakPassword(violations) = self {
+            return HttpResponse::build(self.status_code()).json(PasswordPolicyErrorView::new(
+                &self.to_string(),
+                violations.clone(),
+            ));
+        }
         HttpResponse::build(self.status_code()).body(self.to_string())
     }
 }
@@ -65,17 +99,36 @@ async fn is_first_time(pool: PgPool, user_id: u64) -> bool {
         .unwrap_or(false)
 }
 
+async fn must_change_password(pool: PgPool, policy: &PasswordPolicy, user_id: u64) -> bool {
+    is_first_time(pool.clone(), user_id).await
+        || is_password_expired(&pool, policy, user_id)
+            .await
+            .unwrap_or(false)
+}
+
 async fn change_password(
     pool: PgPool,
+    policy: &PasswordPolicy,
     user_id: u64,
     new_password: &str,
 ) -> Result<(), ForceChanhePasswordError> {
+    check_user_new_password(&pool, policy, new_password, user_id).await?;
+
+    let password_hash = hash_password(new_password, PasswordHasherConfig::from_env())
+        .await
+        .map_err(|e| {
+            eprintln!("Password hashing error: {}", e);
+            ForceChanhePasswordError::PasswordHashError
+        })?;
     unset_first_connection_query(
-        UnsetFirstConnectionQueryView::new(user_id, new_password),
-        pool,
+        UnsetFirstConnectionQueryView::new(user_id, &password_hash),
+        pool.clone(),
     )
     .await
-    .map_err(|_| ForceChanhePasswordError::DatabaseError)
+    .map_err(|_| ForceChanhePasswordError::DatabaseError)?;
+    record_password_change(&pool, policy, user_id, &password_hash)
+        .await
+        .map_err(|_| ForceChanhePasswordError::DatabaseError)
 }
 
 async fn force_change_password_trigger(
@@ -92,11 +145,12 @@ async fn force_change_password_trigger(
         None => return Err(ForceChanhePasswordError::Forbidden),
     };
 
-    if !is_first_time(pool.clone(), user_id).await {
+    let policy = PasswordPolicy::from_env();
+    if !must_change_password(pool.clone(), &policy, user_id).await {
         return Err(ForceChanhePasswordError::Unauthorized);
     }
 
-    change_password(pool.clone(), user_id, view.new_password()).await?;
+    change_password(pool.clone(), &policy, user_id, view.new_password()).await?;
 
     Ok(())
 }
@@ -106,7 +160,7 @@ async fn force_change_password_trigger(
     path = "/",
     responses(
         (status = 200, description = "Password changed successfully"),
-        (status = 400, description = "Bad request"),
+        (status = 400, description = "Bad request, or the password breaks the policy", body = PasswordPolicyErrorView),
         (status = 401, description = "Unauthorized"),
         (status = 403, description = "Unknown user token"),
         (status = 500, description = "Internal server error")
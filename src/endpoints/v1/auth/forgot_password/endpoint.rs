@@ -1,205 +1,158 @@
-use crate::database::auth::is_first_time::{is_first_time_query, IsFirstTimeQueryView};
 use crate::database::get_user_id::{get_user_id_query, GetUserIdQueryView};
+use crate::database::users::get_user_by_id::{get_user_by_id_query, GetUserByIdQueryView};
+use crate::database::users::INVITED_USER_STATUS;
 use crate::endpoints::v1::auth::forgot_password::view::ForgotPasswordView;
-use crate::{build_email, get_email_sender, send_email, EmailDestination};
+use crate::endpoints::v1::auth::forgot_password::{
+    create_password_reset, send_password_reset_email, PasswordResetError,
+};
+use crate::redis::{handle_increment, handle_ttl};
+use crate::send_email_in_background;
+use actix_web::dev::ConnectionInfo;
 use actix_web::http::StatusCode;
 use actix_web::{post, web, HttpResponse, Responder, ResponseError};
-use mairie360_api_lib::database::queries::does_user_exist_by_email_query;
-use mairie360_api_lib::database::query_views::DoesUserExistByEmailQueryView;
-use mairie360_api_lib::pool::redis::simple_key::secured::{handle_secure_get, handle_secure_post};
 use mairie360_api_lib::pool::AppState;
 use sqlx::PgPool;
-use uuid::Uuid;
+
+const MAX_REQUESTS_PER_EMAIL: u64 = 3;
+const MAX_REQUESTS_PER_IP: u64 = 10;
+const REQUEST_WINDOW: u64 = 3600;
 
 #[derive(Debug, Clone, PartialEq)]
-enum ResetPasswordError {
-    AlreadyRequested,
+enum ForgotPasswordError {
     DatabaseError,
-    MailError,
     RedisError,
-    UserFirstTimeError,
-    UserNotFound,
+    TooManyRequests(u64),
+}
+
+impl From<PasswordResetError> for ForgotPasswordError {
+    fn from(error: PasswordResetError) -> Self {
+        match error {
+            PasswordResetError::DatabaseError => ForgotPasswordError::DatabaseError,
+            PasswordResetError::RedisError => ForgotPasswordError::RedisError,
+        }
+    }
 }
 
-impl std::fmt::Display for ResetPasswordError {
+impl std::fmt::Display for ForgotPasswordError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            ResetPasswordError::AlreadyRequested => {
-                write!(f, "Password reset already requested.")
-            }
-            ResetPasswordError::DatabaseError => {
+            ForgotPasswordError::DatabaseError => {
                 write!(f, "An error occurred while accessing the database.")
             }
-            ResetPasswordError::MailError => {
-                write!(f, "An error occurred while sending the email.")
-            }
-            ResetPasswordError::RedisError => {
+            ForgotPasswordError::RedisError => {
                 write!(f, "An error occurred while accessing Redis.")
             }
-            ResetPasswordError::UserFirstTimeError => {
-                write!(f, "User not valid.")
-            }
-            ResetPasswordError::UserNotFound => {
-                write!(f, "User not found.")
+            ForgotPasswordError::TooManyRequests(_) => {
+                write!(f, "Too many password resets requested, retry later.")
             }
         }
     }
 }
 
-impl ResponseError for ResetPasswordError {
+impl ResponseError for ForgotPasswordError {
     fn status_code(&self) -> StatusCode {
         match self {
-            ResetPasswordError::AlreadyRequested => StatusCode::CONFLICT,
-            ResetPasswordError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
-            ResetPasswordError::MailError => StatusCode::INTERNAL_SERVER_ERROR,
-            ResetPasswordError::RedisError => StatusCode::INTERNAL_SERVER_ERROR,
-            ResetPasswordError::UserFirstTimeError => StatusCode::UNAUTHORIZED,
-            ResetPasswordError::UserNotFound => StatusCode::NOT_FOUND,
+            ForgotPasswordError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            ForgotPasswordError::RedisError => StatusCode::INTERNAL_SERVER_ERROR,
+            ForgotPasswordError::TooManyRequests(_) => StatusCode::TOO_MANY_REQUESTS,
         }
     }
 
     fn error_response(&self) -> HttpResponse {
+        if let ForgotPasswordError::TooManyRequests(retry_after) = self {
+            return HttpResponse::build(self.status_code())
+                .append_header(("Retry-After", retry_after.to_string()))
+                .body(self.to_string());
+        }
         HttpResponse::build(self.status_code()).body(self.to_string())
     }
 }
 
-async fn check_user(pool: PgPool, email: &str) -> Result<(), ResetPasswordError> {
-    println!("email: {}", email);
-    let view = DoesUserExistByEmailQueryView::new(email.to_string());
-    let result = does_user_exist_by_email_query(view, pool.clone()).await;
-    match result {
-        Ok(true) => {}
-        _ => return Err(ResetPasswordError::UserNotFound),
-    };
-
-    let view = GetUserIdQueryView::new(email);
-    let user_id = match get_user_id_query(view, pool.clone()).await {
-        Ok(user_id) => user_id,
-        Err(_) => return Err(ResetPasswordError::DatabaseError),
-    };
-
-    let view = IsFirstTimeQueryView::new(user_id as u64);
-    let result = is_first_time_query(view, pool).await.unwrap();
-    if result {
-        Ok(())
-    } else {
-        Err(ResetPasswordError::UserFirstTimeError)
-    }
-}
-
-async fn handle_forgot_password(
-    temporary_token: String,
-    dest: &str,
-) -> Result<(), ResetPasswordError> {
-    // Étape 1 : On récupère où envoyer le mail (les adresses fixes de la CI)
-    let destination = EmailDestination {
-        from: match get_email_sender() {
-            Ok(sender) => sender,
-            Err(e) => {
-                eprintln!("Email Sender Error: {}", e);
-                return Err(ResetPasswordError::MailError);
-            }
-        },
-        to: dest.to_string(),
-    };
-
-    // Préparation du contenu du mail
-    let subject = "Réinitialisation de votre mot de passe";
-    let body = format!(
-        "Bonjour, voici votre jeton de réinitialisation : {}",
-        temporary_token
-    );
-
-    // Étape 2 : On construit le mail avec les bonnes infos
-    let email = match build_email(&destination, subject, &body) {
-        Ok(email) => email,
-        Err(e) => {
-            eprintln!("Email Build Error: {}", e);
-            return Err(ResetPasswordError::MailError);
-        }
-    };
-
-    // Étape 3 : On l'envoie via le serveur SMTP
-    match send_email(email).await {
-        Ok(_) => Ok(()),
-        Err(e) => {
-            eprintln!("Mail Error: {}", e);
-            Err(ResetPasswordError::MailError)
-        }
+async fn check_request_limit(
+    state: &web::Data<AppState>,
+    subject: &str,
+    max: u64,
+) -> Result<(), ForgotPasswordError> {
+    let key = format!("{}/forgot_password_request", subject);
+    let conn = state
+        .get_redis_conn()
+        .await
+        .ok_or(ForgotPasswordError::RedisError)?;
+    let count = handle_increment(conn, &key, REQUEST_WINDOW)
+        .await
+        .map_err(|e| {
+            eprintln!("Redis Error: {}", e);
+            ForgotPasswordError::RedisError
+        })?;
+    if count <= max {
+        return Ok(());
     }
-}
 
-async fn trigger(state: web::Data<AppState>, email: &str) -> Result<(), ResetPasswordError> {
-    let token = Uuid::new_v4().to_string();
-    handle_secure_post(
-        state.get_redis_conn().await.unwrap(),
-        &format!("{}/forgot_password_token", email),
-        &token,
-    )
-    .await
-    .map_err(|e| {
-        eprintln!("Redis Error: {}", e);
-        ResetPasswordError::RedisError
-    })?;
-    handle_secure_post(
-        state.get_redis_conn().await.unwrap(),
-        &format!("{}/forgot_password_email", token),
-        &format!("{}", email),
-    )
-    .await
-    .map_err(|e| {
-        eprintln!("Redis Error: {}", e);
-        ResetPasswordError::RedisError
-    })?;
-    match handle_forgot_password(token, email).await {
-        Ok(_) => Ok(()),
-        Err(_) => Err(ResetPasswordError::MailError),
-    }
+    let conn = state
+        .get_redis_conn()
+        .await
+        .ok_or(ForgotPasswordError::RedisError)?;
+    let retry_after = handle_ttl(conn, &key)
+        .await
+        .map_err(|e| {
+            eprintln!("Redis Error: {}", e);
+            ForgotPasswordError::RedisError
+        })?
+        .unwrap_or(REQUEST_WINDOW);
+    Err(ForgotPasswordError::TooManyRequests(retry_after.max(1)))
 }
 
-async fn forgot_password_trigger(
-    state: web::Data<AppState>,
-    view: ForgotPasswordView,
-) -> Result<(), ResetPasswordError> {
-    let token = handle_secure_get(
-        state.get_redis_conn().await.unwrap(),
-        &format!("{}/forgot_password_token", view.email()),
-    )
-    .await;
-    if token.is_ok() {
-        return Err(ResetPasswordError::AlreadyRequested);
-    }
-
-    let pool = match state.db_pool.clone() {
-        Some(pool) => pool,
-        None => return Err(ResetPasswordError::DatabaseError),
+/**
+ * The account that may reset its password with this address, if any. Invited users choose
+ * their first password through their invitation and archived users cannot log in anyway.
+ */
+async fn find_user(pool: &PgPool, email: &str) -> Result<Option<u64>, ForgotPasswordError> {
+    let user_id = match get_user_id_query(GetUserIdQueryView::new(email), pool.clone()).await {
+        Ok(user_id) => user_id as u64,
+        Err(_) => return Ok(None),
     };
-
-    match check_user(pool.clone(), view.email()).await {
-        Err(err) => Err(err),
-        _ => trigger(state, view.email()).await,
+    let user = get_user_by_id_query(GetUserByIdQueryView::new(user_id), pool.clone())
+        .await
+        .map_err(|e| {
+            eprintln!("Database Error: {}", e);
+            ForgotPasswordError::DatabaseError
+        })?;
+    if user.status() == INVITED_USER_STATUS || user.is_archived() {
+        return Ok(None);
     }
+    Ok(Some(user_id))
 }
 
 #[utoipa::path(
     post,
     path = "/",
+    request_body = ForgotPasswordView,
     responses(
-        (status = 200, description = "Forgot password request sent successfully"),
-        (status = 400, description = "Bad request"),
-        (status = 404, description = "User not found"),
+        (status = 202, description = "A reset link is sent if the address belongs to an account that can reset its password"),
+        (status = 429, description = "Too many password resets requested, retry after the delay given in the Retry-After header"),
         (status = 500, description = "Internal server error")
     ),
-    tag = "Auth",
-    security(
-        ("jwt" = [])
-    )
+    tag = "Auth"
 )]
 #[post("/forgot_password")]
 pub async fn forgot_password(
     state: web::Data<AppState>,
     body: web::Json<ForgotPasswordView>,
-) -> Result<impl Responder, ResetPasswordError> {
-    forgot_password_trigger(state, body.into_inner()).await?;
-    Ok(HttpResponse::Ok())
+    conn: ConnectionInfo,
+) -> Result<impl Responder, ForgotPasswordError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(ForgotPasswordError::DatabaseError),
+    };
+    let ip = conn.realip_remote_addr().unwrap_or("unknown").to_string();
+    check_request_limit(&state, &ip, MAX_REQUESTS_PER_IP).await?;
+    check_request_limit(&state, &body.email().to_lowercase(), MAX_REQUESTS_PER_EMAIL).await?;
+
+    // Même réponse que l'adresse existe ou non, pour ne pas révéler les comptes
+    if let Some(user_id) = find_user(&pool, body.email()).await? {
+        let token = create_password_reset(&state, &pool, user_id).await?;
+        let email = body.email().to_string();
+        send_email_in_background(async move { send_password_reset_email(&email, &token).await });
+    }
+    Ok(HttpResponse::Accepted())
 }
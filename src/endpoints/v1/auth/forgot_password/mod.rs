@@ -1,3 +1,154 @@
 pub mod doc;
 pub mod endpoint;
 pub mod view;
+
+use actix_web::web;
+use mairie360_api_lib::env_manager::get_env_var;
+use mairie360_api_lib::pool::AppState;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+
+use crate::database::auth::get_password_hash::{get_password_hash_query, GetPasswordHashQueryView};
+use crate::redis::{handle_delete, handle_expiring_post, handle_get};
+use crate::security::token::{generate_token, hash_token};
+use crate::{build_email, get_email_link_url, get_email_sender, send_email, EmailDestination};
+
+const DEFAULT_PASSWORD_RESET_TTL: u64 = 3600;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PasswordResetError {
+    DatabaseError,
+    RedisError,
+}
+
+/**
+ * Pending reset, stored in Redis under `{token hash}/password_reset`. The fingerprint of the
+ * password hash at request time kills the link as soon as the password changes, whatever the way.
+ */
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PasswordReset {
+    pub user_id: u64,
+    pub password_fingerprint: String,
+}
+
+/**
+ * Lifetime in seconds of a reset link (`PASSWORD_RESET_TTL`).
+ */
+pub fn password_reset_ttl() -> u64 {
+    get_env_var("PASSWORD_RESET_TTL")
+        .and_then(|value| value.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_PASSWORD_RESET_TTL)
+}
+
+pub fn password_fingerprint(password_hash: &str) -> String {
+    hash_token(password_hash)
+}
+
+pub fn password_reset_key(token_hash: &str) -> String {
+    format!("{}/password_reset", token_hash)
+}
+
+/**
+ * Latest link of a user, so a new request replaces it.
+ */
+pub fn user_password_reset_key(user_id: u64) -> String {
+    format!("{}/password_reset_token", user_id)
+}
+
+/**
+ * Stores a new reset for the user, replacing the previous one, and returns the token to hand
+ * to `/auth/reset_password`.
+ */
+pub async fn create_password_reset(
+    state: &web::Data<AppState>,
+    pool: &PgPool,
+    user_id: u64,
+) -> Result<String, PasswordResetError> {
+    let password_hash =
+        get_password_hash_query(GetPasswordHashQueryView::new(user_id), pool.clone())
+            .await
+            .map_err(|e| {
+                eprintln!("Database Error: {}", e);
+                PasswordResetError::DatabaseError
+            })?
+            .unwrap_or_default();
+    let reset = serde_json::to_string(&PasswordReset {
+        user_id,
+        password_fingerprint: password_fingerprint(&password_hash),
+    })
+    .map_err(|_| PasswordResetError::RedisError)?;
+    let redis_error = |e| {
+        eprintln!("Redis Error: {}", e);
+        PasswordResetError::RedisError
+    };
+    let conn = || async {
+        state
+            .get_redis_conn()
+            .await
+            .ok_or(PasswordResetError::RedisError)
+    };
+
+    // Un seul lien valide à la fois : le précédent ne fonctionne plus
+    let user_key = user_password_reset_key(user_id);
+    if let Some(previous) = handle_get(conn().await?, &user_key)
+        .await
+        .map_err(redis_error)?
+    {
+        handle_delete(conn().await?, &[password_reset_key(&previous)])
+            .await
+            .map_err(redis_error)?;
+    }
+
+    // Seule l'empreinte du jeton est gardée : une copie de Redis ne permet pas de s'en servir
+    let token = generate_token();
+    let token_hash = hash_token(&token);
+    let ttl = password_reset_ttl();
+    handle_expiring_post(conn().await?, &password_reset_key(&token_hash), &reset, ttl)
+        .await
+        .map_err(redis_error)?;
+    handle_expiring_post(conn().await?, &user_key, &token_hash, ttl)
+        .await
+        .map_err(redis_error)?;
+    Ok(token)
+}
+
+/**
+ * Emails a link to the front-end reset page (`PASSWORD_RESET_URL`), which posts the
+ * token and the new password back to `/auth/reset_password`. Failures, including a missing
+ * `PASSWORD_RESET_URL`, are only logged.
+ */
+pub async fn send_password_reset_email(email: &str, token: &str) {
+    let url = match get_email_link_url("PASSWORD_RESET_URL") {
+        Some(url) => url,
+        None => return,
+    };
+    let destination = EmailDestination {
+        from: match get_email_sender() {
+            Ok(sender) => sender,
+            Err(e) => {
+                eprintln!("Email Sender Error: {}", e);
+                return;
+            }
+        },
+        to: email.to_string(),
+    };
+    let subject = "Réinitialisation de votre mot de passe";
+    let body = format!(
+        "Bonjour, choisissez un nouveau mot de passe en ouvrant ce lien, valable {} minutes et une seule fois : {}?token={}\n\
+         Si vous n'êtes pas à l'origine de cette demande, ignorez ce message : votre mot de passe reste inchangé.",
+        password_reset_ttl() / 60,
+        url,
+        token
+    );
+
+    let message = match build_email(&destination, subject, &body) {
+        Ok(message) => message,
+        Err(e) => {
+            eprintln!("Email Build Error: {}", e);
+            return;
+        }
+    };
+    if let Err(e) = send_email(message).await {
+        eprintln!("Mail Error: {}", e);
+    }
+}
@@ -1,25 +1,52 @@
 use super::view::{LoginResponseView, LoginView};
+use crate::database::auth::change_password::{change_password_query, ChangePasswordQueryView};
 use crate::database::auth::login::{login_query, LoginUserQueryView};
+use crate::database::mfa::get_user_mfa::{get_user_mfa_query, GetUserMfaQueryView};
+use crate::database::security_events::create_security_event::{
+    create_security_event_query, CreateSecurityEventQueryView,
+};
 use crate::database::sessions::create_session::CreateSessionQueryView;
-use crate::endpoints::v1::auth::create_new_session;
-use crate::endpoints::v1::auth::login::view::LoginFirstConnectionResponseView;
+use crate::database::users::get_user_by_id::{get_user_by_id_query, GetUserByIdQueryView};
+use crate::database::users::PENDING_USER_STATUS;
+use crate::endpoints::v1::auth::login::throttle::{
+    clear_failures, record_failure, release_attempt, reserve_attempt, ThrottleSubject,
+    ACCOUNT_LOCKED_EVENT,
+};
+use crate::endpoints::v1::auth::login::view::{
+    LoginFirstConnectionResponseView, LoginMfaRequiredResponseView,
+};
+use crate::endpoints::v1::auth::password_policy::is_password_expired;
+use crate::endpoints::v1::auth::sign_in_alert::{
+    is_new_device, notify_new_sign_in, remember_device,
+};
+use crate::endpoints::v1::auth::{create_new_session, NewSessionError};
+use crate::endpoints::v1::mfa::create_mfa_challenge;
+use crate::security::device::{DeviceDetails, UserAgent};
+use crate::security::jwt::generate_jwt;
+use crate::security::login_throttle::LoginThrottleConfig;
+use crate::security::password::{
+    hash_password, verify_password, PasswordHasherConfig, PasswordVerification,
+};
+use crate::security::password_policy::PasswordPolicy;
+use crate::security::token::{generate_token, hash_token};
 use actix_web::{
     dev::ConnectionInfo, http::StatusCode, post, web, HttpResponse, Responder, ResponseError,
 };
-use base64::{engine::general_purpose, Engine as _};
-use mairie360_api_lib::jwt_manager::generate_jwt;
 use mairie360_api_lib::pool::redis::simple_key::secured::{handle_secure_get, handle_secure_post};
 use mairie360_api_lib::pool::AppState;
-use rand::fill;
 use uuid::Uuid;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum LoginError {
     DatabaseError,
+    EmailNotVerified,
     FirstConnectError(String),
     InvalidCredentials,
+    PasswordHashError,
     RedisError,
     TokenGenerationError,
+    TooManyAttempts(u64),
+    TooManySessions,
 }
 
 impl std::fmt::Display for LoginError {
@@ -30,10 +57,23 @@ impl std::fmt::Display for LoginError {
                 write!(f, "An error occurred while accessing the database.")
             }
             LoginError::TokenGenerationError => write!(f, "Failed to generate JWT token."),
+            LoginError::EmailNotVerified => {
+                write!(f, "Email address not verified, check your emails.")
+            }
             LoginError::FirstConnectError(token) => {
                 write!(f, "{}", token.to_string())
             }
+            LoginError::PasswordHashError => write!(f, "Failed to verify password."),
             LoginError::RedisError => write!(f, "Internal Redis error."),
+            LoginError::TooManyAttempts(_) => {
+                write!(f, "Too many failed login attempts, retry later.")
+            }
+            LoginError::TooManySessions => {
+                write!(
+                    f,
+                    "Maximum number of active sessions reached, log out elsewhere first."
+                )
+            }
         }
     }
 }
@@ -42,10 +82,14 @@ impl ResponseError for LoginError {
     fn status_code(&self) -> StatusCode {
         match self {
             LoginError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            LoginError::EmailNotVerified => StatusCode::FORBIDDEN,
             LoginError::FirstConnectError(_) => StatusCode::PRECONDITION_FAILED,
             LoginError::InvalidCredentials => StatusCode::UNAUTHORIZED,
+            LoginError::PasswordHashError => StatusCode::INTERNAL_SERVER_ERROR,
             LoginError::RedisError => StatusCode::INTERNAL_SERVER_ERROR,
             LoginError::TokenGenerationError => StatusCode::INTERNAL_SERVER_ERROR,
+            LoginError::TooManyAttempts(_) => StatusCode::TOO_MANY_REQUESTS,
+            LoginError::TooManySessions => StatusCode::CONFLICT,
         }
     }
 
@@ -54,31 +98,53 @@ impl ResponseError for LoginError {
             return HttpResponse::build(self.status_code())
                 .json(LoginFirstConnectionResponseView::new(self.to_string()));
         }
+        if let LoginError::TooManyAttempts(retry_after) = self {
+            return HttpResponse::build(self.status_code())
+                .append_header(("Retry-After", retry_after.to_string()))
+                .body(self.to_string());
+        }
         HttpResponse::build(self.status_code()).body(self.to_string())
     }
 }
 
-fn generate_refresh_token() -> String {
-    // 32 octets (256 bits) est un standard de sécurité solide
-    let mut buffer = [0u8; 32];
-
-    // Remplissage avec des données aléatoires sécurisées
-    fill(&mut buffer);
-
-    // Encodage en Base64 pour avoir une String lisible
-    general_purpose::URL_SAFE_NO_PAD.encode(buffer)
+pub enum LoginOutcome {
+    Session(String, String),
+    MfaRequired(String),
 }
 
 pub async fn generate_session(
     user_id: u64,
     device_info: &str,
+    user_agent: &UserAgent,
     ip_adress: std::net::IpAddr,
     state: web::Data<AppState>,
 ) -> Result<(String, String), LoginError> {
-    let refresh_token = generate_refresh_token();
-    let view = CreateSessionQueryView::new(user_id, &refresh_token, device_info, ip_adress);
-    create_new_session(state, user_id, view).await;
-    let jwt = generate_jwt(user_id.to_string().as_str()).map_err(|e| {
+    // Comparé à l'historique avant que la nouvelle session n'en fasse partie
+    let new_device = is_new_device(&state, user_id, ip_adress, device_info).await;
+    let refresh_token = generate_token();
+    let details = DeviceDetails::parse(user_agent.value(), device_info);
+    let view =
+        CreateSessionQueryView::new(user_id, &hash_token(&refresh_token), device_info, ip_adress)
+            .with_device(details.clone());
+    let session_id = create_new_session(state.clone(), user_id, view)
+        .await
+        .map_err(|e| match e {
+            NewSessionError::Database(_) => LoginError::DatabaseError,
+            NewSessionError::SessionLimitReached => LoginError::TooManySessions,
+        })?;
+    remember_device(&state, user_id, ip_adress, device_info).await;
+    if new_device {
+        notify_new_sign_in(
+            &state,
+            user_id,
+            session_id,
+            &details,
+            device_info,
+            ip_adress,
+        )
+        .await;
+    }
+    let jwt = generate_jwt(user_id.to_string().as_str(), &session_id).map_err(|e| {
         eprintln!("JWT Generation Error: {}", e);
         LoginError::TokenGenerationError
     })?;
@@ -124,11 +190,121 @@ async fn generate_first_connection_token(
     Ok(token)
 }
 
+async fn upgrade_password_hash(user_id: u64, password: &str, state: &web::Data<AppState>) {
+    let hash = match hash_password(password, PasswordHasherConfig::from_env()).await {
+        Ok(hash) => hash,
+        Err(e) => {
+            eprintln!("Password Rehash Error: {}", e);
+            return;
+        }
+    };
+    change_password_query(
+        ChangePasswordQueryView::new(&hash, user_id),
+        state.db_pool.clone().unwrap(),
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("Password Rehash DB Error: {}", e);
+    })
+    .ok();
+}
+
+async fn is_pending(user_id: u64, state: &web::Data<AppState>) -> Result<bool, LoginError> {
+    let user = get_user_by_id_query(
+        GetUserByIdQueryView::new(user_id),
+        state.db_pool.clone().unwrap(),
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("Login DB Error: {}", e);
+        LoginError::DatabaseError
+    })?;
+    Ok(user.status() == PENDING_USER_STATUS)
+}
+
+async fn is_expired(user_id: u64, state: &web::Data<AppState>) -> Result<bool, LoginError> {
+    is_password_expired(
+        &state.db_pool.clone().unwrap(),
+        &PasswordPolicy::from_env(),
+        user_id,
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("Password Expiry DB Error: {}", e);
+        LoginError::DatabaseError
+    })
+}
+
+pub async fn is_mfa_enabled(user_id: u64, state: &web::Data<AppState>) -> Result<bool, LoginError> {
+    let mfa = get_user_mfa_query(
+        GetUserMfaQueryView::new(user_id),
+        state.db_pool.clone().unwrap(),
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("MFA DB Error: {}", e);
+        LoginError::DatabaseError
+    })?;
+    Ok(mfa.map(|mfa| mfa.enabled()).unwrap_or(false))
+}
+
+async fn check_throttle(
+    state: &web::Data<AppState>,
+    subject: &ThrottleSubject,
+) -> Result<(), LoginError> {
+    match reserve_attempt(state, subject, &LoginThrottleConfig::from_env()).await? {
+        Some(seconds) => Err(LoginError::TooManyAttempts(seconds.max(1))),
+        None => Ok(()),
+    }
+}
+
+async fn record_account_locked(
+    state: &web::Data<AppState>,
+    user_id: u64,
+    ip_adress: std::net::IpAddr,
+    config: &LoginThrottleConfig,
+) {
+    let event = CreateSecurityEventQueryView::new(
+        Some(user_id),
+        ACCOUNT_LOCKED_EVENT,
+        Some(ip_adress),
+        Some(format!("lockout_seconds = {}", config.lockout_seconds())),
+    );
+    if let Err(e) = create_security_event_query(event, state.db_pool.clone().unwrap()).await {
+        eprintln!("Security Event DB Error: {}", e);
+    }
+}
+
+/**
+ * Counts a failed attempt against the IP and, when the email matched, the account.
+ */
+async fn reject_attempt(
+    state: &web::Data<AppState>,
+    user_id: Option<u64>,
+    ip_adress: std::net::IpAddr,
+) -> LoginError {
+    let config = LoginThrottleConfig::from_env();
+    if let Err(e) = record_failure(state, &ThrottleSubject::Ip(ip_adress), &config).await {
+        return e;
+    }
+    if let Some(user_id) = user_id {
+        match record_failure(state, &ThrottleSubject::Account(user_id), &config).await {
+            Ok(true) => record_account_locked(state, user_id, ip_adress, &config).await,
+            Ok(false) => {}
+            Err(e) => return e,
+        }
+    }
+    LoginError::InvalidCredentials
+}
+
 async fn login_user(
     login_view: &LoginView,
     state: web::Data<AppState>,
     ip_adress: std::net::IpAddr,
-) -> Result<(String, String), LoginError> {
+    user_agent: &UserAgent,
+) -> Result<LoginOutcome, LoginError> {
+    check_throttle(&state, &ThrottleSubject::Ip(ip_adress)).await?;
+
     let view = LoginUserQueryView::new(login_view.email(), login_view.password());
 
     let user_record = login_query(view, state.db_pool.clone().unwrap())
@@ -138,27 +314,72 @@ async fn login_user(
             LoginError::DatabaseError
         })?;
 
-    match user_record {
-        Some(user) if user.first_connect() => Err(LoginError::FirstConnectError(
-            generate_first_connection_token(user.user_id() as u64, state).await?,
-        )),
-        Some(user) if login_view.password() == user.password().trim() => {
-            generate_session(
-                user.user_id() as u64,
-                &login_view.device_info(),
-                ip_adress,
-                state,
-            )
-            .await
-        }
-        _ => {
+    let user = match user_record {
+        Some(user) => user,
+        None => {
             eprintln!(
                 "Login failed: Invalid credentials for {}",
                 login_view.email()
             );
-            Err(LoginError::InvalidCredentials)
+            return Err(reject_attempt(&state, None, ip_adress).await);
         }
+    };
+    let user_id = user.user_id() as u64;
+    check_throttle(&state, &ThrottleSubject::Account(user_id)).await?;
+
+    let verification = verify_password(
+        &login_view.password(),
+        user.password(),
+        PasswordHasherConfig::from_env(),
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("Password Verification Error: {}", e);
+        LoginError::PasswordHashError
+    })?;
+
+    if !verification.is_valid() {
+        eprintln!(
+            "Login failed: Invalid credentials for {}",
+            login_view.email()
+        );
+        return Err(reject_attempt(&state, Some(user_id), ip_adress).await);
+    }
+
+    release_attempt(&state, &ThrottleSubject::Ip(ip_adress)).await?;
+    clear_failures(&state, &ThrottleSubject::Account(user_id)).await?;
+    // Vérifié après le mot de passe pour ne pas révéler l'existence du compte
+    if is_pending(user_id, &state).await? {
+        return Err(LoginError::EmailNotVerified);
     }
+    if verification == PasswordVerification::ValidNeedsRehash {
+        upgrade_password_hash(user_id, &login_view.password(), &state).await;
+    }
+
+    // Un mot de passe expiré passe par le même changement forcé qu'une première connexion
+    if user.first_connect() || is_expired(user_id, &state).await? {
+        return Err(LoginError::FirstConnectError(
+            generate_first_connection_token(user_id, state).await?,
+        ));
+    }
+
+    // Le mot de passe seul ne suffit pas : la session n'est créée qu'après mfa_verify
+    if is_mfa_enabled(user_id, &state).await? {
+        let challenge = create_mfa_challenge(&state, user_id, &login_view.device_info())
+            .await
+            .ok_or(LoginError::RedisError)?;
+        return Ok(LoginOutcome::MfaRequired(challenge));
+    }
+
+    let (jwt, refresh_token) = generate_session(
+        user_id,
+        &login_view.device_info(),
+        user_agent,
+        ip_adress,
+        state,
+    )
+    .await?;
+    Ok(LoginOutcome::Session(jwt, refresh_token))
 }
 
 #[utoipa::path(
@@ -167,8 +388,12 @@ async fn login_user(
     request_body = LoginView,
     responses(
         (status = 200, description = "User login successfully!", body = LoginResponseView),
+        (status = 202, description = "Password accepted, a second factor is required on /auth/mfa_verify", body = LoginMfaRequiredResponseView),
         (status = 401, description = "Invalid credentials provided."),
-        (status = 412, description = "User needs to change password because first login", body = LoginFirstConnectionResponseView),
+        (status = 403, description = "Email address not verified yet"),
+        (status = 409, description = "Maximum number of active sessions reached and the session policy refuses new logins"),
+        (status = 412, description = "User needs to change password because first login or expired password", body = LoginFirstConnectionResponseView),
+        (status = 429, description = "Too many failed attempts, retry after the delay given in the Retry-After header"),
         (status = 500, description = "Internal server error")
     ),
     tag = "Auth"
@@ -178,6 +403,7 @@ pub async fn login(
     payload: web::Json<LoginView>,
     state: web::Data<AppState>,
     conn: ConnectionInfo,
+    user_agent: UserAgent,
 ) -> Result<impl Responder, LoginError> {
     let login_view = payload.into_inner();
     let ip_str = conn.realip_remote_addr().unwrap_or("unknown").to_string();
@@ -187,9 +413,12 @@ pub async fn login(
             .unwrap_or(std::net::IpAddr::V4(std::net::Ipv4Addr::new(0, 0, 0, 0))),
     );
 
-    let (jwt, refresh_token) = login_user(&login_view, state, ip_address).await?;
-
-    Ok(HttpResponse::Ok()
-        .append_header(("Authorization", format!("Bearer {}", jwt)))
-        .json(LoginResponseView::from(refresh_token)))
+    match login_user(&login_view, state, ip_address, &user_agent).await? {
+        LoginOutcome::Session(jwt, refresh_token) => Ok(HttpResponse::Ok()
+            .append_header(("Authorization", format!("Bearer {}", jwt)))
+            .json(LoginResponseView::from(refresh_token))),
+        LoginOutcome::MfaRequired(challenge) => {
+            Ok(HttpResponse::Accepted().json(LoginMfaRequiredResponseView::new(challenge)))
+        }
+    }
 }
@@ -1,3 +1,4 @@
 pub mod doc;
 pub mod endpoint;
+pub mod throttle;
 pub mod view;
@@ -0,0 +1,181 @@
+use actix_web::web;
+use deadpool_redis::redis::RedisError;
+use deadpool_redis::Connection;
+use mairie360_api_lib::pool::AppState;
+
+use crate::endpoints::v1::auth::login::endpoint::LoginError;
+use crate::redis::{
+    handle_decrement, handle_delete, handle_expiring_post, handle_get, handle_increment, handle_ttl,
+};
+use crate::security::login_throttle::LoginThrottleConfig;
+
+pub const ACCOUNT_LOCKED_EVENT: &str = "account_locked";
+pub const ACCOUNT_UNLOCKED_EVENT: &str = "account_unlocked";
+
+/**
+ * What failed attempts are counted against: the targeted account and the client IP for
+ * passwords, the account alone for second-factor codes. Unknown emails only count against
+ * the IP. Second-factor failures have their own counters, which a correct password does not
+ * clear, so logging in again does not buy new guesses.
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub enum ThrottleSubject {
+    Account(u64),
+    Ip(std::net::IpAddr),
+    SecondFactor(u64),
+}
+
+impl ThrottleSubject {
+    pub fn key(&self, suffix: &str) -> String {
+        match self {
+            ThrottleSubject::Account(user_id) => format!("{}/login_{}", user_id, suffix),
+            ThrottleSubject::Ip(ip) => format!("{}/login_{}", ip, suffix),
+            ThrottleSubject::SecondFactor(user_id) => format!("{}/mfa_{}", user_id, suffix),
+        }
+    }
+
+    fn max_failures(&self, config: &LoginThrottleConfig) -> u64 {
+        match self {
+            ThrottleSubject::Account(_) => config.max_account_failures(),
+            ThrottleSubject::Ip(_) => config.max_ip_failures(),
+            ThrottleSubject::SecondFactor(_) => config.max_account_failures(),
+        }
+    }
+}
+
+async fn redis_conn(state: &web::Data<AppState>) -> Result<Connection, LoginError> {
+    state.get_redis_conn().await.ok_or(LoginError::RedisError)
+}
+
+fn redis_error(e: RedisError) -> LoginError {
+    eprintln!("Redis Error: {}", e);
+    LoginError::RedisError
+}
+
+/**
+ * Seconds before the subject may try again, covering both the lockout and the
+ * back-off delay. None when an attempt is allowed now.
+ */
+pub async fn retry_after(
+    state: &web::Data<AppState>,
+    subject: &ThrottleSubject,
+) -> Result<Option<u64>, LoginError> {
+    let mut wait = None;
+    for key in [subject.key("locked"), subject.key("delay")] {
+        let ttl = handle_ttl(redis_conn(state).await?, &key)
+            .await
+            .map_err(redis_error)?;
+        wait = wait.max(ttl);
+    }
+    Ok(wait)
+}
+
+/**
+ * Counts the attempt before the credentials are checked, so that concurrent requests
+ * cannot all get past the limit while the first ones are still being verified.
+ * Returns the seconds to wait when the attempt is refused.
+ */
+pub async fn reserve_attempt(
+    state: &web::Data<AppState>,
+    subject: &ThrottleSubject,
+    config: &LoginThrottleConfig,
+) -> Result<Option<u64>, LoginError> {
+    if let Some(seconds) = retry_after(state, subject).await? {
+        return Ok(Some(seconds));
+    }
+    let attempts = handle_increment(
+        redis_conn(state).await?,
+        &subject.key("failures"),
+        config.lockout_seconds(),
+    )
+    .await
+    .map_err(redis_error)?;
+    if attempts <= subject.max_failures(config) {
+        return Ok(None);
+    }
+    // Le budget est déjà pris par des tentatives en cours ou échouées
+    let wait = handle_ttl(redis_conn(state).await?, &subject.key("failures"))
+        .await
+        .map_err(redis_error)?;
+    Ok(Some(wait.unwrap_or(config.lockout_seconds())))
+}
+
+/**
+ * Gives back an attempt reserved by `reserve_attempt` that turned out to be valid.
+ */
+pub async fn release_attempt(
+    state: &web::Data<AppState>,
+    subject: &ThrottleSubject,
+) -> Result<(), LoginError> {
+    handle_decrement(redis_conn(state).await?, &subject.key("failures"))
+        .await
+        .map_err(redis_error)
+}
+
+/**
+ * Turns an attempt reserved by `reserve_attempt` into a failure and starts the next
+ * back-off delay. Returns true when this failure locked the subject.
+ */
+pub async fn record_failure(
+    state: &web::Data<AppState>,
+    subject: &ThrottleSubject,
+    config: &LoginThrottleConfig,
+) -> Result<bool, LoginError> {
+    let failures = handle_get(redis_conn(state).await?, &subject.key("failures"))
+        .await
+        .map_err(redis_error)?
+        .and_then(|failures| failures.parse::<u64>().ok())
+        .unwrap_or(1);
+
+    if failures >= subject.max_failures(config) {
+        handle_expiring_post(
+            redis_conn(state).await?,
+            &subject.key("locked"),
+            "1",
+            config.lockout_seconds(),
+        )
+        .await
+        .map_err(redis_error)?;
+        // Le compteur repart de zéro au déverrouillage automatique
+        handle_delete(
+            redis_conn(state).await?,
+            &[subject.key("failures"), subject.key("delay")],
+        )
+        .await
+        .map_err(redis_error)?;
+        return Ok(true);
+    }
+
+    let delay = config.backoff_seconds(failures);
+    if delay > 0 {
+        handle_expiring_post(redis_conn(state).await?, &subject.key("delay"), "1", delay)
+            .await
+            .map_err(redis_error)?;
+    }
+    Ok(false)
+}
+
+/**
+ * Forgets the failures, delay and lockout of a subject.
+ * Returns whether the subject was locked.
+ */
+pub async fn clear_failures(
+    state: &web::Data<AppState>,
+    subject: &ThrottleSubject,
+) -> Result<bool, LoginError> {
+    let was_locked = handle_ttl(redis_conn(state).await?, &subject.key("locked"))
+        .await
+        .map_err(redis_error)?
+        .is_some();
+    handle_delete(
+        redis_conn(state).await?,
+        &[
+            subject.key("failures"),
+            subject.key("delay"),
+            subject.key("locked"),
+        ],
+    )
+    .await
+    .map_err(redis_error)?;
+    Ok(was_locked)
+}
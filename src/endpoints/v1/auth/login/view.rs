@@ -92,3 +92,36 @@ impl From<String> for LoginFirstConnectionResponseView {
         LoginFirstConnectionResponseView { token }
     }
 }
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct LoginMfaRequiredResponseView {
+    mfa_required: bool,
+    challenge: String,
+}
+
+impl LoginMfaRequiredResponseView {
+    pub fn new(challenge: String) -> Self {
+        LoginMfaRequiredResponseView {
+            mfa_required: true,
+            challenge,
+        }
+    }
+
+    pub fn mfa_required(&self) -> bool {
+        self.mfa_required
+    }
+
+    pub fn challenge(&self) -> &str {
+        &self.challenge
+    }
+}
+
+impl Display for LoginMfaRequiredResponseView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "LoginMfaRequiredResponseView {{ mfa_required: {}, challenge: [PROTECTED] }}",
+            self.mfa_required
+        )
+    }
+}
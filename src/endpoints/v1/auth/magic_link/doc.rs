@@ -0,0 +1,15 @@
+use crate::endpoints::v1::auth::login::view::{LoginMfaRequiredResponseView, LoginResponseView};
+use crate::endpoints::v1::auth::magic_link::endpoint;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(endpoint::request_magic_link, endpoint::redeem_magic_link),
+    components(schemas(
+        super::view::MagicLinkRequestView,
+        super::view::RedeemMagicLinkView,
+        LoginResponseView,
+        LoginMfaRequiredResponseView
+    ))
+)]
+pub struct MagicLinkDoc;
@@ -0,0 +1,293 @@
+use actix_web::{
+    dev::ConnectionInfo, http::StatusCode, post, web, HttpResponse, Responder, ResponseError,
+};
+use mairie360_api_lib::pool::AppState;
+use sqlx::PgPool;
+
+use crate::database::security_events::create_security_event::{
+    create_security_event_query, CreateSecurityEventQueryView,
+};
+use crate::database::users::get_active_user_by_email::{
+    get_active_user_by_email_query, GetActiveUserByEmailQueryView,
+};
+use crate::database::users::get_user_by_id::{get_user_by_id_query, GetUserByIdQueryView};
+use crate::database::users::ACTIVE_USER_STATUS;
+use crate::endpoints::v1::auth::login::endpoint::{generate_session, is_mfa_enabled, LoginError};
+use crate::endpoints::v1::auth::login::view::{LoginMfaRequiredResponseView, LoginResponseView};
+use crate::endpoints::v1::auth::magic_link::view::{MagicLinkRequestView, RedeemMagicLinkView};
+use crate::endpoints::v1::auth::magic_link::{
+    magic_link_ttl, send_magic_link_email, MAGIC_LINK_LOGIN_EVENT,
+};
+use crate::endpoints::v1::mfa::create_mfa_challenge;
+use crate::redis::{handle_expiring_post, handle_get_and_delete, handle_increment, handle_ttl};
+use crate::security::device::UserAgent;
+use crate::security::token::{generate_token, hash_token};
+use crate::send_email_in_background;
+
+const MAX_REQUESTS_PER_EMAIL: u64 = 3;
+const MAX_REQUESTS_PER_IP: u64 = 10;
+const REQUEST_WINDOW: u64 = 3600;
+
+#[derive(Debug, Clone, PartialEq)]
+enum MagicLinkError {
+    DatabaseError,
+    InvalidToken,
+    RedisError,
+    SessionError,
+    TooManyRequests(u64),
+    TooManySessions,
+}
+
+impl std::fmt::Display for MagicLinkError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MagicLinkError::DatabaseError => {
+                write!(f, "An error occurred while accessing the database.")
+            }
+            MagicLinkError::InvalidToken => write!(f, "Invalid, expired or already used link."),
+            MagicLinkError::RedisError => write!(f, "Internal Redis error."),
+            MagicLinkError::SessionError => write!(f, "Failed to create the session."),
+            MagicLinkError::TooManyRequests(_) => {
+                write!(f, "Too many login links requested, retry later.")
+            }
+            MagicLinkError::TooManySessions => write!(
+                f,
+                "Maximum number of active sessions reached, log out elsewhere first."
+            ),
+        }
+    }
+}
+
+impl ResponseError for MagicLinkError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            MagicLinkError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            MagicLinkError::InvalidToken => StatusCode::UNAUTHORIZED,
+            MagicLinkError::RedisError => StatusCode::INTERNAL_SERVER_ERROR,
+            MagicLinkError::SessionError => StatusCode::INTERNAL_SERVER_ERROR,
+            MagicLinkError::TooManyRequests(_) => StatusCode::TOO_MANY_REQUESTS,
+            MagicLinkError::TooManySessions => StatusCode::CONFLICT,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        if let MagicLinkError::TooManyRequests(retry_after) = self {
+            return HttpResponse::build(self.status_code())
+                .append_header(("Retry-After", retry_after.to_string()))
+                .body(self.to_string());
+        }
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+enum MagicLinkOutcome {
+    Session(String, String),
+    MfaRequired(String),
+}
+
+fn get_pool(state: &web::Data<AppState>) -> Result<PgPool, MagicLinkError> {
+    match state.db_pool.clone() {
+        Some(pool) => Ok(pool),
+        None => Err(MagicLinkError::DatabaseError),
+    }
+}
+
+async fn check_request_limit(
+    state: &web::Data<AppState>,
+    subject: &str,
+    max: u64,
+) -> Result<(), MagicLinkError> {
+    let key = format!("{}/magic_link_request", subject);
+    let conn = state
+        .get_redis_conn()
+        .await
+        .ok_or(MagicLinkError::RedisError)?;
+    let count = handle_increment(conn, &key, REQUEST_WINDOW)
+        .await
+        .map_err(|e| {
+            eprintln!("Redis Error: {}", e);
+            MagicLinkError::RedisError
+        })?;
+    if count <= max {
+        return Ok(());
+    }
+
+    let conn = state
+        .get_redis_conn()
+        .await
+        .ok_or(MagicLinkError::RedisError)?;
+    let retry_after = handle_ttl(conn, &key)
+        .await
+        .map_err(|e| {
+            eprintln!("Redis Error: {}", e);
+            MagicLinkError::RedisError
+        })?
+        .unwrap_or(REQUEST_WINDOW);
+    Err(MagicLinkError::TooManyRequests(retry_after.max(1)))
+}
+
+async fn send_link(state: &web::Data<AppState>, email: &str) -> Result<(), MagicLinkError> {
+    let pool = get_pool(state)?;
+    // Les comptes en attente, invités ou archivés ne reçoivent rien
+    let user_id =
+        match get_active_user_by_email_query(GetActiveUserByEmailQueryView::new(email), pool).await
+        {
+            Ok(Some(user_id)) => user_id,
+            Ok(None) => return Ok(()),
+            Err(e) => {
+                eprintln!("Database Error: {}", e);
+                return Err(MagicLinkError::DatabaseError);
+            }
+        };
+
+    // Seule l'empreinte du jeton est gardée : une copie de Redis ne permet pas de se connecter
+    let token = generate_token();
+    let conn = state
+        .get_redis_conn()
+        .await
+        .ok_or(MagicLinkError::RedisError)?;
+    handle_expiring_post(
+        conn,
+        &format!("{}/magic_link", hash_token(&token)),
+        &user_id.to_string(),
+        magic_link_ttl(),
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("Redis Error: {}", e);
+        MagicLinkError::RedisError
+    })?;
+    let email = email.to_string();
+    send_email_in_background(async move { send_magic_link_email(&email, &token).await });
+    Ok(())
+}
+
+#[utoipa::path(
+    post,
+    path = "",
+    request_body = MagicLinkRequestView,
+    responses(
+        (status = 202, description = "A login link is sent if the address belongs to an active account"),
+        (status = 429, description = "Too many login links requested, retry after the delay given in the Retry-After header"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Auth"
+)]
+#[post("/magic_link")]
+pub async fn request_magic_link(
+    payload: web::Json<MagicLinkRequestView>,
+    state: web::Data<AppState>,
+    conn: ConnectionInfo,
+) -> Result<impl Responder, MagicLinkError> {
+    let ip = conn.realip_remote_addr().unwrap_or("unknown").to_string();
+    check_request_limit(&state, &ip, MAX_REQUESTS_PER_IP).await?;
+    check_request_limit(
+        &state,
+        &payload.email().to_lowercase(),
+        MAX_REQUESTS_PER_EMAIL,
+    )
+    .await?;
+
+    // Même réponse que l'adresse existe ou non, pour ne pas révéler les comptes
+    send_link(&state, payload.email()).await?;
+    Ok(HttpResponse::Accepted())
+}
+
+async fn redeem(
+    view: &RedeemMagicLinkView,
+    state: web::Data<AppState>,
+    ip_address: std::net::IpAddr,
+    user_agent: &UserAgent,
+) -> Result<MagicLinkOutcome, MagicLinkError> {
+    let pool = get_pool(&state)?;
+    // Consommé avant toute autre vérification : un lien ne sert qu'une fois
+    let conn = state
+        .get_redis_conn()
+        .await
+        .ok_or(MagicLinkError::RedisError)?;
+    let user_id = handle_get_and_delete(conn, &format!("{}/magic_link", hash_token(view.token())))
+        .await
+        .map_err(|e| {
+            eprintln!("Redis Error: {}", e);
+            MagicLinkError::RedisError
+        })?
+        .and_then(|user_id| user_id.parse::<u64>().ok())
+        .ok_or(MagicLinkError::InvalidToken)?;
+
+    // Le compte a pu être archivé ou suspendu depuis l'envoi du lien
+    let user = get_user_by_id_query(GetUserByIdQueryView::new(user_id), pool.clone())
+        .await
+        .map_err(|_| MagicLinkError::InvalidToken)?;
+    if user.is_archived() || user.status() != ACTIVE_USER_STATUS {
+        return Err(MagicLinkError::InvalidToken);
+    }
+
+    let event = CreateSecurityEventQueryView::new(
+        Some(user_id),
+        MAGIC_LINK_LOGIN_EVENT,
+        Some(ip_address),
+        None,
+    );
+    if let Err(e) = create_security_event_query(event, pool).await {
+        eprintln!("Security Event DB Error: {}", e);
+    }
+
+    // Le lien remplace le mot de passe, pas le second facteur
+    if is_mfa_enabled(user_id, &state)
+        .await
+        .map_err(|_| MagicLinkError::DatabaseError)?
+    {
+        let challenge = create_mfa_challenge(&state, user_id, view.device_info())
+            .await
+            .ok_or(MagicLinkError::RedisError)?;
+        return Ok(MagicLinkOutcome::MfaRequired(challenge));
+    }
+
+    let (jwt, refresh_token) =
+        generate_session(user_id, view.device_info(), user_agent, ip_address, state)
+            .await
+            .map_err(|e| {
+                eprintln!("Session Error: {}", e);
+                if e == LoginError::TooManySessions {
+                    return MagicLinkError::TooManySessions;
+                }
+                MagicLinkError::SessionError
+            })?;
+    Ok(MagicLinkOutcome::Session(jwt, refresh_token))
+}
+
+#[utoipa::path(
+    post,
+    path = "/redeem",
+    request_body = RedeemMagicLinkView,
+    responses(
+        (status = 200, description = "Link accepted, session created", body = LoginResponseView),
+        (status = 202, description = "Link accepted, a second factor is required on /auth/mfa_verify", body = LoginMfaRequiredResponseView),
+        (status = 401, description = "Invalid, expired or already used link."),
+        (status = 409, description = "Maximum number of active sessions reached and the session policy refuses new logins"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Auth"
+)]
+#[post("/magic_link/redeem")]
+pub async fn redeem_magic_link(
+    payload: web::Json<RedeemMagicLinkView>,
+    state: web::Data<AppState>,
+    conn: ConnectionInfo,
+    user_agent: UserAgent,
+) -> Result<impl Responder, MagicLinkError> {
+    let view = payload.into_inner();
+    let ip_str = conn.realip_remote_addr().unwrap_or("unknown").to_string();
+    let ip_address = ip_str
+        .parse::<std::net::IpAddr>()
+        .unwrap_or(std::net::IpAddr::V4(std::net::Ipv4Addr::new(0, 0, 0, 0)));
+
+    match redeem(&view, state, ip_address, &user_agent).await? {
+        MagicLinkOutcome::Session(jwt, refresh_token) => Ok(HttpResponse::Ok()
+            .append_header(("Authorization", format!("Bearer {}", jwt)))
+            .json(LoginResponseView::from(refresh_token))),
+        MagicLinkOutcome::MfaRequired(challenge) => {
+            Ok(HttpResponse::Accepted().json(LoginMfaRequiredResponseView::new(challenge)))
+        }
+    }
+}
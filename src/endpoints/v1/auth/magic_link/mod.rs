@@ -0,0 +1,60 @@
+pub mod doc;
+pub mod endpoint;
+pub mod view;
+
+use mairie360_api_lib::env_manager::get_env_var;
+
+use crate::{build_email, get_email_link_url, get_email_sender, send_email, EmailDestination};
+
+pub const MAGIC_LINK_LOGIN_EVENT: &str = "magic_link_login";
+
+const DEFAULT_MAGIC_LINK_TTL: u64 = 900;
+
+/**
+ * Lifetime in seconds of a login link (`MAGIC_LINK_TTL`).
+ */
+pub fn magic_link_ttl() -> u64 {
+    get_env_var("MAGIC_LINK_TTL")
+        .and_then(|value| value.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_MAGIC_LINK_TTL)
+}
+
+/**
+ * Emails a link to the front-end page (`MAGIC_LINK_URL`) that posts the token back to
+ * `/auth/magic_link/redeem`. Failures are only logged: the user can ask for a new link.
+ */
+pub async fn send_magic_link_email(email: &str, token: &str) {
+    let url = match get_email_link_url("MAGIC_LINK_URL") {
+        Some(url) => url,
+        None => return,
+    };
+    let destination = EmailDestination {
+        from: match get_email_sender() {
+            Ok(sender) => sender,
+            Err(e) => {
+                eprintln!("Email Sender Error: {}", e);
+                return;
+            }
+        },
+        to: email.to_string(),
+    };
+    let subject = "Votre lien de connexion";
+    let body = format!(
+        "Bonjour, connectez-vous en ouvrant ce lien, valable {} minutes et une seule fois : {}?token={}\n\
+         Si vous n'êtes pas à l'origine de cette demande, ignorez ce message.",
+        magic_link_ttl() / 60,
+        url,
+        token
+    );
+
+    let message = match build_email(&destination, subject, &body) {
+        Ok(message) => message,
+        Err(e) => {
+            eprintln!("Email Build Error: {}", e);
+            return;
+        }
+    };
+    if let Err(e) = send_email(message).await {
+        eprintln!("Mail Error: {}", e);
+    }
+}
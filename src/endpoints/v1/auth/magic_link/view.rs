@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct MagicLinkRequestView {
+    email: String,
+}
+
+impl MagicLinkRequestView {
+    pub fn email(&self) -> &str {
+        &self.email
+    }
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct RedeemMagicLinkView {
+    token: String,
+    device_info: String,
+}
+
+impl RedeemMagicLinkView {
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    pub fn device_info(&self) -> &str {
+        &self.device_info
+    }
+}
+
+impl Display for RedeemMagicLinkView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "RedeemMagicLinkView {{ token: [PROTECTED], device_info: {} }}",
+            self.device_info
+        )
+    }
+}
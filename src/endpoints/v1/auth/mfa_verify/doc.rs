@@ -0,0 +1,12 @@
+use crate::endpoints::v1::auth::mfa_verify::endpoint;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(endpoint::mfa_verify),
+    components(schemas(
+        super::view::MfaVerifyView,
+        crate::endpoints::v1::auth::login::view::LoginResponseView
+    ))
+)]
+pub struct MfaVerifyDoc;
@@ -0,0 +1,253 @@
+use actix_web::{
+    dev::ConnectionInfo, http::StatusCode, post, web, HttpResponse, Responder, ResponseError,
+};
+use mairie360_api_lib::pool::redis::simple_key::secured::handle_secure_delete;
+use mairie360_api_lib::pool::AppState;
+
+use crate::database::mfa::get_user_mfa::{get_user_mfa_query, GetUserMfaQueryView};
+use crate::endpoints::v1::auth::login::endpoint::{generate_session, LoginError};
+use crate::endpoints::v1::auth::login::throttle::{
+    clear_failures, record_failure, reserve_attempt, ThrottleSubject,
+};
+use crate::endpoints::v1::auth::login::view::LoginResponseView;
+use crate::endpoints::v1::auth::mfa_verify::view::MfaVerifyView;
+use crate::endpoints::v1::mfa::{
+    check_recovery_code, check_totp_code, mfa_challenge_ttl, MfaChallenge,
+};
+use crate::redis::{handle_get, handle_get_and_delete, handle_increment};
+use crate::security::device::UserAgent;
+use crate::security::login_throttle::LoginThrottleConfig;
+use crate::security::totp::is_totp_code;
+
+const MAX_MFA_ATTEMPTS: u64 = 5;
+
+#[derive(Debug, Clone, PartialEq)]
+enum MfaVerifyError {
+    DatabaseError,
+    InvalidChallenge,
+    InvalidCode,
+    RedisError,
+    SessionError,
+    TooManyAttempts(u64),
+    TooManySessions,
+}
+
+impl std::fmt::Display for MfaVerifyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MfaVerifyError::DatabaseError => {
+                write!(f, "An error occurred while accessing the database.")
+            }
+            MfaVerifyError::InvalidChallenge => write!(f, "Invalid or expired MFA challenge."),
+            MfaVerifyError::InvalidCode => write!(f, "Invalid MFA code."),
+            MfaVerifyError::RedisError => write!(f, "Internal Redis error."),
+            MfaVerifyError::SessionError => write!(f, "Failed to create the session."),
+            MfaVerifyError::TooManyAttempts(_) => {
+                write!(f, "Too many failed attempts, retry later.")
+            }
+            MfaVerifyError::TooManySessions => write!(
+                f,
+                "Maximum number of active sessions reached, log out elsewhere first."
+            ),
+        }
+    }
+}
+
+impl ResponseError for MfaVerifyError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            MfaVerifyError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            MfaVerifyError::InvalidChallenge => StatusCode::UNAUTHORIZED,
+            MfaVerifyError::InvalidCode => StatusCode::UNAUTHORIZED,
+            MfaVerifyError::RedisError => StatusCode::INTERNAL_SERVER_ERROR,
+            MfaVerifyError::SessionError => StatusCode::INTERNAL_SERVER_ERROR,
+            MfaVerifyError::TooManyAttempts(_) => StatusCode::TOO_MANY_REQUESTS,
+            MfaVerifyError::TooManySessions => StatusCode::CONFLICT,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        match self {
+            MfaVerifyError::TooManyAttempts(retry_after) => HttpResponse::build(self.status_code())
+                .append_header(("Retry-After", retry_after.to_string()))
+                .body(self.to_string()),
+            _ => HttpResponse::build(self.status_code()).body(self.to_string()),
+        }
+    }
+}
+
+async fn load_challenge(
+    state: &web::Data<AppState>,
+    challenge: &str,
+) -> Result<MfaChallenge, MfaVerifyError> {
+    let conn = state
+        .get_redis_conn()
+        .await
+        .ok_or(MfaVerifyError::RedisError)?;
+    let value = handle_get(conn, &format!("{}/mfa_challenge", challenge))
+        .await
+        .map_err(|e| {
+            eprintln!("Redis Error: {}", e);
+            MfaVerifyError::RedisError
+        })?
+        .ok_or(MfaVerifyError::InvalidChallenge)?;
+    serde_json::from_str(&value).map_err(|e| {
+        eprintln!("MFA Challenge Error: {}", e);
+        MfaVerifyError::InvalidChallenge
+    })
+}
+
+async fn discard_challenge(state: &web::Data<AppState>, challenge: &str) {
+    for key in [
+        format!("{}/mfa_challenge", challenge),
+        format!("{}/mfa_attempts", challenge),
+    ] {
+        if let Some(conn) = state.get_redis_conn().await {
+            handle_secure_delete(conn, &key).await.ok();
+        }
+    }
+}
+
+async fn record_attempt(
+    state: &web::Data<AppState>,
+    challenge: &str,
+) -> Result<u64, MfaVerifyError> {
+    let conn = state
+        .get_redis_conn()
+        .await
+        .ok_or(MfaVerifyError::RedisError)?;
+    handle_increment(
+        conn,
+        &format!("{}/mfa_attempts", challenge),
+        mfa_challenge_ttl(),
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("Redis Error: {}", e);
+        MfaVerifyError::RedisError
+    })
+}
+
+async fn verify(
+    view: &MfaVerifyView,
+    state: web::Data<AppState>,
+    ip_adress: std::net::IpAddr,
+    user_agent: &UserAgent,
+) -> Result<(String, String), MfaVerifyError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(MfaVerifyError::DatabaseError),
+    };
+
+    let challenge = load_challenge(&state, view.challenge()).await?;
+
+    // Compté par compte et non par challenge : un nouveau login ne redonne pas d'essais
+    let subject = ThrottleSubject::SecondFactor(challenge.user_id);
+    let config = LoginThrottleConfig::from_env();
+    if let Some(seconds) = reserve_attempt(&state, &subject, &config)
+        .await
+        .map_err(|_| MfaVerifyError::RedisError)?
+    {
+        return Err(MfaVerifyError::TooManyAttempts(seconds.max(1)));
+    }
+
+    // Au-delà de MAX_MFA_ATTEMPTS le challenge est détruit : il faut refaire un login complet
+    let attempts = record_attempt(&state, view.challenge()).await?;
+    if attempts > MAX_MFA_ATTEMPTS {
+        discard_challenge(&state, view.challenge()).await;
+        return Err(MfaVerifyError::InvalidChallenge);
+    }
+
+    let mfa = get_user_mfa_query(GetUserMfaQueryView::new(challenge.user_id), pool)
+        .await
+        .map_err(|e| {
+            eprintln!("MFA DB Error: {}", e);
+            MfaVerifyError::DatabaseError
+        })?;
+    let mfa = match mfa {
+        Some(mfa) if mfa.enabled() => mfa,
+        _ => {
+            discard_challenge(&state, view.challenge()).await;
+            return Err(MfaVerifyError::InvalidChallenge);
+        }
+    };
+
+    let valid = if is_totp_code(view.code()) {
+        check_totp_code(&state, challenge.user_id, mfa.totp_secret(), view.code()).await
+    } else {
+        check_recovery_code(&state, challenge.user_id, view.code()).await
+    };
+    if !valid {
+        eprintln!("MFA failed: invalid code for user {}", challenge.user_id);
+        record_failure(&state, &subject, &config)
+            .await
+            .map_err(|_| MfaVerifyError::RedisError)?;
+        return Err(MfaVerifyError::InvalidCode);
+    }
+    clear_failures(&state, &subject)
+        .await
+        .map_err(|_| MfaVerifyError::RedisError)?;
+
+    // GETDEL garantit qu'un même challenge ne produit qu'une seule session
+    let conn = state
+        .get_redis_conn()
+        .await
+        .ok_or(MfaVerifyError::RedisError)?;
+    handle_get_and_delete(conn, &format!("{}/mfa_challenge", view.challenge()))
+        .await
+        .map_err(|e| {
+            eprintln!("Redis Error: {}", e);
+            MfaVerifyError::RedisError
+        })?
+        .ok_or(MfaVerifyError::InvalidChallenge)?;
+    discard_challenge(&state, view.challenge()).await;
+
+    generate_session(
+        challenge.user_id,
+        &challenge.device_info,
+        user_agent,
+        ip_adress,
+        state,
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("Session Error: {}", e);
+        if e == LoginError::TooManySessions {
+            return MfaVerifyError::TooManySessions;
+        }
+        MfaVerifyError::SessionError
+    })
+}
+
+#[utoipa::path(
+    post,
+    path = "",
+    request_body = MfaVerifyView,
+    responses(
+        (status = 200, description = "Second factor accepted, session created", body = LoginResponseView),
+        (status = 401, description = "Invalid MFA code or expired challenge."),
+        (status = 409, description = "Maximum number of active sessions reached and the session policy refuses new logins"),
+        (status = 429, description = "Too many wrong codes for this account, retry after the delay given in the Retry-After header"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Auth"
+)]
+#[post("/mfa_verify")]
+pub async fn mfa_verify(
+    payload: web::Json<MfaVerifyView>,
+    state: web::Data<AppState>,
+    conn: ConnectionInfo,
+    user_agent: UserAgent,
+) -> Result<impl Responder, MfaVerifyError> {
+    let view = payload.into_inner();
+    let ip_str = conn.realip_remote_addr().unwrap_or("unknown").to_string();
+    let ip_address = ip_str
+        .parse::<std::net::IpAddr>()
+        .unwrap_or(std::net::IpAddr::V4(std::net::Ipv4Addr::new(0, 0, 0, 0)));
+
+    let (jwt, refresh_token) = verify(&view, state, ip_address, &user_agent).await?;
+
+    Ok(HttpResponse::Ok()
+        .append_header(("Authorization", format!("Bearer {}", jwt)))
+        .json(LoginResponseView::from(refresh_token)))
+}
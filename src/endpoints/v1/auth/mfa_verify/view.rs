@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct MfaVerifyView {
+    challenge: String,
+    code: String,
+}
+
+impl MfaVerifyView {
+    pub fn challenge(&self) -> &str {
+        &self.challenge
+    }
+
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+}
+
+impl Display for MfaVerifyView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "MfaVerifyView {{ challenge: [PROTECTED], code: [PROTECTED] }}"
+        )
+    }
+}
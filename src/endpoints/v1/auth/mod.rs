@@ -1,26 +1,83 @@
+pub mod accept_invitation;
 pub mod doc;
+pub mod email_change;
+pub mod federated;
 pub mod force_change_password;
 pub mod forgot_password;
 pub mod login;
+pub mod magic_link;
+pub mod mfa_verify;
+pub mod password_policy;
+pub mod refresh;
 pub mod register;
 pub mod reset_password;
+pub mod sign_in_alert;
+pub mod verify_email;
+pub mod webauthn;
 
 use actix_web::web;
+use mairie360_api_lib::database::errors::DatabaseError;
 use mairie360_api_lib::pool::AppState;
+use sqlx::PgPool;
+use std::fmt::Display;
+use uuid::Uuid;
 
+use crate::database::session_policies::get_user_session_policies::{
+    get_user_session_policies_query, GetUserSessionPoliciesQueryView,
+};
+use crate::database::session_policies::RoleSessionPolicy;
 use crate::database::sessions::{
+    count_sessions_by_user::{count_sessions_by_user_query, CountSessionsByUserQueryView},
     create_session::{create_session_query, CreateSessionQueryView},
+    evict_oldest_sessions::{evict_oldest_sessions_query, EvictOldestSessionsQueryView},
     revoke_previous_session::{revoke_previous_session_query, RevokePreviousSessionQueryView},
 };
+use crate::endpoints::v1::sessions::revoke_access_tokens;
+use crate::security::session_policy::{SessionAdmission, SessionPolicy};
+
+#[derive(Debug)]
+pub enum NewSessionError {
+    Database(DatabaseError),
+    SessionLimitReached,
+}
+
+impl Display for NewSessionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NewSessionError::Database(e) => write!(f, "Database error: {}", e),
+            NewSessionError::SessionLimitReached => {
+                write!(f, "Maximum number of active sessions reached.")
+            }
+        }
+    }
+}
+
+impl From<DatabaseError> for NewSessionError {
+    fn from(error: DatabaseError) -> Self {
+        NewSessionError::Database(error)
+    }
+}
 
 pub fn config(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/auth")
+            .service(accept_invitation::endpoint::accept_invitation)
+            .service(email_change::endpoint::confirm_email_change)
+            .service(email_change::endpoint::cancel_email_change)
             .service(force_change_password::endpoint::force_change_password)
             .service(forgot_password::endpoint::forgot_password)
             .service(login::endpoint::login)
+            .service(magic_link::endpoint::request_magic_link)
+            .service(magic_link::endpoint::redeem_magic_link)
+            .service(mfa_verify::endpoint::mfa_verify)
+            .service(refresh::endpoint::refresh)
             .service(register::endpoint::register)
-            .service(reset_password::endpoint::reset_password),
+            .service(reset_password::endpoint::reset_password)
+            .service(sign_in_alert::endpoint::secure_account)
+            .service(verify_email::endpoint::verify_email)
+            .service(verify_email::endpoint::resend_verification)
+            .configure(federated::config)
+            .configure(webauthn::config),
     );
 }
 
@@ -31,19 +88,70 @@ pub async fn revoke_previous_session(
     device_info: &str,
 ) {
     let view = RevokePreviousSessionQueryView::new(user_id, ip_adress.clone(), device_info);
-    revoke_previous_session_query(view, state.db_pool.clone().unwrap())
-        .await
-        .map_err(|e| {
-            eprintln!("Revoke Previous Session DB Error: {}", e);
-        })
-        .ok();
+    match revoke_previous_session_query(view, state.db_pool.clone().unwrap()).await {
+        Ok(session_ids) => revoke_access_tokens(&state, &session_ids).await,
+        Err(e) => eprintln!("Revoke Previous Session DB Error: {}", e),
+    }
+}
+
+/**
+ * Policy of the roles of the user, or the instance-wide default when none has one.
+ */
+pub async fn get_session_policy(
+    pool: PgPool,
+    user_id: u64,
+) -> Result<SessionPolicy, DatabaseError> {
+    let policies =
+        get_user_session_policies_query(GetUserSessionPoliciesQueryView::new(user_id), pool)
+            .await?;
+    Ok(SessionPolicy::for_roles(
+        policies.iter().map(RoleSessionPolicy::to_policy).collect(),
+        SessionPolicy::from_env(),
+    ))
+}
+
+async fn enforce_session_limit(
+    state: &web::Data<AppState>,
+    pool: PgPool,
+    user_id: u64,
+    policy: &SessionPolicy,
+) -> Result<(), NewSessionError> {
+    if policy.max_sessions().is_none() {
+        return Ok(());
+    }
+    let active = count_sessions_by_user_query(
+        CountSessionsByUserQueryView::new(user_id, true, None, None),
+        pool.clone(),
+    )
+    .await?;
+    match policy.admit(active) {
+        SessionAdmission::Allowed => Ok(()),
+        SessionAdmission::EvictOldest(count) => {
+            let evicted = evict_oldest_sessions_query(
+                EvictOldestSessionsQueryView::new(user_id, count),
+                pool,
+            )
+            .await?;
+            revoke_access_tokens(state, &evicted).await;
+            Ok(())
+        }
+        SessionAdmission::Refused => Err(NewSessionError::SessionLimitReached),
+    }
 }
 
+/**
+ * Replaces the session of the same device, applies the session policy of the user and
+ * returns the id of the new session.
+ */
 pub async fn create_new_session(
     state: web::Data<AppState>,
     user_id: u64,
     view: CreateSessionQueryView,
-) {
+) -> Result<Uuid, NewSessionError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(NewSessionError::Database(DatabaseError::NotInitialized)),
+    };
     revoke_previous_session(
         state.clone(),
         user_id,
@@ -51,10 +159,14 @@ pub async fn create_new_session(
         view.get_device_info(),
     )
     .await;
-    create_session_query(view, state.db_pool.clone().unwrap())
-        .await
-        .map_err(|e| {
-            eprintln!("Create Session DB Error: {}", e);
-        })
-        .ok();
+    let policy = get_session_policy(pool.clone(), user_id).await?;
+    enforce_session_limit(&state, pool.clone(), user_id, &policy).await?;
+
+    let view = view.with_policy(&policy);
+    let session_id = *view.get_id();
+    create_session_query(view, pool).await.map_err(|e| {
+        eprintln!("Create Session DB Error: {}", e);
+        e
+    })?;
+    Ok(session_id)
 }
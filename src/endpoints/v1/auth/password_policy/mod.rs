@@ -0,0 +1,150 @@
+pub mod view;
+
+use mairie360_api_lib::database::errors::DatabaseError;
+use sqlx::PgPool;
+
+use crate::database::auth::get_password_history::{
+    get_password_history_query, GetPasswordHistoryQueryView,
+};
+use crate::database::auth::is_password_expired::{
+    is_password_expired_query, IsPasswordExpiredQueryView,
+};
+use crate::database::auth::record_password_change::{
+    record_password_change_query, RecordPasswordChangeQueryView,
+};
+use crate::database::users::get_user_by_id::{get_user_by_id_query, GetUserByIdQueryView};
+use crate::security::password::{verify_password, PasswordHasherConfig};
+use crate::security::password_policy::{personal_words, PasswordPolicy, PasswordViolation};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PasswordPolicyError {
+    DatabaseError,
+    PasswordHashError,
+    Violations(Vec<PasswordViolation>),
+}
+
+impl std::fmt::Display for PasswordPolicyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PasswordPolicyError::DatabaseError => {
+                write!(f, "An error occurred while accessing the database.")
+            }
+            PasswordPolicyError::PasswordHashError => write!(f, "Failed to verify password."),
+            PasswordPolicyError::Violations(_) => {
+                write!(f, "Password does not meet the password policy.")
+            }
+        }
+    }
+}
+
+async fn recently_used(
+    pool: &PgPool,
+    user_id: u64,
+    password: &str,
+    history_size: u64,
+) -> Result<bool, PasswordPolicyError> {
+    let hashes = get_password_history_query(
+        GetPasswordHistoryQueryView::new(user_id, history_size),
+        pool.clone(),
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("Password History DB Error: {}", e);
+        PasswordPolicyError::DatabaseError
+    })?;
+
+    for hash in hashes {
+        let verification = verify_password(password, &hash, PasswordHasherConfig::from_env())
+            .await
+            .map_err(|e| {
+                eprintln!("Password Verification Error: {}", e);
+                PasswordPolicyError::PasswordHashError
+            })?;
+        if verification.is_valid() {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/**
+ * Checks a new password against the policy. `user_id` is None for accounts that do not
+ * exist yet, which have no history to compare with.
+ */
+pub async fn check_new_password(
+    pool: &PgPool,
+    policy: &PasswordPolicy,
+    password: &str,
+    personal_words: &[String],
+    user_id: Option<u64>,
+) -> Result<(), PasswordPolicyError> {
+    let mut violations = policy.check(password, personal_words);
+
+    if let Some(user_id) = user_id {
+        if policy.history_size() > 0
+            && recently_used(pool, user_id, password, policy.history_size()).await?
+        {
+            violations.push(PasswordViolation::RecentlyUsed {
+                history_size: policy.history_size(),
+            });
+        }
+    }
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(PasswordPolicyError::Violations(violations))
+    }
+}
+
+/**
+ * Same as `check_new_password` for an existing account, reading its identity from the database.
+ */
+pub async fn check_user_new_password(
+    pool: &PgPool,
+    policy: &PasswordPolicy,
+    password: &str,
+    user_id: u64,
+) -> Result<(), PasswordPolicyError> {
+    let user = get_user_by_id_query(GetUserByIdQueryView::new(user_id), pool.clone())
+        .await
+        .map_err(|e| {
+            eprintln!("Database Error: {}", e);
+            PasswordPolicyError::DatabaseError
+        })?;
+    let words = personal_words(user.first_name(), user.last_name(), user.email());
+    check_new_password(pool, policy, password, &words, Some(user_id)).await
+}
+
+/**
+ * To call once the new hash is stored, so history and expiry follow every change.
+ */
+pub async fn record_password_change(
+    pool: &PgPool,
+    policy: &PasswordPolicy,
+    user_id: u64,
+    password_hash: &str,
+) -> Result<(), DatabaseError> {
+    record_password_change_query(
+        RecordPasswordChangeQueryView::new(user_id, password_hash, policy.history_size()),
+        pool.clone(),
+    )
+    .await
+}
+
+pub async fn is_password_expired(
+    pool: &PgPool,
+    policy: &PasswordPolicy,
+    user_id: u64,
+) -> Result<bool, DatabaseError> {
+    match policy.max_age_days() {
+        Some(max_age_days) => {
+            is_password_expired_query(
+                IsPasswordExpiredQueryView::new(user_id, max_age_days),
+                pool.clone(),
+            )
+            .await
+        }
+        None => Ok(false),
+    }
+}
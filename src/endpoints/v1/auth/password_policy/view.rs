@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use utoipa::ToSchema;
+
+use crate::security::password_policy::PasswordViolation;
+
+/**
+ * Body of the 400 returned when a new password is refused, listing every broken rule.
+ */
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PasswordPolicyErrorView {
+    error: String,
+    violations: Vec<PasswordViolation>,
+}
+
+impl PasswordPolicyErrorView {
+    pub fn new(error: &str, violations: Vec<PasswordViolation>) -> Self {
+        Self {
+            error: error.to_string(),
+            violations,
+        }
+    }
+
+    pub fn violations(&self) -> &[PasswordViolation] {
+        &self.violations
+    }
+}
+
+impl Display for PasswordPolicyErrorView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "PasswordPolicyErrorView {{ error: {}, violations: {:?} }}",
+            self.error, self.violations
+        )
+    }
+}
@@ -0,0 +1,12 @@
+use crate::endpoints::v1::auth::refresh::endpoint;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(endpoint::refresh),
+    components(schemas(
+        super::view::RefreshRequestView,
+        crate::endpoints::v1::auth::login::view::LoginResponseView
+    ))
+)]
+pub struct RefreshDoc;
@@ -0,0 +1,178 @@
+use crate::security::jwt::generate_jwt;
+use actix_web::http::StatusCode;
+use actix_web::{dev::ConnectionInfo, post, web, HttpResponse, Responder, ResponseError};
+use mairie360_api_lib::pool::AppState;
+use sqlx::PgPool;
+use std::net::IpAddr;
+
+use crate::database::security_events::create_security_event::{
+    create_security_event_query, CreateSecurityEventQueryView,
+};
+use crate::database::sessions::get_session_family::{
+    get_session_family_query, GetSessionFamilyQueryView,
+};
+use crate::database::sessions::revoke_session_family::{
+    revoke_session_family_query, RevokeSessionFamilyQueryView,
+};
+use crate::database::sessions::rotate_session::{rotate_session_query, RotateSessionQueryView};
+use crate::endpoints::v1::auth::login::view::LoginResponseView;
+use crate::endpoints::v1::auth::refresh::refresh_reuse_grace_seconds;
+use crate::endpoints::v1::auth::refresh::view::RefreshRequestView;
+use crate::endpoints::v1::sessions::revoke_access_tokens;
+use crate::security::token::{generate_token, hash_token};
+
+pub const REFRESH_TOKEN_REUSE_EVENT: &str = "refresh_token_reuse";
+
+#[derive(Debug, Clone, PartialEq)]
+enum RefreshError {
+    DatabaseError,
+    InvalidToken,
+    TokenGenerationError,
+}
+
+impl std::fmt::Display for RefreshError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RefreshError::InvalidToken => write!(f, "Session not found"),
+            RefreshError::DatabaseError => {
+                write!(f, "An error occurred while accessing the database.")
+            }
+            RefreshError::TokenGenerationError => write!(f, "Failed to generate JWT token."),
+        }
+    }
+}
+
+impl ResponseError for RefreshError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            RefreshError::InvalidToken => StatusCode::UNAUTHORIZED,
+            RefreshError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            RefreshError::TokenGenerationError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+/**
+ * Called when a refresh token that has already been exchanged comes back: either the
+ * legitimate client or an attacker holds a stolen copy, so every session of the family is
+ * revoked and the incident is recorded. A token rotated within the grace period is only refused.
+ */
+async fn handle_token_reuse(
+    state: &web::Data<AppState>,
+    pool: PgPool,
+    token_hash: &str,
+    ip_adress: IpAddr,
+) -> Result<(), RefreshError> {
+    let family = get_session_family_query(GetSessionFamilyQueryView::new(token_hash), pool.clone())
+        .await
+        .map_err(|e| {
+            eprintln!("Database Error: {}", e);
+            RefreshError::DatabaseError
+        })?;
+
+    let family = match family {
+        Some(family) if family.is_rotated() => family,
+        _ => return Ok(()),
+    };
+    // Plusieurs onglets rafraîchissent souvent en même temps : le perdant reçoit un 401
+    // sans fermer la famille
+    if family.is_rotated_within(refresh_reuse_grace_seconds()) {
+        return Ok(());
+    }
+
+    let revoked = revoke_session_family_query(
+        RevokeSessionFamilyQueryView::new(*family.get_family_id()),
+        pool.clone(),
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("Database Error: {}", e);
+        RefreshError::DatabaseError
+    })?;
+    revoke_access_tokens(state, &revoked).await;
+
+    let event = CreateSecurityEventQueryView::new(
+        Some(family.get_user_id()),
+        REFRESH_TOKEN_REUSE_EVENT,
+        Some(ip_adress),
+        Some(format!(
+            "family_id = {}, revoked_sessions = {}",
+            family.get_family_id(),
+            revoked.len()
+        )),
+    );
+    create_security_event_query(event, pool).await.map_err(|e| {
+        eprintln!("Database Error: {}", e);
+        RefreshError::DatabaseError
+    })
+}
+
+async fn refresh_request(
+    ip_adress: IpAddr,
+    view: RefreshRequestView,
+    state: web::Data<AppState>,
+) -> Result<(String, String), RefreshError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(RefreshError::DatabaseError),
+    };
+
+    let token_hash = hash_token(view.refresh_token());
+    let new_refresh_token = generate_token();
+    let rotate_view =
+        RotateSessionQueryView::new(&token_hash, &hash_token(&new_refresh_token), ip_adress);
+    let session_id = *rotate_view.get_new_session_id();
+
+    let user_id = match rotate_session_query(rotate_view, pool.clone()).await {
+        Ok(Some(user_id)) => user_id,
+        Ok(None) => {
+            handle_token_reuse(&state, pool, &token_hash, ip_adress).await?;
+            return Err(RefreshError::InvalidToken);
+        }
+        Err(e) => {
+            eprintln!("Database Error: {}", e);
+            return Err(RefreshError::DatabaseError);
+        }
+    };
+
+    let jwt = generate_jwt(&user_id.to_string(), &session_id).map_err(|e| {
+        eprintln!("JWT Generation Error: {}", e);
+        RefreshError::TokenGenerationError
+    })?;
+    Ok((jwt, new_refresh_token))
+}
+
+#[utoipa::path(
+    post,
+    path = "",
+    request_body = RefreshRequestView,
+    responses(
+        (status = 200, description = "Token refreshed, the refresh token is rotated", body = LoginResponseView),
+        (status = 400, description = "Bad request"),
+        (status = 401, description = "Unknown, expired, revoked or already used refresh token"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Auth",
+)]
+#[post("/refresh")]
+pub async fn refresh(
+    body: web::Json<RefreshRequestView>,
+    conn: ConnectionInfo,
+    state: web::Data<AppState>,
+) -> Result<impl Responder, RefreshError> {
+    let ip_address = conn
+        .realip_remote_addr()
+        .unwrap_or("unknown")
+        .parse::<IpAddr>()
+        .unwrap_or(IpAddr::V4(std::net::Ipv4Addr::new(0, 0, 0, 0)));
+
+    let (jwt, refresh_token) = refresh_request(ip_address, body.into_inner(), state).await?;
+
+    Ok(HttpResponse::Ok()
+        .append_header(("Authorization", format!("Bearer {}", jwt)))
+        .json(LoginResponseView::from(refresh_token)))
+}
@@ -0,0 +1,17 @@
+pub mod doc;
+pub mod endpoint;
+pub mod view;
+
+use mairie360_api_lib::env_manager::get_env_var;
+
+const DEFAULT_REFRESH_REUSE_GRACE_SECONDS: u64 = 30;
+
+/**
+ * Seconds during which a refresh token that was just rotated can come back without being
+ * taken for a stolen copy (`REFRESH_REUSE_GRACE_SECONDS`), e.g. two tabs refreshing at once.
+ */
+pub fn refresh_reuse_grace_seconds() -> u64 {
+    get_env_var("REFRESH_REUSE_GRACE_SECONDS")
+        .and_then(|value| value.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_REFRESH_REUSE_GRACE_SECONDS)
+}
@@ -8,17 +8,13 @@ pub struct RefreshRequestView {
 }
 
 impl RefreshRequestView {
-    pub fn refresh_token(&self) -> String {
-        self.refresh_token.clone()
+    pub fn refresh_token(&self) -> &str {
+        &self.refresh_token
     }
 }
 
 impl Display for RefreshRequestView {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "RefreshRequestView {{ refresh_token: {} }}",
-            self.refresh_token
-        )
+        write!(f, "RefreshRequestView {{ refresh_token: [PROTECTED] }}")
     }
 }
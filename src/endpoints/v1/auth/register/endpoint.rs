@@ -1,5 +1,14 @@
 use crate::database::auth::register::register_query;
 use crate::database::auth::register::RegisterUserQueryView;
+use crate::database::get_user_id::{get_user_id_query, GetUserIdQueryView};
+use crate::database::users::PENDING_USER_STATUS;
+use crate::endpoints::v1::auth::password_policy::view::PasswordPolicyErrorView;
+use crate::endpoints::v1::auth::password_policy::{
+    check_new_password, record_password_change, PasswordPolicyError,
+};
+use crate::endpoints::v1::auth::verify_email::send_verification_email;
+use crate::security::password::{hash_password, PasswordHasherConfig};
+use crate::security::password_policy::{personal_words, PasswordPolicy, PasswordViolation};
 use actix_web::{error::ResponseError, http::StatusCode, post, web, HttpResponse, Responder};
 use mairie360_api_lib::database::query_views::DoesUserExistByEmailQueryView;
 use mairie360_api_lib::pool::AppState;
@@ -13,6 +22,18 @@ enum RegisterError {
     InvalidData,
     UserAlreadyExists,
     DatabaseError,
+    PasswordHashError,
+    WeakPassword(Vec<PasswordViolation>),
+}
+
+impl From<PasswordPolicyError> for RegisterError {
+    fn from(error: PasswordPolicyError) -> Self {
+        match error {
+            PasswordPolicyError::DatabaseError => RegisterError::DatabaseError,
+            PasswordPolicyError::PasswordHashError => RegisterError::PasswordHashError,
+            PasswordPolicyError::Violations(violations) => RegisterError::WeakPassword(violations),
+        }
+    }
 }
 
 impl std::fmt::Display for RegisterError {
@@ -21,6 +42,10 @@ impl std::fmt::Display for RegisterError {
             RegisterError::InvalidData => write!(f, "Invalid data provided"),
             RegisterError::UserAlreadyExists => write!(f, "User already exists"),
             RegisterError::DatabaseError => write!(f, "Database error occurred"),
+            RegisterError::PasswordHashError => write!(f, "Failed to secure password"),
+            RegisterError::WeakPassword(_) => {
+                write!(f, "Password does not meet the password policy")
+            }
         }
     }
 }
@@ -31,10 +56,18 @@ impl ResponseError for RegisterError {
             RegisterError::InvalidData => StatusCode::BAD_REQUEST,
             RegisterError::UserAlreadyExists => StatusCode::CONFLICT,
             RegisterError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            RegisterError::PasswordHashError => StatusCode::INTERNAL_SERVER_ERROR,
+            RegisterError::WeakPassword(_) => StatusCode::BAD_REQUEST,
         }
     }
 
     fn error_response(&self) -> HttpResponse {
+        if let RegisterError::WeakPassword(violations) = self {
+            return HttpResponse::build(self.status_code()).json(PasswordPolicyErrorView::new(
+                &self.to_string(),
+                violations.clone(),
+            ));
+        }
         HttpResponse::build(self.status_code()).body(self.to_string())
     }
 }
@@ -52,11 +85,6 @@ fn is_valid_email(email: &str) -> bool {
     }
 }
 
-fn is_valid_password(password: &str) -> bool {
-    //Need to be more complex and based on requirements
-    password.len() >= 8
-}
-
 fn is_valid_phone_number(phone_number: Option<&str>) -> bool {
     //Need to be more complex and based on requirements
     match phone_number {
@@ -68,6 +96,7 @@ fn is_valid_phone_number(phone_number: Option<&str>) -> bool {
 async fn can_be_registered(
     register_view: &RegisterView,
     pool: &PgPool,
+    policy: &PasswordPolicy,
 ) -> Result<(), RegisterError> {
     if !is_valid_email(register_view.email()) {
         return Err(RegisterError::InvalidData);
@@ -84,28 +113,70 @@ async fn can_be_registered(
         return Err(RegisterError::UserAlreadyExists);
     }
 
-    if !is_valid_password(register_view.password()) {
-        return Err(RegisterError::InvalidData);
-    }
     if !is_valid_phone_number(register_view.phone_number()) {
         return Err(RegisterError::InvalidData);
     }
+    check_new_password(
+        pool,
+        policy,
+        register_view.password(),
+        &personal_words(
+            register_view.first_name(),
+            register_view.last_name(),
+            register_view.email(),
+        ),
+        None,
+    )
+    .await?;
     Ok(())
 }
 
+/**
+ * The account exists at this point: failures are logged and the user can still ask for
+ * a new verification link.
+ */
+async fn after_registration(
+    pool: &PgPool,
+    policy: &PasswordPolicy,
+    email: &str,
+    password_hash: &str,
+) {
+    let user_id = match get_user_id_query(GetUserIdQueryView::new(email), pool.clone()).await {
+        Ok(user_id) => user_id as u64,
+        Err(e) => {
+            eprintln!("Database error: {}", e);
+            return;
+        }
+    };
+    if let Err(e) = record_password_change(pool, policy, user_id, password_hash).await {
+        eprintln!("Password History DB Error: {}", e);
+    }
+    send_verification_email(user_id, email).await;
+}
+
 async fn register_user(
     register_view: &RegisterView,
     state: web::Data<AppState>,
 ) -> Result<(), RegisterError> {
-    can_be_registered(register_view, &state.db_pool.clone().unwrap()).await?;
+    let pool = state.db_pool.clone().unwrap();
+    let policy = PasswordPolicy::from_env();
+    can_be_registered(register_view, &pool, &policy).await?;
+
+    let password_hash = hash_password(register_view.password(), PasswordHasherConfig::from_env())
+        .await
+        .map_err(|e| {
+            eprintln!("Password hashing error: {}", e);
+            RegisterError::PasswordHashError
+        })?;
 
     let view = RegisterUserQueryView::new(
         register_view.first_name(),
         register_view.last_name(),
         register_view.email(),
-        register_view.password(),
+        &password_hash,
         register_view.phone_number().map(|s| s),
-    );
+    )
+    .with_status(PENDING_USER_STATUS);
 
     let success = register_query(view, state.db_pool.clone().unwrap())
         .await
@@ -115,6 +186,7 @@ async fn register_user(
         })?;
 
     if success {
+        after_registration(&pool, &policy, register_view.email(), &password_hash).await;
         Ok(())
     } else {
         Err(RegisterError::DatabaseError)
@@ -126,8 +198,8 @@ async fn register_user(
     path = "",
     request_body = RegisterView,
     responses(
-        (status = 201, description = "User registered successfully", body = String),
-        (status = 400, description = "Invalid data provided", body = String),
+        (status = 201, description = "User registered, a verification link was emailed to the address", body = String),
+        (status = 400, description = "Invalid data provided, or the password breaks the policy", body = PasswordPolicyErrorView),
         (status = 409, description = "User already exists", body = String),
         (status = 500, description = "Database error occurred", body = String)
     ),
@@ -142,5 +214,6 @@ pub async fn register(
 
     register_user(&register_view, state).await?;
 
-    Ok(HttpResponse::Created().body("User registered successfully!"))
+    Ok(HttpResponse::Created()
+        .body("User registered successfully! Check your emails to verify your address."))
 }
@@ -1,9 +1,10 @@
+use crate::endpoints::v1::auth::login::view::LoginMfaRequiredResponseView;
 use crate::endpoints::v1::auth::reset_password::endpoint;
 use utoipa::OpenApi;
 
 #[derive(OpenApi)]
 #[openapi(
     paths(endpoint::reset_password),
-    components(schemas(super::view::ResetPasswordView))
+    components(schemas(super::view::ResetPasswordView, LoginMfaRequiredResponseView))
 )]
 pub struct ResetPasswordDoc;
@@ -1,22 +1,50 @@
 use crate::database::auth::change_password::{change_password_query, ChangePasswordQueryView};
-use crate::database::get_user_id::{get_user_id_query, GetUserIdQueryView};
-use crate::endpoints::v1::auth::login::endpoint::generate_session;
+use crate::database::auth::get_password_hash::{get_password_hash_query, GetPasswordHashQueryView};
+use crate::database::auth::verify_email::{verify_email_query, VerifyEmailQueryView};
+use crate::endpoints::v1::auth::forgot_password::{
+    password_fingerprint, password_reset_key, user_password_reset_key, PasswordReset,
+};
+use crate::endpoints::v1::auth::login::endpoint::{generate_session, is_mfa_enabled, LoginError};
+use crate::endpoints::v1::auth::login::view::LoginMfaRequiredResponseView;
+use crate::endpoints::v1::auth::password_policy::view::PasswordPolicyErrorView;
+use crate::endpoints::v1::auth::password_policy::{
+    check_user_new_password, record_password_change, PasswordPolicyError,
+};
 use crate::endpoints::v1::auth::reset_password::view::{
     ResetPasswordResponseView, ResetPasswordView,
 };
+use crate::endpoints::v1::mfa::create_mfa_challenge;
+use crate::redis::{handle_delete, handle_get, handle_get_and_delete};
+use crate::security::device::UserAgent;
+use crate::security::password::{hash_password, PasswordHasherConfig};
+use crate::security::password_policy::{PasswordPolicy, PasswordViolation};
+use crate::security::token::hash_token;
 use actix_web::dev::ConnectionInfo;
 use actix_web::http::StatusCode;
 use actix_web::{post, web, HttpResponse, Responder, ResponseError};
-use mairie360_api_lib::pool::redis::simple_key::secured::handle_secure_get;
-use mairie360_api_lib::pool::redis::simple_key::unsecured::handle_delete_data;
 use mairie360_api_lib::pool::AppState;
 
 #[derive(Debug, Clone, PartialEq)]
 enum ResetPasswordError {
     DatabaseError,
+    PasswordHashError,
     RedisError,
     TokenGenerationError,
+    TooManySessions,
     UnknownToken,
+    WeakPassword(Vec<PasswordViolation>),
+}
+
+impl From<PasswordPolicyError> for ResetPasswordError {
+    fn from(error: PasswordPolicyError) -> Self {
+        match error {
+            PasswordPolicyError::DatabaseError => ResetPasswordError::DatabaseError,
+            PasswordPolicyError::PasswordHashError => ResetPasswordError::PasswordHashError,
+            PasswordPolicyError::Violations(violations) => {
+                ResetPasswordError::WeakPassword(violations)
+            }
+        }
+    }
 }
 
 impl std::fmt::Display for ResetPasswordError {
@@ -25,15 +53,27 @@ impl std::fmt::Display for ResetPasswordError {
             ResetPasswordError::DatabaseError => {
                 write!(f, "Internal server error")
             }
+            ResetPasswordError::PasswordHashError => {
+                write!(f, "Internal server error")
+            }
             ResetPasswordError::RedisError => {
                 write!(f, "Internal server error")
             }
             ResetPasswordError::TokenGenerationError => {
                 write!(f, "Internal server error")
             }
+            ResetPasswordError::TooManySessions => {
+                write!(
+                    f,
+                    "Maximum number of active sessions reached, log out elsewhere first."
+                )
+            }
             ResetPasswordError::UnknownToken => {
                 write!(f, "Unknown token")
             }
+            ResetPasswordError::WeakPassword(_) => {
+                write!(f, "Password does not meet the password policy")
+            }
         }
     }
 }
@@ -42,46 +82,110 @@ impl ResponseError for ResetPasswordError {
     fn status_code(&self) -> StatusCode {
         match self {
             ResetPasswordError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            ResetPasswordError::PasswordHashError => StatusCode::INTERNAL_SERVER_ERROR,
             ResetPasswordError::RedisError => StatusCode::INTERNAL_SERVER_ERROR,
             ResetPasswordError::TokenGenerationError => StatusCode::INTERNAL_SERVER_ERROR,
+            ResetPasswordError::TooManySessions => StatusCode::CONFLICT,
             ResetPasswordError::UnknownToken => StatusCode::UNAUTHORIZED,
+            ResetPasswordError::WeakPassword(_) => StatusCode::BAD_REQUEST,
         }
     }
 
     fn error_response(&self) -> HttpResponse {
+        if let ResetPasswordError::WeakPassword(violations) = self {
+            return HttpResponse::build(self.status_code()).json(PasswordPolicyErrorView::new(
+                &self.to_string(),
+                violations.clone(),
+            ));
+        }
         HttpResponse::build(self.status_code()).body(self.to_string())
     }
 }
 
-async fn get_user_id(state: web::Data<AppState>, email: &str) -> Result<u64, ResetPasswordError> {
-    let pool = match state.db_pool.clone() {
-        Some(pool) => pool,
-        None => return Err(ResetPasswordError::DatabaseError),
-    };
-    let view = GetUserIdQueryView::new(&email);
-    match get_user_id_query(view, pool.clone()).await {
-        Ok(user_id) => Ok(user_id as u64),
-        Err(_) => Err(ResetPasswordError::DatabaseError),
-    }
+enum ResetPasswordOutcome {
+    Session(String, String),
+    MfaRequired(String),
 }
 
 async fn reset_pwd(
     pool: &sqlx::pool::Pool<sqlx::Postgres>,
+    policy: &PasswordPolicy,
     new_password: &str,
     user_id: u64,
 ) -> Result<(), ResetPasswordError> {
-    let view = ChangePasswordQueryView::new(new_password, user_id);
+    let password_hash = hash_password(new_password, PasswordHasherConfig::from_env())
+        .await
+        .map_err(|e| {
+            eprintln!("Password hashing error: {}", e);
+            ResetPasswordError::PasswordHashError
+        })?;
+    let view = ChangePasswordQueryView::new(&password_hash, user_id);
     change_password_query(view, pool.clone())
         .await
         .map_err(|_| ResetPasswordError::DatabaseError)?;
+    record_password_change(pool, policy, user_id, &password_hash)
+        .await
+        .map_err(|_| ResetPasswordError::DatabaseError)?;
     Ok(())
 }
 
+/**
+ * Reads the pending reset without consuming it, so a password refused by the policy can be
+ * retried with the same link.
+ */
+async fn get_reset(
+    state: &web::Data<AppState>,
+    token_hash: &str,
+) -> Result<PasswordReset, ResetPasswordError> {
+    let conn = state
+        .get_redis_conn()
+        .await
+        .ok_or(ResetPasswordError::RedisError)?;
+    let value = handle_get(conn, &password_reset_key(token_hash))
+        .await
+        .map_err(|e| {
+            eprintln!("Redis Error: {}", e);
+            ResetPasswordError::RedisError
+        })?
+        .ok_or(ResetPasswordError::UnknownToken)?;
+    serde_json::from_str(&value).map_err(|_| ResetPasswordError::UnknownToken)
+}
+
+async fn consume_reset(
+    state: &web::Data<AppState>,
+    token_hash: &str,
+    user_id: u64,
+) -> Result<(), ResetPasswordError> {
+    let conn = state
+        .get_redis_conn()
+        .await
+        .ok_or(ResetPasswordError::RedisError)?;
+    // Deux requêtes concurrentes avec le même lien : une seule le trouve encore
+    handle_get_and_delete(conn, &password_reset_key(token_hash))
+        .await
+        .map_err(|e| {
+            eprintln!("Redis Error: {}", e);
+            ResetPasswordError::RedisError
+        })?
+        .ok_or(ResetPasswordError::UnknownToken)?;
+    let conn = state
+        .get_redis_conn()
+        .await
+        .ok_or(ResetPasswordError::RedisError)?;
+    handle_delete(conn, &[user_password_reset_key(user_id)])
+        .await
+        .map_err(|e| {
+            eprintln!("Redis Error: {}", e);
+            ResetPasswordError::RedisError
+        })
+}
+
 async fn reset_password_trigger(
     state: web::Data<AppState>,
     view: ResetPasswordView,
     ip_adress: std::net::IpAddr,
-) -> Result<(String, String), ResetPasswordError> {
+    user_agent: &UserAgent,
+) -> Result<ResetPasswordOutcome, ResetPasswordError> {
     let pool = match state.db_pool.clone() {
         Some(pool) => pool,
         None => {
@@ -90,42 +194,46 @@ async fn reset_password_trigger(
         }
     };
 
-    let key = format!("{}/forgot_password_email", view.token());
-    let email = match handle_secure_get(state.get_redis_conn().await.unwrap(), &key).await {
-        Ok(email) => email,
-        Err(e) => {
-            eprintln!("Failed to get email from Redis: {:?}", e);
-            return Err(ResetPasswordError::UnknownToken);
-        }
-    };
-    let user_id = match get_user_id(state.clone(), &email).await {
-        Ok(user_id) => user_id,
-        Err(e) => {
-            eprintln!("Failed to get user ID: {:?}", e);
-            return Err(e);
-        }
-    };
+    let token_hash = hash_token(view.token());
+    let reset = get_reset(&state, &token_hash).await?;
+    let user_id = reset.user_id;
 
-    let reversed_key = format!("{}/forgot_password_token", email);
-    match handle_delete_data(state.get_redis_conn().await.unwrap(), &reversed_key).await {
-        Ok(_) => {}
-        Err(e) => {
-            eprintln!("Failed to delete reversed key: {:?}", e);
-            return Err(ResetPasswordError::RedisError);
-        }
-    }
-    match handle_delete_data(state.get_redis_conn().await.unwrap(), &key).await {
-        Ok(_) => {}
-        Err(e) => {
-            eprintln!("Failed to delete key: {:?}", e);
-            return Err(ResetPasswordError::RedisError);
-        }
+    // Le mot de passe a changé depuis la demande : le lien ne vaut plus rien
+    let password_hash =
+        get_password_hash_query(GetPasswordHashQueryView::new(user_id), pool.clone())
+            .await
+            .map_err(|_| ResetPasswordError::DatabaseError)?
+            .unwrap_or_default();
+    if password_fingerprint(&password_hash) != reset.password_fingerprint {
+        consume_reset(&state, &token_hash, user_id).await?;
+        return Err(ResetPasswordError::UnknownToken);
     }
 
-    reset_pwd(&pool, view.new_password(), user_id).await?;
+    // Vérifié avant de consommer le jeton pour que l'utilisateur puisse réessayer
+    let policy = PasswordPolicy::from_env();
+    check_user_new_password(&pool, &policy, view.new_password(), user_id).await?;
+    consume_reset(&state, &token_hash, user_id).await?;
+
+    reset_pwd(&pool, &policy, view.new_password(), user_id).await?;
+    // Le jeton reçu par email prouve aussi la possession de l'adresse
+    verify_email_query(VerifyEmailQueryView::new(user_id), pool.clone())
+        .await
+        .map_err(|_| ResetPasswordError::DatabaseError)?;
+
+    // Le lien prouve l'accès à la boîte mail, pas la possession du second facteur
+    if is_mfa_enabled(user_id, &state)
+        .await
+        .map_err(|_| ResetPasswordError::DatabaseError)?
+    {
+        let challenge = create_mfa_challenge(&state, user_id, &view.device_info())
+            .await
+            .ok_or(ResetPasswordError::RedisError)?;
+        return Ok(ResetPasswordOutcome::MfaRequired(challenge));
+    }
 
-    match generate_session(user_id, &view.device_info(), ip_adress, state).await {
-        Ok((jwt, refresh_token)) => Ok((jwt, refresh_token)),
+    match generate_session(user_id, &view.device_info(), user_agent, ip_adress, state).await {
+        Ok((jwt, refresh_token)) => Ok(ResetPasswordOutcome::Session(jwt, refresh_token)),
+        Err(LoginError::TooManySessions) => Err(ResetPasswordError::TooManySessions),
         Err(e) => {
             eprintln!("Failed to generate session: {:?}", e);
             Err(ResetPasswordError::TokenGenerationError)
@@ -136,10 +244,13 @@ async fn reset_password_trigger(
 #[utoipa::path(
     post,
     path = "/",
+    request_body = ResetPasswordView,
     responses(
         (status = 200, description = "Password reset successfully", body = ResetPasswordResponseView),
-        (status = 400, description = "Bad request"),
-        (status = 401, description = "Unauthorized, invalid token"),
+        (status = 202, description = "Password reset, a second factor is required on /auth/mfa_verify to open a session", body = LoginMfaRequiredResponseView),
+        (status = 400, description = "Bad request, or the password breaks the policy", body = PasswordPolicyErrorView),
+        (status = 401, description = "Unknown, expired or already used token, or the password changed since the request"),
+        (status = 409, description = "Maximum number of active sessions reached and the session policy refuses new logins"),
         (status = 500, description = "Internal server error")
     ),
     tag = "Auth",
@@ -149,6 +260,7 @@ pub async fn reset_password(
     state: web::Data<AppState>,
     body: web::Json<ResetPasswordView>,
     conn: ConnectionInfo,
+    user_agent: UserAgent,
 ) -> Result<impl Responder, ResetPasswordError> {
     let ip_str = conn.realip_remote_addr().unwrap_or("unknown").to_string();
     let ip_address = std::net::IpAddr::from(
@@ -156,9 +268,12 @@ pub async fn reset_password(
             .parse::<std::net::IpAddr>()
             .unwrap_or(std::net::IpAddr::V4(std::net::Ipv4Addr::new(0, 0, 0, 0))),
     );
-    let (jwt, refresh_token) = reset_password_trigger(state, body.into_inner(), ip_address).await?;
-
-    Ok(HttpResponse::Ok()
-        .append_header(("Authorization", format!("Bearer {}", jwt)))
-        .json(ResetPasswordResponseView::from(refresh_token)))
+    match reset_password_trigger(state, body.into_inner(), ip_address, &user_agent).await? {
+        ResetPasswordOutcome::Session(jwt, refresh_token) => Ok(HttpResponse::Ok()
+            .append_header(("Authorization", format!("Bearer {}", jwt)))
+            .json(ResetPasswordResponseView::from(refresh_token))),
+        ResetPasswordOutcome::MfaRequired(challenge) => {
+            Ok(HttpResponse::Accepted().json(LoginMfaRequiredResponseView::new(challenge)))
+        }
+    }
 }
@@ -0,0 +1,9 @@
+use crate::endpoints::v1::auth::sign_in_alert::endpoint;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(endpoint::secure_account),
+    components(schemas(super::view::SecureAccountView, super::view::SecureAccountResponseView))
+)]
+pub struct SignInAlertDoc;
@@ -0,0 +1,136 @@
+use actix_web::{
+    dev::ConnectionInfo, http::StatusCode, post, web, HttpResponse, Responder, ResponseError,
+};
+use mairie360_api_lib::pool::AppState;
+
+use crate::database::security_events::create_security_event::{
+    create_security_event_query, CreateSecurityEventQueryView,
+};
+use crate::database::sessions::revoke_session_family_by_id::{
+    revoke_session_family_by_id_query, RevokeSessionFamilyByIdQueryView,
+};
+use crate::endpoints::v1::auth::forgot_password::{create_password_reset, PasswordResetError};
+use crate::endpoints::v1::auth::sign_in_alert::view::{
+    SecureAccountResponseView, SecureAccountView,
+};
+use crate::endpoints::v1::auth::sign_in_alert::{
+    sign_in_alert_key, SignInAlert, SIGN_IN_REPORTED_EVENT,
+};
+use crate::endpoints::v1::sessions::revoke_access_tokens;
+use crate::redis::handle_get_and_delete;
+use crate::security::token::hash_token;
+
+#[derive(Debug, Clone, PartialEq)]
+enum SecureAccountError {
+    DatabaseError,
+    InvalidToken,
+    RedisError,
+}
+
+impl From<PasswordResetError> for SecureAccountError {
+    fn from(error: PasswordResetError) -> Self {
+        match error {
+            PasswordResetError::DatabaseError => SecureAccountError::DatabaseError,
+            PasswordResetError::RedisError => SecureAccountError::RedisError,
+        }
+    }
+}
+
+impl std::fmt::Display for SecureAccountError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SecureAccountError::DatabaseError => {
+                write!(f, "An error occurred while accessing the database.")
+            }
+            SecureAccountError::InvalidToken => {
+                write!(f, "Invalid, expired or already used link.")
+            }
+            SecureAccountError::RedisError => write!(f, "Internal Redis error."),
+        }
+    }
+}
+
+impl ResponseError for SecureAccountError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            SecureAccountError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            SecureAccountError::InvalidToken => StatusCode::UNAUTHORIZED,
+            SecureAccountError::RedisError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+async fn secure(
+    state: &web::Data<AppState>,
+    token: &str,
+    ip_address: Option<std::net::IpAddr>,
+) -> Result<String, SecureAccountError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(SecureAccountError::DatabaseError),
+    };
+    // Consommé d'abord : un lien ne sert qu'une fois
+    let conn = state
+        .get_redis_conn()
+        .await
+        .ok_or(SecureAccountError::RedisError)?;
+    let alert = handle_get_and_delete(conn, &sign_in_alert_key(&hash_token(token)))
+        .await
+        .map_err(|e| {
+            eprintln!("Redis Error: {}", e);
+            SecureAccountError::RedisError
+        })?
+        .and_then(|alert| serde_json::from_str::<SignInAlert>(&alert).ok())
+        .ok_or(SecureAccountError::InvalidToken)?;
+
+    // La session a pu être rafraîchie depuis l'alerte : toute sa lignée est fermée
+    let view = RevokeSessionFamilyByIdQueryView::new(alert.user_id, alert.session_id);
+    let revoked = revoke_session_family_by_id_query(view, pool.clone())
+        .await
+        .map_err(|e| {
+            eprintln!("Database Error: {}", e);
+            SecureAccountError::DatabaseError
+        })?;
+    revoke_access_tokens(state, &revoked).await;
+
+    let reset_token = create_password_reset(state, &pool, alert.user_id).await?;
+
+    let event = CreateSecurityEventQueryView::new(
+        Some(alert.user_id),
+        SIGN_IN_REPORTED_EVENT,
+        ip_address,
+        Some(format!("session {}", alert.session_id)),
+    );
+    if let Err(e) = create_security_event_query(event, pool).await {
+        eprintln!("Security Event DB Error: {}", e);
+    }
+    Ok(reset_token)
+}
+
+#[utoipa::path(
+    post,
+    path = "/",
+    request_body = SecureAccountView,
+    responses(
+        (status = 200, description = "The reported session is revoked; post the reset token with a new password to /auth/reset_password", body = SecureAccountResponseView),
+        (status = 401, description = "Invalid, expired or already used link."),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Auth"
+)]
+#[post("/secure_account")]
+pub async fn secure_account(
+    payload: web::Json<SecureAccountView>,
+    state: web::Data<AppState>,
+    conn: ConnectionInfo,
+) -> Result<impl Responder, SecureAccountError> {
+    let ip_address = conn
+        .realip_remote_addr()
+        .and_then(|ip| ip.parse::<std::net::IpAddr>().ok());
+    let reset_token = secure(&state, payload.token(), ip_address).await?;
+    Ok(HttpResponse::Ok().json(SecureAccountResponseView::from(reset_token)))
+}
@@ -0,0 +1,236 @@
+pub mod doc;
+pub mod endpoint;
+pub mod view;
+
+use actix_web::web;
+use chrono::Utc;
+use mairie360_api_lib::database::queries::is_admin_query;
+use mairie360_api_lib::database::query_views::IsAdminQueryView;
+use mairie360_api_lib::env_manager::get_env_var;
+use mairie360_api_lib::pool::AppState;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::database::known_devices::is_new_device::{is_new_device_query, IsNewDeviceQueryView};
+use crate::database::known_devices::record_known_device::{
+    record_known_device_query, RecordKnownDeviceQueryView,
+};
+use crate::database::security_events::create_security_event::{
+    create_security_event_query, CreateSecurityEventQueryView,
+};
+use crate::database::users::get_user_by_id::{get_user_by_id_query, GetUserByIdQueryView};
+use crate::redis::handle_expiring_post;
+use crate::security::device::DeviceDetails;
+use crate::security::token::{generate_token, hash_token};
+use crate::{build_email, get_email_link_url, get_email_sender, send_email, EmailDestination};
+
+pub const NEW_SIGN_IN_ALERT_EVENT: &str = "new_sign_in_alert";
+pub const SIGN_IN_REPORTED_EVENT: &str = "sign_in_reported";
+
+const DEFAULT_SIGN_IN_ALERT_TTL: u64 = 604800;
+
+/**
+ * Session reported by an alert, stored in Redis under `{token hash}/sign_in_alert`.
+ */
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SignInAlert {
+    pub user_id: u64,
+    pub session_id: Uuid,
+}
+
+/**
+ * Lifetime in seconds of the link sent with an alert (`SIGN_IN_ALERT_TTL`).
+ */
+pub fn sign_in_alert_ttl() -> u64 {
+    get_env_var("SIGN_IN_ALERT_TTL")
+        .and_then(|value| value.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_SIGN_IN_ALERT_TTL)
+}
+
+pub fn sign_in_alert_key(token_hash: &str) -> String {
+    format!("{}/sign_in_alert", token_hash)
+}
+
+/**
+ * True when the user never opened a session from this IP and device before. Checked before
+ * the new session is written; on error no alert is sent rather than failing the login.
+ */
+pub async fn is_new_device(
+    state: &web::Data<AppState>,
+    user_id: u64,
+    ip_address: std::net::IpAddr,
+    device_info: &str,
+) -> bool {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return false,
+    };
+    let view = IsNewDeviceQueryView::new(user_id, ip_address, device_info);
+    is_new_device_query(view, pool).await.unwrap_or_else(|e| {
+        eprintln!("Device History DB Error: {}", e);
+        false
+    })
+}
+
+/**
+ * Adds the IP and device to the user's known devices once the session is open. Unlike
+ * `sessions`, this history is never purged by the janitor.
+ */
+pub async fn remember_device(
+    state: &web::Data<AppState>,
+    user_id: u64,
+    ip_address: std::net::IpAddr,
+    device_info: &str,
+) {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return,
+    };
+    let view = RecordKnownDeviceQueryView::new(user_id, ip_address, device_info);
+    if let Err(e) = record_known_device_query(view, pool).await {
+        eprintln!("Device History DB Error: {}", e);
+    }
+}
+
+/**
+ * Admins are always warned; other users can opt out with `new_sign_in_alerts`.
+ * Returns the address to write to, if any.
+ */
+async fn get_recipient(pool: &PgPool, user_id: u64) -> Option<String> {
+    let user = match get_user_by_id_query(GetUserByIdQueryView::new(user_id), pool.clone()).await {
+        Ok(user) => user,
+        Err(e) => {
+            eprintln!("Database Error: {}", e);
+            return None;
+        }
+    };
+    if user.new_sign_in_alerts() {
+        return Some(user.email().to_string());
+    }
+    match is_admin_query(IsAdminQueryView::new(user_id), pool.clone()).await {
+        Ok(true) => Some(user.email().to_string()),
+        Ok(false) => None,
+        Err(e) => {
+            eprintln!("Database Error: {}", e);
+            None
+        }
+    }
+}
+
+fn describe_device(details: &DeviceDetails, device_info: &str) -> String {
+    match (details.browser(), details.os()) {
+        (Some(browser), Some(os)) => format!("{} sur {}", browser, os),
+        (Some(name), None) | (None, Some(name)) => name.to_string(),
+        (None, None) => device_info.to_string(),
+    }
+}
+
+async fn send_sign_in_alert_email(
+    url: &str,
+    email: &str,
+    device: &str,
+    ip_address: &str,
+    token: &str,
+) {
+    let destination = EmailDestination {
+        from: match get_email_sender() {
+            Ok(sender) => sender,
+            Err(e) => {
+                eprintln!("Email Sender Error: {}", e);
+                return;
+            }
+        },
+        to: email.to_string(),
+    };
+    let subject = "Nouvelle connexion à votre compte";
+    let body = format!(
+        "Bonjour, une connexion à votre compte a eu lieu depuis un appareil inconnu.\n\
+         Date : {}\nAppareil : {}\nAdresse IP : {}\n\
+         Si ce n'est pas vous, ouvrez ce lien pour fermer cette session et choisir un nouveau mot de passe : {}?token={}",
+        Utc::now().format("%d/%m/%Y %H:%M UTC"),
+        device,
+        ip_address,
+        url,
+        token
+    );
+
+    let message = match build_email(&destination, subject, &body) {
+        Ok(message) => message,
+        Err(e) => {
+            eprintln!("Email Build Error: {}", e);
+            return;
+        }
+    };
+    if let Err(e) = send_email(message).await {
+        eprintln!("Mail Error: {}", e);
+    }
+}
+
+/**
+ * Emails the user about a session opened from a new device, with a link to the front-end
+ * page (`SIGN_IN_ALERT_URL`) that posts the token to `/auth/secure_account`.
+ * Failures, including a missing `SIGN_IN_ALERT_URL`, are only logged: the login itself
+ * already succeeded.
+ */
+pub async fn notify_new_sign_in(
+    state: &web::Data<AppState>,
+    user_id: u64,
+    session_id: Uuid,
+    details: &DeviceDetails,
+    device_info: &str,
+    ip_address: std::net::IpAddr,
+) {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return,
+    };
+    let email = match get_recipient(&pool, user_id).await {
+        Some(email) => email,
+        None => return,
+    };
+    let url = match get_email_link_url("SIGN_IN_ALERT_URL") {
+        Some(url) => url,
+        None => return,
+    };
+
+    let alert = match serde_json::to_string(&SignInAlert {
+        user_id,
+        session_id,
+    }) {
+        Ok(alert) => alert,
+        Err(e) => {
+            eprintln!("Sign-in Alert Error: {}", e);
+            return;
+        }
+    };
+    let token = generate_token();
+    let conn = match state.get_redis_conn().await {
+        Some(conn) => conn,
+        None => return,
+    };
+    if let Err(e) = handle_expiring_post(
+        conn,
+        &sign_in_alert_key(&hash_token(&token)),
+        &alert,
+        sign_in_alert_ttl(),
+    )
+    .await
+    {
+        eprintln!("Redis Error: {}", e);
+        return;
+    }
+
+    let device = describe_device(details, device_info);
+    send_sign_in_alert_email(&url, &email, &device, &ip_address.to_string(), &token).await;
+
+    let event = CreateSecurityEventQueryView::new(
+        Some(user_id),
+        NEW_SIGN_IN_ALERT_EVENT,
+        Some(ip_address),
+        Some(format!("session {} from {}", session_id, device)),
+    );
+    if let Err(e) = create_security_event_query(event, pool).await {
+        eprintln!("Security Event DB Error: {}", e);
+    }
+}
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SecureAccountView {
+    token: String,
+}
+
+impl SecureAccountView {
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+}
+
+/**
+ * Token to post with the new password to `/auth/reset_password`.
+ */
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct SecureAccountResponseView {
+    reset_token: String,
+}
+
+impl SecureAccountResponseView {
+    pub fn reset_token(&self) -> &str {
+        &self.reset_token
+    }
+}
+
+impl Display for SecureAccountResponseView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SecureAccountResponseView {{ reset_token: [PROTECTED] }}"
+        )
+    }
+}
+
+impl From<String> for SecureAccountResponseView {
+    fn from(reset_token: String) -> Self {
+        SecureAccountResponseView { reset_token }
+    }
+}
@@ -0,0 +1,9 @@
+use crate::endpoints::v1::auth::verify_email::endpoint;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(endpoint::verify_email, endpoint::resend_verification),
+    components(schemas(super::view::VerifyEmailView, super::view::ResendVerificationView))
+)]
+pub struct VerifyEmailDoc;
@@ -0,0 +1,209 @@
+use actix_web::{
+    dev::ConnectionInfo, http::StatusCode, post, web, HttpResponse, Responder, ResponseError,
+};
+use chrono::Utc;
+use mairie360_api_lib::pool::AppState;
+use sqlx::PgPool;
+
+use crate::database::auth::verify_email::{verify_email_query, VerifyEmailQueryView};
+use crate::database::get_user_id::{get_user_id_query, GetUserIdQueryView};
+use crate::database::users::get_user_by_id::{get_user_by_id_query, GetUserByIdQueryView};
+use crate::database::users::PENDING_USER_STATUS;
+use crate::endpoints::v1::auth::verify_email::view::{ResendVerificationView, VerifyEmailView};
+use crate::endpoints::v1::auth::verify_email::{
+    send_verification_email, EMAIL_VERIFICATION_PURPOSE,
+};
+use crate::redis::{handle_increment, handle_ttl};
+use crate::security::email_token::EmailTokenSigner;
+
+const MAX_RESENDS_PER_EMAIL: u64 = 3;
+const MAX_RESENDS_PER_IP: u64 = 10;
+const RESEND_WINDOW: u64 = 3600;
+
+#[derive(Debug, Clone, PartialEq)]
+enum VerifyEmailError {
+    DatabaseError,
+    InvalidToken,
+    RedisError,
+    ServerError,
+    TooManyRequests(u64),
+}
+
+impl std::fmt::Display for VerifyEmailError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VerifyEmailError::DatabaseError => {
+                write!(f, "An error occurred while accessing the database.")
+            }
+            VerifyEmailError::InvalidToken => write!(f, "Invalid or expired verification link."),
+            VerifyEmailError::RedisError => write!(f, "Internal Redis error."),
+            VerifyEmailError::ServerError => write!(f, "Internal server error."),
+            VerifyEmailError::TooManyRequests(_) => {
+                write!(f, "Too many verification emails requested, retry later.")
+            }
+        }
+    }
+}
+
+impl ResponseError for VerifyEmailError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            VerifyEmailError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            VerifyEmailError::InvalidToken => StatusCode::BAD_REQUEST,
+            VerifyEmailError::RedisError => StatusCode::INTERNAL_SERVER_ERROR,
+            VerifyEmailError::ServerError => StatusCode::INTERNAL_SERVER_ERROR,
+            VerifyEmailError::TooManyRequests(_) => StatusCode::TOO_MANY_REQUESTS,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        if let VerifyEmailError::TooManyRequests(retry_after) = self {
+            return HttpResponse::build(self.status_code())
+                .append_header(("Retry-After", retry_after.to_string()))
+                .body(self.to_string());
+        }
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+async fn verify(pool: PgPool, token: &str) -> Result<(), VerifyEmailError> {
+    let signer = EmailTokenSigner::from_env().map_err(|e| {
+        eprintln!("Email Token Error: {}", e);
+        VerifyEmailError::ServerError
+    })?;
+    let user_id = EmailTokenSigner::user_id(token).map_err(|_| VerifyEmailError::InvalidToken)?;
+    let user = get_user_by_id_query(GetUserByIdQueryView::new(user_id), pool.clone())
+        .await
+        .map_err(|_| VerifyEmailError::InvalidToken)?;
+
+    signer
+        .verify(
+            EMAIL_VERIFICATION_PURPOSE,
+            token,
+            user.email(),
+            Utc::now().timestamp(),
+        )
+        .map_err(|e| {
+            eprintln!("Email Verification Error: {}", e);
+            VerifyEmailError::InvalidToken
+        })?;
+
+    // Un lien déjà utilisé ne fait rien de plus : l'adresse reste vérifiée
+    verify_email_query(VerifyEmailQueryView::new(user_id), pool)
+        .await
+        .map_err(|e| {
+            eprintln!("Database Error: {}", e);
+            VerifyEmailError::DatabaseError
+        })?;
+    Ok(())
+}
+
+#[utoipa::path(
+    post,
+    path = "verify_email",
+    request_body = VerifyEmailView,
+    responses(
+        (status = 204, description = "Email address confirmed, the account can log in"),
+        (status = 400, description = "Invalid or expired verification link."),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Auth"
+)]
+#[post("/verify_email")]
+pub async fn verify_email(
+    payload: web::Json<VerifyEmailView>,
+    state: web::Data<AppState>,
+) -> Result<impl Responder, VerifyEmailError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(VerifyEmailError::DatabaseError),
+    };
+    verify(pool, payload.token()).await?;
+    Ok(HttpResponse::NoContent())
+}
+
+async fn check_resend_limit(
+    state: &web::Data<AppState>,
+    subject: &str,
+    max: u64,
+) -> Result<(), VerifyEmailError> {
+    let key = format!("{}/verification_resend", subject);
+    let conn = state
+        .get_redis_conn()
+        .await
+        .ok_or(VerifyEmailError::RedisError)?;
+    let count = handle_increment(conn, &key, RESEND_WINDOW)
+        .await
+        .map_err(|e| {
+            eprintln!("Redis Error: {}", e);
+            VerifyEmailError::RedisError
+        })?;
+    if count <= max {
+        return Ok(());
+    }
+
+    let conn = state
+        .get_redis_conn()
+        .await
+        .ok_or(VerifyEmailError::RedisError)?;
+    let retry_after = handle_ttl(conn, &key)
+        .await
+        .map_err(|e| {
+            eprintln!("Redis Error: {}", e);
+            VerifyEmailError::RedisError
+        })?
+        .unwrap_or(RESEND_WINDOW);
+    Err(VerifyEmailError::TooManyRequests(retry_after.max(1)))
+}
+
+async fn resend(pool: PgPool, email: &str) -> Result<(), VerifyEmailError> {
+    let user_id = match get_user_id_query(GetUserIdQueryView::new(email), pool.clone()).await {
+        Ok(user_id) => user_id as u64,
+        Err(_) => return Ok(()),
+    };
+    let user = get_user_by_id_query(GetUserByIdQueryView::new(user_id), pool)
+        .await
+        .map_err(|e| {
+            eprintln!("Database Error: {}", e);
+            VerifyEmailError::DatabaseError
+        })?;
+    if user.status() == PENDING_USER_STATUS {
+        send_verification_email(user_id, user.email()).await;
+    }
+    Ok(())
+}
+
+#[utoipa::path(
+    post,
+    path = "resend_verification",
+    request_body = ResendVerificationView,
+    responses(
+        (status = 202, description = "A new link is sent if the address belongs to an account waiting for verification"),
+        (status = 429, description = "Too many verification emails requested, retry after the delay given in the Retry-After header"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Auth"
+)]
+#[post("/resend_verification")]
+pub async fn resend_verification(
+    payload: web::Json<ResendVerificationView>,
+    state: web::Data<AppState>,
+    conn: ConnectionInfo,
+) -> Result<impl Responder, VerifyEmailError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(VerifyEmailError::DatabaseError),
+    };
+    let ip = conn.realip_remote_addr().unwrap_or("unknown").to_string();
+    check_resend_limit(&state, &ip, MAX_RESENDS_PER_IP).await?;
+    check_resend_limit(
+        &state,
+        &payload.email().to_lowercase(),
+        MAX_RESENDS_PER_EMAIL,
+    )
+    .await?;
+
+    // Même réponse que l'adresse existe ou non, pour ne pas révéler les comptes
+    resend(pool, payload.email()).await?;
+    Ok(HttpResponse::Accepted())
+}
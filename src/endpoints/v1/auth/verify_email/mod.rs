@@ -0,0 +1,70 @@
+pub mod doc;
+pub mod endpoint;
+pub mod view;
+
+use chrono::Utc;
+use mairie360_api_lib::env_manager::get_env_var;
+
+use crate::security::email_token::EmailTokenSigner;
+use crate::{build_email, get_email_link_url, get_email_sender, send_email, EmailDestination};
+
+pub const EMAIL_VERIFICATION_PURPOSE: &str = "email_verification";
+
+const DEFAULT_EMAIL_VERIFICATION_TTL: u64 = 86400;
+
+pub fn email_verification_ttl() -> u64 {
+    get_env_var("EMAIL_VERIFICATION_TTL")
+        .and_then(|value| value.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_EMAIL_VERIFICATION_TTL)
+}
+
+/**
+ * Emails a link to the front-end page (`EMAIL_VERIFICATION_URL`) that posts the token
+ * back to `/auth/verify_email`. Failures are only logged: the user can ask for a new link.
+ */
+pub async fn send_verification_email(user_id: u64, email: &str) -> bool {
+    let signer = match EmailTokenSigner::from_env() {
+        Ok(signer) => signer,
+        Err(e) => {
+            eprintln!("Email Token Error: {}", e);
+            return false;
+        }
+    };
+    let expires_at = Utc::now().timestamp() + email_verification_ttl() as i64;
+    let token = signer.sign(EMAIL_VERIFICATION_PURPOSE, user_id, email, expires_at);
+    let url = match get_email_link_url("EMAIL_VERIFICATION_URL") {
+        Some(url) => url,
+        None => return false,
+    };
+
+    let destination = EmailDestination {
+        from: match get_email_sender() {
+            Ok(sender) => sender,
+            Err(e) => {
+                eprintln!("Email Sender Error: {}", e);
+                return false;
+            }
+        },
+        to: email.to_string(),
+    };
+    let subject = "Confirmez votre adresse email";
+    let body = format!(
+        "Bonjour, confirmez votre adresse email en ouvrant ce lien : {}?token={}",
+        url, token
+    );
+
+    let message = match build_email(&destination, subject, &body) {
+        Ok(message) => message,
+        Err(e) => {
+            eprintln!("Email Build Error: {}", e);
+            return false;
+        }
+    };
+    match send_email(message).await {
+        Ok(_) => true,
+        Err(e) => {
+            eprintln!("Mail Error: {}", e);
+            false
+        }
+    }
+}
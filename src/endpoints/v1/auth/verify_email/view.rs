@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct VerifyEmailView {
+    token: String,
+}
+
+impl VerifyEmailView {
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ResendVerificationView {
+    email: String,
+}
+
+impl ResendVerificationView {
+    pub fn email(&self) -> &str {
+        &self.email
+    }
+}
@@ -0,0 +1,10 @@
+use crate::endpoints::v1::auth::webauthn::login_finish::doc::WebauthnLoginFinishDoc;
+use crate::endpoints::v1::auth::webauthn::login_start::doc::WebauthnLoginStartDoc;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(nest(
+    (path = "/login_finish", api = WebauthnLoginFinishDoc, tags = ["Auth"]),
+    (path = "/login_start", api = WebauthnLoginStartDoc, tags = ["Auth"]),
+))]
+pub struct WebauthnDoc;
@@ -0,0 +1,14 @@
+use crate::endpoints::v1::auth::webauthn::login_finish::endpoint;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(endpoint::webauthn_login_finish),
+    components(schemas(
+        super::view::WebauthnLoginFinishView,
+        crate::endpoints::v1::auth::webauthn::view::AuthenticationCredentialView,
+        crate::endpoints::v1::auth::webauthn::view::AuthenticatorAssertionResponseView,
+        crate::endpoints::v1::auth::login::view::LoginResponseView
+    ))
+)]
+pub struct WebauthnLoginFinishDoc;
@@ -0,0 +1,204 @@
+use actix_web::{
+    dev::ConnectionInfo, http::StatusCode, post, web, HttpResponse, Responder, ResponseError,
+};
+use mairie360_api_lib::pool::AppState;
+
+use crate::database::webauthn::get_credential_by_credential_id::{
+    get_credential_by_credential_id_query, GetCredentialByCredentialIdQueryView,
+};
+use crate::database::webauthn::update_credential_usage::{
+    update_credential_usage_query, UpdateCredentialUsageQueryView,
+};
+use crate::endpoints::v1::auth::login::endpoint::{generate_session, LoginError};
+use crate::endpoints::v1::auth::login::view::LoginResponseView;
+use crate::endpoints::v1::auth::webauthn::login_finish::view::WebauthnLoginFinishView;
+use crate::endpoints::v1::auth::webauthn::{decode_base64url, encode_base64url};
+use crate::redis::handle_get_and_delete;
+use crate::security::device::UserAgent;
+use crate::security::webauthn::{client_data_challenge, verify_authentication, WebauthnConfig};
+
+#[derive(Debug, Clone, PartialEq)]
+enum WebauthnLoginFinishError {
+    BadRequest,
+    DatabaseError,
+    InvalidChallenge,
+    InvalidCredentials,
+    RedisError,
+    SessionError,
+    TooManySessions,
+}
+
+impl std::fmt::Display for WebauthnLoginFinishError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WebauthnLoginFinishError::BadRequest => write!(f, "Malformed credential."),
+            WebauthnLoginFinishError::DatabaseError => {
+                write!(f, "An error occurred while accessing the database.")
+            }
+            WebauthnLoginFinishError::InvalidChallenge => {
+                write!(f, "Invalid or expired WebAuthn challenge.")
+            }
+            WebauthnLoginFinishError::InvalidCredentials => {
+                write!(f, "Invalid credentials provided.")
+            }
+            WebauthnLoginFinishError::RedisError => write!(f, "Internal Redis error."),
+            WebauthnLoginFinishError::SessionError => write!(f, "Failed to create the session."),
+            WebauthnLoginFinishError::TooManySessions => write!(
+                f,
+                "Maximum number of active sessions reached, log out elsewhere first."
+            ),
+        }
+    }
+}
+
+impl ResponseError for WebauthnLoginFinishError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            WebauthnLoginFinishError::BadRequest => StatusCode::BAD_REQUEST,
+            WebauthnLoginFinishError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            WebauthnLoginFinishError::InvalidChallenge => StatusCode::UNAUTHORIZED,
+            WebauthnLoginFinishError::InvalidCredentials => StatusCode::UNAUTHORIZED,
+            WebauthnLoginFinishError::RedisError => StatusCode::INTERNAL_SERVER_ERROR,
+            WebauthnLoginFinishError::SessionError => StatusCode::INTERNAL_SERVER_ERROR,
+            WebauthnLoginFinishError::TooManySessions => StatusCode::CONFLICT,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+async fn finish(
+    view: &WebauthnLoginFinishView,
+    state: web::Data<AppState>,
+    ip_adress: std::net::IpAddr,
+    user_agent: &UserAgent,
+) -> Result<(String, String), WebauthnLoginFinishError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(WebauthnLoginFinishError::DatabaseError),
+    };
+
+    let response = view.credential().response();
+    let client_data = decode_base64url(response.client_data_json())
+        .ok_or(WebauthnLoginFinishError::BadRequest)?;
+    let authenticator_data = decode_base64url(response.authenticator_data())
+        .ok_or(WebauthnLoginFinishError::BadRequest)?;
+    let signature =
+        decode_base64url(response.signature()).ok_or(WebauthnLoginFinishError::BadRequest)?;
+
+    // Le challenge est consommé avant toute vérification : une assertion ne sert qu'une fois
+    let challenge =
+        client_data_challenge(&client_data).map_err(|_| WebauthnLoginFinishError::BadRequest)?;
+    let conn = state
+        .get_redis_conn()
+        .await
+        .ok_or(WebauthnLoginFinishError::RedisError)?;
+    handle_get_and_delete(conn, &format!("{}/webauthn_authentication", challenge))
+        .await
+        .map_err(|e| {
+            eprintln!("Redis Error: {}", e);
+            WebauthnLoginFinishError::RedisError
+        })?
+        .ok_or(WebauthnLoginFinishError::InvalidChallenge)?;
+
+    let credential = get_credential_by_credential_id_query(
+        GetCredentialByCredentialIdQueryView::new(view.credential().id()),
+        pool.clone(),
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("WebAuthn DB Error: {}", e);
+        WebauthnLoginFinishError::DatabaseError
+    })?
+    .ok_or(WebauthnLoginFinishError::InvalidCredentials)?;
+
+    // userHandle est optionnel, mais s'il est présent il doit désigner le propriétaire de la passkey
+    if let Some(user_handle) = view.credential().response().user_handle() {
+        let expected = encode_base64url(credential.user_id().to_string().as_bytes());
+        if user_handle.trim_end_matches('=') != expected {
+            eprintln!("WebAuthn assertion rejected: user handle mismatch");
+            return Err(WebauthnLoginFinishError::InvalidCredentials);
+        }
+    }
+
+    let sign_count = verify_authentication(
+        &WebauthnConfig::from_env(),
+        &challenge,
+        &client_data,
+        &authenticator_data,
+        &signature,
+        credential.public_key(),
+    )
+    .map_err(|e| {
+        eprintln!("WebAuthn assertion rejected: {}", e);
+        WebauthnLoginFinishError::InvalidCredentials
+    })?;
+
+    let accepted = update_credential_usage_query(
+        UpdateCredentialUsageQueryView::new(credential.id() as u64, sign_count),
+        pool,
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("WebAuthn DB Error: {}", e);
+        WebauthnLoginFinishError::DatabaseError
+    })?;
+    if !accepted {
+        eprintln!(
+            "WebAuthn assertion rejected: signature counter regression for passkey {}",
+            credential.id()
+        );
+        return Err(WebauthnLoginFinishError::InvalidCredentials);
+    }
+
+    generate_session(
+        credential.user_id() as u64,
+        view.device_info(),
+        user_agent,
+        ip_adress,
+        state,
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("Session Error: {}", e);
+        if e == LoginError::TooManySessions {
+            return WebauthnLoginFinishError::TooManySessions;
+        }
+        WebauthnLoginFinishError::SessionError
+    })
+}
+
+#[utoipa::path(
+    post,
+    path = "",
+    request_body = WebauthnLoginFinishView,
+    responses(
+        (status = 200, description = "Passkey accepted, session created", body = LoginResponseView),
+        (status = 400, description = "Malformed credential."),
+        (status = 401, description = "Invalid credentials or expired challenge."),
+        (status = 409, description = "Maximum number of active sessions reached and the session policy refuses new logins"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Auth"
+)]
+#[post("/login_finish")]
+pub async fn webauthn_login_finish(
+    payload: web::Json<WebauthnLoginFinishView>,
+    state: web::Data<AppState>,
+    conn: ConnectionInfo,
+    user_agent: UserAgent,
+) -> Result<impl Responder, WebauthnLoginFinishError> {
+    let view = payload.into_inner();
+    let ip_str = conn.realip_remote_addr().unwrap_or("unknown").to_string();
+    let ip_address = ip_str
+        .parse::<std::net::IpAddr>()
+        .unwrap_or(std::net::IpAddr::V4(std::net::Ipv4Addr::new(0, 0, 0, 0)));
+
+    let (jwt, refresh_token) = finish(&view, state, ip_address, &user_agent).await?;
+
+    Ok(HttpResponse::Ok()
+        .append_header(("Authorization", format!("Bearer {}", jwt)))
+        .json(LoginResponseView::from(refresh_token)))
+}
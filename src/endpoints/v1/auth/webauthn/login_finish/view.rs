@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use utoipa::ToSchema;
+
+use crate::endpoints::v1::auth::webauthn::view::AuthenticationCredentialView;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct WebauthnLoginFinishView {
+    credential: AuthenticationCredentialView,
+    device_info: String,
+}
+
+impl WebauthnLoginFinishView {
+    pub fn credential(&self) -> &AuthenticationCredentialView {
+        &self.credential
+    }
+
+    pub fn device_info(&self) -> &str {
+        &self.device_info
+    }
+}
+
+impl Display for WebauthnLoginFinishView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "WebauthnLoginFinishView {{ credential: {}, device_info: {} }}",
+            self.credential.id(),
+            self.device_info
+        )
+    }
+}
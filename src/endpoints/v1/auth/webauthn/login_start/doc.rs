@@ -0,0 +1,9 @@
+use crate::endpoints::v1::auth::webauthn::login_start::endpoint;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(endpoint::webauthn_login_start),
+    components(schemas(super::view::WebauthnLoginStartResponseView))
+)]
+pub struct WebauthnLoginStartDoc;
@@ -0,0 +1,78 @@
+use actix_web::{http::StatusCode, post, web, HttpResponse, Responder, ResponseError};
+use mairie360_api_lib::pool::AppState;
+
+use crate::endpoints::v1::auth::webauthn::login_start::view::WebauthnLoginStartResponseView;
+use crate::endpoints::v1::auth::webauthn::webauthn_challenge_ttl;
+use crate::redis::handle_expiring_post;
+use crate::security::token::generate_token;
+use crate::security::webauthn::WebauthnConfig;
+
+#[derive(Debug, Clone, PartialEq)]
+enum WebauthnLoginStartError {
+    RedisError,
+}
+
+impl std::fmt::Display for WebauthnLoginStartError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WebauthnLoginStartError::RedisError => write!(f, "Internal Redis error."),
+        }
+    }
+}
+
+impl ResponseError for WebauthnLoginStartError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            WebauthnLoginStartError::RedisError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+async fn start(
+    state: web::Data<AppState>,
+) -> Result<WebauthnLoginStartResponseView, WebauthnLoginStartError> {
+    let challenge = generate_token();
+    let ttl = webauthn_challenge_ttl();
+    let conn = state
+        .get_redis_conn()
+        .await
+        .ok_or(WebauthnLoginStartError::RedisError)?;
+    handle_expiring_post(
+        conn,
+        &format!("{}/webauthn_authentication", challenge),
+        "1",
+        ttl,
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("Redis Error: {}", e);
+        WebauthnLoginStartError::RedisError
+    })?;
+
+    Ok(WebauthnLoginStartResponseView::new(
+        challenge,
+        WebauthnConfig::from_env().rp_id(),
+        ttl * 1000,
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "",
+    responses(
+        (status = 200, description = "Authentication options for navigator.credentials.get()", body = WebauthnLoginStartResponseView),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Auth"
+)]
+#[post("/login_start")]
+pub async fn webauthn_login_start(
+    state: web::Data<AppState>,
+) -> Result<impl Responder, WebauthnLoginStartError> {
+    let response = start(state).await?;
+    Ok(HttpResponse::Ok().json(response))
+}
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::endpoints::v1::auth::webauthn::view::CredentialDescriptorView;
+
+/**
+ * `PublicKeyCredentialRequestOptionsJSON`, to pass to `PublicKeyCredential.parseRequestOptionsFromJSON()`.
+ * `allowCredentials` is empty: the browser offers the discoverable passkeys of the site.
+ */
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WebauthnLoginStartResponseView {
+    challenge: String,
+    rp_id: String,
+    timeout: u64,
+    user_verification: String,
+    allow_credentials: Vec<CredentialDescriptorView>,
+}
+
+impl WebauthnLoginStartResponseView {
+    pub fn new(challenge: String, rp_id: &str, timeout: u64) -> Self {
+        Self {
+            challenge,
+            rp_id: rp_id.to_string(),
+            timeout,
+            user_verification: "required".to_string(),
+            allow_credentials: Vec::new(),
+        }
+    }
+}
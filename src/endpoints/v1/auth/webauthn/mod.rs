@@ -0,0 +1,40 @@
+pub mod doc;
+pub mod login_finish;
+pub mod login_start;
+pub mod view;
+
+use actix_web::web;
+use base64::{engine::general_purpose, Engine as _};
+use mairie360_api_lib::env_manager::get_env_var;
+
+const DEFAULT_WEBAUTHN_CHALLENGE_TTL: u64 = 300;
+
+pub fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/webauthn")
+            .service(login_finish::endpoint::webauthn_login_finish)
+            .service(login_start::endpoint::webauthn_login_start),
+    );
+}
+
+/**
+ * Lifetime in seconds of a registration or authentication challenge (`WEBAUTHN_CHALLENGE_TTL`).
+ */
+pub fn webauthn_challenge_ttl() -> u64 {
+    get_env_var("WEBAUTHN_CHALLENGE_TTL")
+        .and_then(|value| value.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_WEBAUTHN_CHALLENGE_TTL)
+}
+
+/**
+ * Decodes the base64url fields sent by `PublicKeyCredential.toJSON()`, padded or not.
+ */
+pub fn decode_base64url(value: &str) -> Option<Vec<u8>> {
+    general_purpose::URL_SAFE_NO_PAD
+        .decode(value.trim_end_matches('='))
+        .ok()
+}
+
+pub fn encode_base64url(bytes: &[u8]) -> String {
+    general_purpose::URL_SAFE_NO_PAD.encode(bytes)
+}
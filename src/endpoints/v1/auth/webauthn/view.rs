@@ -0,0 +1,103 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CredentialDescriptorView {
+    #[serde(rename = "type")]
+    credential_type: String,
+    id: String,
+}
+
+impl CredentialDescriptorView {
+    pub fn new(id: &str) -> Self {
+        Self {
+            credential_type: "public-key".to_string(),
+            id: id.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AuthenticatorAttestationResponseView {
+    #[serde(rename = "clientDataJSON")]
+    client_data_json: String,
+    #[serde(rename = "attestationObject")]
+    attestation_object: String,
+}
+
+impl AuthenticatorAttestationResponseView {
+    pub fn client_data_json(&self) -> &str {
+        &self.client_data_json
+    }
+
+    pub fn attestation_object(&self) -> &str {
+        &self.attestation_object
+    }
+}
+
+/**
+ * Result of `navigator.credentials.create()` serialized with `PublicKeyCredential.toJSON()`.
+ */
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RegistrationCredentialView {
+    id: String,
+    response: AuthenticatorAttestationResponseView,
+}
+
+impl RegistrationCredentialView {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn response(&self) -> &AuthenticatorAttestationResponseView {
+        &self.response
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AuthenticatorAssertionResponseView {
+    #[serde(rename = "clientDataJSON")]
+    client_data_json: String,
+    #[serde(rename = "authenticatorData")]
+    authenticator_data: String,
+    signature: String,
+    #[serde(rename = "userHandle")]
+    user_handle: Option<String>,
+}
+
+impl AuthenticatorAssertionResponseView {
+    pub fn client_data_json(&self) -> &str {
+        &self.client_data_json
+    }
+
+    pub fn authenticator_data(&self) -> &str {
+        &self.authenticator_data
+    }
+
+    pub fn signature(&self) -> &str {
+        &self.signature
+    }
+
+    pub fn user_handle(&self) -> Option<&str> {
+        self.user_handle.as_deref()
+    }
+}
+
+/**
+ * Result of `navigator.credentials.get()` serialized with `PublicKeyCredential.toJSON()`.
+ */
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AuthenticationCredentialView {
+    id: String,
+    response: AuthenticatorAssertionResponseView,
+}
+
+impl AuthenticationCredentialView {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn response(&self) -> &AuthenticatorAssertionResponseView {
+        &self.response
+    }
+}
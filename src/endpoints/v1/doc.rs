@@ -1,6 +1,10 @@
 use super::admin::doc::AdminDoc;
 use super::auth::doc::AuthDoc;
 use super::groups::doc::GroupsDoc;
+use super::mfa::doc::MfaDoc;
+use super::modules::doc::ModulesDoc;
+use super::oauth::doc::OAuthDoc;
+use super::oidc::doc::OidcDoc;
 use super::ressources::doc::RessourcesDoc;
 use super::roles::doc::RolesDoc;
 use super::sessions::doc::SessionsDoc;
@@ -12,6 +16,10 @@ use utoipa::OpenApi;
     (path = "/admin", api = AdminDoc),
     (path = "/auth", api = AuthDoc),
     (path = "/groups", api = GroupsDoc),
+    (path = "/mfa", api = MfaDoc),
+    (path = "/modules", api = ModulesDoc),
+    (path = "/oauth", api = OAuthDoc),
+    (path = "/oidc", api = OidcDoc),
     (path = "/ressources", api = RessourcesDoc),
     (path = "/roles", api = RolesDoc),
     (path = "/sessions", api = SessionsDoc),
@@ -0,0 +1,12 @@
+use crate::endpoints::v1::mfa::confirm::endpoint;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(endpoint::confirm_mfa),
+    components(schemas(
+        crate::endpoints::v1::mfa::view::MfaCodeView,
+        crate::endpoints::v1::mfa::view::RecoveryCodesResponseView
+    ))
+)]
+pub struct ConfirmMfaDoc;
@@ -0,0 +1,127 @@
+use actix_web::http::StatusCode;
+use actix_web::{post, web, HttpResponse, Responder, ResponseError};
+use mairie360_api_lib::pool::AppState;
+use mairie360_api_lib::security::AuthenticatedUser;
+
+use crate::database::mfa::enable_mfa::{enable_mfa_query, EnableMfaQueryView};
+use crate::database::mfa::get_user_mfa::{get_user_mfa_query, GetUserMfaQueryView};
+use crate::endpoints::v1::mfa::view::{MfaCodeView, RecoveryCodesResponseView};
+use crate::endpoints::v1::mfa::{check_totp_code, renew_recovery_codes};
+use crate::security::impersonation::Impersonation;
+
+#[derive(Debug, Clone, PartialEq)]
+enum ConfirmMfaError {
+    AlreadyEnabled,
+    DatabaseError,
+    Impersonated,
+    InvalidCode,
+    NotEnrolled,
+}
+
+impl std::fmt::Display for ConfirmMfaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfirmMfaError::AlreadyEnabled => write!(f, "MFA is already enabled."),
+            ConfirmMfaError::DatabaseError => {
+                write!(f, "An error occurred while accessing the database.")
+            }
+            ConfirmMfaError::Impersonated => {
+                write!(f, "Not allowed while an administrator acts as the user.")
+            }
+            ConfirmMfaError::InvalidCode => write!(f, "Invalid MFA code."),
+            ConfirmMfaError::NotEnrolled => write!(f, "No pending MFA enrollment."),
+        }
+    }
+}
+
+impl ResponseError for ConfirmMfaError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            ConfirmMfaError::AlreadyEnabled => StatusCode::CONFLICT,
+            ConfirmMfaError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            ConfirmMfaError::Impersonated => StatusCode::FORBIDDEN,
+            ConfirmMfaError::InvalidCode => StatusCode::UNAUTHORIZED,
+            ConfirmMfaError::NotEnrolled => StatusCode::BAD_REQUEST,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+async fn confirm(
+    state: web::Data<AppState>,
+    user_id: u64,
+    code: &str,
+) -> Result<Vec<String>, ConfirmMfaError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(ConfirmMfaError::DatabaseError),
+    };
+
+    let mfa = get_user_mfa_query(GetUserMfaQueryView::new(user_id), pool.clone())
+        .await
+        .map_err(|e| {
+            eprintln!("Error: {}", e);
+            ConfirmMfaError::DatabaseError
+        })?
+        .ok_or(ConfirmMfaError::NotEnrolled)?;
+    if mfa.enabled() {
+        return Err(ConfirmMfaError::AlreadyEnabled);
+    }
+
+    if !check_totp_code(&state, user_id, mfa.totp_secret(), code).await {
+        return Err(ConfirmMfaError::InvalidCode);
+    }
+
+    let codes = renew_recovery_codes(pool.clone(), user_id)
+        .await
+        .map_err(|e| {
+            eprintln!("Error: {}", e);
+            ConfirmMfaError::DatabaseError
+        })?;
+
+    let enabled = enable_mfa_query(EnableMfaQueryView::new(user_id), pool)
+        .await
+        .map_err(|e| {
+            eprintln!("Error: {}", e);
+            ConfirmMfaError::DatabaseError
+        })?;
+    if !enabled {
+        return Err(ConfirmMfaError::AlreadyEnabled);
+    }
+
+    Ok(codes)
+}
+
+#[utoipa::path(
+    post,
+    path = "",
+    request_body = MfaCodeView,
+    responses(
+        (status = 200, description = "MFA enabled, recovery codes are shown only once", body = RecoveryCodesResponseView),
+        (status = 400, description = "No pending MFA enrollment."),
+        (status = 401, description = "Invalid MFA code."),
+        (status = 403, description = "Not allowed while an administrator acts as the user."),
+        (status = 409, description = "MFA is already enabled."),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "MFA",
+    security(
+        ("jwt" = [])
+    )
+)]
+#[post("/confirm")]
+pub async fn confirm_mfa(
+    state: web::Data<AppState>,
+    payload: web::Json<MfaCodeView>,
+    auth_user: AuthenticatedUser,
+    impersonation: Option<Impersonation>,
+) -> Result<impl Responder, ConfirmMfaError> {
+    if impersonation.is_some() {
+        return Err(ConfirmMfaError::Impersonated);
+    }
+    let codes = confirm(state, auth_user.id, payload.code()).await?;
+    Ok(HttpResponse::Ok().json(RecoveryCodesResponseView::new(codes)))
+}
@@ -0,0 +1,12 @@
+use crate::endpoints::v1::mfa::confirm::doc::ConfirmMfaDoc;
+use crate::endpoints::v1::mfa::enroll::doc::EnrollMfaDoc;
+use crate::endpoints::v1::mfa::recovery_codes::doc::RecoveryCodesDoc;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(nest(
+    (path = "/confirm", api = ConfirmMfaDoc, tags = ["MFA"]),
+    (path = "/enroll", api = EnrollMfaDoc, tags = ["MFA"]),
+    (path = "/recovery_codes", api = RecoveryCodesDoc, tags = ["MFA"]),
+))]
+pub struct MfaDoc;
@@ -0,0 +1,9 @@
+use crate::endpoints::v1::mfa::enroll::endpoint;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(endpoint::enroll_mfa),
+    components(schemas(super::view::EnrollMfaResponseView))
+)]
+pub struct EnrollMfaDoc;
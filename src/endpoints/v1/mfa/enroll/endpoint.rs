@@ -0,0 +1,111 @@
+use actix_web::http::StatusCode;
+use actix_web::{post, web, HttpResponse, Responder, ResponseError};
+use mairie360_api_lib::pool::AppState;
+use mairie360_api_lib::security::AuthenticatedUser;
+
+use crate::database::mfa::set_mfa_secret::{set_mfa_secret_query, SetMfaSecretQueryView};
+use crate::database::users::get_user_by_id::{get_user_by_id_query, GetUserByIdQueryView};
+use crate::endpoints::v1::mfa::enroll::view::EnrollMfaResponseView;
+use crate::security::impersonation::Impersonation;
+use crate::security::totp::{generate_totp_secret, totp_uri, TotpConfig};
+
+#[derive(Debug, Clone, PartialEq)]
+enum EnrollMfaError {
+    AlreadyEnabled,
+    DatabaseError,
+    Impersonated,
+    TotpError,
+}
+
+impl std::fmt::Display for EnrollMfaError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EnrollMfaError::AlreadyEnabled => write!(f, "MFA is already enabled."),
+            EnrollMfaError::DatabaseError => {
+                write!(f, "An error occurred while accessing the database.")
+            }
+            EnrollMfaError::Impersonated => {
+                write!(f, "Not allowed while an administrator acts as the user.")
+            }
+            EnrollMfaError::TotpError => write!(f, "Failed to generate the TOTP secret."),
+        }
+    }
+}
+
+impl ResponseError for EnrollMfaError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            EnrollMfaError::AlreadyEnabled => StatusCode::CONFLICT,
+            EnrollMfaError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            EnrollMfaError::Impersonated => StatusCode::FORBIDDEN,
+            EnrollMfaError::TotpError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+async fn enroll(
+    state: web::Data<AppState>,
+    user_id: u64,
+) -> Result<EnrollMfaResponseView, EnrollMfaError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(EnrollMfaError::DatabaseError),
+    };
+
+    let user = get_user_by_id_query(GetUserByIdQueryView::new(user_id), pool.clone())
+        .await
+        .map_err(|e| {
+            eprintln!("Error: {}", e);
+            EnrollMfaError::DatabaseError
+        })?;
+
+    let secret = generate_totp_secret();
+    let uri = totp_uri(&secret, user.email(), &TotpConfig::from_env()).map_err(|e| {
+        eprintln!("TOTP Error: {}", e);
+        EnrollMfaError::TotpError
+    })?;
+
+    let stored = set_mfa_secret_query(SetMfaSecretQueryView::new(user_id, &secret), pool)
+        .await
+        .map_err(|e| {
+            eprintln!("Error: {}", e);
+            EnrollMfaError::DatabaseError
+        })?;
+    if !stored {
+        return Err(EnrollMfaError::AlreadyEnabled);
+    }
+
+    Ok(EnrollMfaResponseView::new(secret, uri))
+}
+
+#[utoipa::path(
+    post,
+    path = "",
+    responses(
+        (status = 200, description = "TOTP secret generated, waiting for confirmation", body = EnrollMfaResponseView),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not allowed while an administrator acts as the user."),
+        (status = 409, description = "MFA is already enabled."),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "MFA",
+    security(
+        ("jwt" = [])
+    )
+)]
+#[post("/enroll")]
+pub async fn enroll_mfa(
+    state: web::Data<AppState>,
+    auth_user: AuthenticatedUser,
+    impersonation: Option<Impersonation>,
+) -> Result<impl Responder, EnrollMfaError> {
+    if impersonation.is_some() {
+        return Err(EnrollMfaError::Impersonated);
+    }
+    let response = enroll(state, auth_user.id).await?;
+    Ok(HttpResponse::Ok().json(response))
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct EnrollMfaResponseView {
+    secret: String,
+    otpauth_uri: String,
+}
+
+impl EnrollMfaResponseView {
+    pub fn new(secret: String, otpauth_uri: String) -> Self {
+        Self {
+            secret,
+            otpauth_uri,
+        }
+    }
+}
+
+impl Display for EnrollMfaResponseView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "EnrollMfaResponseView {{ secret: [PROTECTED], otpauth_uri: [PROTECTED] }}"
+        )
+    }
+}
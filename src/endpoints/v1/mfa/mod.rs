@@ -0,0 +1,148 @@
+mod confirm;
+pub mod doc;
+mod enroll;
+mod recovery_codes;
+pub mod view;
+
+use actix_web::web;
+use mairie360_api_lib::database::errors::DatabaseError;
+use mairie360_api_lib::env_manager::get_env_var;
+use mairie360_api_lib::pool::AppState;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+
+use crate::database::mfa::consume_recovery_code::{
+    consume_recovery_code_query, ConsumeRecoveryCodeQueryView,
+};
+use crate::database::mfa::replace_recovery_codes::{
+    replace_recovery_codes_query, ReplaceRecoveryCodesQueryView,
+};
+use crate::redis::{handle_expiring_post, handle_expiring_secure_post};
+use crate::security::recovery_codes::{generate_recovery_codes, hash_recovery_code};
+use crate::security::token::generate_token;
+use crate::security::totp::{current_time, is_totp_code, verify_totp_code};
+
+const DEFAULT_MFA_CHALLENGE_TTL: u64 = 300;
+// Couvre toute la fenêtre de tolérance TOTP (pas courant +/- 1)
+const TOTP_REPLAY_TTL: u64 = 120;
+
+pub fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/mfa")
+            .service(confirm::endpoint::confirm_mfa)
+            .service(enroll::endpoint::enroll_mfa)
+            .service(recovery_codes::endpoint::regenerate_recovery_codes),
+    );
+}
+
+/**
+ * Pending second step of a login, stored in Redis under `{challenge}/mfa_challenge`.
+ */
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MfaChallenge {
+    pub user_id: u64,
+    pub device_info: String,
+}
+
+pub fn mfa_challenge_ttl() -> u64 {
+    get_env_var("MFA_CHALLENGE_TTL")
+        .and_then(|value| value.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_MFA_CHALLENGE_TTL)
+}
+
+pub async fn create_mfa_challenge(
+    state: &web::Data<AppState>,
+    user_id: u64,
+    device_info: &str,
+) -> Option<String> {
+    let challenge = generate_token();
+    let value = serde_json::to_string(&MfaChallenge {
+        user_id,
+        device_info: device_info.to_string(),
+    })
+    .ok()?;
+    let conn = state.get_redis_conn().await?;
+    handle_expiring_post(
+        conn,
+        &format!("{}/mfa_challenge", challenge),
+        &value,
+        mfa_challenge_ttl(),
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("Redis Error: {}", e);
+    })
+    .ok()?;
+    Some(challenge)
+}
+
+/**
+ * Checks a TOTP code and refuses a code already accepted for the same time step.
+ * Any internal error is logged and treated as an invalid code.
+ */
+pub async fn check_totp_code(
+    state: &web::Data<AppState>,
+    user_id: u64,
+    secret: &str,
+    code: &str,
+) -> bool {
+    if !is_totp_code(code) {
+        return false;
+    }
+    let step = match current_time().and_then(|time| verify_totp_code(secret, code, time)) {
+        Ok(Some(step)) => step,
+        Ok(None) => return false,
+        Err(e) => {
+            eprintln!("TOTP Error: {}", e);
+            return false;
+        }
+    };
+    let conn = match state.get_redis_conn().await {
+        Some(conn) => conn,
+        None => {
+            eprintln!("Redis Error: no connection available");
+            return false;
+        }
+    };
+    handle_expiring_secure_post(
+        conn,
+        &format!("{}/mfa_used_step/{}", user_id, step),
+        "1",
+        TOTP_REPLAY_TTL,
+    )
+    .await
+    .unwrap_or_else(|e| {
+        eprintln!("Redis Error: {}", e);
+        false
+    })
+}
+
+/**
+ * Redeems a one-time recovery code. Any internal error is logged and treated as an invalid code.
+ */
+pub async fn check_recovery_code(state: &web::Data<AppState>, user_id: u64, code: &str) -> bool {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return false,
+    };
+    let view = ConsumeRecoveryCodeQueryView::new(user_id, &hash_recovery_code(code));
+    consume_recovery_code_query(view, pool)
+        .await
+        .unwrap_or_else(|e| {
+            eprintln!("Recovery Code DB Error: {}", e);
+            false
+        })
+}
+
+/**
+ * Replaces every recovery code of the user and returns the new plaintext codes.
+ */
+pub async fn renew_recovery_codes(
+    pool: PgPool,
+    user_id: u64,
+) -> Result<Vec<String>, DatabaseError> {
+    let codes = generate_recovery_codes();
+    let hashes = codes.iter().map(|code| hash_recovery_code(code)).collect();
+    replace_recovery_codes_query(ReplaceRecoveryCodesQueryView::new(user_id, hashes), pool).await?;
+    Ok(codes)
+}
@@ -0,0 +1,12 @@
+use crate::endpoints::v1::mfa::recovery_codes::endpoint;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(endpoint::regenerate_recovery_codes),
+    components(schemas(
+        crate::endpoints::v1::mfa::view::MfaCodeView,
+        crate::endpoints::v1::mfa::view::RecoveryCodesResponseView
+    ))
+)]
+pub struct RecoveryCodesDoc;
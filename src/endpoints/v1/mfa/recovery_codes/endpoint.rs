@@ -0,0 +1,109 @@
+use actix_web::http::StatusCode;
+use actix_web::{post, web, HttpResponse, Responder, ResponseError};
+use mairie360_api_lib::pool::AppState;
+use mairie360_api_lib::security::AuthenticatedUser;
+
+use crate::database::mfa::get_user_mfa::{get_user_mfa_query, GetUserMfaQueryView};
+use crate::endpoints::v1::mfa::view::{MfaCodeView, RecoveryCodesResponseView};
+use crate::endpoints::v1::mfa::{check_totp_code, renew_recovery_codes};
+use crate::security::impersonation::Impersonation;
+
+#[derive(Debug, Clone, PartialEq)]
+enum RecoveryCodesError {
+    DatabaseError,
+    Impersonated,
+    InvalidCode,
+    NotEnabled,
+}
+
+impl std::fmt::Display for RecoveryCodesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecoveryCodesError::DatabaseError => {
+                write!(f, "An error occurred while accessing the database.")
+            }
+            RecoveryCodesError::Impersonated => {
+                write!(f, "Not allowed while an administrator acts as the user.")
+            }
+            RecoveryCodesError::InvalidCode => write!(f, "Invalid MFA code."),
+            RecoveryCodesError::NotEnabled => write!(f, "MFA is not enabled."),
+        }
+    }
+}
+
+impl ResponseError for RecoveryCodesError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            RecoveryCodesError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            RecoveryCodesError::Impersonated => StatusCode::FORBIDDEN,
+            RecoveryCodesError::InvalidCode => StatusCode::UNAUTHORIZED,
+            RecoveryCodesError::NotEnabled => StatusCode::BAD_REQUEST,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+async fn regenerate(
+    state: web::Data<AppState>,
+    user_id: u64,
+    code: &str,
+) -> Result<Vec<String>, RecoveryCodesError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(RecoveryCodesError::DatabaseError),
+    };
+
+    let mfa = get_user_mfa_query(GetUserMfaQueryView::new(user_id), pool.clone())
+        .await
+        .map_err(|e| {
+            eprintln!("Error: {}", e);
+            RecoveryCodesError::DatabaseError
+        })?;
+    let mfa = match mfa {
+        Some(mfa) if mfa.enabled() => mfa,
+        _ => return Err(RecoveryCodesError::NotEnabled),
+    };
+
+    // Un code TOTP frais est exigé : un JWT volé ne suffit pas à récupérer de nouveaux codes
+    if !check_totp_code(&state, user_id, mfa.totp_secret(), code).await {
+        return Err(RecoveryCodesError::InvalidCode);
+    }
+
+    renew_recovery_codes(pool, user_id).await.map_err(|e| {
+        eprintln!("Error: {}", e);
+        RecoveryCodesError::DatabaseError
+    })
+}
+
+#[utoipa::path(
+    post,
+    path = "",
+    request_body = MfaCodeView,
+    responses(
+        (status = 200, description = "Previous recovery codes revoked, new ones are shown only once", body = RecoveryCodesResponseView),
+        (status = 400, description = "MFA is not enabled."),
+        (status = 401, description = "Invalid MFA code."),
+        (status = 403, description = "Not allowed while an administrator acts as the user."),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "MFA",
+    security(
+        ("jwt" = [])
+    )
+)]
+#[post("/recovery_codes")]
+pub async fn regenerate_recovery_codes(
+    state: web::Data<AppState>,
+    payload: web::Json<MfaCodeView>,
+    auth_user: AuthenticatedUser,
+    impersonation: Option<Impersonation>,
+) -> Result<impl Responder, RecoveryCodesError> {
+    if impersonation.is_some() {
+        return Err(RecoveryCodesError::Impersonated);
+    }
+    let codes = regenerate(state, auth_user.id, payload.code()).await?;
+    Ok(HttpResponse::Ok().json(RecoveryCodesResponseView::new(codes)))
+}
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct MfaCodeView {
+    code: String,
+}
+
+impl MfaCodeView {
+    pub fn new(code: &str) -> Self {
+        Self {
+            code: code.to_string(),
+        }
+    }
+
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+}
+
+impl Display for MfaCodeView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MfaCodeView {{ code: [PROTECTED] }}")
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RecoveryCodesResponseView {
+    recovery_codes: Vec<String>,
+}
+
+impl RecoveryCodesResponseView {
+    pub fn new(recovery_codes: Vec<String>) -> Self {
+        Self { recovery_codes }
+    }
+
+    pub fn recovery_codes(&self) -> &Vec<String> {
+        &self.recovery_codes
+    }
+}
+
+impl Display for RecoveryCodesResponseView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "RecoveryCodesResponseView {{ recovery_codes: [PROTECTED] }}"
+        )
+    }
+}
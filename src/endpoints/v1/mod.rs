@@ -2,19 +2,27 @@ pub mod admin;
 pub mod auth;
 pub mod doc;
 pub mod groups;
+pub mod mfa;
+pub mod modules;
+pub mod oauth;
+pub mod oidc;
 pub mod ressources;
 pub mod roles;
 pub mod sessions;
 pub mod user;
 
+use crate::security::middleware::AdminMiddleware;
 use actix_web::web;
-use mairie360_api_lib::security::AdminMiddleware;
 
 pub fn config(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/v1")
             .configure(auth::config)
             .configure(groups::config)
+            .configure(mfa::config)
+            .configure(modules::config)
+            .configure(oauth::config)
+            .configure(oidc::config)
             .configure(roles::config)
             .configure(sessions::config)
             .configure(user::config)
@@ -0,0 +1,8 @@
+use super::users_lookup::doc::UsersLookupDoc;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(nest(
+    (path = "/", api = UsersLookupDoc, tags = ["Modules"]),
+))]
+pub struct ModulesDoc;
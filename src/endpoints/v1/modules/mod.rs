@@ -0,0 +1,11 @@
+pub mod doc;
+mod users_lookup;
+
+use actix_web::web;
+
+/**
+ * Machine-only routes, served to module tokens from the client credentials grant.
+ */
+pub fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::scope("/modules").service(users_lookup::endpoint::users_lookup));
+}
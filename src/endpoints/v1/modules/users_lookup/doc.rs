@@ -0,0 +1,13 @@
+use crate::endpoints::v1::modules::users_lookup::endpoint;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(endpoint::users_lookup),
+    components(schemas(
+        super::view::UsersLookupView,
+        super::view::UsersLookupResponseView,
+        super::view::UserSummarySchema
+    ))
+)]
+pub struct UsersLookupDoc;
@@ -0,0 +1,94 @@
+use actix_web::http::StatusCode;
+use actix_web::{post, web, HttpResponse, Responder, ResponseError};
+use mairie360_api_lib::pool::AppState;
+
+use crate::database::users::get_users_by_ids::{get_users_by_ids_query, GetUsersByIdsQueryView};
+use crate::endpoints::v1::modules::users_lookup::view::{UsersLookupResponseView, UsersLookupView};
+use crate::security::service_account::{AuthenticatedModule, USERS_READ_SCOPE};
+
+const MAX_LOOKUP_IDS: usize = 100;
+
+#[derive(Debug, Clone, PartialEq)]
+enum UsersLookupError {
+    DatabaseError,
+    InvalidData,
+    MissingScope,
+}
+
+impl std::fmt::Display for UsersLookupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UsersLookupError::DatabaseError => {
+                write!(f, "An error occurred while accessing the database.")
+            }
+            UsersLookupError::InvalidData => {
+                write!(f, "Between 1 and {} user ids are expected.", MAX_LOOKUP_IDS)
+            }
+            UsersLookupError::MissingScope => {
+                write!(f, "Forbidden: The {} scope is required.", USERS_READ_SCOPE)
+            }
+        }
+    }
+}
+
+impl ResponseError for UsersLookupError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            UsersLookupError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            UsersLookupError::InvalidData => StatusCode::BAD_REQUEST,
+            UsersLookupError::MissingScope => StatusCode::FORBIDDEN,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "users/lookup",
+    request_body = UsersLookupView,
+    responses(
+        (status = 200, description = "Users found, in id order; unknown and archived ids are left out", body = UsersLookupResponseView),
+        (status = 400, description = "No ids, or too many"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not a module token, or the token lacks the users:read scope"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Modules",
+    security(
+        ("jwt" = [])
+    )
+)]
+#[post("/users/lookup")]
+pub async fn users_lookup(
+    state: web::Data<AppState>,
+    body: web::Json<UsersLookupView>,
+    module: AuthenticatedModule,
+) -> Result<impl Responder, UsersLookupError> {
+    if !module.has_scope(USERS_READ_SCOPE) {
+        return Err(UsersLookupError::MissingScope);
+    }
+    let mut ids = body.ids().to_vec();
+    ids.sort_unstable();
+    ids.dedup();
+    if ids.is_empty() || ids.len() > MAX_LOOKUP_IDS {
+        return Err(UsersLookupError::InvalidData);
+    }
+
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(UsersLookupError::DatabaseError),
+    };
+    let users = get_users_by_ids_query(GetUsersByIdsQueryView::new(ids), pool)
+        .await
+        .map_err(|e| {
+            eprintln!("Database Error: {}", e);
+            UsersLookupError::DatabaseError
+        })?;
+
+    Ok(HttpResponse::Ok().json(UsersLookupResponseView::new(
+        users.into_iter().map(|u| u.into()).collect(),
+    )))
+}
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use utoipa::ToSchema;
+
+use crate::database::users::get_users_by_ids::UserSummary;
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct UsersLookupView {
+    ids: Vec<u64>,
+}
+
+impl UsersLookupView {
+    pub fn ids(&self) -> &[u64] {
+        &self.ids
+    }
+}
+
+impl Display for UsersLookupView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "UsersLookupView {{ ids: {:?} }}", self.ids)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UserSummarySchema {
+    id: i32,
+    first_name: String,
+    last_name: String,
+    email: String,
+    status: String,
+}
+
+impl From<UserSummary> for UserSummarySchema {
+    fn from(user: UserSummary) -> Self {
+        UserSummarySchema {
+            id: user.id(),
+            first_name: user.first_name().to_string(),
+            last_name: user.last_name().to_string(),
+            email: user.email().to_string(),
+            status: user.status().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct UsersLookupResponseView {
+    users: Vec<UserSummarySchema>,
+}
+
+impl UsersLookupResponseView {
+    pub fn new(users: Vec<UserSummarySchema>) -> Self {
+        UsersLookupResponseView { users }
+    }
+}
+
+impl Display for UsersLookupResponseView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "UsersLookupResponseView {{ users: {:?} }}", self.users)
+    }
+}
@@ -0,0 +1,12 @@
+use super::introspect::doc::IntrospectDoc;
+use super::revoke::doc::RevokeDoc;
+use super::token::doc::TokenDoc;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(nest(
+    (path = "/introspect", api = IntrospectDoc, tags = ["OAuth"]),
+    (path = "/revoke", api = RevokeDoc, tags = ["OAuth"]),
+    (path = "/token", api = TokenDoc, tags = ["OAuth"]),
+))]
+pub struct OAuthDoc;
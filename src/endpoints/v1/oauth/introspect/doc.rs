@@ -0,0 +1,13 @@
+use crate::endpoints::v1::oauth::introspect::endpoint;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(endpoint::introspect),
+    components(schemas(
+        super::view::IntrospectResponseView,
+        crate::endpoints::v1::oauth::view::TokenRequestView,
+        crate::endpoints::v1::oauth::view::OAuthErrorView
+    ))
+)]
+pub struct IntrospectDoc;
@@ -0,0 +1,116 @@
+use actix_web::{post, web, HttpRequest, HttpResponse, Responder};
+use chrono::Utc;
+use mairie360_api_lib::pool::AppState;
+use sqlx::PgPool;
+
+use crate::database::sessions::get_session_by_token::{
+    get_session_by_token_query, GetSessionByTokenQueryView,
+};
+use crate::endpoints::v1::oauth::introspect::view::IntrospectResponseView;
+use crate::endpoints::v1::oauth::view::{OAuthErrorView, TokenRequestView};
+use crate::endpoints::v1::oauth::{authenticate_client, is_access_token, OAuthError};
+use crate::security::jwt::{decode_jwt, is_session_denied, key_store};
+use crate::security::token::hash_token;
+
+pub const ACCESS_TOKEN_TYPE: &str = "access_token";
+pub const REFRESH_TOKEN_TYPE: &str = "refresh_token";
+
+async fn introspect_access_token(
+    state: &AppState,
+    token: &str,
+) -> Result<IntrospectResponseView, OAuthError> {
+    let store = key_store().map_err(|e| {
+        eprintln!("JWT Key Error: {}", e);
+        OAuthError::ServerError
+    })?;
+    let claims = match decode_jwt(store, token, Utc::now()) {
+        Ok(claims) => claims,
+        Err(_) => return Ok(IntrospectResponseView::inactive()),
+    };
+
+    if let Some(session_id) = claims.get_session_id() {
+        if is_session_denied(state, session_id)
+            .await
+            .map_err(|_| OAuthError::ServerError)?
+        {
+            return Ok(IntrospectResponseView::inactive());
+        }
+    }
+
+    let response = IntrospectResponseView::active(
+        ACCESS_TOKEN_TYPE,
+        claims.get_user_id(),
+        claims.get_session_id().map(str::to_string),
+        claims.get_issued_at() as i64,
+        claims.get_expiration() as i64,
+    );
+    Ok(match claims.get_client_id() {
+        Some(client_id) => response.with_client(client_id, claims.get_scope().unwrap_or_default()),
+        None => response,
+    })
+}
+
+async fn introspect_refresh_token(
+    pool: PgPool,
+    token: &str,
+) -> Result<IntrospectResponseView, OAuthError> {
+    let session =
+        get_session_by_token_query(GetSessionByTokenQueryView::new(hash_token(token)), pool)
+            .await
+            .map_err(|e| {
+                eprintln!("Database Error: {}", e);
+                OAuthError::ServerError
+            })?;
+
+    // Une session révoquée (déconnexion, rotation, réutilisation) est inactive immédiatement
+    Ok(match session {
+        Some(session) if session.revoked_at().is_none() && *session.expires_at() > Utc::now() => {
+            IntrospectResponseView::active(
+                REFRESH_TOKEN_TYPE,
+                &session.user_id().to_string(),
+                Some(session.id().to_string()),
+                session.created_at().timestamp(),
+                session.expires_at().timestamp(),
+            )
+        }
+        _ => IntrospectResponseView::inactive(),
+    })
+}
+
+#[utoipa::path(
+    post,
+    path = "",
+    request_body(content = TokenRequestView, content_type = "application/x-www-form-urlencoded"),
+    responses(
+        (status = 200, description = "Token state, `active: false` for unknown, expired or revoked tokens", body = IntrospectResponseView),
+        (status = 401, description = "Client authentication failed", body = OAuthErrorView),
+        (status = 500, description = "Internal server error", body = OAuthErrorView)
+    ),
+    tag = "OAuth",
+    security(
+        ("client_credentials" = [])
+    )
+)]
+#[post("/introspect")]
+pub async fn introspect(
+    form: web::Form<TokenRequestView>,
+    request: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<impl Responder, OAuthError> {
+    let view = form.into_inner();
+    authenticate_client(&state, &request, view.client_id(), view.client_secret()).await?;
+
+    let response = if is_access_token(view.token()) {
+        introspect_access_token(&state, view.token()).await?
+    } else {
+        let pool = match state.db_pool.clone() {
+            Some(pool) => pool,
+            None => return Err(OAuthError::ServerError),
+        };
+        introspect_refresh_token(pool, view.token()).await?
+    };
+
+    Ok(HttpResponse::Ok()
+        .insert_header(("Cache-Control", "no-store"))
+        .json(response))
+}
@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use utoipa::ToSchema;
+
+/**
+ * RFC 7662 response. An inactive token only carries `active: false`, whatever the reason.
+ */
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
+pub struct IntrospectResponseView {
+    active: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    token_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sub: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scope: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    iat: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exp: Option<i64>,
+}
+
+impl IntrospectResponseView {
+    pub fn inactive() -> Self {
+        Self::default()
+    }
+
+    pub fn active(token_type: &str, sub: &str, sid: Option<String>, iat: i64, exp: i64) -> Self {
+        Self {
+            active: true,
+            token_type: Some(token_type.to_string()),
+            sub: Some(sub.to_string()),
+            sid,
+            client_id: None,
+            scope: None,
+            iat: Some(iat),
+            exp: Some(exp),
+        }
+    }
+
+    /**
+     * Marks a module token, so callers can tell it from a user token.
+     */
+    pub fn with_client(mut self, client_id: &str, scope: &str) -> Self {
+        self.client_id = Some(client_id.to_string());
+        self.scope = Some(scope.to_string());
+        self
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+}
+
+impl Display for IntrospectResponseView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IntrospectResponseView {{ active: {}, token_type: {:?}, sub: {:?}, sid: {:?}, client_id: {:?} }}",
+            self.active, self.token_type, self.sub, self.sid, self.client_id
+        )
+    }
+}
@@ -0,0 +1,131 @@
+pub mod doc;
+pub mod introspect;
+pub mod revoke;
+pub mod token;
+pub mod view;
+
+use actix_web::http::{header, StatusCode};
+use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
+use mairie360_api_lib::pool::AppState;
+
+use crate::database::oauth_clients::get_oauth_client::{
+    get_oauth_client_query, GetOAuthClientQueryResultView, GetOAuthClientQueryView,
+};
+use crate::endpoints::v1::oauth::view::OAuthErrorView;
+use crate::security::client_credentials::{
+    parse_basic_authorization, verify_client_secret, ClientCredentials,
+};
+
+pub fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/oauth")
+            .service(introspect::endpoint::introspect)
+            .service(revoke::endpoint::revoke)
+            .service(token::endpoint::token),
+    );
+}
+
+/**
+ * Errors shared by the OAuth endpoints, rendered as RFC 6749 `{"error": ...}` bodies.
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub enum OAuthError {
+    InvalidClient,
+    InvalidGrant,
+    InvalidRequest,
+    InvalidScope,
+    ServerError,
+    UnauthorizedClient,
+    UnsupportedGrantType,
+    UnsupportedTokenType,
+}
+
+impl std::fmt::Display for OAuthError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OAuthError::InvalidClient => write!(f, "invalid_client"),
+            OAuthError::InvalidGrant => write!(f, "invalid_grant"),
+            OAuthError::InvalidRequest => write!(f, "invalid_request"),
+            OAuthError::InvalidScope => write!(f, "invalid_scope"),
+            OAuthError::ServerError => write!(f, "server_error"),
+            OAuthError::UnauthorizedClient => write!(f, "unauthorized_client"),
+            OAuthError::UnsupportedGrantType => write!(f, "unsupported_grant_type"),
+            OAuthError::UnsupportedTokenType => write!(f, "unsupported_token_type"),
+        }
+    }
+}
+
+impl ResponseError for OAuthError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            OAuthError::InvalidClient => StatusCode::UNAUTHORIZED,
+            OAuthError::InvalidGrant => StatusCode::BAD_REQUEST,
+            OAuthError::InvalidRequest => StatusCode::BAD_REQUEST,
+            OAuthError::InvalidScope => StatusCode::BAD_REQUEST,
+            OAuthError::ServerError => StatusCode::INTERNAL_SERVER_ERROR,
+            OAuthError::UnauthorizedClient => StatusCode::BAD_REQUEST,
+            OAuthError::UnsupportedGrantType => StatusCode::BAD_REQUEST,
+            OAuthError::UnsupportedTokenType => StatusCode::BAD_REQUEST,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        let mut response = HttpResponse::build(self.status_code());
+        if *self == OAuthError::InvalidClient {
+            response.insert_header((header::WWW_AUTHENTICATE, "Basic realm=\"mairie360\""));
+        }
+        response.json(OAuthErrorView::new(&self.to_string()))
+    }
+}
+
+/**
+ * Access tokens are JWTs while refresh tokens are opaque base64url strings without dots, so
+ * the token itself tells its type and `token_type_hint` can safely be ignored.
+ */
+pub fn is_access_token(token: &str) -> bool {
+    token.split('.').count() == 3
+}
+
+/**
+ * Authenticates the calling module, from HTTP Basic or from `client_id`/`client_secret`
+ * form fields. Unknown clients and wrong secrets are indistinguishable.
+ */
+pub async fn authenticate_client(
+    state: &web::Data<AppState>,
+    request: &HttpRequest,
+    form_client_id: Option<&str>,
+    form_client_secret: Option<&str>,
+) -> Result<GetOAuthClientQueryResultView, OAuthError> {
+    let credentials = match request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+    {
+        Some(value) => parse_basic_authorization(value).ok_or(OAuthError::InvalidClient)?,
+        None => match (form_client_id, form_client_secret) {
+            (Some(client_id), Some(client_secret)) => ClientCredentials {
+                client_id: client_id.to_string(),
+                client_secret: client_secret.to_string(),
+            },
+            _ => return Err(OAuthError::InvalidClient),
+        },
+    };
+
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(OAuthError::ServerError),
+    };
+
+    let client = get_oauth_client_query(GetOAuthClientQueryView::new(&credentials.client_id), pool)
+        .await
+        .map_err(|e| {
+            eprintln!("Database Error: {}", e);
+            OAuthError::ServerError
+        })?
+        .ok_or(OAuthError::InvalidClient)?;
+
+    if !verify_client_secret(&credentials.client_secret, client.get_client_secret_hash()) {
+        return Err(OAuthError::InvalidClient);
+    }
+    Ok(client)
+}
@@ -0,0 +1,12 @@
+use crate::endpoints::v1::oauth::revoke::endpoint;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(endpoint::revoke),
+    components(schemas(
+        crate::endpoints::v1::oauth::view::TokenRequestView,
+        crate::endpoints::v1::oauth::view::OAuthErrorView
+    ))
+)]
+pub struct RevokeDoc;
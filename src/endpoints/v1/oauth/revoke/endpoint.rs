@@ -0,0 +1,126 @@
+use actix_web::{post, web, HttpRequest, HttpResponse, Responder};
+use chrono::Utc;
+use mairie360_api_lib::pool::AppState;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::database::sessions::get_session_by_token::{
+    get_session_by_token_query, GetSessionByTokenQueryView,
+};
+use crate::database::sessions::revoke_session_by_id::{
+    revoke_session_by_id_query, RevokeSessionByIdQueryView,
+};
+use crate::database::sessions::revoke_session_by_token::{
+    revoke_session_by_token_query, RevokeSessionByTokenQueryView,
+};
+use crate::endpoints::v1::oauth::view::{OAuthErrorView, TokenRequestView};
+use crate::endpoints::v1::oauth::{authenticate_client, is_access_token, OAuthError};
+use crate::endpoints::v1::sessions::revoke_access_tokens;
+use crate::security::jwt::{decode_jwt, key_store};
+use crate::security::token::hash_token;
+
+/**
+ * Revoking an access token ends the session it was issued for, refresh token included.
+ */
+async fn revoke_access_token(
+    state: &web::Data<AppState>,
+    pool: PgPool,
+    token: &str,
+) -> Result<(), OAuthError> {
+    let store = key_store().map_err(|e| {
+        eprintln!("JWT Key Error: {}", e);
+        OAuthError::ServerError
+    })?;
+    let claims = match decode_jwt(store, token, Utc::now()) {
+        Ok(claims) => claims,
+        Err(_) => return Ok(()),
+    };
+    let (user_id, session_id) = match (
+        claims.get_user_id().parse::<u64>(),
+        claims.get_session_id().map(Uuid::parse_str),
+    ) {
+        (Ok(user_id), Some(Ok(session_id))) => (user_id, session_id),
+        _ => return Err(OAuthError::UnsupportedTokenType),
+    };
+
+    revoke_session_by_id_query(RevokeSessionByIdQueryView::new(user_id, session_id), pool)
+        .await
+        .map_err(|e| {
+            eprintln!("Database Error: {}", e);
+            OAuthError::ServerError
+        })?;
+    revoke_access_tokens(state, &[session_id]).await;
+    Ok(())
+}
+
+async fn revoke_refresh_token(
+    state: &web::Data<AppState>,
+    pool: PgPool,
+    token: &str,
+) -> Result<(), OAuthError> {
+    let token_hash = hash_token(token);
+    let session = get_session_by_token_query(
+        GetSessionByTokenQueryView::new(token_hash.clone()),
+        pool.clone(),
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("Database Error: {}", e);
+        OAuthError::ServerError
+    })?;
+
+    // RFC 7009 : un jeton inconnu ou déjà révoqué n'est pas une erreur
+    let session = match session {
+        Some(session) if session.revoked_at().is_none() => session,
+        _ => return Ok(()),
+    };
+
+    revoke_session_by_token_query(
+        RevokeSessionByTokenQueryView::new(session.user_id() as u64, &token_hash),
+        pool,
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("Database Error: {}", e);
+        OAuthError::ServerError
+    })?;
+    revoke_access_tokens(state, &[*session.id()]).await;
+    Ok(())
+}
+
+#[utoipa::path(
+    post,
+    path = "",
+    request_body(content = TokenRequestView, content_type = "application/x-www-form-urlencoded"),
+    responses(
+        (status = 200, description = "Token revoked, or already invalid"),
+        (status = 400, description = "Access token not bound to a session", body = OAuthErrorView),
+        (status = 401, description = "Client authentication failed", body = OAuthErrorView),
+        (status = 500, description = "Internal server error", body = OAuthErrorView)
+    ),
+    tag = "OAuth",
+    security(
+        ("client_credentials" = [])
+    )
+)]
+#[post("/revoke")]
+pub async fn revoke(
+    form: web::Form<TokenRequestView>,
+    request: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<impl Responder, OAuthError> {
+    let view = form.into_inner();
+    authenticate_client(&state, &request, view.client_id(), view.client_secret()).await?;
+
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(OAuthError::ServerError),
+    };
+    if is_access_token(view.token()) {
+        revoke_access_token(&state, pool, view.token()).await?;
+    } else {
+        revoke_refresh_token(&state, pool, view.token()).await?;
+    }
+
+    Ok(HttpResponse::Ok().finish())
+}
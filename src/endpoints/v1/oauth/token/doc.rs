@@ -0,0 +1,13 @@
+use crate::endpoints::v1::oauth::token::endpoint;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(endpoint::token),
+    components(schemas(
+        super::view::TokenGrantRequestView,
+        super::view::TokenResponseView,
+        crate::endpoints::v1::oauth::view::OAuthErrorView
+    ))
+)]
+pub struct TokenDoc;
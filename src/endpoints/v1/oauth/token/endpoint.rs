@@ -0,0 +1,226 @@
+use actix_web::http::header;
+use actix_web::{post, web, HttpRequest, HttpResponse, Responder};
+use chrono::Utc;
+use mairie360_api_lib::pool::AppState;
+use sqlx::PgPool;
+
+use crate::database::oidc::consume_authorization_code::{
+    consume_authorization_code_query, ConsumeAuthorizationCodeQueryView,
+};
+use crate::database::oidc::get_oidc_client::{get_oidc_client_query, GetOidcClientQueryView};
+use crate::database::oidc::OidcClient;
+use crate::database::users::get_user_by_id::{get_user_by_id_query, GetUserByIdQueryView};
+use crate::endpoints::v1::oauth::token::view::{TokenGrantRequestView, TokenResponseView};
+use crate::endpoints::v1::oauth::token::{
+    module_token_ttl, AUTHORIZATION_CODE_GRANT_TYPE, CLIENT_CREDENTIALS_GRANT_TYPE,
+};
+use crate::endpoints::v1::oauth::view::OAuthErrorView;
+use crate::endpoints::v1::oauth::{authenticate_client, OAuthError};
+use crate::endpoints::v1::oidc::oidc_issuer;
+use crate::security::client_credentials::{parse_basic_authorization, verify_client_secret};
+use crate::security::jwt::{
+    encode_jwt, generate_client_jwt, generate_module_jwt, is_session_denied, jwt_timeout, key_store,
+};
+use crate::security::oidc::{verify_code_challenge, IdTokenClaims, UserClaims};
+use crate::security::service_account::grant_scopes;
+use crate::security::token::hash_token;
+
+async fn client_credentials(
+    state: &web::Data<AppState>,
+    request: &HttpRequest,
+    view: &TokenGrantRequestView,
+) -> Result<TokenResponseView, OAuthError> {
+    let client =
+        authenticate_client(state, request, view.client_id(), view.client_secret()).await?;
+
+    let module_id = client
+        .get_module_id()
+        .ok_or(OAuthError::UnauthorizedClient)?;
+    let scopes = grant_scopes(view.scope(), client.get_scopes()).ok_or(OAuthError::InvalidScope)?;
+
+    let ttl = module_token_ttl();
+    let access_token = generate_module_jwt(client.get_client_id(), module_id, &scopes, ttl)
+        .map_err(|e| {
+            eprintln!("JWT Error: {}", e);
+            OAuthError::ServerError
+        })?;
+    Ok(TokenResponseView::new(access_token, ttl, &scopes))
+}
+
+/**
+ * OpenID Connect clients live in their own table. Public clients send their `client_id`
+ * alone and are authenticated by PKCE; confidential ones must also prove their secret.
+ */
+async fn authenticate_oidc_client(
+    pool: &PgPool,
+    request: &HttpRequest,
+    view: &TokenGrantRequestView,
+) -> Result<OidcClient, OAuthError> {
+    let (client_id, client_secret) = match request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+    {
+        Some(value) => {
+            let credentials = parse_basic_authorization(value).ok_or(OAuthError::InvalidClient)?;
+            (credentials.client_id, Some(credentials.client_secret))
+        }
+        None => (
+            view.client_id()
+                .ok_or(OAuthError::InvalidClient)?
+                .to_string(),
+            view.client_secret().map(str::to_string),
+        ),
+    };
+
+    let client = get_oidc_client_query(GetOidcClientQueryView::new(&client_id), pool.clone())
+        .await
+        .map_err(|e| {
+            eprintln!("Database Error: {}", e);
+            OAuthError::ServerError
+        })?
+        .ok_or(OAuthError::InvalidClient)?;
+
+    if let Some(secret_hash) = client.client_secret_hash() {
+        match client_secret {
+            Some(secret) if verify_client_secret(&secret, secret_hash) => {}
+            _ => return Err(OAuthError::InvalidClient),
+        }
+    }
+    Ok(client)
+}
+
+async fn authorization_code(
+    state: &web::Data<AppState>,
+    request: &HttpRequest,
+    view: &TokenGrantRequestView,
+) -> Result<TokenResponseView, OAuthError> {
+    let (code, redirect_uri, code_verifier) =
+        match (view.code(), view.redirect_uri(), view.code_verifier()) {
+            (Some(code), Some(redirect_uri), Some(code_verifier)) => {
+                (code, redirect_uri, code_verifier)
+            }
+            _ => return Err(OAuthError::InvalidRequest),
+        };
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(OAuthError::ServerError),
+    };
+    let client = authenticate_oidc_client(&pool, request, view).await?;
+
+    let grant = consume_authorization_code_query(
+        ConsumeAuthorizationCodeQueryView::new(&hash_token(code)),
+        pool.clone(),
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("Database Error: {}", e);
+        OAuthError::ServerError
+    })?
+    .ok_or(OAuthError::InvalidGrant)?;
+    if grant.client_id() != client.client_id()
+        || grant.redirect_uri() != redirect_uri
+        || *grant.expires_at() <= Utc::now()
+        || !verify_code_challenge(code_verifier, grant.code_challenge())
+    {
+        return Err(OAuthError::InvalidGrant);
+    }
+
+    // La session approuvée a pu être fermée entre-temps
+    let session_id = grant.session_id().map(|id| id.to_string());
+    if let Some(session_id) = &session_id {
+        if is_session_denied(state, session_id)
+            .await
+            .map_err(|_| OAuthError::ServerError)?
+        {
+            return Err(OAuthError::InvalidGrant);
+        }
+    }
+    let user_id = grant.user_id() as u64;
+    let user = get_user_by_id_query(GetUserByIdQueryView::new(user_id), pool)
+        .await
+        .map_err(|_| OAuthError::InvalidGrant)?;
+    if user.is_archived() {
+        return Err(OAuthError::InvalidGrant);
+    }
+
+    let issuer = oidc_issuer().ok_or_else(|| {
+        eprintln!("OIDC_ISSUER is missing");
+        OAuthError::ServerError
+    })?;
+    let ttl = jwt_timeout().map_err(|e| {
+        eprintln!("JWT Error: {}", e);
+        OAuthError::ServerError
+    })?;
+    let access_token = generate_client_jwt(
+        &user_id.to_string(),
+        grant.session_id(),
+        client.client_id(),
+        grant.scopes(),
+    )
+    .map_err(|e| {
+        eprintln!("JWT Error: {}", e);
+        OAuthError::ServerError
+    })?;
+
+    let now = Utc::now();
+    let issued_at = now.timestamp() as u64;
+    let user_claims = UserClaims::new(
+        user_id,
+        grant.scopes(),
+        user.first_name(),
+        user.last_name(),
+        user.email(),
+        user.phone_number(),
+    );
+    let id_claims = IdTokenClaims::new(
+        &issuer,
+        client.client_id(),
+        user_claims,
+        issued_at,
+        issued_at + ttl,
+    )
+    .with_nonce(grant.nonce())
+    .with_session_id(session_id.as_deref());
+    let id_token = key_store()
+        .and_then(|store| encode_jwt(store, &id_claims, now))
+        .map_err(|e| {
+            eprintln!("JWT Error: {}", e);
+            OAuthError::ServerError
+        })?;
+
+    Ok(TokenResponseView::new(access_token, ttl, grant.scopes()).with_id_token(id_token))
+}
+
+#[utoipa::path(
+    post,
+    path = "",
+    request_body(content = TokenGrantRequestView, content_type = "application/x-www-form-urlencoded"),
+    responses(
+        (status = 200, description = "Module token (client credentials), or access and ID tokens for the user (authorization code), sent as `Authorization: Bearer`", body = TokenResponseView),
+        (status = 400, description = "Unsupported grant type, invalid or used code, wrong PKCE verifier, scope not allowed for this client, or client not tied to a module", body = OAuthErrorView),
+        (status = 401, description = "Client authentication failed", body = OAuthErrorView),
+        (status = 500, description = "Internal server error", body = OAuthErrorView)
+    ),
+    tag = "OAuth",
+    security(
+        ("client_credentials" = [])
+    )
+)]
+#[post("/token")]
+pub async fn token(
+    form: web::Form<TokenGrantRequestView>,
+    request: HttpRequest,
+    state: web::Data<AppState>,
+) -> Result<impl Responder, OAuthError> {
+    let view = form.into_inner();
+    let response = match view.grant_type() {
+        CLIENT_CREDENTIALS_GRANT_TYPE => client_credentials(&state, &request, &view).await?,
+        AUTHORIZATION_CODE_GRANT_TYPE => authorization_code(&state, &request, &view).await?,
+        _ => return Err(OAuthError::UnsupportedGrantType),
+    };
+
+    Ok(HttpResponse::Ok()
+        .insert_header(("Cache-Control", "no-store"))
+        .json(response))
+}
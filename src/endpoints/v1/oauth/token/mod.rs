@@ -0,0 +1,18 @@
+pub mod doc;
+pub mod endpoint;
+pub mod view;
+
+use mairie360_api_lib::env_manager::get_env_var;
+
+pub const CLIENT_CREDENTIALS_GRANT_TYPE: &str = "client_credentials";
+pub const AUTHORIZATION_CODE_GRANT_TYPE: &str = "authorization_code";
+
+/**
+ * Lifetime of module tokens in seconds, read from `MODULE_TOKEN_TTL`. Kept short because
+ * they cannot be revoked: modules simply ask for a new one.
+ */
+pub fn module_token_ttl() -> u64 {
+    get_env_var("MODULE_TOKEN_TTL")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(300)
+}
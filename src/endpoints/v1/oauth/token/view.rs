@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use utoipa::ToSchema;
+
+/**
+ * RFC 6749 token request, for the client credentials grant (`scope`, space separated) and
+ * the authorization code grant (`code`, `redirect_uri` and the PKCE `code_verifier`).
+ */
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct TokenGrantRequestView {
+    grant_type: String,
+    scope: Option<String>,
+    code: Option<String>,
+    redirect_uri: Option<String>,
+    code_verifier: Option<String>,
+    client_id: Option<String>,
+    client_secret: Option<String>,
+}
+
+impl TokenGrantRequestView {
+    pub fn grant_type(&self) -> &str {
+        &self.grant_type
+    }
+
+    pub fn scope(&self) -> Option<&str> {
+        self.scope.as_deref()
+    }
+
+    pub fn code(&self) -> Option<&str> {
+        self.code.as_deref()
+    }
+
+    pub fn redirect_uri(&self) -> Option<&str> {
+        self.redirect_uri.as_deref()
+    }
+
+    pub fn code_verifier(&self) -> Option<&str> {
+        self.code_verifier.as_deref()
+    }
+
+    pub fn client_id(&self) -> Option<&str> {
+        self.client_id.as_deref()
+    }
+
+    pub fn client_secret(&self) -> Option<&str> {
+        self.client_secret.as_deref()
+    }
+}
+
+impl Display for TokenGrantRequestView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "TokenGrantRequestView {{ grant_type: {}, scope: {:?}, code: [PROTECTED], redirect_uri: {:?}, code_verifier: [PROTECTED], client_id: {:?}, client_secret: [PROTECTED] }}",
+            self.grant_type, self.scope, self.redirect_uri, self.client_id
+        )
+    }
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct TokenResponseView {
+    access_token: String,
+    token_type: String,
+    expires_in: u64,
+    scope: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id_token: Option<String>,
+}
+
+impl TokenResponseView {
+    pub fn new(access_token: String, expires_in: u64, scopes: &[String]) -> Self {
+        Self {
+            access_token,
+            token_type: "Bearer".to_string(),
+            expires_in,
+            scope: scopes.join(" "),
+            id_token: None,
+        }
+    }
+
+    pub fn with_id_token(mut self, id_token: String) -> Self {
+        self.id_token = Some(id_token);
+        self
+    }
+
+    pub fn access_token(&self) -> &str {
+        &self.access_token
+    }
+
+    pub fn expires_in(&self) -> u64 {
+        self.expires_in
+    }
+
+    pub fn scope(&self) -> &str {
+        &self.scope
+    }
+
+    pub fn id_token(&self) -> Option<&str> {
+        self.id_token.as_deref()
+    }
+}
+
+impl Display for TokenResponseView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "TokenResponseView {{ access_token: [PROTECTED], token_type: {}, expires_in: {}, scope: {}, id_token: [PROTECTED] }}",
+            self.token_type, self.expires_in, self.scope
+        )
+    }
+}
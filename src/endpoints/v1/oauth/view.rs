@@ -0,0 +1,65 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct OAuthErrorView {
+    error: String,
+}
+
+impl OAuthErrorView {
+    pub fn new(error: &str) -> Self {
+        Self {
+            error: error.to_string(),
+        }
+    }
+
+    pub fn error(&self) -> &str {
+        &self.error
+    }
+}
+
+impl Display for OAuthErrorView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "OAuthErrorView {{ error: {} }}", self.error)
+    }
+}
+
+/**
+ * Form body shared by introspection (RFC 7662) and revocation (RFC 7009).
+ */
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct TokenRequestView {
+    token: String,
+    token_type_hint: Option<String>,
+    client_id: Option<String>,
+    client_secret: Option<String>,
+}
+
+impl TokenRequestView {
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    pub fn token_type_hint(&self) -> Option<&str> {
+        self.token_type_hint.as_deref()
+    }
+
+    pub fn client_id(&self) -> Option<&str> {
+        self.client_id.as_deref()
+    }
+
+    pub fn client_secret(&self) -> Option<&str> {
+        self.client_secret.as_deref()
+    }
+}
+
+impl Display for TokenRequestView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "TokenRequestView {{ token: [PROTECTED], token_type_hint: {:?}, client_id: {:?} }}",
+            self.token_type_hint, self.client_id
+        )
+    }
+}
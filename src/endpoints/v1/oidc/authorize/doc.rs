@@ -0,0 +1,14 @@
+use crate::endpoints::v1::oidc::authorize::endpoint;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(endpoint::authorize, endpoint::approve_authorization),
+    components(schemas(
+        super::view::AuthorizeRequestView,
+        super::view::ApproveAuthorizationView,
+        super::view::AuthorizeResponseView,
+        super::view::ConsentRequiredView
+    ))
+)]
+pub struct AuthorizeDoc;
@@ -0,0 +1,298 @@
+use actix_web::dev::ConnectionInfo;
+use actix_web::http::{header, StatusCode};
+use actix_web::{get, post, web, HttpRequest, HttpResponse, Responder, ResponseError};
+use mairie360_api_lib::jwt_manager::get_jwt_from_request;
+use mairie360_api_lib::pool::AppState;
+use sqlx::PgPool;
+use url::Url;
+
+use crate::database::oidc::create_authorization_code::{
+    create_authorization_code_query, CreateAuthorizationCodeQueryView,
+};
+use crate::database::oidc::get_oidc_client::{get_oidc_client_query, GetOidcClientQueryView};
+use crate::database::oidc::get_oidc_consent::{get_oidc_consent_query, GetOidcConsentQueryView};
+use crate::database::oidc::save_oidc_consent::{save_oidc_consent_query, SaveOidcConsentQueryView};
+use crate::database::oidc::OidcClient;
+use crate::database::security_events::create_security_event::{
+    create_security_event_query, CreateSecurityEventQueryView,
+};
+use crate::endpoints::v1::oidc::authorize::view::{
+    ApproveAuthorizationView, AuthorizeRequestView, AuthorizeResponseView, ConsentRequiredView,
+};
+use crate::endpoints::v1::oidc::{oidc_login_url, AUTHORIZATION_CODE_TTL};
+use crate::security::jwt::{check_jwt_validity, session_id_from_request};
+use crate::security::oidc::{
+    authorization_redirect, is_registered_redirect_uri, parse_oidc_scopes, PKCE_METHOD,
+};
+use crate::security::token::{generate_token, hash_token};
+
+pub const OIDC_CONSENT_GRANTED_EVENT: &str = "oidc_consent_granted";
+
+#[derive(Debug, Clone, PartialEq)]
+enum AuthorizeError {
+    ConsentRequired(String, Vec<String>),
+    DatabaseError,
+    InvalidClient,
+    LoginRequired,
+    ServerError,
+}
+
+impl std::fmt::Display for AuthorizeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuthorizeError::ConsentRequired(_, _) => write!(f, "consent_required"),
+            AuthorizeError::DatabaseError => {
+                write!(f, "An error occurred while accessing the database.")
+            }
+            AuthorizeError::InvalidClient => write!(f, "Unknown client or redirect URI."),
+            AuthorizeError::LoginRequired => write!(f, "Unauthorized: Log in first."),
+            AuthorizeError::ServerError => write!(f, "Internal server error."),
+        }
+    }
+}
+
+impl ResponseError for AuthorizeError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            AuthorizeError::ConsentRequired(_, _) => StatusCode::FORBIDDEN,
+            AuthorizeError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            AuthorizeError::InvalidClient => StatusCode::BAD_REQUEST,
+            AuthorizeError::LoginRequired => StatusCode::UNAUTHORIZED,
+            AuthorizeError::ServerError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        if let AuthorizeError::ConsentRequired(client_name, scopes) = self {
+            return HttpResponse::build(self.status_code())
+                .json(ConsentRequiredView::new(client_name, scopes.clone()));
+        }
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+fn get_pool(state: &web::Data<AppState>) -> Result<PgPool, AuthorizeError> {
+    match state.db_pool.clone() {
+        Some(pool) => Ok(pool),
+        None => Err(AuthorizeError::DatabaseError),
+    }
+}
+
+/**
+ * Until the redirect URI is known to belong to the client, errors are shown to the user
+ * instead of being sent to a possibly hostile site.
+ */
+async fn get_client(
+    pool: &PgPool,
+    view: &AuthorizeRequestView,
+) -> Result<OidcClient, AuthorizeError> {
+    let client = get_oidc_client_query(GetOidcClientQueryView::new(view.client_id()), pool.clone())
+        .await
+        .map_err(|e| {
+            eprintln!("Database Error: {}", e);
+            AuthorizeError::DatabaseError
+        })?
+        .ok_or(AuthorizeError::InvalidClient)?;
+    if !is_registered_redirect_uri(view.redirect_uri(), client.redirect_uris()) {
+        return Err(AuthorizeError::InvalidClient);
+    }
+    Ok(client)
+}
+
+/**
+ * Returns the scopes to grant, or the error code to send back to the client.
+ */
+fn check_request(view: &AuthorizeRequestView) -> Result<Vec<String>, &'static str> {
+    if view.response_type() != "code" {
+        return Err("unsupported_response_type");
+    }
+    if view.code_challenge().is_none() || view.code_challenge_method() != Some(PKCE_METHOD) {
+        return Err("invalid_request");
+    }
+    parse_oidc_scopes(view.scope()).ok_or("invalid_scope")
+}
+
+fn error_redirect(view: &AuthorizeRequestView, error: &str) -> Result<String, AuthorizeError> {
+    authorization_redirect(view.redirect_uri(), &[("error", error)], view.state())
+        .ok_or(AuthorizeError::InvalidClient)
+}
+
+#[utoipa::path(
+    get,
+    path = "authorize",
+    params(
+        ("response_type" = String, Query, description = "Always `code`"),
+        ("client_id" = String, Query, description = "Client registered in `oidc_clients`"),
+        ("redirect_uri" = String, Query, description = "One of the client's registered redirect URIs"),
+        ("scope" = String, Query, description = "Space separated, must include `openid`"),
+        ("state" = Option<String>, Query, description = "Returned untouched to the client"),
+        ("nonce" = Option<String>, Query, description = "Copied into the ID token"),
+        ("code_challenge" = String, Query, description = "PKCE challenge"),
+        ("code_challenge_method" = String, Query, description = "Always `S256`")
+    ),
+    responses(
+        (status = 302, description = "To the login page with the same parameters, or back to the client with an `error`"),
+        (status = 400, description = "Unknown client or redirect URI"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "OpenID Connect"
+)]
+#[get("/authorize")]
+pub async fn authorize(
+    state: web::Data<AppState>,
+    query: web::Query<AuthorizeRequestView>,
+    req: HttpRequest,
+) -> Result<impl Responder, AuthorizeError> {
+    let pool = get_pool(&state)?;
+    let view = query.into_inner();
+    get_client(&pool, &view).await?;
+
+    let location = match check_request(&view) {
+        Ok(_) => {
+            // La page de connexion rejoue la requête vers POST /authorize une fois connecté
+            let mut login_url = oidc_login_url()
+                .and_then(|url| Url::parse(&url).ok())
+                .ok_or_else(|| {
+                    eprintln!("OIDC_LOGIN_URL is missing or invalid");
+                    AuthorizeError::ServerError
+                })?;
+            login_url.set_query(Some(req.query_string()));
+            login_url.to_string()
+        }
+        Err(error) => error_redirect(&view, error)?,
+    };
+
+    Ok(HttpResponse::Found()
+        .insert_header((header::LOCATION, location))
+        .finish())
+}
+
+async fn issue_code(
+    pool: &PgPool,
+    view: &AuthorizeRequestView,
+    user_id: u64,
+    req: &HttpRequest,
+    scopes: Vec<String>,
+) -> Result<String, AuthorizeError> {
+    let code = generate_token();
+    let db_view = CreateAuthorizationCodeQueryView::new(
+        &hash_token(&code),
+        view.client_id(),
+        user_id,
+        session_id_from_request(req),
+        view.redirect_uri(),
+        scopes,
+        view.nonce(),
+        view.code_challenge().unwrap_or_default(),
+        AUTHORIZATION_CODE_TTL,
+    );
+    create_authorization_code_query(db_view, pool.clone())
+        .await
+        .map_err(|e| {
+            eprintln!("Database Error: {}", e);
+            AuthorizeError::DatabaseError
+        })?;
+    authorization_redirect(view.redirect_uri(), &[("code", &code)], view.state())
+        .ok_or(AuthorizeError::InvalidClient)
+}
+
+async fn approve(
+    pool: &PgPool,
+    body: &ApproveAuthorizationView,
+    user_id: u64,
+    req: &HttpRequest,
+    conn: &ConnectionInfo,
+) -> Result<String, AuthorizeError> {
+    let view = body.request();
+    let client = get_client(pool, view).await?;
+    let scopes = match check_request(view) {
+        Ok(scopes) => scopes,
+        Err(error) => return error_redirect(view, error),
+    };
+    if body.consent() == Some(false) {
+        return error_redirect(view, "access_denied");
+    }
+
+    // Les front ends mairie360 n'ont pas à demander le consentement
+    if !client.is_first_party() {
+        let granted = get_oidc_consent_query(
+            GetOidcConsentQueryView::new(user_id, client.client_id()),
+            pool.clone(),
+        )
+        .await
+        .map_err(|e| {
+            eprintln!("Database Error: {}", e);
+            AuthorizeError::DatabaseError
+        })?;
+        if !scopes.iter().all(|scope| granted.contains(scope)) {
+            if body.consent() != Some(true) {
+                return Err(AuthorizeError::ConsentRequired(
+                    client.name().to_string(),
+                    scopes,
+                ));
+            }
+            let db_view =
+                SaveOidcConsentQueryView::new(user_id, client.client_id(), scopes.clone());
+            save_oidc_consent_query(db_view, pool.clone())
+                .await
+                .map_err(|e| {
+                    eprintln!("Database Error: {}", e);
+                    AuthorizeError::DatabaseError
+                })?;
+            let ip_address = conn
+                .realip_remote_addr()
+                .and_then(|ip| ip.parse::<std::net::IpAddr>().ok());
+            let event = CreateSecurityEventQueryView::new(
+                Some(user_id),
+                OIDC_CONSENT_GRANTED_EVENT,
+                ip_address,
+                Some(format!(
+                    "client_id = {}, scopes = {}",
+                    client.client_id(),
+                    scopes.join(" ")
+                )),
+            );
+            if let Err(e) = create_security_event_query(event, pool.clone()).await {
+                eprintln!("Security Event DB Error: {}", e);
+            }
+        }
+    }
+
+    issue_code(pool, view, user_id, req, scopes).await
+}
+
+#[utoipa::path(
+    post,
+    path = "authorize",
+    request_body = ApproveAuthorizationView,
+    responses(
+        (status = 200, description = "Where to send the browser: the client's redirect URI with a code, or with an `error`", body = AuthorizeResponseView),
+        (status = 400, description = "Unknown client or redirect URI"),
+        (status = 401, description = "No valid session token"),
+        (status = 403, description = "The user has to consent first; call again with `consent`", body = ConsentRequiredView),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "OpenID Connect",
+    security(
+        ("jwt" = [])
+    )
+)]
+#[post("/authorize")]
+pub async fn approve_authorization(
+    state: web::Data<AppState>,
+    body: web::Json<ApproveAuthorizationView>,
+    req: HttpRequest,
+    conn: ConnectionInfo,
+) -> Result<impl Responder, AuthorizeError> {
+    // Seule une session ouverte sur Core peut approuver, pas un jeton d'accès personnel
+    let jwt = get_jwt_from_request(&req).ok_or(AuthorizeError::LoginRequired)?;
+    let user_id = check_jwt_validity(&jwt, &state)
+        .await
+        .map_err(|_| AuthorizeError::LoginRequired)?;
+    let pool = get_pool(&state)?;
+
+    let redirect_to = approve(&pool, &body, user_id, &req, &conn).await?;
+    Ok(HttpResponse::Ok()
+        .insert_header(("Cache-Control", "no-store"))
+        .json(AuthorizeResponseView::new(redirect_to)))
+}
@@ -0,0 +1,144 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use utoipa::ToSchema;
+
+/**
+ * OpenID Connect authentication request, authorization code flow with PKCE.
+ */
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct AuthorizeRequestView {
+    response_type: String,
+    client_id: String,
+    redirect_uri: String,
+    scope: String,
+    state: Option<String>,
+    nonce: Option<String>,
+    code_challenge: Option<String>,
+    code_challenge_method: Option<String>,
+}
+
+impl AuthorizeRequestView {
+    pub fn response_type(&self) -> &str {
+        &self.response_type
+    }
+
+    pub fn client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    pub fn redirect_uri(&self) -> &str {
+        &self.redirect_uri
+    }
+
+    pub fn scope(&self) -> &str {
+        &self.scope
+    }
+
+    pub fn state(&self) -> Option<&str> {
+        self.state.as_deref()
+    }
+
+    pub fn nonce(&self) -> Option<&str> {
+        self.nonce.as_deref()
+    }
+
+    pub fn code_challenge(&self) -> Option<&str> {
+        self.code_challenge.as_deref()
+    }
+
+    pub fn code_challenge_method(&self) -> Option<&str> {
+        self.code_challenge_method.as_deref()
+    }
+}
+
+impl Display for AuthorizeRequestView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "AuthorizeRequestView {{ response_type: {}, client_id: {}, redirect_uri: {}, scope: {} }}",
+            self.response_type, self.client_id, self.redirect_uri, self.scope
+        )
+    }
+}
+
+/**
+ * Sent by the login page once the user is logged in, with the parameters it was opened with.
+ * `consent` is the user's answer when the previous call asked for it.
+ */
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ApproveAuthorizationView {
+    #[serde(flatten)]
+    request: AuthorizeRequestView,
+    consent: Option<bool>,
+}
+
+impl ApproveAuthorizationView {
+    pub fn request(&self) -> &AuthorizeRequestView {
+        &self.request
+    }
+
+    pub fn consent(&self) -> Option<bool> {
+        self.consent
+    }
+}
+
+impl Display for ApproveAuthorizationView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ApproveAuthorizationView {{ request: {}, consent: {:?} }}",
+            self.request, self.consent
+        )
+    }
+}
+
+/**
+ * Where the login page sends the browser back: the client, with a code or an error.
+ */
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct AuthorizeResponseView {
+    redirect_to: String,
+}
+
+impl AuthorizeResponseView {
+    pub fn new(redirect_to: String) -> Self {
+        Self { redirect_to }
+    }
+
+    pub fn redirect_to(&self) -> &str {
+        &self.redirect_to
+    }
+}
+
+impl Display for AuthorizeResponseView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AuthorizeResponseView {{ redirect_to: [PROTECTED] }}")
+    }
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ConsentRequiredView {
+    error: String,
+    client_name: String,
+    scopes: Vec<String>,
+}
+
+impl ConsentRequiredView {
+    pub fn new(client_name: &str, scopes: Vec<String>) -> Self {
+        Self {
+            error: "consent_required".to_string(),
+            client_name: client_name.to_string(),
+            scopes,
+        }
+    }
+}
+
+impl Display for ConsentRequiredView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ConsentRequiredView {{ client_name: {}, scopes: {:?} }}",
+            self.client_name, self.scopes
+        )
+    }
+}
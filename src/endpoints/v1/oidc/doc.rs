@@ -0,0 +1,10 @@
+use super::authorize::doc::AuthorizeDoc;
+use super::userinfo::doc::UserinfoDoc;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(nest(
+    (path = "/", api = AuthorizeDoc, tags = ["OpenID Connect"]),
+    (path = "/", api = UserinfoDoc, tags = ["OpenID Connect"]),
+))]
+pub struct OidcDoc;
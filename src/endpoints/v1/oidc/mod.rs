@@ -0,0 +1,38 @@
+pub mod authorize;
+pub mod doc;
+pub mod userinfo;
+
+use actix_web::web;
+use mairie360_api_lib::env_manager::get_env_var;
+
+/**
+ * OpenID Connect provider routes. Like the OAuth endpoints they authenticate the caller
+ * themselves: the JWT middleware lets them through.
+ */
+pub fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/oidc")
+            .service(authorize::endpoint::authorize)
+            .service(authorize::endpoint::approve_authorization)
+            .service(userinfo::endpoint::userinfo),
+    );
+}
+
+/**
+ * Authorization codes are exchanged by the client right after the redirect.
+ */
+pub const AUTHORIZATION_CODE_TTL: u64 = 60;
+
+/**
+ * `iss` of ID tokens and base of the discovery document, read from `OIDC_ISSUER`.
+ */
+pub fn oidc_issuer() -> Option<String> {
+    get_env_var("OIDC_ISSUER").map(|issuer| issuer.trim_end_matches('/').to_string())
+}
+
+/**
+ * Front-end page where users log in and approve a client, read from `OIDC_LOGIN_URL`.
+ */
+pub fn oidc_login_url() -> Option<String> {
+    get_env_var("OIDC_LOGIN_URL")
+}
@@ -0,0 +1,9 @@
+use crate::endpoints::v1::oidc::userinfo::endpoint;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(endpoint::userinfo),
+    components(schemas(crate::security::oidc::UserClaims))
+)]
+pub struct UserinfoDoc;
@@ -0,0 +1,126 @@
+use actix_web::http::{header, StatusCode};
+use actix_web::{get, web, HttpRequest, HttpResponse, Responder, ResponseError};
+use chrono::Utc;
+use mairie360_api_lib::jwt_manager::get_jwt_from_request;
+use mairie360_api_lib::pool::AppState;
+
+use crate::database::users::get_user_by_id::{get_user_by_id_query, GetUserByIdQueryView};
+use crate::security::jwt::{decode_jwt, is_session_denied, key_store, Claims};
+use crate::security::oidc::{UserClaims, OPENID_SCOPE};
+
+#[derive(Debug, Clone, PartialEq)]
+enum UserinfoError {
+    DatabaseError,
+    InvalidToken,
+    ServerError,
+}
+
+impl std::fmt::Display for UserinfoError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UserinfoError::DatabaseError => {
+                write!(f, "An error occurred while accessing the database.")
+            }
+            UserinfoError::InvalidToken => write!(f, "Unauthorized: Invalid access token."),
+            UserinfoError::ServerError => write!(f, "Internal server error."),
+        }
+    }
+}
+
+impl ResponseError for UserinfoError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            UserinfoError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            UserinfoError::InvalidToken => StatusCode::UNAUTHORIZED,
+            UserinfoError::ServerError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        let mut response = HttpResponse::build(self.status_code());
+        if *self == UserinfoError::InvalidToken {
+            response.insert_header((header::WWW_AUTHENTICATE, "Bearer error=\"invalid_token\""));
+        }
+        response.body(self.to_string())
+    }
+}
+
+/**
+ * Only access tokens issued to an OpenID Connect client with the `openid` scope are accepted.
+ */
+async fn check_client_token(state: &AppState, jwt: &str) -> Result<Claims, UserinfoError> {
+    let store = key_store().map_err(|e| {
+        eprintln!("JWT Key Error: {}", e);
+        UserinfoError::ServerError
+    })?;
+    let claims = decode_jwt(store, jwt, Utc::now()).map_err(|_| UserinfoError::InvalidToken)?;
+    let has_openid = claims
+        .get_scope()
+        .is_some_and(|scope| scope.split_whitespace().any(|s| s == OPENID_SCOPE));
+    if claims.is_module_token() || claims.get_client_id().is_none() || !has_openid {
+        return Err(UserinfoError::InvalidToken);
+    }
+    if let Some(session_id) = claims.get_session_id() {
+        if is_session_denied(state, session_id)
+            .await
+            .map_err(|_| UserinfoError::ServerError)?
+        {
+            return Err(UserinfoError::InvalidToken);
+        }
+    }
+    Ok(claims)
+}
+
+#[utoipa::path(
+    get,
+    path = "userinfo",
+    responses(
+        (status = 200, description = "Claims about the user, limited to the granted scopes", body = UserClaims),
+        (status = 401, description = "Missing, invalid or expired access token"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "OpenID Connect",
+    security(
+        ("jwt" = [])
+    )
+)]
+#[get("/userinfo")]
+pub async fn userinfo(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> Result<impl Responder, UserinfoError> {
+    let jwt = get_jwt_from_request(&req).ok_or(UserinfoError::InvalidToken)?;
+    let claims = check_client_token(&state, &jwt).await?;
+    let user_id: u64 = claims
+        .get_user_id()
+        .parse()
+        .map_err(|_| UserinfoError::InvalidToken)?;
+
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(UserinfoError::DatabaseError),
+    };
+    let user = get_user_by_id_query(GetUserByIdQueryView::new(user_id), pool)
+        .await
+        .map_err(|_| UserinfoError::InvalidToken)?;
+    if user.is_archived() {
+        return Err(UserinfoError::InvalidToken);
+    }
+
+    let scopes: Vec<String> = claims
+        .get_scope()
+        .unwrap_or_default()
+        .split_whitespace()
+        .map(str::to_string)
+        .collect();
+    Ok(HttpResponse::Ok()
+        .insert_header(("Cache-Control", "no-store"))
+        .json(UserClaims::new(
+            user_id,
+            &scopes,
+            user.first_name(),
+            user.last_name(),
+            user.email(),
+            user.phone_number(),
+        )))
+}
@@ -1,14 +1,16 @@
 use super::get::doc::GetDoc;
 use super::history::doc::HistoryDoc;
-use super::refresh::doc::RefreshDoc;
 use super::revoke::doc::RevokeDoc;
+use super::revoke_all::doc::RevokeAllDoc;
+use super::security_events::doc::SecurityEventsDoc;
 use utoipa::OpenApi;
 
 #[derive(OpenApi)]
 #[openapi(nest(
     (path = "/", api = GetDoc, tags = ["Sessions"]),
     (path = "/", api = HistoryDoc, tags = ["Sessions"]),
-    (path = "/", api = RefreshDoc, tags = ["Sessions"]),
     (path = "/", api = RevokeDoc, tags = ["Sessions"]),
+    (path = "/", api = RevokeAllDoc, tags = ["Sessions"]),
+    (path = "/", api = SecurityEventsDoc, tags = ["Sessions"]),
 ))]
 pub struct SessionsDoc;
@@ -1,18 +1,69 @@
 pub mod doc;
 mod get;
 mod history;
-mod refresh;
 mod revoke;
+mod revoke_all;
+mod security_events;
 pub mod view;
 
 use actix_web::web;
+use mairie360_api_lib::pool::AppState;
+use uuid::Uuid;
+
+use crate::database::sessions::get_rotated_family_sessions::{
+    get_rotated_family_sessions_query, GetRotatedFamilySessionsQueryView,
+};
+use crate::redis::handle_deny_session;
+use crate::security::jwt::jwt_timeout;
 
 pub fn config(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/sessions")
             .service(get::endpoint::get_active_sessions)
             .service(history::endpoint::history)
-            .service(refresh::endpoint::refresh)
-            .service(revoke::endpoint::revoke),
+            .service(revoke::endpoint::revoke)
+            .service(revoke_all::endpoint::revoke_all)
+            .service(security_events::endpoint::security_events),
     );
 }
+
+/**
+ * Denylists revoked sessions so their access tokens stop working at once instead of at
+ * expiry. The entries live for `JWT_TIMEOUT`, the longest a token of the session can last.
+ * Sessions of the same families refreshed within that time are denylisted too: the tokens
+ * issued before the refresh carry their ids.
+ */
+pub async fn revoke_access_tokens(state: &web::Data<AppState>, session_ids: &[Uuid]) {
+    if session_ids.is_empty() {
+        return;
+    }
+    let ttl = match jwt_timeout() {
+        Ok(ttl) => ttl,
+        Err(e) => {
+            eprintln!("JWT Configuration Error: {}", e);
+            return;
+        }
+    };
+    let mut denied = session_ids.to_vec();
+    if let Some(pool) = state.db_pool.clone() {
+        let view = GetRotatedFamilySessionsQueryView::new(session_ids, ttl);
+        match get_rotated_family_sessions_query(view, pool).await {
+            Ok(rotated) => denied.extend(rotated),
+            Err(e) => eprintln!("Database Error: {}", e),
+        }
+    }
+    denied.sort();
+    denied.dedup();
+    for session_id in &denied {
+        let conn = match state.get_redis_conn().await {
+            Some(conn) => conn,
+            None => {
+                eprintln!("Redis Error: no connection available");
+                return;
+            }
+        };
+        if let Err(e) = handle_deny_session(conn, &session_id.to_string(), ttl).await {
+            eprintln!("Redis Error: {}", e);
+        }
+    }
+}
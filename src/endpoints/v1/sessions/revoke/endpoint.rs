@@ -1,9 +1,14 @@
 use std::net::IpAddr;
 
+use crate::database::sessions::get_session_by_token::{
+    get_session_by_token_query, GetSessionByTokenQueryView,
+};
 use crate::database::sessions::revoke_session_by_token::{
     revoke_session_by_token_query, RevokeSessionByTokenQueryView,
 };
 use crate::endpoints::v1::sessions::revoke::request_view::RevokeRequestView;
+use crate::endpoints::v1::sessions::revoke_access_tokens;
+use crate::security::token::hash_token;
 use mairie360_api_lib::security::AuthenticatedUser;
 
 use actix_web::http::StatusCode;
@@ -58,18 +63,34 @@ async fn revoke_request(
 ) -> Result<(), RevokeError> {
     let user_id = user.id;
 
-    let db_view = IsSessionTokenValidQueryView::new(user_id, view.refresh_token(), ip_adress);
+    let token_hash = hash_token(&view.refresh_token());
+
+    let db_view = IsSessionTokenValidQueryView::new(user_id, token_hash.clone(), ip_adress);
 
     let is_valid = is_session_token_valid_query(db_view, state.db_pool.clone().unwrap()).await;
 
     let db_view = match is_valid {
-        Ok(true) => RevokeSessionByTokenQueryView::new(user_id, &view.refresh_token()),
+        Ok(true) => RevokeSessionByTokenQueryView::new(user_id, &token_hash),
         Ok(false) => return Err(RevokeError::InvalidToken),
         Err(_) => return Err(RevokeError::DatabaseError),
     };
 
+    let session = match get_session_by_token_query(
+        GetSessionByTokenQueryView::new(token_hash.clone()),
+        state.db_pool.clone().unwrap(),
+    )
+    .await
+    {
+        Ok(Some(session)) => session,
+        Ok(None) => return Err(RevokeError::InvalidToken),
+        Err(_) => return Err(RevokeError::DatabaseError),
+    };
+
     match revoke_session_by_token_query(db_view, state.db_pool.clone().unwrap()).await {
-        Ok(_) => Ok(()),
+        Ok(_) => {
+            revoke_access_tokens(&state, &[*session.id()]).await;
+            Ok(())
+        }
         Err(_) => Err(RevokeError::DatabaseError),
     }
 }
@@ -0,0 +1,70 @@
+use actix_web::http::StatusCode;
+use actix_web::{post, web, HttpResponse, Responder, ResponseError};
+use mairie360_api_lib::pool::AppState;
+use mairie360_api_lib::security::AuthenticatedUser;
+
+use crate::database::sessions::revoke_user_sessions::{
+    revoke_user_sessions_query, RevokeUserSessionsQueryView,
+};
+use crate::endpoints::v1::sessions::revoke_access_tokens;
+
+#[derive(Debug, Clone, PartialEq)]
+enum RevokeAllError {
+    DatabaseError,
+}
+
+impl std::fmt::Display for RevokeAllError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RevokeAllError::DatabaseError => {
+                write!(f, "An error occurred while accessing the database.")
+            }
+        }
+    }
+}
+
+impl ResponseError for RevokeAllError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            RevokeAllError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "revoke_all",
+    responses(
+        (status = 200, description = "Every session of the user is revoked, including the current one"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Sessions",
+    security(
+        ("jwt" = [])
+    )
+)]
+#[post("/revoke_all")]
+pub async fn revoke_all(
+    user: AuthenticatedUser,
+    state: web::Data<AppState>,
+) -> Result<impl Responder, RevokeAllError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(RevokeAllError::DatabaseError),
+    };
+
+    let session_ids = revoke_user_sessions_query(RevokeUserSessionsQueryView::new(user.id), pool)
+        .await
+        .map_err(|e| {
+            eprintln!("Database Error: {}", e);
+            RevokeAllError::DatabaseError
+        })?;
+    revoke_access_tokens(&state, &session_ids).await;
+
+    Ok(HttpResponse::Ok().body("All sessions revoked successfully"))
+}
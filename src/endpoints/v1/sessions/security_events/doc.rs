@@ -0,0 +1,12 @@
+use crate::endpoints::v1::sessions::security_events::endpoint;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(endpoint::security_events),
+    components(schemas(
+        super::response_view::SecurityEventsResponseView,
+        super::response_view::SecurityEventSchema
+    ),)
+)]
+pub struct SecurityEventsDoc;
@@ -0,0 +1,72 @@
+use actix_web::http::StatusCode;
+use actix_web::{get, web, HttpResponse, Responder, ResponseError};
+use mairie360_api_lib::pool::AppState;
+use mairie360_api_lib::security::AuthenticatedUser;
+
+use crate::database::security_events::get_security_events_by_user::{
+    get_security_events_by_user_query, GetSecurityEventsByUserQueryView,
+};
+use crate::endpoints::v1::sessions::security_events::response_view::SecurityEventsResponseView;
+
+#[derive(Debug, Clone, PartialEq)]
+enum SecurityEventsError {
+    DatabaseError,
+}
+
+impl std::fmt::Display for SecurityEventsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SecurityEventsError::DatabaseError => {
+                write!(f, "An error occurred while accessing the database.")
+            }
+        }
+    }
+}
+
+impl ResponseError for SecurityEventsError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            SecurityEventsError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "security_events",
+    responses(
+        (status = 200, description = "Security events of the user, most recent first", body = SecurityEventsResponseView),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Sessions",
+    security(
+        ("jwt" = [])
+    )
+)]
+#[get("/security_events")]
+pub async fn security_events(
+    user: AuthenticatedUser,
+    state: web::Data<AppState>,
+) -> Result<impl Responder, SecurityEventsError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(SecurityEventsError::DatabaseError),
+    };
+
+    let events =
+        get_security_events_by_user_query(GetSecurityEventsByUserQueryView::new(user.id), pool)
+            .await
+            .map_err(|e| {
+                eprintln!("Database Error: {}", e);
+                SecurityEventsError::DatabaseError
+            })?;
+
+    Ok(HttpResponse::Ok().json(SecurityEventsResponseView::new(
+        events.into_iter().map(|e| e.into()).collect(),
+    )))
+}
@@ -0,0 +1,3 @@
+pub mod doc;
+pub mod endpoint;
+pub mod response_view;
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use utoipa::ToSchema;
+
+use crate::database::security_events::SecurityEvent;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct SecurityEventSchema {
+    event_type: String,
+    ip_address: Option<String>,
+    details: Option<String>,
+    created_at: String,
+}
+
+impl From<SecurityEvent> for SecurityEventSchema {
+    fn from(event: SecurityEvent) -> Self {
+        SecurityEventSchema {
+            event_type: event.event_type().to_string(),
+            ip_address: event.ip_address().map(|ip| ip.to_string()),
+            details: event.details().map(str::to_string),
+            created_at: event.created_at().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct SecurityEventsResponseView {
+    events: Vec<SecurityEventSchema>,
+}
+
+impl SecurityEventsResponseView {
+    pub fn new(events: Vec<SecurityEventSchema>) -> Self {
+        SecurityEventsResponseView { events }
+    }
+}
+
+impl Display for SecurityEventsResponseView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SecurityEventsResponseView {{ events: {:?} }}",
+            self.events
+        )
+    }
+}
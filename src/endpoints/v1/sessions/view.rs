@@ -7,6 +7,10 @@ use crate::database::sessions::Session;
 pub struct SessionSchema {
     id: String,
     device_info: String,
+    browser: Option<String>,
+    os: Option<String>,
+    device_type: Option<String>,
+    app_version: Option<String>,
     ip_address: String,
     created_at: String,
     expires_at: String,
@@ -18,6 +22,10 @@ impl From<Session> for SessionSchema {
         SessionSchema {
             id: session.id().to_string(),
             device_info: session.device_info().to_string(),
+            browser: session.browser().map(str::to_string),
+            os: session.os().map(str::to_string),
+            device_type: session.device_type().map(str::to_string),
+            app_version: session.app_version().map(str::to_string),
             ip_address: session.ip_address().to_string(),
             created_at: session.created_at().to_string(),
             expires_at: session.expires_at().to_string(),
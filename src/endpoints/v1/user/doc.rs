@@ -1,9 +1,10 @@
-use crate::endpoints::v1::user::{id::doc::IdDoc, me::doc::MeDoc};
+use crate::endpoints::v1::user::{id::doc::IdDoc, me::doc::MeDoc, me::passkeys::doc::PasskeysDoc};
 use utoipa::OpenApi;
 
 #[derive(OpenApi)]
 #[openapi(nest(
     (path = "/me", api = MeDoc, tags = ["Users"]),
+    (path = "/me/passkeys", api = PasskeysDoc, tags = ["Users"]),
     (path = "/{id}", api = IdDoc, tags = ["Users"]),
 ))]
 pub struct UserDoc;
@@ -1,10 +1,37 @@
+use crate::endpoints::v1::user::me::federated_identity::endpoint::{
+    __path_link_my_federated_identity, __path_unlink_my_federated_identity,
+};
 use crate::endpoints::v1::user::me::get::endpoint::__path_get_me;
+use crate::endpoints::v1::user::me::password::endpoint::__path_change_my_password;
 use crate::endpoints::v1::user::me::patch::endpoint::__path_patch_me;
+use crate::endpoints::v1::user::me::tokens::endpoint::{
+    __path_create_my_token, __path_get_my_tokens, __path_revoke_my_token,
+};
+use crate::security::personal_access_token::PersonalAccessTokenScope;
 use utoipa::OpenApi;
 
 #[derive(OpenApi)]
 #[openapi(
-    paths(get_me, patch_me),
-    components(schemas(super::get::view::GetMeResponseView, super::patch::view::PatchMeView))
+    paths(
+        get_me,
+        patch_me,
+        change_my_password,
+        get_my_tokens,
+        create_my_token,
+        revoke_my_token,
+        link_my_federated_identity,
+        unlink_my_federated_identity
+    ),
+    components(schemas(
+        super::federated_identity::view::LinkFederatedIdentityResponseView,
+        super::get::view::GetMeResponseView,
+        super::patch::view::PatchMeView,
+        super::password::view::ChangePasswordView,
+        super::tokens::view::CreateTokenView,
+        super::tokens::view::CreatedTokenResponseView,
+        super::tokens::view::TokenSchema,
+        super::tokens::view::TokensResponseView,
+        PersonalAccessTokenScope
+    ))
 )]
 pub struct MeDoc;
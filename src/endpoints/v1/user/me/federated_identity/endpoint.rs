@@ -0,0 +1,199 @@
+use actix_web::dev::ConnectionInfo;
+use actix_web::http::StatusCode;
+use actix_web::{
+    delete, post, web, HttpMessage, HttpRequest, HttpResponse, Responder, ResponseError,
+};
+use mairie360_api_lib::pool::AppState;
+use mairie360_api_lib::security::AuthenticatedUser;
+
+use crate::database::federated_identities::delete_federated_identity::{
+    delete_federated_identity_query, DeleteFederatedIdentityQueryView,
+};
+use crate::database::security_events::create_security_event::{
+    create_security_event_query, CreateSecurityEventQueryView,
+};
+use crate::endpoints::v1::auth::federated::{
+    start_federation, FederationStartError, FEDERATED_IDENTITY_UNLINKED_EVENT,
+};
+use crate::endpoints::v1::user::me::federated_identity::view::LinkFederatedIdentityResponseView;
+use crate::security::federation::{FederationConfig, FederationError};
+use crate::security::impersonation::Impersonation;
+use crate::security::personal_access_token::PersonalAccessToken;
+
+#[derive(Debug, Clone, PartialEq)]
+enum FederatedIdentityError {
+    DatabaseError,
+    Impersonated,
+    NotConfigured,
+    NotFound,
+    ProviderError,
+    RedisError,
+    SessionRequired,
+}
+
+impl From<FederationStartError> for FederatedIdentityError {
+    fn from(error: FederationStartError) -> Self {
+        match error {
+            FederationStartError::Federation(FederationError::Configuration(e)) => {
+                eprintln!("Federation Error: {}", e);
+                FederatedIdentityError::NotConfigured
+            }
+            FederationStartError::Federation(e) => {
+                eprintln!("Federation Error: {}", e);
+                FederatedIdentityError::ProviderError
+            }
+            FederationStartError::RedisError => FederatedIdentityError::RedisError,
+        }
+    }
+}
+
+impl std::fmt::Display for FederatedIdentityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FederatedIdentityError::DatabaseError => {
+                write!(f, "An error occurred while accessing the database.")
+            }
+            FederatedIdentityError::Impersonated => {
+                write!(f, "Not allowed while an administrator acts as the user.")
+            }
+            FederatedIdentityError::NotConfigured => {
+                write!(f, "Login through an identity provider is not configured.")
+            }
+            FederatedIdentityError::NotFound => write!(f, "No external account is linked."),
+            FederatedIdentityError::ProviderError => {
+                write!(f, "The identity provider could not be reached.")
+            }
+            FederatedIdentityError::RedisError => write!(f, "Internal Redis error."),
+            FederatedIdentityError::SessionRequired => {
+                write!(
+                    f,
+                    "Access tokens cannot manage linked accounts, log in instead."
+                )
+            }
+        }
+    }
+}
+
+impl ResponseError for FederatedIdentityError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            FederatedIdentityError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            FederatedIdentityError::Impersonated => StatusCode::FORBIDDEN,
+            FederatedIdentityError::NotConfigured => StatusCode::SERVICE_UNAVAILABLE,
+            FederatedIdentityError::NotFound => StatusCode::NOT_FOUND,
+            FederatedIdentityError::ProviderError => StatusCode::BAD_GATEWAY,
+            FederatedIdentityError::RedisError => StatusCode::INTERNAL_SERVER_ERROR,
+            FederatedIdentityError::SessionRequired => StatusCode::FORBIDDEN,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+fn require_session(
+    req: &HttpRequest,
+    impersonation: &Option<Impersonation>,
+) -> Result<(), FederatedIdentityError> {
+    if req.extensions().get::<PersonalAccessToken>().is_some() {
+        return Err(FederatedIdentityError::SessionRequired);
+    }
+    if impersonation.is_some() {
+        return Err(FederatedIdentityError::Impersonated);
+    }
+    Ok(())
+}
+
+#[utoipa::path(
+    post,
+    path = "/federated_identity",
+    responses(
+        (status = 200, description = "Identity provider URL to open; its callback page must post to /auth/federated/callback with this session's token", body = LinkFederatedIdentityResponseView),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Called with an access token instead of a session, or by an administrator acting as the user"),
+        (status = 500, description = "Internal server error"),
+        (status = 502, description = "The identity provider could not be reached"),
+        (status = 503, description = "No identity provider is configured")
+    ),
+    tag = "Users",
+    security(
+        ("jwt" = [])
+    )
+)]
+#[post("/federated_identity")]
+pub async fn link_my_federated_identity(
+    state: web::Data<AppState>,
+    auth_user: AuthenticatedUser,
+    req: HttpRequest,
+    impersonation: Option<Impersonation>,
+) -> Result<impl Responder, FederatedIdentityError> {
+    require_session(&req, &impersonation)?;
+    let url = start_federation(&state, Some(auth_user.id)).await?;
+
+    Ok(HttpResponse::Ok()
+        .insert_header(("Cache-Control", "no-store"))
+        .json(LinkFederatedIdentityResponseView::new(url)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/federated_identity",
+    responses(
+        (status = 204, description = "External account unlinked; it can no longer be used to log in"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Called with an access token instead of a session, or by an administrator acting as the user"),
+        (status = 404, description = "No external account is linked"),
+        (status = 500, description = "Internal server error"),
+        (status = 503, description = "No identity provider is configured")
+    ),
+    tag = "Users",
+    security(
+        ("jwt" = [])
+    )
+)]
+#[delete("/federated_identity")]
+pub async fn unlink_my_federated_identity(
+    state: web::Data<AppState>,
+    auth_user: AuthenticatedUser,
+    req: HttpRequest,
+    impersonation: Option<Impersonation>,
+    conn: ConnectionInfo,
+) -> Result<impl Responder, FederatedIdentityError> {
+    require_session(&req, &impersonation)?;
+    let config = FederationConfig::from_env().map_err(|e| {
+        eprintln!("Federation Error: {}", e);
+        FederatedIdentityError::NotConfigured
+    })?;
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(FederatedIdentityError::DatabaseError),
+    };
+
+    let deleted = delete_federated_identity_query(
+        DeleteFederatedIdentityQueryView::new(auth_user.id, config.issuer()),
+        pool.clone(),
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("Database Error: {}", e);
+        FederatedIdentityError::DatabaseError
+    })?;
+    if !deleted {
+        return Err(FederatedIdentityError::NotFound);
+    }
+
+    let ip_address = conn
+        .realip_remote_addr()
+        .and_then(|ip| ip.parse::<std::net::IpAddr>().ok());
+    let event = CreateSecurityEventQueryView::new(
+        Some(auth_user.id),
+        FEDERATED_IDENTITY_UNLINKED_EVENT,
+        ip_address,
+        Some(format!("issuer = {}", config.issuer())),
+    );
+    if let Err(e) = create_security_event_query(event, pool).await {
+        eprintln!("Security Event DB Error: {}", e);
+    }
+    Ok(HttpResponse::NoContent())
+}
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct LinkFederatedIdentityResponseView {
+    authorization_url: String,
+}
+
+impl LinkFederatedIdentityResponseView {
+    pub fn new(authorization_url: String) -> Self {
+        Self { authorization_url }
+    }
+
+    pub fn authorization_url(&self) -> &str {
+        &self.authorization_url
+    }
+}
+
+impl Display for LinkFederatedIdentityResponseView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "LinkFederatedIdentityResponseView {{ authorization_url: {} }}",
+            self.authorization_url
+        )
+    }
+}
@@ -76,7 +76,8 @@ async fn trigger_get_me(
         result.status(),
         role[0].name(),
         groups,
-    ))
+    )
+    .with_new_sign_in_alerts(result.new_sign_in_alerts()))
 }
 
 #[utoipa::path(
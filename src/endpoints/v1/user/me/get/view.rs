@@ -14,6 +14,7 @@ pub struct GetMeResponseView {
     status: String,
     role: String,
     groups: Vec<Group>,
+    new_sign_in_alerts: bool,
 }
 
 impl GetMeResponseView {
@@ -34,9 +35,15 @@ impl GetMeResponseView {
             status: status.to_string(),
             role: role.to_string(),
             groups,
+            new_sign_in_alerts: true,
         }
     }
 
+    pub fn with_new_sign_in_alerts(mut self, new_sign_in_alerts: bool) -> Self {
+        self.new_sign_in_alerts = new_sign_in_alerts;
+        self
+    }
+
     pub fn first_name(&self) -> &str {
         &self.first_name
     }
@@ -64,13 +71,17 @@ impl GetMeResponseView {
     pub fn groups(&self) -> &[Group] {
         &self.groups
     }
+
+    pub fn new_sign_in_alerts(&self) -> bool {
+        self.new_sign_in_alerts
+    }
 }
 
 impl Display for GetMeResponseView {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "GetMeResponseView {{ first_name: {}, last_name: {}, email: {}, phone: {:?}, status: {}, role: {}, groups: {} }}",
+            "GetMeResponseView {{ first_name: {}, last_name: {}, email: {}, phone: {:?}, status: {}, role: {}, groups: {}, new_sign_in_alerts: {} }}",
             self.first_name,
             self.last_name,
             self.email,
@@ -78,6 +89,7 @@ impl Display for GetMeResponseView {
             self.status,
             self.role,
             self.groups.len(),
+            self.new_sign_in_alerts,
         )
     }
 }
@@ -92,6 +104,7 @@ impl From<GetUserByIdQueryResultView> for GetMeResponseView {
             status: query_result.status().to_string(),
             role: "".to_string(),
             groups: Vec::new(),
+            new_sign_in_alerts: query_result.new_sign_in_alerts(),
         }
     }
 }
@@ -1,13 +1,24 @@
 use actix_web::web;
 
 pub mod doc;
+pub mod federated_identity;
 pub mod get;
+pub mod passkeys;
+pub mod password;
 pub mod patch;
+pub mod tokens;
 
 pub fn config(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/me")
+            .configure(passkeys::config)
+            .service(federated_identity::endpoint::link_my_federated_identity)
+            .service(federated_identity::endpoint::unlink_my_federated_identity)
             .service(get::endpoint::get_me)
-            .service(patch::endpoint::patch_me),
+            .service(password::endpoint::change_my_password)
+            .service(patch::endpoint::patch_me)
+            .service(tokens::endpoint::get_my_tokens)
+            .service(tokens::endpoint::create_my_token)
+            .service(tokens::endpoint::revoke_my_token),
     );
 }
@@ -0,0 +1,96 @@
+use actix_web::http::StatusCode;
+use actix_web::{delete, web, HttpResponse, Responder, ResponseError};
+use mairie360_api_lib::pool::AppState;
+use mairie360_api_lib::security::AuthenticatedUser;
+
+use crate::database::webauthn::delete_credential::{
+    delete_credential_query, DeleteCredentialQueryView,
+};
+use crate::security::impersonation::Impersonation;
+
+#[derive(Debug, Clone, PartialEq)]
+enum DeletePasskeyError {
+    DatabaseError,
+    Impersonated,
+    NotFound,
+}
+
+impl std::fmt::Display for DeletePasskeyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeletePasskeyError::DatabaseError => {
+                write!(f, "An error occurred while accessing the database.")
+            }
+            DeletePasskeyError::Impersonated => {
+                write!(f, "Not allowed while an administrator acts as the user.")
+            }
+            DeletePasskeyError::NotFound => write!(f, "Passkey not found."),
+        }
+    }
+}
+
+impl ResponseError for DeletePasskeyError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            DeletePasskeyError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            DeletePasskeyError::Impersonated => StatusCode::FORBIDDEN,
+            DeletePasskeyError::NotFound => StatusCode::NOT_FOUND,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+async fn trigger_delete_passkey(
+    state: web::Data<AppState>,
+    user_id: u64,
+    passkey_id: u64,
+) -> Result<(), DeletePasskeyError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(DeletePasskeyError::DatabaseError),
+    };
+
+    let view = DeleteCredentialQueryView::new(passkey_id, user_id);
+    let deleted = delete_credential_query(view, pool).await.map_err(|e| {
+        eprintln!("Error: {}", e);
+        DeletePasskeyError::DatabaseError
+    })?;
+    if !deleted {
+        return Err(DeletePasskeyError::NotFound);
+    }
+    Ok(())
+}
+
+#[utoipa::path(
+    delete,
+    path = "/{passkeyId}",
+    params(
+        ("passkeyId" = u64, Path, description = "ID de la passkey")
+    ),
+    responses(
+        (status = 204, description = "Passkey deleted successfully"),
+        (status = 403, description = "Not allowed while an administrator acts as the user."),
+        (status = 404, description = "Passkey not found."),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Users",
+    security(
+        ("jwt" = [])
+    )
+)]
+#[delete("/{passkeyId}")]
+pub async fn delete_passkey(
+    state: web::Data<AppState>,
+    path: web::Path<u64>,
+    auth_user: AuthenticatedUser,
+    impersonation: Option<Impersonation>,
+) -> Result<impl Responder, DeletePasskeyError> {
+    if impersonation.is_some() {
+        return Err(DeletePasskeyError::Impersonated);
+    }
+    trigger_delete_passkey(state, auth_user.id, path.into_inner()).await?;
+    Ok(HttpResponse::NoContent())
+}
@@ -0,0 +1,27 @@
+use crate::endpoints::v1::user::me::passkeys::delete::endpoint::__path_delete_passkey;
+use crate::endpoints::v1::user::me::passkeys::get::endpoint::__path_get_passkeys;
+use crate::endpoints::v1::user::me::passkeys::patch::endpoint::__path_rename_passkey;
+use crate::endpoints::v1::user::me::passkeys::register_finish::endpoint::__path_register_passkey_finish;
+use crate::endpoints::v1::user::me::passkeys::register_start::endpoint::__path_register_passkey_start;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_passkeys,
+        register_passkey_start,
+        register_passkey_finish,
+        rename_passkey,
+        delete_passkey
+    ),
+    components(schemas(
+        super::view::PasskeyView,
+        super::get::view::GetPasskeysResponseView,
+        super::patch::view::RenamePasskeyView,
+        super::register_start::view::RegisterPasskeyStartResponseView,
+        super::register_finish::view::RegisterPasskeyFinishView,
+        crate::endpoints::v1::auth::webauthn::view::RegistrationCredentialView,
+        crate::endpoints::v1::auth::webauthn::view::AuthenticatorAttestationResponseView
+    ))
+)]
+pub struct PasskeysDoc;
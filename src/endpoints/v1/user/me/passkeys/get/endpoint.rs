@@ -0,0 +1,78 @@
+use actix_web::http::StatusCode;
+use actix_web::{get, web, HttpResponse, Responder, ResponseError};
+use mairie360_api_lib::pool::AppState;
+use mairie360_api_lib::security::AuthenticatedUser;
+
+use crate::database::webauthn::get_user_credentials::{
+    get_user_credentials_query, GetUserCredentialsQueryView,
+};
+use crate::endpoints::v1::user::me::passkeys::get::view::GetPasskeysResponseView;
+
+#[derive(Debug, Clone, PartialEq)]
+enum GetPasskeysError {
+    DatabaseError,
+}
+
+impl std::fmt::Display for GetPasskeysError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GetPasskeysError::DatabaseError => {
+                write!(f, "An error occurred while accessing the database.")
+            }
+        }
+    }
+}
+
+impl ResponseError for GetPasskeysError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            GetPasskeysError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+async fn trigger_get_passkeys(
+    state: web::Data<AppState>,
+    user_id: u64,
+) -> Result<GetPasskeysResponseView, GetPasskeysError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(GetPasskeysError::DatabaseError),
+    };
+
+    let credentials = get_user_credentials_query(GetUserCredentialsQueryView::new(user_id), pool)
+        .await
+        .map_err(|e| {
+            eprintln!("Error: {}", e);
+            GetPasskeysError::DatabaseError
+        })?;
+
+    Ok(GetPasskeysResponseView::new(
+        credentials.into_iter().map(|c| c.into()).collect(),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "",
+    responses(
+        (status = 200, description = "Passkeys retrieved successfully", body = GetPasskeysResponseView),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Users",
+    security(
+        ("jwt" = [])
+    )
+)]
+#[get("/")]
+pub async fn get_passkeys(
+    state: web::Data<AppState>,
+    auth_user: AuthenticatedUser,
+) -> Result<impl Responder, GetPasskeysError> {
+    let response = trigger_get_passkeys(state, auth_user.id).await?;
+    Ok(HttpResponse::Ok().json(response))
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::endpoints::v1::user::me::passkeys::view::PasskeyView;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct GetPasskeysResponseView {
+    passkeys: Vec<PasskeyView>,
+}
+
+impl GetPasskeysResponseView {
+    pub fn new(passkeys: Vec<PasskeyView>) -> Self {
+        Self { passkeys }
+    }
+}
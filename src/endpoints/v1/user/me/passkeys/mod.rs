@@ -0,0 +1,20 @@
+use actix_web::web;
+
+pub mod delete;
+pub mod doc;
+pub mod get;
+pub mod patch;
+pub mod register_finish;
+pub mod register_start;
+pub mod view;
+
+pub fn config(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/passkeys")
+            .service(get::endpoint::get_passkeys)
+            .service(register_finish::endpoint::register_passkey_finish)
+            .service(register_start::endpoint::register_passkey_start)
+            .service(patch::endpoint::rename_passkey)
+            .service(delete::endpoint::delete_passkey),
+    );
+}
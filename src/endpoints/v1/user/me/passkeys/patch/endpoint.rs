@@ -0,0 +1,108 @@
+use actix_web::http::StatusCode;
+use actix_web::{patch, web, HttpResponse, Responder, ResponseError};
+use mairie360_api_lib::pool::AppState;
+use mairie360_api_lib::security::AuthenticatedUser;
+
+use crate::database::webauthn::rename_credential::{
+    rename_credential_query, RenameCredentialQueryView,
+};
+use crate::endpoints::v1::user::me::passkeys::patch::view::RenamePasskeyView;
+use crate::endpoints::v1::user::me::passkeys::view::is_valid_passkey_name;
+use crate::security::impersonation::Impersonation;
+
+#[derive(Debug, Clone, PartialEq)]
+enum RenamePasskeyError {
+    DatabaseError,
+    Impersonated,
+    InvalidName,
+    NotFound,
+}
+
+impl std::fmt::Display for RenamePasskeyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RenamePasskeyError::DatabaseError => {
+                write!(f, "An error occurred while accessing the database.")
+            }
+            RenamePasskeyError::Impersonated => {
+                write!(f, "Not allowed while an administrator acts as the user.")
+            }
+            RenamePasskeyError::InvalidName => write!(f, "Invalid passkey name."),
+            RenamePasskeyError::NotFound => write!(f, "Passkey not found."),
+        }
+    }
+}
+
+impl ResponseError for RenamePasskeyError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            RenamePasskeyError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            RenamePasskeyError::Impersonated => StatusCode::FORBIDDEN,
+            RenamePasskeyError::InvalidName => StatusCode::BAD_REQUEST,
+            RenamePasskeyError::NotFound => StatusCode::NOT_FOUND,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+async fn trigger_rename_passkey(
+    state: web::Data<AppState>,
+    user_id: u64,
+    passkey_id: u64,
+    name: &str,
+) -> Result<(), RenamePasskeyError> {
+    if !is_valid_passkey_name(name) {
+        return Err(RenamePasskeyError::InvalidName);
+    }
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(RenamePasskeyError::DatabaseError),
+    };
+
+    let view = RenameCredentialQueryView::new(passkey_id, user_id, name.trim());
+    let renamed = rename_credential_query(view, pool).await.map_err(|e| {
+        eprintln!("Error: {}", e);
+        RenamePasskeyError::DatabaseError
+    })?;
+    if !renamed {
+        return Err(RenamePasskeyError::NotFound);
+    }
+    Ok(())
+}
+
+#[utoipa::path(
+    patch,
+    path = "/{passkeyId}",
+    params(
+        ("passkeyId" = u64, Path, description = "ID de la passkey")
+    ),
+    request_body = RenamePasskeyView,
+    responses(
+        (status = 200, description = "Passkey renamed successfully"),
+        (status = 400, description = "Invalid passkey name."),
+        (status = 403, description = "Not allowed while an administrator acts as the user."),
+        (status = 404, description = "Passkey not found."),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Users",
+    security(
+        ("jwt" = [])
+    )
+)]
+#[patch("/{passkeyId}")]
+pub async fn rename_passkey(
+    state: web::Data<AppState>,
+    path: web::Path<u64>,
+    view: web::Json<RenamePasskeyView>,
+    auth_user: AuthenticatedUser,
+    impersonation: Option<Impersonation>,
+) -> Result<impl Responder, RenamePasskeyError> {
+    if impersonation.is_some() {
+        return Err(RenamePasskeyError::Impersonated);
+    }
+    trigger_rename_passkey(state, auth_user.id, path.into_inner(), view.name()).await?;
+    Ok(HttpResponse::Ok())
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RenamePasskeyView {
+    name: String,
+}
+
+impl RenamePasskeyView {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Display for RenamePasskeyView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RenamePasskeyView {{ name: {} }}", self.name)
+    }
+}
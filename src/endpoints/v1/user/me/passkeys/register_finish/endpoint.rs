@@ -0,0 +1,171 @@
+use actix_web::http::StatusCode;
+use actix_web::{post, web, HttpResponse, Responder, ResponseError};
+use mairie360_api_lib::database::errors::DatabaseError;
+use mairie360_api_lib::database::queries::QueryError;
+use mairie360_api_lib::pool::AppState;
+use mairie360_api_lib::security::AuthenticatedUser;
+
+use crate::database::webauthn::create_credential::{
+    create_credential_query, CreateCredentialQueryView,
+};
+use crate::endpoints::v1::auth::webauthn::{decode_base64url, encode_base64url};
+use crate::endpoints::v1::user::me::passkeys::register_finish::view::RegisterPasskeyFinishView;
+use crate::endpoints::v1::user::me::passkeys::view::is_valid_passkey_name;
+use crate::redis::handle_get_and_delete;
+use crate::security::impersonation::Impersonation;
+use crate::security::webauthn::{verify_registration, WebauthnConfig};
+
+#[derive(Debug, Clone, PartialEq)]
+enum RegisterPasskeyFinishError {
+    AlreadyRegistered,
+    BadRequest,
+    DatabaseError,
+    Impersonated,
+    InvalidChallenge,
+    InvalidCredential,
+    InvalidName,
+    RedisError,
+}
+
+impl std::fmt::Display for RegisterPasskeyFinishError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RegisterPasskeyFinishError::AlreadyRegistered => {
+                write!(f, "Passkey is already registered.")
+            }
+            RegisterPasskeyFinishError::BadRequest => write!(f, "Malformed credential."),
+            RegisterPasskeyFinishError::DatabaseError => {
+                write!(f, "An error occurred while accessing the database.")
+            }
+            RegisterPasskeyFinishError::Impersonated => {
+                write!(f, "Not allowed while an administrator acts as the user.")
+            }
+            RegisterPasskeyFinishError::InvalidChallenge => {
+                write!(f, "Invalid or expired WebAuthn challenge.")
+            }
+            RegisterPasskeyFinishError::InvalidCredential => {
+                write!(f, "Passkey registration rejected.")
+            }
+            RegisterPasskeyFinishError::InvalidName => write!(f, "Invalid passkey name."),
+            RegisterPasskeyFinishError::RedisError => write!(f, "Internal Redis error."),
+        }
+    }
+}
+
+impl ResponseError for RegisterPasskeyFinishError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            RegisterPasskeyFinishError::AlreadyRegistered => StatusCode::CONFLICT,
+            RegisterPasskeyFinishError::BadRequest => StatusCode::BAD_REQUEST,
+            RegisterPasskeyFinishError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            RegisterPasskeyFinishError::Impersonated => StatusCode::FORBIDDEN,
+            RegisterPasskeyFinishError::InvalidChallenge => StatusCode::BAD_REQUEST,
+            RegisterPasskeyFinishError::InvalidCredential => StatusCode::BAD_REQUEST,
+            RegisterPasskeyFinishError::InvalidName => StatusCode::BAD_REQUEST,
+            RegisterPasskeyFinishError::RedisError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+async fn trigger_register_finish(
+    state: web::Data<AppState>,
+    user_id: u64,
+    view: &RegisterPasskeyFinishView,
+) -> Result<(), RegisterPasskeyFinishError> {
+    if !is_valid_passkey_name(view.name()) {
+        return Err(RegisterPasskeyFinishError::InvalidName);
+    }
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(RegisterPasskeyFinishError::DatabaseError),
+    };
+
+    let response = view.credential().response();
+    let client_data = decode_base64url(response.client_data_json())
+        .ok_or(RegisterPasskeyFinishError::BadRequest)?;
+    let attestation_object = decode_base64url(response.attestation_object())
+        .ok_or(RegisterPasskeyFinishError::BadRequest)?;
+
+    let conn = state
+        .get_redis_conn()
+        .await
+        .ok_or(RegisterPasskeyFinishError::RedisError)?;
+    let challenge = handle_get_and_delete(conn, &format!("{}/webauthn_registration", user_id))
+        .await
+        .map_err(|e| {
+            eprintln!("Redis Error: {}", e);
+            RegisterPasskeyFinishError::RedisError
+        })?
+        .ok_or(RegisterPasskeyFinishError::InvalidChallenge)?;
+
+    let credential = verify_registration(
+        &WebauthnConfig::from_env(),
+        &challenge,
+        &client_data,
+        &attestation_object,
+    )
+    .map_err(|e| {
+        eprintln!("WebAuthn registration rejected: {}", e);
+        RegisterPasskeyFinishError::InvalidCredential
+    })?;
+
+    let credential_id = encode_base64url(credential.credential_id());
+    if credential_id != view.credential().id().trim_end_matches('=') {
+        return Err(RegisterPasskeyFinishError::InvalidCredential);
+    }
+
+    let db_view = CreateCredentialQueryView::new(
+        user_id,
+        &credential_id,
+        credential.public_key(),
+        credential.algorithm(),
+        credential.sign_count(),
+        view.name().trim(),
+    );
+    create_credential_query(db_view, pool)
+        .await
+        .map_err(|e| match e {
+            DatabaseError::Query(QueryError::ConstraintViolation(_)) => {
+                RegisterPasskeyFinishError::AlreadyRegistered
+            }
+            _ => {
+                eprintln!("Error: {}", e);
+                RegisterPasskeyFinishError::DatabaseError
+            }
+        })?;
+    Ok(())
+}
+
+#[utoipa::path(
+    post,
+    path = "/register_finish",
+    request_body = RegisterPasskeyFinishView,
+    responses(
+        (status = 201, description = "Passkey registered successfully"),
+        (status = 400, description = "Invalid name, challenge or credential"),
+        (status = 403, description = "Not allowed while an administrator acts as the user."),
+        (status = 409, description = "Passkey is already registered."),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Users",
+    security(
+        ("jwt" = [])
+    )
+)]
+#[post("/register_finish")]
+pub async fn register_passkey_finish(
+    state: web::Data<AppState>,
+    payload: web::Json<RegisterPasskeyFinishView>,
+    auth_user: AuthenticatedUser,
+    impersonation: Option<Impersonation>,
+) -> Result<impl Responder, RegisterPasskeyFinishError> {
+    if impersonation.is_some() {
+        return Err(RegisterPasskeyFinishError::Impersonated);
+    }
+    trigger_register_finish(state, auth_user.id, &payload.into_inner()).await?;
+    Ok(HttpResponse::Created())
+}
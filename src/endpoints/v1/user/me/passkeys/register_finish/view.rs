@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use utoipa::ToSchema;
+
+use crate::endpoints::v1::auth::webauthn::view::RegistrationCredentialView;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RegisterPasskeyFinishView {
+    name: String,
+    credential: RegistrationCredentialView,
+}
+
+impl RegisterPasskeyFinishView {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn credential(&self) -> &RegistrationCredentialView {
+        &self.credential
+    }
+}
+
+impl Display for RegisterPasskeyFinishView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "RegisterPasskeyFinishView {{ name: {}, credential: {} }}",
+            self.name,
+            self.credential.id()
+        )
+    }
+}
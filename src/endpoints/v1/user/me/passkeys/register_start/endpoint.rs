@@ -0,0 +1,134 @@
+use actix_web::http::StatusCode;
+use actix_web::{post, web, HttpResponse, Responder, ResponseError};
+use mairie360_api_lib::pool::AppState;
+use mairie360_api_lib::security::AuthenticatedUser;
+
+use crate::database::users::get_user_by_id::{get_user_by_id_query, GetUserByIdQueryView};
+use crate::database::webauthn::get_user_credentials::{
+    get_user_credentials_query, GetUserCredentialsQueryView,
+};
+use crate::endpoints::v1::auth::webauthn::view::CredentialDescriptorView;
+use crate::endpoints::v1::auth::webauthn::{encode_base64url, webauthn_challenge_ttl};
+use crate::endpoints::v1::user::me::passkeys::register_start::view::RegisterPasskeyStartResponseView;
+use crate::redis::handle_expiring_post;
+use crate::security::impersonation::Impersonation;
+use crate::security::token::generate_token;
+use crate::security::webauthn::WebauthnConfig;
+
+#[derive(Debug, Clone, PartialEq)]
+enum RegisterPasskeyStartError {
+    DatabaseError,
+    Impersonated,
+    RedisError,
+}
+
+impl std::fmt::Display for RegisterPasskeyStartError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RegisterPasskeyStartError::DatabaseError => {
+                write!(f, "An error occurred while accessing the database.")
+            }
+            RegisterPasskeyStartError::Impersonated => {
+                write!(f, "Not allowed while an administrator acts as the user.")
+            }
+            RegisterPasskeyStartError::RedisError => write!(f, "Internal Redis error."),
+        }
+    }
+}
+
+impl ResponseError for RegisterPasskeyStartError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            RegisterPasskeyStartError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            RegisterPasskeyStartError::Impersonated => StatusCode::FORBIDDEN,
+            RegisterPasskeyStartError::RedisError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+async fn trigger_register_start(
+    state: web::Data<AppState>,
+    user_id: u64,
+) -> Result<RegisterPasskeyStartResponseView, RegisterPasskeyStartError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(RegisterPasskeyStartError::DatabaseError),
+    };
+
+    let user = get_user_by_id_query(GetUserByIdQueryView::new(user_id), pool.clone())
+        .await
+        .map_err(|e| {
+            eprintln!("Error: {}", e);
+            RegisterPasskeyStartError::DatabaseError
+        })?;
+    // Empêche d'enregistrer deux fois le même authentificateur
+    let existing = get_user_credentials_query(GetUserCredentialsQueryView::new(user_id), pool)
+        .await
+        .map_err(|e| {
+            eprintln!("Error: {}", e);
+            RegisterPasskeyStartError::DatabaseError
+        })?;
+
+    let challenge = generate_token();
+    let ttl = webauthn_challenge_ttl();
+    let conn = state
+        .get_redis_conn()
+        .await
+        .ok_or(RegisterPasskeyStartError::RedisError)?;
+    handle_expiring_post(
+        conn,
+        &format!("{}/webauthn_registration", user_id),
+        &challenge,
+        ttl,
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("Redis Error: {}", e);
+        RegisterPasskeyStartError::RedisError
+    })?;
+
+    let config = WebauthnConfig::from_env();
+    Ok(RegisterPasskeyStartResponseView::new(
+        challenge,
+        config.rp_id(),
+        config.rp_name(),
+        encode_base64url(user_id.to_string().as_bytes()),
+        user.email(),
+        format!("{} {}", user.first_name(), user.last_name()),
+        ttl * 1000,
+        existing
+            .iter()
+            .map(|c| CredentialDescriptorView::new(c.credential_id()))
+            .collect(),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/register_start",
+    responses(
+        (status = 200, description = "Registration options for navigator.credentials.create()", body = RegisterPasskeyStartResponseView),
+        (status = 403, description = "Not allowed while an administrator acts as the user."),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Users",
+    security(
+        ("jwt" = [])
+    )
+)]
+#[post("/register_start")]
+pub async fn register_passkey_start(
+    state: web::Data<AppState>,
+    auth_user: AuthenticatedUser,
+    impersonation: Option<Impersonation>,
+) -> Result<impl Responder, RegisterPasskeyStartError> {
+    if impersonation.is_some() {
+        return Err(RegisterPasskeyStartError::Impersonated);
+    }
+    let response = trigger_register_start(state, auth_user.id).await?;
+    Ok(HttpResponse::Ok().json(response))
+}
@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::endpoints::v1::auth::webauthn::view::CredentialDescriptorView;
+use crate::security::webauthn::{COSE_ALG_EDDSA, COSE_ALG_ES256};
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct RelyingPartyView {
+    id: String,
+    name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PasskeyUserView {
+    id: String,
+    name: String,
+    display_name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PubKeyCredParamView {
+    #[serde(rename = "type")]
+    credential_type: String,
+    alg: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthenticatorSelectionView {
+    resident_key: String,
+    user_verification: String,
+}
+
+/**
+ * `PublicKeyCredentialCreationOptionsJSON`, to pass to `PublicKeyCredential.parseCreationOptionsFromJSON()`.
+ */
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RegisterPasskeyStartResponseView {
+    challenge: String,
+    rp: RelyingPartyView,
+    user: PasskeyUserView,
+    pub_key_cred_params: Vec<PubKeyCredParamView>,
+    timeout: u64,
+    attestation: String,
+    authenticator_selection: AuthenticatorSelectionView,
+    exclude_credentials: Vec<CredentialDescriptorView>,
+}
+
+impl RegisterPasskeyStartResponseView {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        challenge: String,
+        rp_id: &str,
+        rp_name: &str,
+        user_handle: String,
+        email: &str,
+        display_name: String,
+        timeout: u64,
+        exclude_credentials: Vec<CredentialDescriptorView>,
+    ) -> Self {
+        Self {
+            challenge,
+            rp: RelyingPartyView {
+                id: rp_id.to_string(),
+                name: rp_name.to_string(),
+            },
+            user: PasskeyUserView {
+                id: user_handle,
+                name: email.to_string(),
+                display_name,
+            },
+            pub_key_cred_params: [COSE_ALG_ES256, COSE_ALG_EDDSA]
+                .into_iter()
+                .map(|alg| PubKeyCredParamView {
+                    credential_type: "public-key".to_string(),
+                    alg,
+                })
+                .collect(),
+            timeout,
+            attestation: "none".to_string(),
+            authenticator_selection: AuthenticatorSelectionView {
+                resident_key: "required".to_string(),
+                user_verification: "required".to_string(),
+            },
+            exclude_credentials,
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::database::webauthn::WebauthnCredential;
+
+pub const PASSKEY_NAME_MAX_LENGTH: usize = 64;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PasskeyView {
+    id: i32,
+    name: String,
+    created_at: String,
+    last_used_at: Option<String>,
+}
+
+impl From<WebauthnCredential> for PasskeyView {
+    fn from(credential: WebauthnCredential) -> Self {
+        Self {
+            id: credential.id(),
+            name: credential.name().to_string(),
+            created_at: credential.created_at().to_string(),
+            last_used_at: credential.last_used_at().map(|t| t.to_string()),
+        }
+    }
+}
+
+/**
+ * A passkey name is shown in the account settings: it must be set and reasonably short.
+ */
+pub fn is_valid_passkey_name(name: &str) -> bool {
+    let name = name.trim();
+    !name.is_empty() && name.chars().count() <= PASSKEY_NAME_MAX_LENGTH
+}
@@ -0,0 +1,309 @@
+use actix_web::dev::ConnectionInfo;
+use actix_web::http::StatusCode;
+use actix_web::{post, web, HttpRequest, HttpResponse, Responder, ResponseError};
+use mairie360_api_lib::pool::AppState;
+use mairie360_api_lib::security::AuthenticatedUser;
+use sqlx::PgPool;
+
+use crate::database::auth::change_password::{change_password_query, ChangePasswordQueryView};
+use crate::database::auth::get_password_hash::{get_password_hash_query, GetPasswordHashQueryView};
+use crate::database::security_events::create_security_event::{
+    create_security_event_query, CreateSecurityEventQueryView,
+};
+use crate::database::sessions::revoke_user_sessions::{
+    revoke_user_sessions_query, RevokeUserSessionsQueryView,
+};
+use crate::database::users::get_user_by_id::{get_user_by_id_query, GetUserByIdQueryView};
+use crate::endpoints::v1::auth::login::throttle::{
+    clear_failures, record_failure, reserve_attempt, ThrottleSubject,
+};
+use crate::endpoints::v1::auth::password_policy::view::PasswordPolicyErrorView;
+use crate::endpoints::v1::auth::password_policy::{
+    check_user_new_password, record_password_change, PasswordPolicyError,
+};
+use crate::endpoints::v1::sessions::revoke_access_tokens;
+use crate::endpoints::v1::user::me::password::view::ChangePasswordView;
+use crate::security::impersonation::Impersonation;
+use crate::security::jwt::session_id_from_request;
+use crate::security::login_throttle::LoginThrottleConfig;
+use crate::security::password::{hash_password, verify_password, PasswordHasherConfig};
+use crate::security::password_policy::{PasswordPolicy, PasswordViolation};
+use crate::{build_email, get_email_sender, send_email, EmailDestination};
+
+pub const PASSWORD_CHANGED_EVENT: &str = "password_changed";
+
+#[derive(Debug, Clone, PartialEq)]
+enum ChangePasswordError {
+    DatabaseError,
+    Impersonated,
+    InvalidCurrentPassword,
+    PasswordHashError,
+    RedisError,
+    TooManyAttempts(u64),
+    WeakPassword(Vec<PasswordViolation>),
+}
+
+impl From<PasswordPolicyError> for ChangePasswordError {
+    fn from(error: PasswordPolicyError) -> Self {
+        match error {
+            PasswordPolicyError::DatabaseError => ChangePasswordError::DatabaseError,
+            PasswordPolicyError::PasswordHashError => ChangePasswordError::PasswordHashError,
+            PasswordPolicyError::Violations(violations) => {
+                ChangePasswordError::WeakPassword(violations)
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for ChangePasswordError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChangePasswordError::DatabaseError => {
+                write!(f, "An error occurred while accessing the database.")
+            }
+            ChangePasswordError::Impersonated => {
+                write!(f, "Not allowed while an administrator acts as the user.")
+            }
+            ChangePasswordError::InvalidCurrentPassword => {
+                write!(f, "Current password is incorrect.")
+            }
+            ChangePasswordError::PasswordHashError => write!(f, "Failed to secure password."),
+            ChangePasswordError::RedisError => write!(f, "Internal Redis error."),
+            ChangePasswordError::TooManyAttempts(_) => {
+                write!(f, "Too many failed attempts, retry later.")
+            }
+            ChangePasswordError::WeakPassword(_) => {
+                write!(f, "Password does not meet the password policy.")
+            }
+        }
+    }
+}
+
+impl ResponseError for ChangePasswordError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            ChangePasswordError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            ChangePasswordError::Impersonated => StatusCode::FORBIDDEN,
+            ChangePasswordError::InvalidCurrentPassword => StatusCode::FORBIDDEN,
+            ChangePasswordError::PasswordHashError => StatusCode::INTERNAL_SERVER_ERROR,
+            ChangePasswordError::RedisError => StatusCode::INTERNAL_SERVER_ERROR,
+            ChangePasswordError::TooManyAttempts(_) => StatusCode::TOO_MANY_REQUESTS,
+            ChangePasswordError::WeakPassword(_) => StatusCode::BAD_REQUEST,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        match self {
+            ChangePasswordError::WeakPassword(violations) => {
+                HttpResponse::build(self.status_code()).json(PasswordPolicyErrorView::new(
+                    &self.to_string(),
+                    violations.clone(),
+                ))
+            }
+            ChangePasswordError::TooManyAttempts(retry_after) => {
+                HttpResponse::build(self.status_code())
+                    .append_header(("Retry-After", retry_after.to_string()))
+                    .body(self.to_string())
+            }
+            _ => HttpResponse::build(self.status_code()).body(self.to_string()),
+        }
+    }
+}
+
+/**
+ * Wrong current passwords count against the same per-account limit as failed logins,
+ * so a stolen access token cannot be used to guess the password.
+ */
+async fn verify_current_password(
+    state: &web::Data<AppState>,
+    pool: &PgPool,
+    user_id: u64,
+    password: &str,
+) -> Result<(), ChangePasswordError> {
+    let subject = ThrottleSubject::Account(user_id);
+    let config = LoginThrottleConfig::from_env();
+    if let Some(seconds) = reserve_attempt(state, &subject, &config)
+        .await
+        .map_err(|_| ChangePasswordError::RedisError)?
+    {
+        return Err(ChangePasswordError::TooManyAttempts(seconds.max(1)));
+    }
+
+    let hash = get_password_hash_query(GetPasswordHashQueryView::new(user_id), pool.clone())
+        .await
+        .map_err(|e| {
+            eprintln!("Database Error: {}", e);
+            ChangePasswordError::DatabaseError
+        })?
+        .ok_or(ChangePasswordError::DatabaseError)?;
+    let verification = verify_password(password, &hash, PasswordHasherConfig::from_env())
+        .await
+        .map_err(|e| {
+            eprintln!("Password Verification Error: {}", e);
+            ChangePasswordError::PasswordHashError
+        })?;
+
+    if !verification.is_valid() {
+        record_failure(state, &subject, &config)
+            .await
+            .map_err(|_| ChangePasswordError::RedisError)?;
+        return Err(ChangePasswordError::InvalidCurrentPassword);
+    }
+    clear_failures(state, &subject)
+        .await
+        .map_err(|_| ChangePasswordError::RedisError)?;
+    Ok(())
+}
+
+async fn revoke_other_sessions(
+    state: &web::Data<AppState>,
+    pool: &PgPool,
+    user_id: u64,
+    req: &HttpRequest,
+) -> Result<usize, ChangePasswordError> {
+    let mut view = RevokeUserSessionsQueryView::new(user_id);
+    if let Some(session_id) = session_id_from_request(req) {
+        view = view.keeping(session_id);
+    }
+    let revoked = revoke_user_sessions_query(view, pool.clone())
+        .await
+        .map_err(|e| {
+            eprintln!("Database Error: {}", e);
+            ChangePasswordError::DatabaseError
+        })?;
+    revoke_access_tokens(state, &revoked).await;
+    Ok(revoked.len())
+}
+
+async fn send_confirmation_email(pool: &PgPool, user_id: u64) {
+    let user = match get_user_by_id_query(GetUserByIdQueryView::new(user_id), pool.clone()).await {
+        Ok(user) => user,
+        Err(e) => {
+            eprintln!("Database Error: {}", e);
+            return;
+        }
+    };
+    let destination = EmailDestination {
+        from: match get_email_sender() {
+            Ok(sender) => sender,
+            Err(e) => {
+                eprintln!("Email Sender Error: {}", e);
+                return;
+            }
+        },
+        to: user.email().to_string(),
+    };
+
+    let subject = "Votre mot de passe a été modifié";
+    let body = "Bonjour, le mot de passe de votre compte vient d'être modifié. \
+        Si vous n'êtes pas à l'origine de ce changement, réinitialisez-le immédiatement \
+        et contactez un administrateur.";
+
+    let email = match build_email(&destination, subject, body) {
+        Ok(email) => email,
+        Err(e) => {
+            eprintln!("Email Build Error: {}", e);
+            return;
+        }
+    };
+    if let Err(e) = send_email(email).await {
+        eprintln!("Mail Error: {}", e);
+    }
+}
+
+async fn change_password(
+    state: web::Data<AppState>,
+    view: ChangePasswordView,
+    user_id: u64,
+    req: &HttpRequest,
+    ip_adress: std::net::IpAddr,
+) -> Result<(), ChangePasswordError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(ChangePasswordError::DatabaseError),
+    };
+
+    verify_current_password(&state, &pool, user_id, view.current_password()).await?;
+
+    let policy = PasswordPolicy::from_env();
+    check_user_new_password(&pool, &policy, view.new_password(), user_id).await?;
+
+    let password_hash = hash_password(view.new_password(), PasswordHasherConfig::from_env())
+        .await
+        .map_err(|e| {
+            eprintln!("Password hashing error: {}", e);
+            ChangePasswordError::PasswordHashError
+        })?;
+    change_password_query(
+        ChangePasswordQueryView::new(&password_hash, user_id),
+        pool.clone(),
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("Database Error: {}", e);
+        ChangePasswordError::DatabaseError
+    })?;
+    record_password_change(&pool, &policy, user_id, &password_hash)
+        .await
+        .map_err(|e| {
+            eprintln!("Database Error: {}", e);
+            ChangePasswordError::DatabaseError
+        })?;
+
+    let revoked = if view.revoke_other_sessions() {
+        revoke_other_sessions(&state, &pool, user_id, req).await?
+    } else {
+        0
+    };
+
+    let event = CreateSecurityEventQueryView::new(
+        Some(user_id),
+        PASSWORD_CHANGED_EVENT,
+        Some(ip_adress),
+        Some(format!("revoked_sessions = {}", revoked)),
+    );
+    if let Err(e) = create_security_event_query(event, pool.clone()).await {
+        eprintln!("Security Event DB Error: {}", e);
+    }
+
+    send_confirmation_email(&pool, user_id).await;
+    Ok(())
+}
+
+#[utoipa::path(
+    post,
+    path = "/password",
+    request_body = ChangePasswordView,
+    responses(
+        (status = 204, description = "Password changed, other sessions revoked when requested"),
+        (status = 400, description = "The new password breaks the policy", body = PasswordPolicyErrorView),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Current password is incorrect, or an administrator acts as the user"),
+        (status = 429, description = "Too many failed attempts, retry after the delay given in the Retry-After header"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Users",
+    security(
+        ("jwt" = [])
+    )
+)]
+#[post("/password")]
+pub async fn change_my_password(
+    state: web::Data<AppState>,
+    view: web::Json<ChangePasswordView>,
+    auth_user: AuthenticatedUser,
+    impersonation: Option<Impersonation>,
+    req: HttpRequest,
+    conn: ConnectionInfo,
+) -> Result<impl Responder, ChangePasswordError> {
+    if impersonation.is_some() {
+        return Err(ChangePasswordError::Impersonated);
+    }
+    let ip_str = conn.realip_remote_addr().unwrap_or("unknown").to_string();
+    let ip_address = ip_str
+        .parse::<std::net::IpAddr>()
+        .unwrap_or(std::net::IpAddr::V4(std::net::Ipv4Addr::new(0, 0, 0, 0)));
+
+    change_password(state, view.into_inner(), auth_user.id, &req, ip_address).await?;
+    Ok(HttpResponse::NoContent())
+}
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ChangePasswordView {
+    current_password: String,
+    new_password: String,
+    #[serde(default)]
+    revoke_other_sessions: bool,
+}
+
+impl ChangePasswordView {
+    pub fn new(current_password: &str, new_password: &str, revoke_other_sessions: bool) -> Self {
+        Self {
+            current_password: current_password.to_string(),
+            new_password: new_password.to_string(),
+            revoke_other_sessions,
+        }
+    }
+
+    pub fn current_password(&self) -> &str {
+        &self.current_password
+    }
+
+    pub fn new_password(&self) -> &str {
+        &self.new_password
+    }
+
+    pub fn revoke_other_sessions(&self) -> bool {
+        self.revoke_other_sessions
+    }
+}
+
+impl Display for ChangePasswordView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ChangePasswordView {{ current_password: [PROTECTED], new_password: [PROTECTED], revoke_other_sessions: {} }}",
+            self.revoke_other_sessions
+        )
+    }
+}
@@ -1,14 +1,38 @@
+use actix_web::dev::ConnectionInfo;
 use actix_web::http::StatusCode;
 use actix_web::{patch, web, HttpResponse, Responder, ResponseError};
 use mairie360_api_lib::pool::AppState;
 use mairie360_api_lib::security::AuthenticatedUser;
+use sqlx::PgPool;
 
+use crate::database::security_events::create_security_event::{
+    create_security_event_query, CreateSecurityEventQueryView,
+};
+use crate::database::users::get_user_by_id::{get_user_by_id_query, GetUserByIdQueryView};
 use crate::database::users::patch_user::{patch_user_query, PatchUserQueryView};
+use crate::endpoints::v1::auth::email_change::{
+    request_email_change, EmailChangeError, EMAIL_CHANGE_REQUESTED_EVENT,
+};
 use crate::endpoints::v1::user::me::patch::view::PatchMeView;
+use crate::security::impersonation::Impersonation;
 
 #[derive(Debug, Clone, PartialEq)]
 enum PatchMeError {
     DatabaseError,
+    EmailAlreadyUsed,
+    Impersonated,
+    InvalidEmail,
+    ServerError,
+}
+
+impl From<EmailChangeError> for PatchMeError {
+    fn from(error: EmailChangeError) -> Self {
+        match error {
+            EmailChangeError::AddressTaken => PatchMeError::EmailAlreadyUsed,
+            EmailChangeError::DatabaseError => PatchMeError::DatabaseError,
+            EmailChangeError::ServerError => PatchMeError::ServerError,
+        }
+    }
 }
 
 impl std::fmt::Display for PatchMeError {
@@ -17,6 +41,14 @@ impl std::fmt::Display for PatchMeError {
             PatchMeError::DatabaseError => {
                 write!(f, "An error occurred while accessing the database.")
             }
+            PatchMeError::EmailAlreadyUsed => {
+                write!(f, "This email address is already used by another account.")
+            }
+            PatchMeError::Impersonated => {
+                write!(f, "Not allowed while an administrator acts as the user.")
+            }
+            PatchMeError::InvalidEmail => write!(f, "Invalid email format."),
+            PatchMeError::ServerError => write!(f, "Internal server error."),
         }
     }
 }
@@ -25,6 +57,10 @@ impl ResponseError for PatchMeError {
     fn status_code(&self) -> StatusCode {
         match self {
             PatchMeError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            PatchMeError::EmailAlreadyUsed => StatusCode::CONFLICT,
+            PatchMeError::Impersonated => StatusCode::FORBIDDEN,
+            PatchMeError::InvalidEmail => StatusCode::BAD_REQUEST,
+            PatchMeError::ServerError => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
 
@@ -33,36 +69,97 @@ impl ResponseError for PatchMeError {
     }
 }
 
+fn is_valid_email(email: &str) -> bool {
+    if email.is_empty() {
+        return false;
+    }
+    match email.find('@') {
+        Some(index) => {
+            let domain = &email[index + 1..];
+            !domain.is_empty() && domain.contains('.')
+        }
+        None => false,
+    }
+}
+
+/**
+ * A new email is never written directly: it waits for the link sent to the new address.
+ * Returns true when such a change was started.
+ */
+async fn start_email_change(
+    pool: &PgPool,
+    user_id: u64,
+    new_email: &str,
+    ip_address: Option<std::net::IpAddr>,
+) -> Result<bool, PatchMeError> {
+    let user = get_user_by_id_query(GetUserByIdQueryView::new(user_id), pool.clone())
+        .await
+        .map_err(|e| {
+            eprintln!("Database Error: {}", e);
+            PatchMeError::DatabaseError
+        })?;
+    if user.email().eq_ignore_ascii_case(new_email) {
+        return Ok(false);
+    }
+    if !is_valid_email(new_email) {
+        return Err(PatchMeError::InvalidEmail);
+    }
+
+    request_email_change(pool, user_id, user.email(), new_email).await?;
+
+    let event = CreateSecurityEventQueryView::new(
+        Some(user_id),
+        EMAIL_CHANGE_REQUESTED_EVENT,
+        ip_address,
+        Some(new_email.to_string()),
+    );
+    if let Err(e) = create_security_event_query(event, pool.clone()).await {
+        eprintln!("Security Event DB Error: {}", e);
+    }
+    Ok(true)
+}
+
 async fn trigger_patch_me(
     state: web::Data<AppState>,
     view: PatchMeView,
     user_id: u64,
-) -> Result<(), PatchMeError> {
+    ip_address: Option<std::net::IpAddr>,
+) -> Result<bool, PatchMeError> {
     let pool = match state.db_pool.clone() {
         Some(pool) => pool,
         None => return Err(PatchMeError::DatabaseError),
     };
 
+    let email_change_pending = match view.email() {
+        Some(email) => start_email_change(&pool, user_id, email.trim(), ip_address).await?,
+        None => false,
+    };
+
     let db_view = PatchUserQueryView::new(
         user_id,
         view.first_name(),
         view.last_name(),
-        view.email(),
+        None,
         view.phone(),
         None,
-    );
+    )
+    .with_new_sign_in_alerts(view.new_sign_in_alerts());
     patch_user_query(db_view, &pool).await.map_err(|e| {
         eprintln!("Error: {:?}", e);
         PatchMeError::DatabaseError
     })?;
-    Ok(())
+    Ok(email_change_pending)
 }
 
 #[utoipa::path(
     patch,
     path = "/",
     responses(
-        (status = 200, description = "User updated successfully"),
+        (status = 200, description = "User updated successfully. `new_sign_in_alerts` is stored for admins too but only applies once they lose the admin role"),
+        (status = 202, description = "User updated, the new email waits for the link sent to that address"),
+        (status = 400, description = "Invalid email format"),
+        (status = 403, description = "Email change requested while an administrator acts as the user"),
+        (status = 409, description = "The new email is already used by another account"),
         (status = 500, description = "Internal server error")
     ),
     tag = "Users",
@@ -75,7 +172,18 @@ pub async fn patch_me(
     state: web::Data<AppState>,
     view: web::Json<PatchMeView>,
     auth_user: AuthenticatedUser,
+    impersonation: Option<Impersonation>,
+    conn: ConnectionInfo,
 ) -> Result<impl Responder, PatchMeError> {
-    trigger_patch_me(state, view.into_inner(), auth_user.id).await?;
+    // L'adresse email sert à récupérer le compte : elle reste hors de portée de l'administrateur
+    if impersonation.is_some() && view.email().is_some() {
+        return Err(PatchMeError::Impersonated);
+    }
+    let ip_address = conn
+        .realip_remote_addr()
+        .and_then(|ip| ip.parse::<std::net::IpAddr>().ok());
+    if trigger_patch_me(state, view.into_inner(), auth_user.id, ip_address).await? {
+        return Ok(HttpResponse::Accepted());
+    }
     Ok(HttpResponse::Ok())
 }
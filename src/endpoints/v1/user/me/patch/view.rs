@@ -8,6 +8,7 @@ pub struct PatchMeView {
     last_name: Option<String>,
     email: Option<String>,
     phone: Option<String>,
+    new_sign_in_alerts: Option<bool>,
 }
 
 impl PatchMeView {
@@ -22,6 +23,7 @@ impl PatchMeView {
             last_name,
             email,
             phone,
+            new_sign_in_alerts: None,
         }
     }
 
@@ -40,14 +42,18 @@ impl PatchMeView {
     pub fn phone(&self) -> Option<&str> {
         self.phone.as_deref()
     }
+
+    pub fn new_sign_in_alerts(&self) -> Option<bool> {
+        self.new_sign_in_alerts
+    }
 }
 
 impl Display for PatchMeView {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "PatchMeView {{ first_name: {:?}, last_name: {:?}, email: {:?}, phone: {:?} }}",
-            self.first_name, self.last_name, self.email, self.phone
+            "PatchMeView {{ first_name: {:?}, last_name: {:?}, email: {:?}, phone: {:?}, new_sign_in_alerts: {:?} }}",
+            self.first_name, self.last_name, self.email, self.phone, self.new_sign_in_alerts
         )
     }
 }
@@ -0,0 +1,274 @@
+use actix_web::dev::ConnectionInfo;
+use actix_web::http::StatusCode;
+use actix_web::{
+    delete, get, post, web, HttpMessage, HttpRequest, HttpResponse, Responder, ResponseError,
+};
+use mairie360_api_lib::pool::AppState;
+use mairie360_api_lib::security::AuthenticatedUser;
+use sqlx::PgPool;
+
+use crate::database::personal_access_tokens::create_personal_access_token::{
+    create_personal_access_token_query, CreatePersonalAccessTokenQueryView,
+};
+use crate::database::personal_access_tokens::get_personal_access_tokens_by_user::{
+    get_personal_access_tokens_by_user_query, GetPersonalAccessTokensByUserQueryView,
+};
+use crate::database::personal_access_tokens::revoke_personal_access_token::{
+    revoke_personal_access_token_query, RevokePersonalAccessTokenQueryView,
+};
+use crate::database::security_events::create_security_event::{
+    create_security_event_query, CreateSecurityEventQueryView,
+};
+use crate::endpoints::v1::user::me::tokens::view::{
+    CreateTokenView, CreatedTokenResponseView, TokenSchema, TokensResponseView,
+};
+use crate::security::impersonation::Impersonation;
+use crate::security::personal_access_token::{generate_personal_access_token, PersonalAccessToken};
+use crate::security::token::hash_token;
+
+pub const TOKEN_CREATED_EVENT: &str = "personal_access_token_created";
+pub const TOKEN_REVOKED_EVENT: &str = "personal_access_token_revoked";
+
+const MAX_TOKEN_NAME_LENGTH: usize = 100;
+
+#[derive(Debug, Clone, PartialEq)]
+enum TokensError {
+    DatabaseError,
+    Impersonated,
+    InvalidData,
+    NotFound,
+    SessionRequired,
+}
+
+impl std::fmt::Display for TokensError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TokensError::DatabaseError => {
+                write!(f, "An error occurred while accessing the database.")
+            }
+            TokensError::Impersonated => {
+                write!(f, "Not allowed while an administrator acts as the user.")
+            }
+            TokensError::InvalidData => {
+                write!(f, "A token needs a name and at least one scope.")
+            }
+            TokensError::NotFound => write!(f, "Token not found."),
+            TokensError::SessionRequired => {
+                write!(
+                    f,
+                    "Access tokens cannot manage access tokens, log in instead."
+                )
+            }
+        }
+    }
+}
+
+impl ResponseError for TokensError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            TokensError::DatabaseError => StatusCode::INTERNAL_SERVER_ERROR,
+            TokensError::Impersonated => StatusCode::FORBIDDEN,
+            TokensError::InvalidData => StatusCode::BAD_REQUEST,
+            TokensError::NotFound => StatusCode::NOT_FOUND,
+            TokensError::SessionRequired => StatusCode::FORBIDDEN,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).body(self.to_string())
+    }
+}
+
+/**
+ * A leaked token must not be able to mint new ones or hide its use by revoking others.
+ * An administrator acting as the user must not leave a token behind either.
+ */
+fn require_session(
+    req: &HttpRequest,
+    impersonation: &Option<Impersonation>,
+) -> Result<(), TokensError> {
+    if req.extensions().get::<PersonalAccessToken>().is_some() {
+        return Err(TokensError::SessionRequired);
+    }
+    if impersonation.is_some() {
+        return Err(TokensError::Impersonated);
+    }
+    Ok(())
+}
+
+fn get_pool(state: &web::Data<AppState>) -> Result<PgPool, TokensError> {
+    match state.db_pool.clone() {
+        Some(pool) => Ok(pool),
+        None => Err(TokensError::DatabaseError),
+    }
+}
+
+async fn record_event(
+    pool: &PgPool,
+    user_id: u64,
+    event_type: &str,
+    conn: &ConnectionInfo,
+    details: String,
+) {
+    let ip_address = conn
+        .realip_remote_addr()
+        .and_then(|ip| ip.parse::<std::net::IpAddr>().ok());
+    let event =
+        CreateSecurityEventQueryView::new(Some(user_id), event_type, ip_address, Some(details));
+    if let Err(e) = create_security_event_query(event, pool.clone()).await {
+        eprintln!("Security Event DB Error: {}", e);
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/tokens",
+    responses(
+        (status = 200, description = "Personal access tokens of the user that are not revoked, expired ones included", body = TokensResponseView),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Users",
+    security(
+        ("jwt" = [])
+    )
+)]
+#[get("/tokens")]
+pub async fn get_my_tokens(
+    state: web::Data<AppState>,
+    auth_user: AuthenticatedUser,
+) -> Result<impl Responder, TokensError> {
+    let pool = get_pool(&state)?;
+    let tokens = get_personal_access_tokens_by_user_query(
+        GetPersonalAccessTokensByUserQueryView::new(auth_user.id),
+        pool,
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("Database Error: {}", e);
+        TokensError::DatabaseError
+    })?;
+
+    Ok(HttpResponse::Ok().json(TokensResponseView::new(
+        tokens.into_iter().map(|t| t.into()).collect(),
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/tokens",
+    request_body = CreateTokenView,
+    responses(
+        (status = 201, description = "Token created; its value is only returned this once", body = CreatedTokenResponseView),
+        (status = 400, description = "Missing name or scopes"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Called with an access token instead of a session, or by an administrator acting as the user"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Users",
+    security(
+        ("jwt" = [])
+    )
+)]
+#[post("/tokens")]
+pub async fn create_my_token(
+    state: web::Data<AppState>,
+    view: web::Json<CreateTokenView>,
+    auth_user: AuthenticatedUser,
+    req: HttpRequest,
+    impersonation: Option<Impersonation>,
+    conn: ConnectionInfo,
+) -> Result<impl Responder, TokensError> {
+    require_session(&req, &impersonation)?;
+    let name = view.name().trim();
+    if name.is_empty() || name.len() > MAX_TOKEN_NAME_LENGTH || view.scopes().is_empty() {
+        return Err(TokensError::InvalidData);
+    }
+    let mut scopes: Vec<String> = view.scopes().iter().map(|s| s.to_string()).collect();
+    scopes.sort();
+    scopes.dedup();
+
+    let pool = get_pool(&state)?;
+    let token = generate_personal_access_token();
+    let db_view = CreatePersonalAccessTokenQueryView::new(
+        auth_user.id,
+        name,
+        &hash_token(&token),
+        scopes,
+        view.expires_in_days(),
+    );
+    let stored = create_personal_access_token_query(db_view, pool.clone())
+        .await
+        .map_err(|e| {
+            eprintln!("Database Error: {}", e);
+            TokensError::DatabaseError
+        })?;
+
+    record_event(
+        &pool,
+        auth_user.id,
+        TOKEN_CREATED_EVENT,
+        &conn,
+        format!("token_id = {}", stored.id()),
+    )
+    .await;
+    Ok(HttpResponse::Created().json(CreatedTokenResponseView::new(
+        token,
+        TokenSchema::from(stored),
+    )))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/tokens/{tokenId}",
+    params(
+        ("tokenId" = u64, Path, description = "ID du jeton")
+    ),
+    responses(
+        (status = 204, description = "Token revoked"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Called with an access token instead of a session, or by an administrator acting as the user"),
+        (status = 404, description = "Token not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Users",
+    security(
+        ("jwt" = [])
+    )
+)]
+#[delete("/tokens/{tokenId}")]
+pub async fn revoke_my_token(
+    state: web::Data<AppState>,
+    path: web::Path<u64>,
+    auth_user: AuthenticatedUser,
+    req: HttpRequest,
+    impersonation: Option<Impersonation>,
+    conn: ConnectionInfo,
+) -> Result<impl Responder, TokensError> {
+    require_session(&req, &impersonation)?;
+    let pool = get_pool(&state)?;
+    let token_id = path.into_inner();
+
+    let revoked = revoke_personal_access_token_query(
+        RevokePersonalAccessTokenQueryView::new(auth_user.id, token_id),
+        pool.clone(),
+    )
+    .await
+    .map_err(|e| {
+        eprintln!("Database Error: {}", e);
+        TokensError::DatabaseError
+    })?;
+    if !revoked {
+        return Err(TokensError::NotFound);
+    }
+
+    record_event(
+        &pool,
+        auth_user.id,
+        TOKEN_REVOKED_EVENT,
+        &conn,
+        format!("token_id = {}", token_id),
+    )
+    .await;
+    Ok(HttpResponse::NoContent())
+}
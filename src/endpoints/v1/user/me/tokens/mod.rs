@@ -0,0 +1,2 @@
+pub mod endpoint;
+pub mod view;
@@ -0,0 +1,99 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use utoipa::ToSchema;
+
+use crate::database::personal_access_tokens::StoredPersonalAccessToken;
+use crate::security::personal_access_token::PersonalAccessTokenScope;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CreateTokenView {
+    name: String,
+    scopes: Vec<PersonalAccessTokenScope>,
+    expires_in_days: Option<u32>,
+}
+
+impl CreateTokenView {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn scopes(&self) -> &[PersonalAccessTokenScope] {
+        &self.scopes
+    }
+
+    pub fn expires_in_days(&self) -> Option<u32> {
+        self.expires_in_days
+    }
+}
+
+impl Display for CreateTokenView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CreateTokenView {{ name: {}, scopes: {:?}, expires_in_days: {:?} }}",
+            self.name, self.scopes, self.expires_in_days
+        )
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TokenSchema {
+    id: i32,
+    name: String,
+    scopes: Vec<String>,
+    created_at: String,
+    expires_at: Option<String>,
+    last_used_at: Option<String>,
+}
+
+impl From<StoredPersonalAccessToken> for TokenSchema {
+    fn from(token: StoredPersonalAccessToken) -> Self {
+        TokenSchema {
+            id: token.id(),
+            name: token.name().to_string(),
+            scopes: token.scopes().to_vec(),
+            created_at: token.created_at().to_string(),
+            expires_at: token.expires_at().map(|date| date.to_string()),
+            last_used_at: token.last_used_at().map(|date| date.to_string()),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CreatedTokenResponseView {
+    token: String,
+    details: TokenSchema,
+}
+
+impl CreatedTokenResponseView {
+    pub fn new(token: String, details: TokenSchema) -> Self {
+        CreatedTokenResponseView { token, details }
+    }
+}
+
+impl Display for CreatedTokenResponseView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CreatedTokenResponseView {{ token: [PROTECTED], details: {:?} }}",
+            self.details
+        )
+    }
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct TokensResponseView {
+    tokens: Vec<TokenSchema>,
+}
+
+impl TokensResponseView {
+    pub fn new(tokens: Vec<TokenSchema>) -> Self {
+        TokensResponseView { tokens }
+    }
+}
+
+impl Display for TokensResponseView {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TokensResponseView {{ tokens: {:?} }}", self.tokens)
+    }
+}
@@ -0,0 +1,54 @@
+use mairie360_api_lib::env_manager::get_env_var;
+
+const DEFAULT_INTERVAL_SECONDS: u64 = 3600;
+const DEFAULT_RETENTION_DAYS: u32 = 90;
+
+/**
+ * Schedule of the session janitor.
+ * A run happens every `interval_seconds`; sessions whose `expires_at` is older than
+ * `retention_days` are deleted. Values are read from `SESSION_JANITOR_INTERVAL_SECONDS`
+ * and `SESSION_RETENTION_DAYS`; an interval of 0 disables the janitor.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JanitorConfig {
+    interval_seconds: u64,
+    retention_days: u32,
+}
+
+impl JanitorConfig {
+    pub fn new(interval_seconds: u64, retention_days: u32) -> Self {
+        Self {
+            interval_seconds,
+            retention_days,
+        }
+    }
+
+    pub fn from_env() -> Self {
+        Self::new(
+            get_env_var("SESSION_JANITOR_INTERVAL_SECONDS")
+                .and_then(|value| value.parse::<u64>().ok())
+                .unwrap_or(DEFAULT_INTERVAL_SECONDS),
+            get_env_var("SESSION_RETENTION_DAYS")
+                .and_then(|value| value.parse::<u32>().ok())
+                .unwrap_or(DEFAULT_RETENTION_DAYS),
+        )
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.interval_seconds > 0
+    }
+
+    pub fn interval_seconds(&self) -> u64 {
+        self.interval_seconds
+    }
+
+    pub fn retention_days(&self) -> u32 {
+        self.retention_days
+    }
+}
+
+impl Default for JanitorConfig {
+    fn default() -> Self {
+        Self::new(DEFAULT_INTERVAL_SECONDS, DEFAULT_RETENTION_DAYS)
+    }
+}
@@ -0,0 +1,26 @@
+use sqlx::{PgPool, Postgres, Transaction};
+
+// Clé arbitraire partagée par toutes les instances de Core
+const JANITOR_LOCK_KEY: i64 = 0x5e55_1015;
+
+/**
+ * Takes the cluster-wide janitor lock, or returns None when another instance holds it.
+ * The lock is a transaction-level advisory lock: it is released when the returned
+ * transaction ends, including when the connection is lost mid-run.
+ */
+pub async fn try_lock(
+    pool: &PgPool,
+) -> Result<Option<Transaction<'static, Postgres>>, sqlx::Error> {
+    let mut transaction = pool.begin().await?;
+    let locked: bool = sqlx::query_scalar("SELECT pg_try_advisory_xact_lock($1)")
+        .bind(JANITOR_LOCK_KEY)
+        .fetch_one(&mut *transaction)
+        .await?;
+
+    if locked {
+        Ok(Some(transaction))
+    } else {
+        transaction.rollback().await?;
+        Ok(None)
+    }
+}
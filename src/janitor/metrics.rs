@@ -0,0 +1,101 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+static RUNS: AtomicU64 = AtomicU64::new(0);
+static SKIPPED_RUNS: AtomicU64 = AtomicU64::new(0);
+static FAILED_RUNS: AtomicU64 = AtomicU64::new(0);
+static SESSIONS_EXPIRED: AtomicU64 = AtomicU64::new(0);
+static SESSIONS_PURGED: AtomicU64 = AtomicU64::new(0);
+static REDIS_KEYS_REMOVED: AtomicU64 = AtomicU64::new(0);
+static LAST_RUN_TIMESTAMP: AtomicU64 = AtomicU64::new(0);
+
+/**
+ * Counts of one janitor run, added to the process-wide counters once it is done.
+ */
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct JanitorReport {
+    pub sessions_expired: u64,
+    pub sessions_purged: u64,
+    pub redis_keys_removed: u64,
+}
+
+pub fn record_run(report: &JanitorReport, timestamp: u64) {
+    RUNS.fetch_add(1, Ordering::Relaxed);
+    SESSIONS_EXPIRED.fetch_add(report.sessions_expired, Ordering::Relaxed);
+    SESSIONS_PURGED.fetch_add(report.sessions_purged, Ordering::Relaxed);
+    REDIS_KEYS_REMOVED.fetch_add(report.redis_keys_removed, Ordering::Relaxed);
+    LAST_RUN_TIMESTAMP.store(timestamp, Ordering::Relaxed);
+}
+
+/**
+ * Another instance held the lock, nothing was done.
+ */
+pub fn record_skipped_run() {
+    SKIPPED_RUNS.fetch_add(1, Ordering::Relaxed);
+}
+
+pub fn record_failed_run() {
+    FAILED_RUNS.fetch_add(1, Ordering::Relaxed);
+}
+
+/**
+ * Counters of this instance in the Prometheus text exposition format.
+ */
+pub fn render() -> String {
+    let metrics = [
+        (
+            "core_session_janitor_runs_total",
+            "counter",
+            "Janitor runs completed by this instance.",
+            RUNS.load(Ordering::Relaxed),
+        ),
+        (
+            "core_session_janitor_skipped_runs_total",
+            "counter",
+            "Janitor runs skipped because another instance held the lock.",
+            SKIPPED_RUNS.load(Ordering::Relaxed),
+        ),
+        (
+            "core_session_janitor_failed_runs_total",
+            "counter",
+            "Janitor runs interrupted by a database or Redis error.",
+            FAILED_RUNS.load(Ordering::Relaxed),
+        ),
+        (
+            "core_session_janitor_sessions_expired_total",
+            "counter",
+            "Sessions marked as expired.",
+            SESSIONS_EXPIRED.load(Ordering::Relaxed),
+        ),
+        (
+            "core_session_janitor_sessions_purged_total",
+            "counter",
+            "Sessions deleted after the retention period.",
+            SESSIONS_PURGED.load(Ordering::Relaxed),
+        ),
+        (
+            "core_session_janitor_redis_keys_removed_total",
+            "counter",
+            "Orphaned Redis keys removed.",
+            REDIS_KEYS_REMOVED.load(Ordering::Relaxed),
+        ),
+        (
+            "core_session_janitor_last_run_timestamp_seconds",
+            "gauge",
+            "Unix time of the last completed janitor run.",
+            LAST_RUN_TIMESTAMP.load(Ordering::Relaxed),
+        ),
+    ];
+
+    metrics
+        .iter()
+        .map(|(name, kind, help, value)| {
+            format!(
+                "# HELP {name} {help}\n# TYPE {name} {kind}\n{name} {value}\n",
+                name = name,
+                help = help,
+                kind = kind,
+                value = value
+            )
+        })
+        .collect()
+}
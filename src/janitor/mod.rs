@@ -0,0 +1,110 @@
+mod config;
+pub use config::JanitorConfig;
+
+mod lock;
+
+pub mod metrics;
+pub use metrics::JanitorReport;
+
+mod redis_keys;
+
+use crate::database::sessions::expire_sessions::{expire_sessions_query, ExpireSessionsQueryView};
+use crate::database::sessions::purge_sessions::{purge_sessions_query, PurgeSessionsQueryView};
+use actix_web::web;
+use mairie360_api_lib::database::errors::DatabaseError;
+use mairie360_api_lib::pool::AppState;
+use std::fmt::Display;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[derive(Debug)]
+pub enum JanitorError {
+    Database(DatabaseError),
+    DatabaseUnavailable,
+    Redis(String),
+    RedisUnavailable,
+}
+
+impl Display for JanitorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JanitorError::Database(e) => write!(f, "Database error: {}", e),
+            JanitorError::DatabaseUnavailable => write!(f, "Database pool unavailable"),
+            JanitorError::Redis(e) => write!(f, "Redis error: {}", e),
+            JanitorError::RedisUnavailable => write!(f, "Redis pool unavailable"),
+        }
+    }
+}
+
+impl From<DatabaseError> for JanitorError {
+    fn from(error: DatabaseError) -> Self {
+        JanitorError::Database(error)
+    }
+}
+
+impl From<sqlx::Error> for JanitorError {
+    fn from(error: sqlx::Error) -> Self {
+        JanitorError::Database(error.into())
+    }
+}
+
+/**
+ * Runs one cleanup pass if no other instance is already running one.
+ * Returns None when the pass was skipped because the lock was taken.
+ */
+pub async fn run_once(
+    state: &AppState,
+    config: &JanitorConfig,
+) -> Result<Option<JanitorReport>, JanitorError> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(JanitorError::DatabaseUnavailable),
+    };
+    let lock = match lock::try_lock(&pool).await? {
+        Some(lock) => lock,
+        None => return Ok(None),
+    };
+
+    let report = JanitorReport {
+        sessions_expired: expire_sessions_query(ExpireSessionsQueryView::new(), pool.clone())
+            .await?,
+        sessions_purged: purge_sessions_query(
+            PurgeSessionsQueryView::new(config.retention_days()),
+            pool.clone(),
+        )
+        .await?,
+        redis_keys_removed: redis_keys::remove_orphaned_keys(state, pool).await?,
+    };
+
+    lock.commit().await?;
+    Ok(Some(report))
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+
+/**
+ * Starts the janitor loop in the background; does nothing when it is disabled.
+ */
+pub fn spawn(state: web::Data<AppState>, config: JanitorConfig) {
+    if !config.is_enabled() {
+        return;
+    }
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(config.interval_seconds()));
+        loop {
+            interval.tick().await;
+            match run_once(&state, &config).await {
+                Ok(Some(report)) => metrics::record_run(&report, now()),
+                Ok(None) => metrics::record_skipped_run(),
+                Err(e) => {
+                    eprintln!("Session janitor error: {}", e);
+                    metrics::record_failed_run();
+                }
+            }
+        }
+    });
+}
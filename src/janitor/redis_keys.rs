@@ -0,0 +1,108 @@
+use crate::database::users::get_first_connection_users::{
+    get_first_connection_users_query, GetFirstConnectionUsersQueryView,
+};
+use crate::janitor::JanitorError;
+use crate::redis::{handle_delete, handle_scan};
+use mairie360_api_lib::pool::redis::simple_key::secured::handle_secure_get;
+use mairie360_api_lib::pool::AppState;
+use sqlx::PgPool;
+use std::collections::HashSet;
+
+// Clés de l'ancien flux de réinitialisation, posées sans TTL et plus jamais lues
+const LEGACY_PATTERNS: [&str; 2] = ["*/forgot_password_token", "*/forgot_password_email"];
+
+const FIRST_CONNECTION_TOKEN_SUFFIX: &str = "/first_connection_token";
+const FIRST_CONNECTION_ID_SUFFIX: &str = "/first_connection_id";
+
+async fn scan(state: &AppState, pattern: &str) -> Result<Vec<String>, JanitorError> {
+    let conn = state
+        .get_redis_conn()
+        .await
+        .ok_or(JanitorError::RedisUnavailable)?;
+    handle_scan(conn, pattern)
+        .await
+        .map_err(|e| JanitorError::Redis(e.to_string()))
+}
+
+async fn delete(state: &AppState, keys: &[String]) -> Result<u64, JanitorError> {
+    if keys.is_empty() {
+        return Ok(0);
+    }
+    let conn = state
+        .get_redis_conn()
+        .await
+        .ok_or(JanitorError::RedisUnavailable)?;
+    handle_delete(conn, keys)
+        .await
+        .map_err(|e| JanitorError::Redis(e.to_string()))?;
+    Ok(keys.len() as u64)
+}
+
+/**
+ * Reads the user behind a `{token}/first_connection_id` key, None when the value
+ * cannot be decrypted anymore.
+ */
+async fn first_connection_owner(state: &AppState, key: &str) -> Result<Option<u64>, JanitorError> {
+    let conn = state
+        .get_redis_conn()
+        .await
+        .ok_or(JanitorError::RedisUnavailable)?;
+    Ok(handle_secure_get(conn, key)
+        .await
+        .ok()
+        .and_then(|id| id.parse().ok()))
+}
+
+/**
+ * First connection keys are only useful while the user still has to choose a password;
+ * the ones left behind by users who did, or who were deleted, are removed.
+ */
+async fn remove_first_connection_keys(state: &AppState, pool: PgPool) -> Result<u64, JanitorError> {
+    let mut owned_keys: Vec<(String, Option<u64>)> = Vec::new();
+    for key in scan(state, &format!("*{}", FIRST_CONNECTION_TOKEN_SUFFIX)).await? {
+        let owner = key
+            .strip_suffix(FIRST_CONNECTION_TOKEN_SUFFIX)
+            .and_then(|id| id.parse().ok());
+        owned_keys.push((key, owner));
+    }
+    for key in scan(state, &format!("*{}", FIRST_CONNECTION_ID_SUFFIX)).await? {
+        let owner = first_connection_owner(state, &key).await?;
+        owned_keys.push((key, owner));
+    }
+
+    let owners: Vec<u64> = owned_keys
+        .iter()
+        .filter_map(|(_, owner)| *owner)
+        .collect::<HashSet<u64>>()
+        .into_iter()
+        .collect();
+    let pending: HashSet<u64> = if owners.is_empty() {
+        HashSet::new()
+    } else {
+        get_first_connection_users_query(GetFirstConnectionUsersQueryView::new(owners), pool)
+            .await?
+            .into_iter()
+            .collect()
+    };
+
+    let orphans: Vec<String> = owned_keys
+        .into_iter()
+        .filter(|(_, owner)| !owner.is_some_and(|id| pending.contains(&id)))
+        .map(|(key, _)| key)
+        .collect();
+    delete(state, &orphans).await
+}
+
+/**
+ * Removes the Redis keys that were written without a TTL and no longer lead anywhere.
+ * Returns the number of keys deleted.
+ */
+pub async fn remove_orphaned_keys(state: &AppState, pool: PgPool) -> Result<u64, JanitorError> {
+    let mut removed = 0;
+    for pattern in LEGACY_PATTERNS {
+        let keys = scan(state, pattern).await?;
+        removed += delete(state, &keys).await?;
+    }
+    removed += remove_first_connection_keys(state, pool).await?;
+    Ok(removed)
+}
@@ -1,5 +1,8 @@
 pub mod database;
 pub mod endpoints;
+pub mod janitor;
+pub mod redis;
+pub mod security;
 
 use lettre::{
     transport::smtp::authentication::Credentials, AsyncSmtpTransport, AsyncTransport, Message,
@@ -21,6 +24,21 @@ pub fn get_email_sender() -> Result<String, Box<dyn std::error::Error>> {
     Ok(env::var("EMAIL_FROM").unwrap_or_else(|_| "Beta App <noreply@votrebeta.com>".to_string()))
 }
 
+/**
+ * Front-end page a link sent by email points to, read from the `name` variable. There is no
+ * fallback host: when the variable is missing the error is logged and the email must not be sent.
+ */
+pub fn get_email_link_url(name: &str) -> Option<String> {
+    let url = env::var(name).ok().filter(|url| !url.is_empty());
+    if url.is_none() {
+        eprintln!(
+            "Email Link Error: {} is not set, the email is not sent",
+            name
+        );
+    }
+    url
+}
+
 pub fn build_email(
     destination: &EmailDestination,
     subject: &str,
@@ -66,3 +84,12 @@ pub async fn send_email(email: Message) -> Result<(), Box<dyn std::error::Error>
 
     Ok(())
 }
+
+/**
+ * Sends an email without making the request wait for it. Used for the emails whose mere
+ * sending depends on the address having an account: the duration of the SMTP exchange
+ * would otherwise tell existing accounts apart.
+ */
+pub fn send_email_in_background(send: impl std::future::Future<Output = ()> + Send + 'static) {
+    tokio::spawn(send);
+}
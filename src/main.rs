@@ -2,8 +2,10 @@ use actix_web::{middleware, web, App, HttpServer};
 
 use core_api::endpoints::config;
 use core_api::endpoints::swagger::ApiDoc;
-use core_api::endpoints::{health, hello};
-use mairie360_api_lib::security::JwtMiddleware;
+use core_api::endpoints::{health, hello, jwks, metrics, openid_configuration};
+use core_api::janitor::{self, JanitorConfig};
+use core_api::security::jwt::key_store;
+use core_api::security::middleware::JwtMiddleware;
 
 use mairie360_api_lib::env_manager::get_critical_env_var;
 use mairie360_api_lib::pool::AppState;
@@ -24,8 +26,12 @@ async fn main() -> std::io::Result<()> {
         "postgres://{}:{}@{}:{}/{}",
         db_user, db_password, db_host, db_port, db_name
     );
+    // Les clés de signature sont chargées au démarrage pour échouer tôt
+    key_store().unwrap_or_else(|e| panic!("{}", e));
     let state = AppState::new(redis_url, pg_url).await;
     let data = web::Data::new(state);
+    // Une seule instance à la fois nettoie les sessions, grâce à un verrou Postgres
+    janitor::spawn(data.clone(), JanitorConfig::from_env());
     let host = get_critical_env_var("HOST");
     let port = get_critical_env_var("PORT");
     let bind_address = format!("{}:{}", host, port);
@@ -40,6 +46,9 @@ async fn main() -> std::io::Result<()> {
             )
             .service(health::health)
             .service(hello::hello)
+            .service(jwks::jwks)
+            .service(metrics::metrics)
+            .service(openid_configuration::openid_configuration)
             // 3. Endpoints Protégés par JWT
             .service(
                 web::scope("/api").wrap(JwtMiddleware).configure(config), // Tes routes v1, etc.
@@ -0,0 +1,14 @@
+use deadpool_redis::redis::{AsyncCommands, RedisError};
+use deadpool_redis::Connection;
+
+/**
+ * Decrements a counter kept by `handle_increment`, deleting it once it reaches zero so
+ * that it never goes negative.
+ */
+pub async fn handle_decrement(mut conn: Connection, key: &str) -> Result<(), RedisError> {
+    let count: i64 = conn.decr(key, 1).await?;
+    if count <= 0 {
+        conn.del::<&str, ()>(key).await?;
+    }
+    Ok(())
+}
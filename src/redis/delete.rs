@@ -0,0 +1,9 @@
+use deadpool_redis::redis::{AsyncCommands, RedisError};
+use deadpool_redis::Connection;
+
+/**
+ * Deletes keys without failing when some of them do not exist.
+ */
+pub async fn handle_delete(mut conn: Connection, keys: &[String]) -> Result<(), RedisError> {
+    conn.del::<&[String], ()>(keys).await
+}
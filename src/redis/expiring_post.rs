@@ -0,0 +1,15 @@
+use deadpool_redis::redis::{AsyncCommands, RedisError};
+use deadpool_redis::Connection;
+
+/**
+ * Stores a key that Redis deletes on its own after `ttl_seconds`.
+ * An existing value is overwritten and its TTL reset.
+ */
+pub async fn handle_expiring_post(
+    mut conn: Connection,
+    key: &str,
+    value: &str,
+    ttl_seconds: u64,
+) -> Result<(), RedisError> {
+    conn.set_ex::<&str, &str, ()>(key, value, ttl_seconds).await
+}
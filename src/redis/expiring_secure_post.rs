@@ -0,0 +1,23 @@
+use deadpool_redis::redis::{RedisError, Value};
+use deadpool_redis::Connection;
+
+/**
+ * Stores an expiring key only if it does not exist yet (SET NX EX).
+ * Returns false when the key was already present.
+ */
+pub async fn handle_expiring_secure_post(
+    mut conn: Connection,
+    key: &str,
+    value: &str,
+    ttl_seconds: u64,
+) -> Result<bool, RedisError> {
+    let result: Value = deadpool_redis::redis::cmd("SET")
+        .arg(key)
+        .arg(value)
+        .arg("NX")
+        .arg("EX")
+        .arg(ttl_seconds)
+        .query_async(&mut conn)
+        .await?;
+    Ok(result != Value::Nil)
+}
@@ -0,0 +1,9 @@
+use deadpool_redis::redis::{AsyncCommands, RedisError};
+use deadpool_redis::Connection;
+
+/**
+ * Reads a key, returning None instead of an error when it does not exist.
+ */
+pub async fn handle_get(mut conn: Connection, key: &str) -> Result<Option<String>, RedisError> {
+    conn.get::<&str, Option<String>>(key).await
+}
@@ -0,0 +1,13 @@
+use deadpool_redis::redis::{AsyncCommands, RedisError};
+use deadpool_redis::Connection;
+
+/**
+ * Atomically reads and removes a key (GETDEL), so a single-use token
+ * can never be redeemed twice even by concurrent requests.
+ */
+pub async fn handle_get_and_delete(
+    mut conn: Connection,
+    key: &str,
+) -> Result<Option<String>, RedisError> {
+    conn.get_del::<&str, Option<String>>(key).await
+}
@@ -0,0 +1,18 @@
+use deadpool_redis::redis::{AsyncCommands, RedisError};
+use deadpool_redis::Connection;
+
+/**
+ * Increments a counter and starts its expiry window on the first hit.
+ * Returns the value after the increment.
+ */
+pub async fn handle_increment(
+    mut conn: Connection,
+    key: &str,
+    ttl_seconds: u64,
+) -> Result<u64, RedisError> {
+    let count: u64 = conn.incr(key, 1).await?;
+    if count == 1 {
+        conn.expire::<&str, ()>(key, ttl_seconds as i64).await?;
+    }
+    Ok(count)
+}
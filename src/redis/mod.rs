@@ -0,0 +1,29 @@
+mod decrement;
+pub use decrement::handle_decrement;
+
+mod delete;
+pub use delete::handle_delete;
+
+mod expiring_post;
+pub use expiring_post::handle_expiring_post;
+
+mod expiring_secure_post;
+pub use expiring_secure_post::handle_expiring_secure_post;
+
+mod get;
+pub use get::handle_get;
+
+mod get_and_delete;
+pub use get_and_delete::handle_get_and_delete;
+
+mod increment;
+pub use increment::handle_increment;
+
+mod scan;
+pub use scan::handle_scan;
+
+mod session_denylist;
+pub use session_denylist::{handle_deny_session, handle_is_session_denied};
+
+mod ttl;
+pub use ttl::handle_ttl;
@@ -0,0 +1,14 @@
+use deadpool_redis::redis::{AsyncCommands, RedisError};
+use deadpool_redis::Connection;
+
+/**
+ * Lists the keys matching a glob pattern with SCAN, so Redis is never blocked like with KEYS.
+ */
+pub async fn handle_scan(mut conn: Connection, pattern: &str) -> Result<Vec<String>, RedisError> {
+    let mut iter = conn.scan_match::<&str, String>(pattern).await?;
+    let mut keys = Vec::new();
+    while let Some(key) = iter.next_item().await {
+        keys.push(key?);
+    }
+    Ok(keys)
+}
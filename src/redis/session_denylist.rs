@@ -0,0 +1,26 @@
+use deadpool_redis::redis::{AsyncCommands, RedisError};
+use deadpool_redis::Connection;
+
+fn revoked_session_key(session_id: &str) -> String {
+    format!("{}/revoked_session", session_id)
+}
+
+/**
+ * Denylists a revoked session until the last access token issued for it has expired.
+ */
+pub async fn handle_deny_session(
+    mut conn: Connection,
+    session_id: &str,
+    ttl_seconds: u64,
+) -> Result<(), RedisError> {
+    conn.set_ex::<String, &str, ()>(revoked_session_key(session_id), "1", ttl_seconds)
+        .await
+}
+
+pub async fn handle_is_session_denied(
+    mut conn: Connection,
+    session_id: &str,
+) -> Result<bool, RedisError> {
+    conn.exists::<String, bool>(revoked_session_key(session_id))
+        .await
+}
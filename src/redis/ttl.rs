@@ -0,0 +1,11 @@
+use deadpool_redis::redis::{AsyncCommands, RedisError};
+use deadpool_redis::Connection;
+
+/**
+ * Returns the seconds left before a key expires, or None when it does not exist
+ * or never expires.
+ */
+pub async fn handle_ttl(mut conn: Connection, key: &str) -> Result<Option<u64>, RedisError> {
+    let ttl: i64 = conn.ttl(key).await?;
+    Ok(u64::try_from(ttl).ok())
+}
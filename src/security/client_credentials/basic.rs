@@ -0,0 +1,28 @@
+use base64::{engine::general_purpose, Engine as _};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientCredentials {
+    pub client_id: String,
+    pub client_secret: String,
+}
+
+/**
+ * Reads `Authorization: Basic base64(client_id:client_secret)` (RFC 6749 section 2.3.1).
+ * Client ids and secrets issued by Core never need form-encoding, so none is undone here.
+ */
+pub fn parse_basic_authorization(header: &str) -> Option<ClientCredentials> {
+    let (scheme, encoded) = header.trim().split_once(' ')?;
+    if !scheme.eq_ignore_ascii_case("basic") {
+        return None;
+    }
+    let decoded = general_purpose::STANDARD.decode(encoded.trim()).ok()?;
+    let decoded = String::from_utf8(decoded).ok()?;
+    let (client_id, client_secret) = decoded.split_once(':')?;
+    if client_id.is_empty() || client_secret.is_empty() {
+        return None;
+    }
+    Some(ClientCredentials {
+        client_id: client_id.to_string(),
+        client_secret: client_secret.to_string(),
+    })
+}
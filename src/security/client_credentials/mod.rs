@@ -0,0 +1,5 @@
+mod basic;
+pub use basic::{parse_basic_authorization, ClientCredentials};
+
+mod verify;
+pub use verify::verify_client_secret;
@@ -0,0 +1,10 @@
+use subtle::ConstantTimeEq;
+
+use crate::security::token::hash_token;
+
+/**
+ * Client secrets are random tokens, so their SHA-256 is stored rather than an Argon2 hash.
+ */
+pub fn verify_client_secret(secret: &str, stored_hash: &str) -> bool {
+    bool::from(hash_token(secret).as_bytes().ct_eq(stored_hash.as_bytes()))
+}
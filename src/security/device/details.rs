@@ -0,0 +1,205 @@
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DeviceType {
+    Bot,
+    Desktop,
+    Mobile,
+    Tablet,
+    #[default]
+    Unknown,
+}
+
+impl DeviceType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DeviceType::Bot => "bot",
+            DeviceType::Desktop => "desktop",
+            DeviceType::Mobile => "mobile",
+            DeviceType::Tablet => "tablet",
+            DeviceType::Unknown => "unknown",
+        }
+    }
+}
+
+/**
+ * What a session was opened from, read from the `User-Agent` header and the `device_info`
+ * sent by the client. Browsers are matched before the engines they borrow tokens from
+ * (Edge and Opera also announce Chrome, Chrome also announces Safari).
+ */
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeviceDetails {
+    browser: Option<String>,
+    os: Option<String>,
+    device_type: DeviceType,
+    app_version: Option<String>,
+}
+
+// (jeton du User-Agent, nom affiché), dans l'ordre où il faut les tester
+const BROWSERS: [(&str, &str); 11] = [
+    ("Edg/", "Edge"),
+    ("EdgA/", "Edge"),
+    ("EdgiOS/", "Edge"),
+    ("OPR/", "Opera"),
+    ("SamsungBrowser/", "Samsung Internet"),
+    ("Firefox/", "Firefox"),
+    ("FxiOS/", "Firefox"),
+    ("CriOS/", "Chrome"),
+    ("Chrome/", "Chrome"),
+    ("Version/", "Safari"),
+    ("MSIE ", "Internet Explorer"),
+];
+
+const BOT_MARKERS: [&str; 4] = ["bot", "crawler", "spider", "curl/"];
+
+fn major_version(user_agent: &str, token: &str) -> Option<String> {
+    let start = user_agent.find(token)? + token.len();
+    let major: String = user_agent[start..]
+        .chars()
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    (!major.is_empty()).then_some(major)
+}
+
+fn parse_browser(user_agent: &str) -> Option<String> {
+    for (token, name) in BROWSERS {
+        if !user_agent.contains(token) {
+            continue;
+        }
+        // "Version/" n'identifie Safari que s'il est accompagné de "Safari/"
+        if token == "Version/" && !user_agent.contains("Safari/") {
+            continue;
+        }
+        return Some(match major_version(user_agent, token) {
+            Some(major) => format!("{} {}", name, major),
+            None => name.to_string(),
+        });
+    }
+    if user_agent.contains("Trident/") {
+        return Some("Internet Explorer".to_string());
+    }
+    None
+}
+
+fn dotted_version(user_agent: &str, token: &str) -> Option<String> {
+    let start = user_agent.find(token)? + token.len();
+    let version: String = user_agent[start..]
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == '_' || *c == '.')
+        .collect();
+    let version = version.replace('_', ".");
+    let version = version.trim_end_matches('.');
+    (!version.is_empty()).then(|| version.to_string())
+}
+
+fn parse_os(user_agent: &str) -> Option<String> {
+    if user_agent.contains("Windows") {
+        return Some("Windows".to_string());
+    }
+    if user_agent.contains("Android") {
+        return Some(match dotted_version(user_agent, "Android ") {
+            Some(version) => format!("Android {}", version),
+            None => "Android".to_string(),
+        });
+    }
+    if user_agent.contains("iPhone") || user_agent.contains("iPad") || user_agent.contains("iPod") {
+        let version = dotted_version(user_agent, "iPhone OS ")
+            .or_else(|| dotted_version(user_agent, "CPU OS "));
+        return Some(match version {
+            Some(version) => format!("iOS {}", version),
+            None => "iOS".to_string(),
+        });
+    }
+    if user_agent.contains("CrOS") {
+        return Some("ChromeOS".to_string());
+    }
+    if user_agent.contains("Mac OS X") || user_agent.contains("Macintosh") {
+        return Some("macOS".to_string());
+    }
+    if user_agent.contains("Linux") || user_agent.contains("X11") {
+        return Some("Linux".to_string());
+    }
+    None
+}
+
+fn parse_device_type(user_agent: &str) -> DeviceType {
+    let lowercase = user_agent.to_lowercase();
+    if BOT_MARKERS.iter().any(|marker| lowercase.contains(marker)) {
+        return DeviceType::Bot;
+    }
+    if user_agent.contains("iPad")
+        || user_agent.contains("Tablet")
+        || (user_agent.contains("Android") && !user_agent.contains("Mobile"))
+    {
+        return DeviceType::Tablet;
+    }
+    if user_agent.contains("Mobi") || user_agent.contains("iPhone") || user_agent.contains("iPod") {
+        return DeviceType::Mobile;
+    }
+    if parse_os(user_agent).is_some() {
+        return DeviceType::Desktop;
+    }
+    DeviceType::Unknown
+}
+
+/**
+ * Version of the mairie360 app when `device_info` starts with a `<app>/<version>` product
+ * token, like `mairie360-mobile/2.4.0 (Pixel 7)`. Browsers sending their own user agent
+ * as device info start with `Mozilla/` and have none.
+ */
+fn parse_app_version(device_info: &str) -> Option<String> {
+    let product = device_info.split_whitespace().next()?;
+    let (name, version) = product.split_once('/')?;
+    if name.is_empty() || version.is_empty() || name.eq_ignore_ascii_case("mozilla") {
+        return None;
+    }
+    Some(version.to_string())
+}
+
+impl DeviceDetails {
+    pub fn new(
+        browser: Option<String>,
+        os: Option<String>,
+        device_type: DeviceType,
+        app_version: Option<String>,
+    ) -> Self {
+        Self {
+            browser,
+            os,
+            device_type,
+            app_version,
+        }
+    }
+
+    /**
+     * The `User-Agent` header wins; `device_info` fills in what it does not tell, which
+     * covers native apps that send a generic HTTP client user agent.
+     */
+    pub fn parse(user_agent: Option<&str>, device_info: &str) -> Self {
+        let user_agent = user_agent.unwrap_or_default();
+        let mut device_type = parse_device_type(user_agent);
+        if device_type == DeviceType::Unknown {
+            device_type = parse_device_type(device_info);
+        }
+        Self {
+            browser: parse_browser(user_agent).or_else(|| parse_browser(device_info)),
+            os: parse_os(user_agent).or_else(|| parse_os(device_info)),
+            device_type,
+            app_version: parse_app_version(device_info),
+        }
+    }
+
+    pub fn browser(&self) -> Option<&str> {
+        self.browser.as_deref()
+    }
+
+    pub fn os(&self) -> Option<&str> {
+        self.os.as_deref()
+    }
+
+    pub fn device_type(&self) -> DeviceType {
+        self.device_type
+    }
+
+    pub fn app_version(&self) -> Option<&str> {
+        self.app_version.as_deref()
+    }
+}
@@ -0,0 +1,5 @@
+mod details;
+pub use details::{DeviceDetails, DeviceType};
+
+mod user_agent;
+pub use user_agent::UserAgent;
@@ -0,0 +1,33 @@
+use actix_web::http::header::USER_AGENT;
+use actix_web::{dev::Payload, FromRequest, HttpRequest};
+use futures_util::future::{ready, Ready};
+
+/**
+ * `User-Agent` header of the request, None when it is missing or not valid UTF-8.
+ */
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UserAgent(Option<String>);
+
+impl UserAgent {
+    pub fn new(value: Option<String>) -> Self {
+        Self(value)
+    }
+
+    pub fn value(&self) -> Option<&str> {
+        self.0.as_deref()
+    }
+}
+
+impl FromRequest for UserAgent {
+    type Error = actix_web::Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        let value = req
+            .headers()
+            .get(USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        ready(Ok(UserAgent(value)))
+    }
+}
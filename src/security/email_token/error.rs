@@ -0,0 +1,22 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EmailTokenError {
+    Configuration(String),
+    Expired,
+    InvalidSignature,
+    Malformed,
+}
+
+impl std::fmt::Display for EmailTokenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EmailTokenError::Configuration(reason) => {
+                write!(f, "Email token configuration error: {}", reason)
+            }
+            EmailTokenError::Expired => write!(f, "Email token has expired"),
+            EmailTokenError::InvalidSignature => write!(f, "Email token signature is invalid"),
+            EmailTokenError::Malformed => write!(f, "Email token is malformed"),
+        }
+    }
+}
+
+impl std::error::Error for EmailTokenError {}
@@ -0,0 +1,5 @@
+mod error;
+pub use error::EmailTokenError;
+
+mod signer;
+pub use signer::EmailTokenSigner;
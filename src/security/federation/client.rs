@@ -0,0 +1,116 @@
+use jsonwebtoken::jwk::JwkSet;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::time::Duration;
+use ureq::Agent;
+
+use crate::security::federation::{FederationConfig, FederationError, ProviderMetadata};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    id_token: Option<String>,
+}
+
+fn agent() -> Agent {
+    Agent::config_builder()
+        .timeout_global(Some(REQUEST_TIMEOUT))
+        .build()
+        .into()
+}
+
+fn provider_error(e: impl std::fmt::Display) -> FederationError {
+    FederationError::Provider(e.to_string())
+}
+
+/**
+ * ureq is blocking: requests run on the blocking pool instead of an actix worker.
+ */
+async fn blocking<T, F>(request: F) -> Result<T, FederationError>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T, FederationError> + Send + 'static,
+{
+    tokio::task::spawn_blocking(request)
+        .await
+        .map_err(provider_error)?
+}
+
+async fn get_json<T: DeserializeOwned + Send + 'static>(url: String) -> Result<T, FederationError> {
+    blocking(move || {
+        agent()
+            .get(&url)
+            .call()
+            .map_err(provider_error)?
+            .body_mut()
+            .read_json::<T>()
+            .map_err(provider_error)
+    })
+    .await
+}
+
+/**
+ * Reads `{issuer}/.well-known/openid-configuration` and checks it describes that issuer.
+ */
+pub async fn fetch_provider_metadata(issuer: &str) -> Result<ProviderMetadata, FederationError> {
+    let metadata: ProviderMetadata =
+        get_json(format!("{}/.well-known/openid-configuration", issuer)).await?;
+    if metadata.issuer().trim_end_matches('/') != issuer {
+        return Err(FederationError::Provider(format!(
+            "discovery document is for {}",
+            metadata.issuer()
+        )));
+    }
+    Ok(metadata)
+}
+
+pub async fn fetch_jwks(metadata: &ProviderMetadata) -> Result<JwkSet, FederationError> {
+    get_json(metadata.jwks_uri().to_string()).await
+}
+
+/**
+ * Redeems the authorization code at the token endpoint and returns the ID token. The client
+ * secret, when there is one, is sent in the form (`client_secret_post`).
+ */
+pub async fn exchange_code(
+    metadata: &ProviderMetadata,
+    config: &FederationConfig,
+    code: &str,
+    code_verifier: &str,
+) -> Result<String, FederationError> {
+    let token_endpoint = metadata.token_endpoint().to_string();
+    let mut form = vec![
+        ("grant_type".to_string(), "authorization_code".to_string()),
+        ("code".to_string(), code.to_string()),
+        (
+            "redirect_uri".to_string(),
+            config.redirect_uri().to_string(),
+        ),
+        ("code_verifier".to_string(), code_verifier.to_string()),
+        ("client_id".to_string(), config.client_id().to_string()),
+    ];
+    if let Some(secret) = config.client_secret() {
+        form.push(("client_secret".to_string(), secret.to_string()));
+    }
+
+    let response: TokenResponse = blocking(move || {
+        agent()
+            .post(&token_endpoint)
+            .send_form(form)
+            .map_err(|e| match e {
+                // Code inconnu, expiré ou déjà échangé : la faute n'est pas au fournisseur
+                ureq::Error::StatusCode(status) if (400..500).contains(&status) => {
+                    FederationError::CodeRejected(status)
+                }
+                e => provider_error(e),
+            })?
+            .body_mut()
+            .read_json::<TokenResponse>()
+            .map_err(provider_error)
+    })
+    .await?;
+    response
+        .id_token
+        .ok_or_else(|| FederationError::Provider("no id_token in the token response".to_string()))
+}
@@ -0,0 +1,82 @@
+use mairie360_api_lib::env_manager::get_env_var;
+
+use crate::security::federation::FederationError;
+
+const DEFAULT_SCOPES: &str = "openid email profile";
+
+/**
+ * External OpenID Connect provider agents log in with, e.g. the regional identity provider.
+ * Core is registered there as a client whose redirect URI is the front-end callback page.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FederationConfig {
+    issuer: String,
+    client_id: String,
+    client_secret: Option<String>,
+    redirect_uri: String,
+    scopes: String,
+}
+
+impl FederationConfig {
+    pub fn new(
+        issuer: &str,
+        client_id: &str,
+        client_secret: Option<&str>,
+        redirect_uri: &str,
+    ) -> Self {
+        Self {
+            issuer: issuer.trim_end_matches('/').to_string(),
+            client_id: client_id.to_string(),
+            client_secret: client_secret.map(str::to_string),
+            redirect_uri: redirect_uri.to_string(),
+            scopes: DEFAULT_SCOPES.to_string(),
+        }
+    }
+
+    pub fn with_scopes(mut self, scopes: &str) -> Self {
+        self.scopes = scopes.to_string();
+        self
+    }
+
+    /**
+     * Reads `FEDERATION_ISSUER`, `FEDERATION_CLIENT_ID`, `FEDERATION_CLIENT_SECRET` (optional),
+     * `FEDERATION_REDIRECT_URI` and `FEDERATION_SCOPES` (default `openid email profile`).
+     */
+    pub fn from_env() -> Result<Self, FederationError> {
+        let required = |name: &str| {
+            get_env_var(name)
+                .ok_or_else(|| FederationError::Configuration(format!("{} is not set", name)))
+        };
+        let client_secret = get_env_var("FEDERATION_CLIENT_SECRET");
+        let config = Self::new(
+            &required("FEDERATION_ISSUER")?,
+            &required("FEDERATION_CLIENT_ID")?,
+            client_secret.as_deref(),
+            &required("FEDERATION_REDIRECT_URI")?,
+        );
+        Ok(match get_env_var("FEDERATION_SCOPES") {
+            Some(scopes) => config.with_scopes(&scopes),
+            None => config,
+        })
+    }
+
+    pub fn issuer(&self) -> &str {
+        &self.issuer
+    }
+
+    pub fn client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    pub fn client_secret(&self) -> Option<&str> {
+        self.client_secret.as_deref()
+    }
+
+    pub fn redirect_uri(&self) -> &str {
+        &self.redirect_uri
+    }
+
+    pub fn scopes(&self) -> &str {
+        &self.scopes
+    }
+}
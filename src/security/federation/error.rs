@@ -0,0 +1,34 @@
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FederationError {
+    Configuration(String),
+    Provider(String),
+    CodeRejected(u16),
+    InvalidIdToken(String),
+    UnknownKey(String),
+    ExpiredIdToken,
+    NonceMismatch,
+}
+
+impl Display for FederationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FederationError::Configuration(e) => {
+                write!(f, "Invalid identity provider configuration: {}", e)
+            }
+            FederationError::Provider(e) => write!(f, "Identity provider request failed: {}", e),
+            FederationError::CodeRejected(status) => write!(
+                f,
+                "Authorization code rejected by the identity provider (HTTP {})",
+                status
+            ),
+            FederationError::InvalidIdToken(e) => write!(f, "Invalid ID token: {}", e),
+            FederationError::UnknownKey(kid) => write!(f, "Unknown ID token key id: {}", kid),
+            FederationError::ExpiredIdToken => write!(f, "ID token is expired"),
+            FederationError::NonceMismatch => write!(f, "ID token nonce does not match"),
+        }
+    }
+}
+
+impl std::error::Error for FederationError {}
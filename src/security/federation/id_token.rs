@@ -0,0 +1,108 @@
+use chrono::{DateTime, Utc};
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{decode, decode_header, Algorithm, DecodingKey, Validation};
+use serde::Deserialize;
+
+use crate::security::federation::{FederationConfig, FederationError};
+
+#[derive(Deserialize)]
+struct ExternalIdTokenClaims {
+    sub: String,
+    exp: u64,
+    nonce: Option<String>,
+    email: Option<String>,
+    // Certains fournisseurs envoient "true" sous forme de chaîne
+    email_verified: Option<serde_json::Value>,
+}
+
+/**
+ * Who the provider vouches for. `email` is only trusted when `email_verified` is true.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FederatedIdentity {
+    subject: String,
+    email: Option<String>,
+    email_verified: bool,
+}
+
+impl FederatedIdentity {
+    pub fn subject(&self) -> &str {
+        &self.subject
+    }
+
+    pub fn email(&self) -> Option<&str> {
+        self.email.as_deref()
+    }
+
+    pub fn verified_email(&self) -> Option<&str> {
+        if self.email_verified {
+            self.email()
+        } else {
+            None
+        }
+    }
+}
+
+fn is_asymmetric(algorithm: Algorithm) -> bool {
+    !matches!(
+        algorithm,
+        Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512
+    )
+}
+
+/**
+ * Checks the ID token returned by the provider: signature by a key of its JWKS, issuer,
+ * audience, expiry and the nonce of the login it answers.
+ */
+pub fn verify_id_token(
+    id_token: &str,
+    jwks: &JwkSet,
+    config: &FederationConfig,
+    nonce: &str,
+    now: DateTime<Utc>,
+) -> Result<FederatedIdentity, FederationError> {
+    let header =
+        decode_header(id_token).map_err(|e| FederationError::InvalidIdToken(e.to_string()))?;
+    // Un secret partagé n'a rien à faire ici : seules les clés publiques du JWKS comptent
+    if !is_asymmetric(header.alg) {
+        return Err(FederationError::InvalidIdToken(format!(
+            "unsupported algorithm {:?}",
+            header.alg
+        )));
+    }
+    let jwk = match &header.kid {
+        Some(kid) => jwks.find(kid),
+        None if jwks.keys.len() == 1 => jwks.keys.first(),
+        None => None,
+    }
+    .ok_or_else(|| FederationError::UnknownKey(header.kid.clone().unwrap_or_default()))?;
+    let key =
+        DecodingKey::from_jwk(jwk).map_err(|e| FederationError::InvalidIdToken(e.to_string()))?;
+
+    let mut validation = Validation::new(header.alg);
+    validation.set_issuer(&[config.issuer(), &format!("{}/", config.issuer())]);
+    validation.set_audience(&[config.client_id()]);
+    // L'expiration est vérifiée à la main pour rester cohérente avec `now`
+    validation.validate_exp = false;
+    let claims = decode::<ExternalIdTokenClaims>(id_token, &key, &validation)
+        .map_err(|e| FederationError::InvalidIdToken(e.to_string()))?
+        .claims;
+
+    if claims.exp <= now.timestamp() as u64 {
+        return Err(FederationError::ExpiredIdToken);
+    }
+    if claims.nonce.as_deref() != Some(nonce) {
+        return Err(FederationError::NonceMismatch);
+    }
+
+    let email_verified = match claims.email_verified {
+        Some(serde_json::Value::Bool(verified)) => verified,
+        Some(serde_json::Value::String(verified)) => verified == "true",
+        _ => false,
+    };
+    Ok(FederatedIdentity {
+        subject: claims.sub,
+        email: claims.email,
+        email_verified,
+    })
+}
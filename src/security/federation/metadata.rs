@@ -0,0 +1,53 @@
+use serde::Deserialize;
+use url::Url;
+
+use crate::security::federation::FederationConfig;
+use crate::security::oidc::PKCE_METHOD;
+
+/**
+ * The parts of the provider's discovery document the login needs.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ProviderMetadata {
+    issuer: String,
+    authorization_endpoint: String,
+    token_endpoint: String,
+    jwks_uri: String,
+}
+
+impl ProviderMetadata {
+    pub fn issuer(&self) -> &str {
+        &self.issuer
+    }
+
+    pub fn token_endpoint(&self) -> &str {
+        &self.token_endpoint
+    }
+
+    pub fn jwks_uri(&self) -> &str {
+        &self.jwks_uri
+    }
+
+    /**
+     * Where to send the browser, with PKCE so an intercepted code is useless on its own.
+     */
+    pub fn authorization_url(
+        &self,
+        config: &FederationConfig,
+        state: &str,
+        nonce: &str,
+        code_challenge: &str,
+    ) -> Option<String> {
+        let mut url = Url::parse(&self.authorization_endpoint).ok()?;
+        url.query_pairs_mut()
+            .append_pair("response_type", "code")
+            .append_pair("client_id", config.client_id())
+            .append_pair("redirect_uri", config.redirect_uri())
+            .append_pair("scope", config.scopes())
+            .append_pair("state", state)
+            .append_pair("nonce", nonce)
+            .append_pair("code_challenge", code_challenge)
+            .append_pair("code_challenge_method", PKCE_METHOD);
+        Some(url.to_string())
+    }
+}
@@ -0,0 +1,14 @@
+mod client;
+pub use client::{exchange_code, fetch_jwks, fetch_provider_metadata};
+
+mod config;
+pub use config::FederationConfig;
+
+mod error;
+pub use error::FederationError;
+
+mod id_token;
+pub use id_token::{verify_id_token, FederatedIdentity};
+
+mod metadata;
+pub use metadata::ProviderMetadata;
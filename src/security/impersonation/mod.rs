@@ -0,0 +1,2 @@
+mod session;
+pub use session::Impersonation;
@@ -0,0 +1,54 @@
+use actix_web::HttpMessage;
+use actix_web::{dev::Payload, FromRequest, HttpRequest};
+use futures_util::future::{ready, Ready};
+use uuid::Uuid;
+
+/**
+ * Stored in the request extensions next to the `AuthenticatedUser` when an administrator acts
+ * as that user. Handlers take `Option<Impersonation>` to tell the two apart.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Impersonation {
+    id: Uuid,
+    user_id: u64,
+    admin_id: u64,
+}
+
+impl Impersonation {
+    pub fn new(id: Uuid, user_id: u64, admin_id: u64) -> Self {
+        Self {
+            id,
+            user_id,
+            admin_id,
+        }
+    }
+
+    /**
+     * Row of `impersonation_sessions`, also the `sid` of the token.
+     */
+    pub fn id(&self) -> &Uuid {
+        &self.id
+    }
+
+    pub fn user_id(&self) -> u64 {
+        self.user_id
+    }
+
+    pub fn admin_id(&self) -> u64 {
+        self.admin_id
+    }
+}
+
+impl FromRequest for Impersonation {
+    type Error = actix_web::Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        match req.extensions().get::<Impersonation>() {
+            Some(impersonation) => ready(Ok(impersonation.clone())),
+            None => ready(Err(actix_web::error::ErrorForbidden(
+                "Forbidden: Impersonation session required.",
+            ))),
+        }
+    }
+}
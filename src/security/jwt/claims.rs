@@ -0,0 +1,147 @@
+use serde::{Deserialize, Serialize};
+
+/**
+ * RFC 8693 `act` claim: the party acting on behalf of `sub`.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Actor {
+    sub: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Claims {
+    sub: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    sid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    client_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    module_id: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    scope: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    act: Option<Actor>,
+    iat: u64,
+    exp: u64,
+}
+
+impl Claims {
+    pub fn new(user_id: &str, issued_at: u64, expiration: u64) -> Self {
+        Self {
+            sub: user_id.to_string(),
+            sid: None,
+            client_id: None,
+            module_id: None,
+            scope: None,
+            act: None,
+            iat: issued_at,
+            exp: expiration,
+        }
+    }
+
+    /**
+     * Access token a client got for the user through OpenID Connect. It is only good for
+     * `userinfo`: Core routes refuse tokens carrying a `client_id`.
+     */
+    pub fn with_client(mut self, client_id: &str, scopes: &[String]) -> Self {
+        self.client_id = Some(client_id.to_string());
+        self.scope = Some(scopes.join(" "));
+        self
+    }
+
+    /**
+     * Token issued to a module through the client credentials grant. `sub` is the client id,
+     * which is never numeric, so user checks reject it even without looking at `client_id`.
+     */
+    pub fn for_module(
+        client_id: &str,
+        module_id: i32,
+        scopes: &[String],
+        issued_at: u64,
+        expiration: u64,
+    ) -> Self {
+        Self {
+            sub: client_id.to_string(),
+            sid: None,
+            client_id: Some(client_id.to_string()),
+            module_id: Some(module_id),
+            scope: Some(scopes.join(" ")),
+            act: None,
+            iat: issued_at,
+            exp: expiration,
+        }
+    }
+
+    /**
+     * Ties the token to a row of `sessions`, so that revoking the session revokes the token.
+     */
+    pub fn with_session_id(mut self, session_id: &str) -> Self {
+        self.sid = Some(session_id.to_string());
+        self
+    }
+
+    /**
+     * Token an administrator got to act as the user: `sub` is the user, `act.sub` the administrator.
+     */
+    pub fn with_actor(mut self, admin_id: &str) -> Self {
+        self.act = Some(Actor {
+            sub: admin_id.to_string(),
+        });
+        self
+    }
+
+    pub fn get_user_id(&self) -> &str {
+        &self.sub
+    }
+
+    pub fn get_session_id(&self) -> Option<&str> {
+        self.sid.as_deref()
+    }
+
+    pub fn get_client_id(&self) -> Option<&str> {
+        self.client_id.as_deref()
+    }
+
+    pub fn get_module_id(&self) -> Option<i32> {
+        self.module_id
+    }
+
+    /**
+     * Space separated, as in the `scope` parameter of RFC 6749.
+     */
+    pub fn get_scope(&self) -> Option<&str> {
+        self.scope.as_deref()
+    }
+
+    pub fn get_actor_id(&self) -> Option<&str> {
+        self.act.as_ref().map(|act| act.sub.as_str())
+    }
+
+    pub fn is_module_token(&self) -> bool {
+        self.module_id.is_some()
+    }
+
+    pub fn get_issued_at(&self) -> u64 {
+        self.iat
+    }
+
+    pub fn get_expiration(&self) -> u64 {
+        self.exp
+    }
+}
+
+impl std::fmt::Display for Claims {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Claims {{ sub: {}, sid: {:?}, client_id: {:?}, scope: {:?}, act: {:?}, iat: {}, exp: {} }}",
+            self.sub,
+            self.sid,
+            self.client_id,
+            self.scope,
+            self.get_actor_id(),
+            self.iat,
+            self.exp
+        )
+    }
+}
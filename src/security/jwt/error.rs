@@ -0,0 +1,28 @@
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JwtError {
+    Configuration(String),
+    InvalidKey(String),
+    NoSigningKey,
+    UnknownKey(String),
+    InvalidToken(String),
+    ExpiredToken,
+    Encoding(String),
+}
+
+impl Display for JwtError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JwtError::Configuration(e) => write!(f, "Invalid JWT key configuration: {}", e),
+            JwtError::InvalidKey(e) => write!(f, "Invalid JWT key: {}", e),
+            JwtError::NoSigningKey => write!(f, "No JWT signing key is active"),
+            JwtError::UnknownKey(kid) => write!(f, "Unknown JWT key id: {}", kid),
+            JwtError::InvalidToken(e) => write!(f, "Invalid JWT: {}", e),
+            JwtError::ExpiredToken => write!(f, "JWT is expired"),
+            JwtError::Encoding(e) => write!(f, "Failed to encode JWT: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for JwtError {}
@@ -0,0 +1,163 @@
+use base64::{engine::general_purpose, Engine as _};
+use chrono::{DateTime, Utc};
+use ed25519_dalek::pkcs8::{DecodePrivateKey, EncodePrivateKey};
+use jsonwebtoken::jwk::{
+    AlgorithmParameters, CommonParameters, EllipticCurve, Jwk, KeyAlgorithm,
+    OctetKeyPairParameters, OctetKeyPairType, PublicKeyUse, RSAKeyParameters, RSAKeyType,
+};
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey};
+use rsa::pkcs1::{DecodeRsaPrivateKey, EncodeRsaPrivateKey};
+use rsa::traits::PublicKeyParts;
+use serde::Deserialize;
+
+use crate::security::jwt::JwtError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum JwtAlgorithm {
+    EdDSA,
+    RS256,
+}
+
+impl JwtAlgorithm {
+    pub fn algorithm(&self) -> Algorithm {
+        match self {
+            JwtAlgorithm::EdDSA => Algorithm::EdDSA,
+            JwtAlgorithm::RS256 => Algorithm::RS256,
+        }
+    }
+}
+
+/**
+ * A signing key identified by its `kid`.
+ * A key signs new tokens from `not_before` until a more recent key takes over, and keeps
+ * verifying (and being published) until `retire_at`, so tokens issued just before a
+ * rotation stay valid for their whole lifetime.
+ */
+pub struct JwtKey {
+    kid: String,
+    algorithm: JwtAlgorithm,
+    encoding_key: EncodingKey,
+    decoding_key: DecodingKey,
+    jwk: Jwk,
+    not_before: Option<DateTime<Utc>>,
+    retire_at: Option<DateTime<Utc>>,
+}
+
+impl JwtKey {
+    /**
+     * Builds a key from a PKCS#8 PEM private key (PKCS#1 is also accepted for RSA).
+     */
+    pub fn from_pem(
+        kid: &str,
+        algorithm: JwtAlgorithm,
+        pem: &str,
+        not_before: Option<DateTime<Utc>>,
+        retire_at: Option<DateTime<Utc>>,
+    ) -> Result<Self, JwtError> {
+        let (encoding_key, decoding_key, parameters) = match algorithm {
+            JwtAlgorithm::EdDSA => ed25519_keys(pem)?,
+            JwtAlgorithm::RS256 => rsa_keys(pem)?,
+        };
+
+        let jwk = Jwk {
+            common: CommonParameters {
+                public_key_use: Some(PublicKeyUse::Signature),
+                key_algorithm: Some(match algorithm {
+                    JwtAlgorithm::EdDSA => KeyAlgorithm::EdDSA,
+                    JwtAlgorithm::RS256 => KeyAlgorithm::RS256,
+                }),
+                key_id: Some(kid.to_string()),
+                ..Default::default()
+            },
+            algorithm: parameters,
+        };
+
+        Ok(Self {
+            kid: kid.to_string(),
+            algorithm,
+            encoding_key,
+            decoding_key,
+            jwk,
+            not_before,
+            retire_at,
+        })
+    }
+
+    pub fn kid(&self) -> &str {
+        &self.kid
+    }
+
+    pub fn algorithm(&self) -> JwtAlgorithm {
+        self.algorithm
+    }
+
+    pub fn encoding_key(&self) -> &EncodingKey {
+        &self.encoding_key
+    }
+
+    pub fn decoding_key(&self) -> &DecodingKey {
+        &self.decoding_key
+    }
+
+    pub fn jwk(&self) -> &Jwk {
+        &self.jwk
+    }
+
+    pub fn not_before(&self) -> Option<&DateTime<Utc>> {
+        self.not_before.as_ref()
+    }
+
+    pub fn is_retired(&self, now: DateTime<Utc>) -> bool {
+        self.retire_at.is_some_and(|retire_at| retire_at <= now)
+    }
+
+    pub fn can_sign(&self, now: DateTime<Utc>) -> bool {
+        !self.is_retired(now) && self.not_before.is_none_or(|not_before| not_before <= now)
+    }
+}
+
+fn encode_base64url(bytes: &[u8]) -> String {
+    general_purpose::URL_SAFE_NO_PAD.encode(bytes)
+}
+
+fn ed25519_keys(pem: &str) -> Result<(EncodingKey, DecodingKey, AlgorithmParameters), JwtError> {
+    let signing_key = ed25519_dalek::SigningKey::from_pkcs8_pem(pem)
+        .map_err(|e| JwtError::InvalidKey(e.to_string()))?;
+    let der = signing_key
+        .to_pkcs8_der()
+        .map_err(|e| JwtError::InvalidKey(e.to_string()))?;
+    let x = encode_base64url(signing_key.verifying_key().as_bytes());
+    let decoding_key =
+        DecodingKey::from_ed_components(&x).map_err(|e| JwtError::InvalidKey(e.to_string()))?;
+
+    Ok((
+        EncodingKey::from_ed_der(der.as_bytes()),
+        decoding_key,
+        AlgorithmParameters::OctetKeyPair(OctetKeyPairParameters {
+            key_type: OctetKeyPairType::OctetKeyPair,
+            curve: EllipticCurve::Ed25519,
+            x,
+        }),
+    ))
+}
+
+fn rsa_keys(pem: &str) -> Result<(EncodingKey, DecodingKey, AlgorithmParameters), JwtError> {
+    let private_key = rsa::RsaPrivateKey::from_pkcs8_pem(pem)
+        .or_else(|_| rsa::RsaPrivateKey::from_pkcs1_pem(pem))
+        .map_err(|e| JwtError::InvalidKey(e.to_string()))?;
+    let der = private_key
+        .to_pkcs1_der()
+        .map_err(|e| JwtError::InvalidKey(e.to_string()))?;
+    let n = private_key.n().to_bytes_be();
+    let e = private_key.e().to_bytes_be();
+
+    Ok((
+        EncodingKey::from_rsa_der(der.as_bytes()),
+        DecodingKey::from_rsa_raw_components(&n, &e),
+        AlgorithmParameters::RSA(RSAKeyParameters {
+            key_type: RSAKeyType::RSA,
+            n: encode_base64url(&n),
+            e: encode_base64url(&e),
+        }),
+    ))
+}
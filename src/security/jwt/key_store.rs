@@ -0,0 +1,132 @@
+use chrono::{DateTime, Utc};
+use jsonwebtoken::jwk::JwkSet;
+use mairie360_api_lib::env_manager::get_env_var;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::security::jwt::{JwtAlgorithm, JwtError, JwtKey};
+
+static KEY_STORE: OnceLock<KeyStore> = OnceLock::new();
+
+#[derive(Deserialize)]
+struct KeyManifest {
+    keys: Vec<KeyManifestEntry>,
+}
+
+#[derive(Deserialize)]
+struct KeyManifestEntry {
+    kid: String,
+    algorithm: JwtAlgorithm,
+    private_key: PathBuf,
+    not_before: Option<DateTime<Utc>>,
+    retire_at: Option<DateTime<Utc>>,
+}
+
+/**
+ * The set of keys Core signs access tokens with.
+ * Several keys can be loaded at once: the one that most recently reached its `not_before`
+ * signs, the others only verify until they are retired.
+ */
+pub struct KeyStore {
+    keys: Vec<JwtKey>,
+}
+
+impl KeyStore {
+    pub fn new(keys: Vec<JwtKey>) -> Result<Self, JwtError> {
+        if keys.is_empty() {
+            return Err(JwtError::Configuration("no key configured".to_string()));
+        }
+        for (index, key) in keys.iter().enumerate() {
+            if keys[..index].iter().any(|other| other.kid() == key.kid()) {
+                return Err(JwtError::Configuration(format!(
+                    "duplicate kid {}",
+                    key.kid()
+                )));
+            }
+        }
+        Ok(Self { keys })
+    }
+
+    /**
+     * Loads the JSON manifest listing the keys. Relative `private_key` paths are resolved
+     * from the manifest's directory.
+     */
+    pub fn from_manifest(path: &Path) -> Result<Self, JwtError> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| JwtError::Configuration(format!("{}: {}", path.display(), e)))?;
+        let manifest: KeyManifest = serde_json::from_str(&content)
+            .map_err(|e| JwtError::Configuration(format!("{}: {}", path.display(), e)))?;
+        let base = path.parent().unwrap_or_else(|| Path::new("."));
+
+        let keys = manifest
+            .keys
+            .into_iter()
+            .map(|entry| {
+                let pem_path = base.join(&entry.private_key);
+                let pem = std::fs::read_to_string(&pem_path).map_err(|e| {
+                    JwtError::Configuration(format!("{}: {}", pem_path.display(), e))
+                })?;
+                JwtKey::from_pem(
+                    &entry.kid,
+                    entry.algorithm,
+                    &pem,
+                    entry.not_before,
+                    entry.retire_at,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::new(keys)
+    }
+
+    /**
+     * Reads the manifest path from `JWT_KEYS_FILE`.
+     */
+    pub fn from_env() -> Result<Self, JwtError> {
+        let path = get_env_var("JWT_KEYS_FILE").ok_or_else(|| {
+            JwtError::Configuration("JWT_KEYS_FILE environment variable not set".to_string())
+        })?;
+        Self::from_manifest(Path::new(&path))
+    }
+
+    pub fn signing_key(&self, now: DateTime<Utc>) -> Result<&JwtKey, JwtError> {
+        self.keys
+            .iter()
+            .filter(|key| key.can_sign(now))
+            .max_by_key(|key| key.not_before().copied())
+            .ok_or(JwtError::NoSigningKey)
+    }
+
+    pub fn verification_key(&self, kid: &str, now: DateTime<Utc>) -> Result<&JwtKey, JwtError> {
+        self.keys
+            .iter()
+            .find(|key| key.kid() == kid && !key.is_retired(now))
+            .ok_or_else(|| JwtError::UnknownKey(kid.to_string()))
+    }
+
+    /**
+     * Public keys of every non-retired key, including keys scheduled for a future
+     * rotation so that verifiers already know them when they start signing.
+     */
+    pub fn jwks(&self, now: DateTime<Utc>) -> JwkSet {
+        JwkSet {
+            keys: self
+                .keys
+                .iter()
+                .filter(|key| !key.is_retired(now))
+                .map(|key| key.jwk().clone())
+                .collect(),
+        }
+    }
+}
+
+/**
+ * Process-wide key store, loaded from `JWT_KEYS_FILE` on first use.
+ */
+pub fn key_store() -> Result<&'static KeyStore, JwtError> {
+    if let Some(store) = KEY_STORE.get() {
+        return Ok(store);
+    }
+    let store = KeyStore::from_env()?;
+    Ok(KEY_STORE.get_or_init(|| store))
+}
@@ -0,0 +1,18 @@
+mod claims;
+pub use claims::Claims;
+
+mod error;
+pub use error::JwtError;
+
+mod key;
+pub use key::{JwtAlgorithm, JwtKey};
+
+mod key_store;
+pub use key_store::{key_store, KeyStore};
+
+mod token;
+pub use token::{
+    check_access_token, check_jwt_validity, decode_jwt, encode_jwt, generate_client_jwt,
+    generate_impersonation_jwt, generate_jwt, generate_module_jwt, is_session_denied, jwt_timeout,
+    session_id_from_request, AccessTokenSubject,
+};
@@ -0,0 +1,261 @@
+use actix_web::HttpRequest;
+use chrono::{DateTime, Utc};
+use jsonwebtoken::{decode, decode_header, encode, Header, Validation};
+use mairie360_api_lib::database::queries::does_user_exist_by_id_query;
+use mairie360_api_lib::database::query_views::DoesUserExistByIdQueryView;
+use mairie360_api_lib::jwt_manager::{get_jwt_from_request, get_jwt_timeout, JWTCheckError};
+use mairie360_api_lib::pool::AppState;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::redis::handle_is_session_denied;
+use crate::security::impersonation::Impersonation;
+use crate::security::jwt::{key_store, Claims, JwtError, KeyStore};
+use crate::security::service_account::AuthenticatedModule;
+
+/**
+ * Signs `claims` with the store's current signing key, advertising its `kid` in the header.
+ */
+pub fn encode_jwt<T: Serialize>(
+    store: &KeyStore,
+    claims: &T,
+    now: DateTime<Utc>,
+) -> Result<String, JwtError> {
+    let key = store.signing_key(now)?;
+    let mut header = Header::new(key.algorithm().algorithm());
+    header.kid = Some(key.kid().to_string());
+    encode(&header, claims, key.encoding_key()).map_err(|e| JwtError::Encoding(e.to_string()))
+}
+
+/**
+ * Verifies the signature with the key named by the token's `kid` and checks the expiry.
+ */
+pub fn decode_jwt(store: &KeyStore, token: &str, now: DateTime<Utc>) -> Result<Claims, JwtError> {
+    let header = decode_header(token).map_err(|e| JwtError::InvalidToken(e.to_string()))?;
+    let kid = header
+        .kid
+        .ok_or_else(|| JwtError::InvalidToken("missing kid".to_string()))?;
+    let key = store.verification_key(&kid, now)?;
+
+    let mut validation = Validation::new(key.algorithm().algorithm());
+    // L'expiration est vérifiée à la main pour rester cohérente avec `now`
+    validation.validate_exp = false;
+    let claims = decode::<Claims>(token, key.decoding_key(), &validation)
+        .map_err(|e| JwtError::InvalidToken(e.to_string()))?
+        .claims;
+
+    if claims.get_expiration() <= now.timestamp() as u64 {
+        return Err(JwtError::ExpiredToken);
+    }
+    Ok(claims)
+}
+
+/**
+ * Lifetime of access tokens in seconds, read from `JWT_TIMEOUT`.
+ */
+pub fn jwt_timeout() -> Result<u64, JwtError> {
+    get_jwt_timeout()
+        .map(|timeout| timeout as u64)
+        .map_err(|e| JwtError::Configuration(format!("{:?}", e)))
+}
+
+/**
+ * Issues an access token for `user_id` bound to `session_id`, valid for `JWT_TIMEOUT` seconds.
+ */
+pub fn generate_jwt(user_id: &str, session_id: &Uuid) -> Result<String, JwtError> {
+    let now = Utc::now();
+    let issued_at = now.timestamp() as u64;
+    let claims = Claims::new(user_id, issued_at, issued_at + jwt_timeout()?)
+        .with_session_id(&session_id.to_string());
+    encode_jwt(key_store()?, &claims, now)
+}
+
+/**
+ * Issues the access token an OpenID Connect client gets for `user_id`, valid for
+ * `JWT_TIMEOUT` seconds. It stays bound to the Core session the user approved from.
+ */
+pub fn generate_client_jwt(
+    user_id: &str,
+    session_id: Option<&Uuid>,
+    client_id: &str,
+    scopes: &[String],
+) -> Result<String, JwtError> {
+    let now = Utc::now();
+    let issued_at = now.timestamp() as u64;
+    let mut claims =
+        Claims::new(user_id, issued_at, issued_at + jwt_timeout()?).with_client(client_id, scopes);
+    if let Some(session_id) = session_id {
+        claims = claims.with_session_id(&session_id.to_string());
+    }
+    encode_jwt(key_store()?, &claims, now)
+}
+
+/**
+ * Issues a module token for the client credentials grant, valid for `ttl` seconds.
+ */
+pub fn generate_module_jwt(
+    client_id: &str,
+    module_id: i32,
+    scopes: &[String],
+    ttl: u64,
+) -> Result<String, JwtError> {
+    let now = Utc::now();
+    let issued_at = now.timestamp() as u64;
+    let claims = Claims::for_module(client_id, module_id, scopes, issued_at, issued_at + ttl);
+    encode_jwt(key_store()?, &claims, now)
+}
+
+/**
+ * Issues the token of an impersonation session, valid for `ttl` seconds and never refreshed.
+ * Its `sid` is the impersonation session, so ending it denylists the token.
+ */
+pub fn generate_impersonation_jwt(
+    user_id: u64,
+    admin_id: u64,
+    impersonation_id: &Uuid,
+    ttl: u64,
+) -> Result<String, JwtError> {
+    let now = Utc::now();
+    let issued_at = now.timestamp() as u64;
+    let claims = Claims::new(&user_id.to_string(), issued_at, issued_at + ttl)
+        .with_session_id(&impersonation_id.to_string())
+        .with_actor(&admin_id.to_string());
+    encode_jwt(key_store()?, &claims, now)
+}
+
+/**
+ * Session bound to the access token of a request the middleware already let through.
+ */
+pub fn session_id_from_request(req: &HttpRequest) -> Option<Uuid> {
+    let jwt = get_jwt_from_request(req)?;
+    let claims = decode_jwt(key_store().ok()?, &jwt, Utc::now()).ok()?;
+    Uuid::parse_str(claims.get_session_id()?).ok()
+}
+
+/**
+ * Fails closed: without Redis a revoked session cannot be told apart from a live one.
+ */
+pub async fn is_session_denied(state: &AppState, session_id: &str) -> Result<bool, JWTCheckError> {
+    let conn = state
+        .get_redis_conn()
+        .await
+        .ok_or(JWTCheckError::DatabaseError)?;
+    handle_is_session_denied(conn, session_id)
+        .await
+        .map_err(|e| {
+            eprintln!("Redis Error: {}", e);
+            JWTCheckError::DatabaseError
+        })
+}
+
+/**
+ * Caller identified by a valid access token.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccessTokenSubject {
+    User(u64),
+    Module(AuthenticatedModule),
+    Impersonation(Impersonation),
+}
+
+/**
+ * Counterpart of the shared library check, for tokens signed by Core's own keys.
+ * Tokens whose session has been revoked are refused through the Redis denylist.
+ * Module tokens carry no session and live a few minutes, so they are trusted until expiry.
+ */
+pub async fn check_access_token(
+    jwt: &str,
+    state: &AppState,
+) -> Result<AccessTokenSubject, JWTCheckError> {
+    if jwt.is_empty() {
+        return Err(JWTCheckError::NoTokenProvided);
+    }
+    let store = key_store().map_err(|e| {
+        eprintln!("JWT Key Error: {}", e);
+        JWTCheckError::InvalidToken
+    })?;
+
+    let claims = match decode_jwt(store, jwt, Utc::now()) {
+        Ok(claims) => claims,
+        Err(JwtError::ExpiredToken) => return Err(JWTCheckError::ExpiredToken),
+        Err(e) => {
+            eprintln!("JWT Error: {}", e);
+            return Err(JWTCheckError::InvalidToken);
+        }
+    };
+
+    if claims.is_module_token() {
+        return match (claims.get_client_id(), claims.get_module_id()) {
+            (Some(client_id), Some(module_id)) => {
+                let scopes = claims
+                    .get_scope()
+                    .unwrap_or_default()
+                    .split_whitespace()
+                    .map(str::to_string)
+                    .collect();
+                Ok(AccessTokenSubject::Module(AuthenticatedModule::new(
+                    client_id, module_id, scopes,
+                )))
+            }
+            _ => Err(JWTCheckError::InvalidToken),
+        };
+    }
+    // Jeton délivré à un client OpenID Connect : il ne vaut que pour userinfo
+    if claims.get_client_id().is_some() {
+        return Err(JWTCheckError::InvalidToken);
+    }
+
+    if let Some(session_id) = claims.get_session_id() {
+        if is_session_denied(state, session_id).await? {
+            return Err(JWTCheckError::InvalidToken);
+        }
+    }
+
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => return Err(JWTCheckError::DatabaseError),
+    };
+
+    let user_id: u64 = claims
+        .get_user_id()
+        .parse()
+        .map_err(|_| JWTCheckError::InvalidToken)?;
+
+    let impersonation = match claims.get_actor_id() {
+        Some(admin_id) => {
+            let admin_id: u64 = admin_id.parse().map_err(|_| JWTCheckError::InvalidToken)?;
+            let id = claims
+                .get_session_id()
+                .and_then(|sid| Uuid::parse_str(sid).ok())
+                .ok_or(JWTCheckError::InvalidToken)?;
+            Some(Impersonation::new(id, user_id, admin_id))
+        }
+        None => None,
+    };
+
+    match does_user_exist_by_id_query(DoesUserExistByIdQueryView::new(user_id), pool).await {
+        Ok(true) => Ok(match impersonation {
+            Some(impersonation) => AccessTokenSubject::Impersonation(impersonation),
+            None => AccessTokenSubject::User(user_id),
+        }),
+        Ok(false) => Err(JWTCheckError::UnknownUser),
+        Err(e) => {
+            eprintln!("Database query error: {}", e);
+            Err(JWTCheckError::DatabaseError)
+        }
+    }
+}
+
+/**
+ * Same check restricted to tokens of the user themselves. Returns the user id carried by a
+ * valid token. Impersonation tokens are refused: they must not reach admin routes or approve
+ * anything on the user's behalf.
+ */
+pub async fn check_jwt_validity(jwt: &str, state: &AppState) -> Result<u64, JWTCheckError> {
+    match check_access_token(jwt, state).await? {
+        AccessTokenSubject::User(user_id) => Ok(user_id),
+        AccessTokenSubject::Module(_) | AccessTokenSubject::Impersonation(_) => {
+            Err(JWTCheckError::InvalidToken)
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use mairie360_api_lib::env_manager::get_env_var;
+
+const DEFAULT_MAX_ACCOUNT_FAILURES: u64 = 5;
+const DEFAULT_MAX_IP_FAILURES: u64 = 20;
+const DEFAULT_LOCKOUT_SECONDS: u64 = 900;
+const DEFAULT_BASE_DELAY_SECONDS: u64 = 1;
+
+/**
+ * Brute-force policy applied to `/auth/login`.
+ * Failures are counted per account and per IP over `lockout_seconds`; each failure
+ * doubles the wait before the next attempt, and reaching the limit locks for the
+ * whole window. Values are read from `LOGIN_MAX_ACCOUNT_FAILURES`,
+ * `LOGIN_MAX_IP_FAILURES`, `LOGIN_LOCKOUT_SECONDS` and `LOGIN_BASE_DELAY_SECONDS`.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoginThrottleConfig {
+    max_account_failures: u64,
+    max_ip_failures: u64,
+    lockout_seconds: u64,
+    base_delay_seconds: u64,
+}
+
+fn env_u64(name: &str, default: u64) -> u64 {
+    get_env_var(name)
+        .and_then(|value| value.parse::<u64>().ok())
+        .unwrap_or(default)
+}
+
+impl LoginThrottleConfig {
+    pub fn new(
+        max_account_failures: u64,
+        max_ip_failures: u64,
+        lockout_seconds: u64,
+        base_delay_seconds: u64,
+    ) -> Self {
+        Self {
+            max_account_failures: max_account_failures.max(1),
+            max_ip_failures: max_ip_failures.max(1),
+            lockout_seconds,
+            base_delay_seconds,
+        }
+    }
+
+    pub fn from_env() -> Self {
+        Self::new(
+            env_u64("LOGIN_MAX_ACCOUNT_FAILURES", DEFAULT_MAX_ACCOUNT_FAILURES),
+            env_u64("LOGIN_MAX_IP_FAILURES", DEFAULT_MAX_IP_FAILURES),
+            env_u64("LOGIN_LOCKOUT_SECONDS", DEFAULT_LOCKOUT_SECONDS),
+            env_u64("LOGIN_BASE_DELAY_SECONDS", DEFAULT_BASE_DELAY_SECONDS),
+        )
+    }
+
+    pub fn max_account_failures(&self) -> u64 {
+        self.max_account_failures
+    }
+
+    pub fn max_ip_failures(&self) -> u64 {
+        self.max_ip_failures
+    }
+
+    pub fn lockout_seconds(&self) -> u64 {
+        self.lockout_seconds
+    }
+
+    /**
+     * Seconds to wait after the `failures`-th consecutive failure: 0 for the first one,
+     * then base, 2 * base, 4 * base..., never longer than the lockout window.
+     */
+    pub fn backoff_seconds(&self, failures: u64) -> u64 {
+        if failures <= 1 {
+            return 0;
+        }
+        let exponent = (failures - 2).min(32) as u32;
+        self.base_delay_seconds
+            .saturating_mul(1u64 << exponent)
+            .min(self.lockout_seconds)
+    }
+}
+
+impl Default for LoginThrottleConfig {
+    fn default() -> Self {
+        Self::new(
+            DEFAULT_MAX_ACCOUNT_FAILURES,
+            DEFAULT_MAX_IP_FAILURES,
+            DEFAULT_LOCKOUT_SECONDS,
+            DEFAULT_BASE_DELAY_SECONDS,
+        )
+    }
+}
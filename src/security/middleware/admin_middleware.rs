@@ -0,0 +1,132 @@
+use actix_web::{
+    body::EitherBody,
+    dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+    Error, HttpMessage, HttpResponse,
+};
+use futures_util::future::LocalBoxFuture;
+use mairie360_api_lib::database::queries::is_admin_query;
+use mairie360_api_lib::database::query_views::IsAdminQueryView;
+use mairie360_api_lib::jwt_manager::get_jwt_from_request;
+use mairie360_api_lib::pool::AppState;
+use mairie360_api_lib::security::AuthenticatedUser;
+use std::future::{ready, Ready};
+use std::rc::Rc;
+
+use super::{is_admin_path, jwt_error_response};
+use crate::security::jwt::check_jwt_validity;
+use crate::security::personal_access_token::PersonalAccessToken;
+
+/**
+ * Restricts `/api/v{n}/admin` routes to administrators, validating tokens with Core's keys.
+ */
+pub struct AdminMiddleware;
+
+impl<S, B> Transform<S, ServiceRequest> for AdminMiddleware
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type InitError = ();
+    type Transform = AdminMiddlewareService<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(AdminMiddlewareService {
+            service: Rc::new(service),
+        }))
+    }
+}
+
+pub struct AdminMiddlewareService<S> {
+    service: Rc<S>,
+}
+
+impl<S, B> Service<ServiceRequest> for AdminMiddlewareService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let svc = self.service.clone();
+        let state = req.app_data::<actix_web::web::Data<AppState>>().cloned();
+
+        if !is_admin_path(req.path()) {
+            return Box::pin(async move {
+                let res = svc.call(req).await?;
+                Ok(res.map_into_left_body())
+            });
+        }
+
+        Box::pin(async move {
+            let (state, pool) = match state {
+                Some(state) => match state.db_pool.clone() {
+                    Some(pool) => (state, pool),
+                    None => {
+                        let res = HttpResponse::InternalServerError()
+                            .body("DB Pool missing")
+                            .map_into_right_body();
+                        return Ok(req.into_response(res));
+                    }
+                },
+                None => {
+                    let res = HttpResponse::InternalServerError()
+                        .body("DB Pool missing")
+                        .map_into_right_body();
+                    return Ok(req.into_response(res));
+                }
+            };
+
+            // Jeton personnel déjà validé, scope admin compris, par JwtMiddleware
+            let token_user = req
+                .extensions()
+                .get::<PersonalAccessToken>()
+                .map(|token| token.user_id());
+            let user_id = match token_user {
+                Some(user_id) => user_id,
+                None => {
+                    let jwt = get_jwt_from_request(req.request()).unwrap_or_default();
+                    match check_jwt_validity(&jwt, &state).await {
+                        Ok(user_id) => user_id,
+                        Err(error) => {
+                            return Ok(
+                                req.into_response(jwt_error_response(error).map_into_right_body())
+                            )
+                        }
+                    }
+                }
+            };
+
+            match is_admin_query(IsAdminQueryView::new(user_id), pool).await {
+                Ok(true) => {
+                    req.extensions_mut()
+                        .insert(AuthenticatedUser { id: user_id });
+                    let res = svc.call(req).await?;
+                    Ok(res.map_into_left_body())
+                }
+                Ok(false) => {
+                    let response = HttpResponse::Forbidden()
+                        .body("Forbidden: User is not an admin.")
+                        .map_into_right_body();
+                    Ok(req.into_response(response))
+                }
+                Err(e) => {
+                    eprintln!("Database query error: {}", e);
+                    let response = HttpResponse::InternalServerError()
+                        .body("Internal server error: Database not initialized.")
+                        .map_into_right_body();
+                    Ok(req.into_response(response))
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,143 @@
+use actix_web::{
+    body::EitherBody,
+    dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+    Error, HttpMessage, HttpResponse,
+};
+use futures_util::future::LocalBoxFuture;
+use mairie360_api_lib::jwt_manager::get_jwt_from_request;
+use mairie360_api_lib::pool::AppState;
+use mairie360_api_lib::security::AuthenticatedUser;
+use std::future::{ready, Ready};
+use std::rc::Rc;
+
+use super::{
+    check_personal_access_token, jwt_error_response, personal_access_token_from_request,
+    record_impersonated_request,
+};
+use crate::security::jwt::{check_access_token, AccessTokenSubject};
+
+/**
+ * Checks the access token of every request outside the public routes and exposes the
+ * caller as an `AuthenticatedUser`. Replaces the shared library middleware, which only
+ * knows HS256 tokens signed with `JWT_SECRET`. OAuth and OpenID Connect endpoints
+ * authenticate the caller themselves. Scripts may send
+ * `Authorization: Token <personal access token>` instead.
+ * Module tokens expose an `AuthenticatedModule` and no user, so user routes refuse them.
+ * Impersonation tokens expose the user along with an `Impersonation`, once the request is
+ * written to the impersonation audit log.
+ */
+pub struct JwtMiddleware;
+
+impl<S, B> Transform<S, ServiceRequest> for JwtMiddleware
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type InitError = ();
+    type Transform = JwtMiddlewareService<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(JwtMiddlewareService {
+            service: Rc::new(service),
+        }))
+    }
+}
+
+pub struct JwtMiddlewareService<S> {
+    service: Rc<S>,
+}
+
+impl<S, B> Service<ServiceRequest> for JwtMiddlewareService<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let svc = self.service.clone();
+        let state = req.app_data::<actix_web::web::Data<AppState>>().cloned();
+
+        let path = req.path();
+        if path == "/"
+            || path.starts_with("/swagger-ui")
+            || path.starts_with("/api-docs")
+            || path.contains("/auth")
+            || path.contains("/oauth/")
+            || path.contains("/oidc/")
+        {
+            return Box::pin(async move {
+                let res = svc.call(req).await?;
+                Ok(res.map_into_left_body())
+            });
+        }
+
+        Box::pin(async move {
+            let state = match state {
+                Some(state) if state.db_pool.is_some() => state,
+                _ => {
+                    let res = HttpResponse::InternalServerError()
+                        .body("DB Pool missing")
+                        .map_into_right_body();
+                    return Ok(req.into_response(res));
+                }
+            };
+
+            if let Some(token) = personal_access_token_from_request(&req) {
+                return match check_personal_access_token(&token, &req, &state).await {
+                    Ok(token) => {
+                        req.extensions_mut().insert(AuthenticatedUser {
+                            id: token.user_id(),
+                        });
+                        req.extensions_mut().insert(token);
+                        let res = svc.call(req).await?;
+                        Ok(res.map_into_left_body())
+                    }
+                    Err(response) => Ok(req.into_response(response.map_into_right_body())),
+                };
+            }
+
+            let jwt = get_jwt_from_request(req.request()).unwrap_or_default();
+
+            match check_access_token(&jwt, &state).await {
+                Ok(AccessTokenSubject::User(user_id)) => {
+                    req.extensions_mut()
+                        .insert(AuthenticatedUser { id: user_id });
+                    let res = svc.call(req).await?;
+                    Ok(res.map_into_left_body())
+                }
+                Ok(AccessTokenSubject::Module(module)) => {
+                    req.extensions_mut().insert(module);
+                    let res = svc.call(req).await?;
+                    Ok(res.map_into_left_body())
+                }
+                Ok(AccessTokenSubject::Impersonation(impersonation)) => {
+                    // Aucune requête sans trace dans le journal d'audit
+                    if let Err(response) =
+                        record_impersonated_request(&impersonation, &req, &state).await
+                    {
+                        return Ok(req.into_response(response.map_into_right_body()));
+                    }
+                    req.extensions_mut().insert(AuthenticatedUser {
+                        id: impersonation.user_id(),
+                    });
+                    req.extensions_mut().insert(impersonation);
+                    let res = svc.call(req).await?;
+                    Ok(res.map_into_left_body())
+                }
+                Err(error) => {
+                    Ok(req.into_response(jwt_error_response(error).map_into_right_body()))
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,133 @@
+mod admin_middleware;
+pub use admin_middleware::AdminMiddleware;
+
+mod auth_middleware;
+pub use auth_middleware::JwtMiddleware;
+
+use actix_web::dev::ServiceRequest;
+use actix_web::HttpResponse;
+use mairie360_api_lib::jwt_manager::JWTCheckError;
+use mairie360_api_lib::pool::AppState;
+
+use crate::database::impersonations::record_impersonated_request::{
+    record_impersonated_request_query, RecordImpersonatedRequestQueryView,
+};
+use crate::database::personal_access_tokens::use_personal_access_token::{
+    use_personal_access_token_query, UsePersonalAccessTokenQueryView,
+};
+use crate::security::impersonation::Impersonation;
+use crate::security::personal_access_token::{
+    parse_token_authorization, PersonalAccessToken, PersonalAccessTokenScope,
+};
+use crate::security::token::hash_token;
+
+/**
+ * Matches paths containing `/api/v<digits>/admin`.
+ */
+fn is_admin_path(path: &str) -> bool {
+    let segments: Vec<&str> = path.split('/').collect();
+    segments.windows(3).any(|window| {
+        window[0] == "api"
+            && window[1].len() > 1
+            && window[1].starts_with('v')
+            && window[1][1..].chars().all(|c| c.is_ascii_digit())
+            && window[2].starts_with("admin")
+    })
+}
+
+fn personal_access_token_from_request(req: &ServiceRequest) -> Option<String> {
+    req.headers()
+        .get("Authorization")
+        .and_then(|header| header.to_str().ok())
+        .and_then(parse_token_authorization)
+        .map(str::to_string)
+}
+
+/**
+ * Resolves `Authorization: Token ...` and checks its scopes against the request.
+ */
+async fn check_personal_access_token(
+    token: &str,
+    req: &ServiceRequest,
+    state: &AppState,
+) -> Result<PersonalAccessToken, HttpResponse> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => {
+            return Err(HttpResponse::InternalServerError()
+                .body("Internal server error: Database not initialized."))
+        }
+    };
+    let view = UsePersonalAccessTokenQueryView::new(&hash_token(token));
+    let token = match use_personal_access_token_query(view, pool).await {
+        Ok(Some(token)) => token,
+        Ok(None) => {
+            return Err(HttpResponse::Unauthorized().body("Unauthorized: Invalid access token."))
+        }
+        Err(e) => {
+            eprintln!("Database query error: {}", e);
+            return Err(HttpResponse::InternalServerError()
+                .body("Internal server error: Database not initialized."));
+        }
+    };
+
+    if !PersonalAccessTokenScope::allows(token.scopes(), req.method(), is_admin_path(req.path())) {
+        return Err(HttpResponse::Forbidden()
+            .body("Forbidden: Access token scopes do not allow this request."));
+    }
+    Ok(token)
+}
+
+/**
+ * Writes the request to the impersonation audit log. Refuses it once the impersonation is
+ * ended or expired, even if the token itself is still valid.
+ */
+async fn record_impersonated_request(
+    impersonation: &Impersonation,
+    req: &ServiceRequest,
+    state: &AppState,
+) -> Result<(), HttpResponse> {
+    let pool = match state.db_pool.clone() {
+        Some(pool) => pool,
+        None => {
+            return Err(HttpResponse::InternalServerError()
+                .body("Internal server error: Database not initialized."))
+        }
+    };
+    let view = RecordImpersonatedRequestQueryView::new(
+        *impersonation.id(),
+        req.method().as_str(),
+        req.path(),
+    );
+    match record_impersonated_request_query(view, pool).await {
+        Ok(true) => Ok(()),
+        Ok(false) => {
+            Err(HttpResponse::Unauthorized().body("Unauthorized: Impersonation has ended."))
+        }
+        Err(e) => {
+            eprintln!("Database query error: {}", e);
+            Err(HttpResponse::InternalServerError()
+                .body("Internal server error: Database not initialized."))
+        }
+    }
+}
+
+/**
+ * Same responses as the shared library middlewares, so clients see no difference.
+ */
+fn jwt_error_response(error: JWTCheckError) -> HttpResponse {
+    match error {
+        JWTCheckError::DatabaseError => HttpResponse::InternalServerError()
+            .body("Internal server error: Database not initialized."),
+        JWTCheckError::NoTokenProvided => {
+            HttpResponse::Unauthorized().body("Unauthorized: No JWT token provided.")
+        }
+        JWTCheckError::ExpiredToken => {
+            HttpResponse::Unauthorized().body("Unauthorized: JWT token is expired.")
+        }
+        JWTCheckError::InvalidToken => {
+            HttpResponse::Unauthorized().body("Unauthorized: Invalid JWT token.")
+        }
+        JWTCheckError::UnknownUser => HttpResponse::NotFound().body("User not found."),
+    }
+}
@@ -0,0 +1,18 @@
+pub mod client_credentials;
+pub mod device;
+pub mod email_token;
+pub mod federation;
+pub mod impersonation;
+pub mod jwt;
+pub mod login_throttle;
+pub mod middleware;
+pub mod oidc;
+pub mod password;
+pub mod password_policy;
+pub mod personal_access_token;
+pub mod recovery_codes;
+pub mod service_account;
+pub mod session_policy;
+pub mod token;
+pub mod totp;
+pub mod webauthn;
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+use crate::security::oidc::UserClaims;
+
+/**
+ * OpenID Connect ID token, signed with the same keys as access tokens. `aud` is the client
+ * the token was issued to, so a client must not accept ID tokens meant for another one.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdTokenClaims {
+    iss: String,
+    aud: String,
+    iat: u64,
+    exp: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    nonce: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    sid: Option<String>,
+    #[serde(flatten)]
+    user: UserClaims,
+}
+
+impl IdTokenClaims {
+    pub fn new(
+        issuer: &str,
+        client_id: &str,
+        user: UserClaims,
+        issued_at: u64,
+        expiration: u64,
+    ) -> Self {
+        Self {
+            iss: issuer.to_string(),
+            aud: client_id.to_string(),
+            iat: issued_at,
+            exp: expiration,
+            nonce: None,
+            sid: None,
+            user,
+        }
+    }
+
+    /**
+     * Echoes the `nonce` of the authorization request, which the client checks against replays.
+     */
+    pub fn with_nonce(mut self, nonce: Option<&str>) -> Self {
+        self.nonce = nonce.map(str::to_string);
+        self
+    }
+
+    pub fn with_session_id(mut self, session_id: Option<&str>) -> Self {
+        self.sid = session_id.map(str::to_string);
+        self
+    }
+
+    pub fn get_issuer(&self) -> &str {
+        &self.iss
+    }
+
+    pub fn get_audience(&self) -> &str {
+        &self.aud
+    }
+
+    pub fn get_nonce(&self) -> Option<&str> {
+        self.nonce.as_deref()
+    }
+
+    pub fn get_user(&self) -> &UserClaims {
+        &self.user
+    }
+}
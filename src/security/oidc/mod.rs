@@ -0,0 +1,16 @@
+mod id_token;
+pub use id_token::IdTokenClaims;
+
+mod pkce;
+pub use pkce::{code_challenge, verify_code_challenge, PKCE_METHOD};
+
+mod redirect;
+pub use redirect::{authorization_redirect, is_registered_redirect_uri};
+
+mod scope;
+pub use scope::{
+    parse_oidc_scopes, EMAIL_SCOPE, OPENID_SCOPE, PHONE_SCOPE, PROFILE_SCOPE, SUPPORTED_SCOPES,
+};
+
+mod user_claims;
+pub use user_claims::UserClaims;
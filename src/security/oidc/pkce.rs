@@ -0,0 +1,34 @@
+use base64::{engine::general_purpose, Engine as _};
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+
+/**
+ * Only `S256` is accepted: `plain` would hand the verifier to anyone who sees the request.
+ */
+pub const PKCE_METHOD: &str = "S256";
+
+fn is_valid_verifier(verifier: &str) -> bool {
+    (43..=128).contains(&verifier.len())
+        && verifier
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '_' | '~'))
+}
+
+/**
+ * RFC 7636: the challenge sent to `/authorize` is the base64url SHA-256 of the verifier
+ * sent with the code.
+ */
+pub fn code_challenge(verifier: &str) -> String {
+    general_purpose::URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()))
+}
+
+pub fn verify_code_challenge(verifier: &str, challenge: &str) -> bool {
+    if !is_valid_verifier(verifier) {
+        return false;
+    }
+    bool::from(
+        code_challenge(verifier)
+            .as_bytes()
+            .ct_eq(challenge.as_bytes()),
+    )
+}
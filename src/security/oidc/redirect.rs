@@ -0,0 +1,30 @@
+use url::Url;
+
+/**
+ * Redirect URIs are compared as registered, without any normalisation or prefix matching.
+ */
+pub fn is_registered_redirect_uri(redirect_uri: &str, registered: &[String]) -> bool {
+    registered.iter().any(|uri| uri == redirect_uri)
+}
+
+/**
+ * Appends `params` and the client's `state` to the query of `redirect_uri`, keeping the
+ * parameters the client registered it with.
+ */
+pub fn authorization_redirect(
+    redirect_uri: &str,
+    params: &[(&str, &str)],
+    state: Option<&str>,
+) -> Option<String> {
+    let mut url = Url::parse(redirect_uri).ok()?;
+    {
+        let mut query = url.query_pairs_mut();
+        for (name, value) in params {
+            query.append_pair(name, value);
+        }
+        if let Some(state) = state {
+            query.append_pair("state", state);
+        }
+    }
+    Some(url.to_string())
+}
@@ -0,0 +1,24 @@
+pub const OPENID_SCOPE: &str = "openid";
+pub const PROFILE_SCOPE: &str = "profile";
+pub const EMAIL_SCOPE: &str = "email";
+pub const PHONE_SCOPE: &str = "phone";
+
+pub const SUPPORTED_SCOPES: [&str; 4] = [OPENID_SCOPE, PROFILE_SCOPE, EMAIL_SCOPE, PHONE_SCOPE];
+
+/**
+ * Keeps the supported scopes of a space separated `scope` parameter, ignoring the others as
+ * OpenID Connect allows. Requests without `openid` are not OpenID Connect requests.
+ */
+pub fn parse_oidc_scopes(scope: &str) -> Option<Vec<String>> {
+    let mut scopes: Vec<String> = scope
+        .split_whitespace()
+        .filter(|scope| SUPPORTED_SCOPES.contains(scope))
+        .map(str::to_string)
+        .collect();
+    if !scopes.iter().any(|scope| scope == OPENID_SCOPE) {
+        return None;
+    }
+    scopes.sort();
+    scopes.dedup();
+    Some(scopes)
+}
@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::security::oidc::{EMAIL_SCOPE, PHONE_SCOPE, PROFILE_SCOPE};
+
+/**
+ * Standard claims about the user, limited to what the granted scopes allow. Served by
+ * `userinfo` and copied into ID tokens.
+ */
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct UserClaims {
+    sub: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    given_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    family_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    email: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    phone_number: Option<String>,
+}
+
+impl UserClaims {
+    pub fn new(
+        user_id: u64,
+        scopes: &[String],
+        first_name: &str,
+        last_name: &str,
+        email: &str,
+        phone_number: Option<&str>,
+    ) -> Self {
+        let granted = |scope: &str| scopes.iter().any(|s| s == scope);
+        let mut claims = Self {
+            sub: user_id.to_string(),
+            ..Self::default()
+        };
+        if granted(PROFILE_SCOPE) {
+            claims.name = Some(format!("{} {}", first_name, last_name));
+            claims.given_name = Some(first_name.to_string());
+            claims.family_name = Some(last_name.to_string());
+        }
+        if granted(EMAIL_SCOPE) {
+            claims.email = Some(email.to_string());
+        }
+        if granted(PHONE_SCOPE) {
+            claims.phone_number = phone_number.map(str::to_string);
+        }
+        claims
+    }
+
+    pub fn sub(&self) -> &str {
+        &self.sub
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn email(&self) -> Option<&str> {
+        self.email.as_deref()
+    }
+
+    pub fn phone_number(&self) -> Option<&str> {
+        self.phone_number.as_deref()
+    }
+}
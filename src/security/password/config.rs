@@ -0,0 +1,84 @@
+use argon2::{Algorithm, Argon2, Params, Version};
+use mairie360_api_lib::env_manager::get_env_var;
+
+use super::PasswordHashError;
+
+// Valeurs recommandées par l'OWASP pour Argon2id (19 MiB, 2 itérations, 1 thread)
+const DEFAULT_MEMORY_COST: u32 = 19_456;
+const DEFAULT_TIME_COST: u32 = 2;
+const DEFAULT_PARALLELISM: u32 = 1;
+
+/**
+ * Argon2id cost parameters, read from `ARGON2_MEMORY_COST` (KiB),
+ * `ARGON2_TIME_COST` and `ARGON2_PARALLELISM`.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PasswordHasherConfig {
+    memory_cost: u32,
+    time_cost: u32,
+    parallelism: u32,
+}
+
+fn get_u32_env_var(name: &str, default: u32) -> u32 {
+    get_env_var(name)
+        .and_then(|value| value.parse::<u32>().ok())
+        .unwrap_or(default)
+}
+
+impl PasswordHasherConfig {
+    pub fn new(memory_cost: u32, time_cost: u32, parallelism: u32) -> Self {
+        Self {
+            memory_cost,
+            time_cost,
+            parallelism,
+        }
+    }
+
+    pub fn from_env() -> Self {
+        Self::new(
+            get_u32_env_var("ARGON2_MEMORY_COST", DEFAULT_MEMORY_COST),
+            get_u32_env_var("ARGON2_TIME_COST", DEFAULT_TIME_COST),
+            get_u32_env_var("ARGON2_PARALLELISM", DEFAULT_PARALLELISM),
+        )
+    }
+
+    pub fn memory_cost(&self) -> u32 {
+        self.memory_cost
+    }
+
+    pub fn time_cost(&self) -> u32 {
+        self.time_cost
+    }
+
+    pub fn parallelism(&self) -> u32 {
+        self.parallelism
+    }
+
+    pub fn params(&self) -> Result<Params, PasswordHashError> {
+        Params::new(self.memory_cost, self.time_cost, self.parallelism, None)
+            .map_err(|e| PasswordHashError::InvalidConfiguration(e.to_string()))
+    }
+
+    pub fn hasher(&self) -> Result<Argon2<'static>, PasswordHashError> {
+        Ok(Argon2::new(
+            Algorithm::Argon2id,
+            Version::V0x13,
+            self.params()?,
+        ))
+    }
+
+    /**
+     * Returns true when a stored hash was produced with exactly these parameters.
+     */
+    pub fn matches(&self, params: &Params) -> bool {
+        params.m_cost() == self.memory_cost
+            && params.t_cost() == self.time_cost
+            && params.p_cost() == self.parallelism
+    }
+}
+
+impl Default for PasswordHasherConfig {
+    fn default() -> Self {
+        Self::new(DEFAULT_MEMORY_COST, DEFAULT_TIME_COST, DEFAULT_PARALLELISM)
+    }
+}
@@ -0,0 +1,22 @@
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PasswordHashError {
+    InvalidConfiguration(String),
+    HashingFailed(String),
+    TaskFailed,
+}
+
+impl Display for PasswordHashError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PasswordHashError::InvalidConfiguration(e) => {
+                write!(f, "Invalid password hasher configuration: {}", e)
+            }
+            PasswordHashError::HashingFailed(e) => write!(f, "Password hashing failed: {}", e),
+            PasswordHashError::TaskFailed => write!(f, "Password hashing task failed"),
+        }
+    }
+}
+
+impl std::error::Error for PasswordHashError {}
@@ -0,0 +1,29 @@
+use argon2::password_hash::{rand_core::OsRng, PasswordHasher, SaltString};
+
+use super::{PasswordHashError, PasswordHasherConfig};
+
+fn hash_password_sync(
+    password: &str,
+    config: PasswordHasherConfig,
+) -> Result<String, PasswordHashError> {
+    let salt = SaltString::generate(&mut OsRng);
+    let hash = config
+        .hasher()?
+        .hash_password(password.as_bytes(), &salt)
+        .map_err(|e| PasswordHashError::HashingFailed(e.to_string()))?;
+    Ok(hash.to_string())
+}
+
+/**
+ * Hashes a password with Argon2id and returns the PHC string to store in `users.password`.
+ * The work is done on the blocking thread pool so it never stalls the async executor.
+ */
+pub async fn hash_password(
+    password: &str,
+    config: PasswordHasherConfig,
+) -> Result<String, PasswordHashError> {
+    let password = password.to_string();
+    tokio::task::spawn_blocking(move || hash_password_sync(&password, config))
+        .await
+        .map_err(|_| PasswordHashError::TaskFailed)?
+}
@@ -0,0 +1,11 @@
+mod config;
+pub use config::PasswordHasherConfig;
+
+mod error;
+pub use error::PasswordHashError;
+
+mod hash;
+pub use hash::hash_password;
+
+mod verify;
+pub use verify::{verify_password, PasswordVerification};
@@ -0,0 +1,75 @@
+use argon2::password_hash::{PasswordHash, PasswordVerifier};
+use argon2::{Algorithm, Params};
+use subtle::ConstantTimeEq;
+
+use super::{PasswordHashError, PasswordHasherConfig};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PasswordVerification {
+    Invalid,
+    Valid,
+    /// The password matches but the stored value is plaintext or uses outdated parameters.
+    ValidNeedsRehash,
+}
+
+impl PasswordVerification {
+    pub fn is_valid(&self) -> bool {
+        !matches!(self, PasswordVerification::Invalid)
+    }
+}
+
+fn verify_legacy(password: &str, stored: &str) -> PasswordVerification {
+    // Les anciennes lignes stockent le mot de passe en clair dans une colonne CHAR(60)
+    if bool::from(password.as_bytes().ct_eq(stored.trim().as_bytes())) {
+        PasswordVerification::ValidNeedsRehash
+    } else {
+        PasswordVerification::Invalid
+    }
+}
+
+fn verify_password_sync(
+    password: &str,
+    stored: &str,
+    config: PasswordHasherConfig,
+) -> Result<PasswordVerification, PasswordHashError> {
+    let parsed = match PasswordHash::new(stored.trim()) {
+        Ok(parsed) => parsed,
+        Err(_) => return Ok(verify_legacy(password, stored)),
+    };
+
+    if config
+        .hasher()?
+        .verify_password(password.as_bytes(), &parsed)
+        .is_err()
+    {
+        return Ok(PasswordVerification::Invalid);
+    }
+
+    let up_to_date = parsed.algorithm == Algorithm::Argon2id.ident()
+        && Params::try_from(&parsed)
+            .map(|params| config.matches(&params))
+            .unwrap_or(false);
+
+    if up_to_date {
+        Ok(PasswordVerification::Valid)
+    } else {
+        Ok(PasswordVerification::ValidNeedsRehash)
+    }
+}
+
+/**
+ * Checks a password against the value stored in `users.password`.
+ * Values that are not PHC strings are treated as legacy plaintext so that
+ * existing rows keep working until they are re-hashed on the next login.
+ */
+pub async fn verify_password(
+    password: &str,
+    stored: &str,
+    config: PasswordHasherConfig,
+) -> Result<PasswordVerification, PasswordHashError> {
+    let password = password.to_string();
+    let stored = stored.to_string();
+    tokio::task::spawn_blocking(move || verify_password_sync(&password, &stored, config))
+        .await
+        .map_err(|_| PasswordHashError::TaskFailed)?
+}
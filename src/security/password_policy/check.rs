@@ -0,0 +1,61 @@
+use crate::security::password_policy::{PasswordPolicy, PasswordViolation};
+
+// En dessous de cette longueur un mot « personnel » (ex: initiales) rejetterait trop de mots de passe
+const MIN_PERSONAL_WORD_LENGTH: usize = 3;
+
+/**
+ * Words taken from the user's identity that must not appear in their password:
+ * first and last name, and the parts of the email's local part.
+ */
+pub fn personal_words(first_name: &str, last_name: &str, email: &str) -> Vec<String> {
+    let local_part = email.split('@').next().unwrap_or_default();
+    [first_name, last_name]
+        .into_iter()
+        .chain(local_part.split(['.', '_', '-', '+']))
+        .map(|word| word.trim().to_lowercase())
+        .filter(|word| word.chars().count() >= MIN_PERSONAL_WORD_LENGTH)
+        .collect()
+}
+
+impl PasswordPolicy {
+    /**
+     * Checks the rules that only need the password itself and the user's identity.
+     * History is checked separately since it needs the stored hashes.
+     */
+    pub fn check(&self, password: &str, personal_words: &[String]) -> Vec<PasswordViolation> {
+        let mut violations = Vec::new();
+        let lowered = password.to_lowercase();
+
+        if password.chars().count() < self.min_length {
+            violations.push(PasswordViolation::TooShort {
+                min_length: self.min_length,
+            });
+        }
+        if self.require_lowercase && !password.chars().any(char::is_lowercase) {
+            violations.push(PasswordViolation::MissingLowercase);
+        }
+        if self.require_uppercase && !password.chars().any(char::is_uppercase) {
+            violations.push(PasswordViolation::MissingUppercase);
+        }
+        if self.require_digit && !password.chars().any(|c| c.is_ascii_digit()) {
+            violations.push(PasswordViolation::MissingDigit);
+        }
+        if self.require_symbol && password.chars().all(char::is_alphanumeric) {
+            violations.push(PasswordViolation::MissingSymbol);
+        }
+        if self
+            .forbidden_words
+            .iter()
+            .any(|word| lowered.contains(word.as_str()))
+        {
+            violations.push(PasswordViolation::ContainsForbiddenWord);
+        }
+        if personal_words
+            .iter()
+            .any(|word| lowered.contains(word.as_str()))
+        {
+            violations.push(PasswordViolation::ContainsPersonalInformation);
+        }
+        violations
+    }
+}
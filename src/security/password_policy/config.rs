@@ -0,0 +1,122 @@
+use mairie360_api_lib::env_manager::get_env_var;
+
+const DEFAULT_MIN_LENGTH: usize = 12;
+const DEFAULT_HISTORY_SIZE: u64 = 5;
+const DEFAULT_FORBIDDEN_WORDS: &[&str] =
+    &["password", "motdepasse", "azerty", "qwerty", "mairie360"];
+
+/**
+ * Rules every new password must follow, whatever the path setting it.
+ * Read from `PASSWORD_MIN_LENGTH`, `PASSWORD_REQUIRE_LOWERCASE`, `PASSWORD_REQUIRE_UPPERCASE`,
+ * `PASSWORD_REQUIRE_DIGIT`, `PASSWORD_REQUIRE_SYMBOL`, `PASSWORD_FORBIDDEN_WORDS` (comma separated),
+ * `PASSWORD_HISTORY_SIZE` and `PASSWORD_MAX_AGE_DAYS` (0 disables expiry).
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordPolicy {
+    pub(super) min_length: usize,
+    pub(super) require_lowercase: bool,
+    pub(super) require_uppercase: bool,
+    pub(super) require_digit: bool,
+    pub(super) require_symbol: bool,
+    pub(super) forbidden_words: Vec<String>,
+    history_size: u64,
+    max_age_days: Option<u64>,
+}
+
+fn env_parse<T: std::str::FromStr>(name: &str) -> Option<T> {
+    get_env_var(name).and_then(|value| value.trim().parse::<T>().ok())
+}
+
+impl PasswordPolicy {
+    pub fn new(min_length: usize) -> Self {
+        Self {
+            min_length,
+            require_lowercase: false,
+            require_uppercase: false,
+            require_digit: false,
+            require_symbol: false,
+            forbidden_words: Vec::new(),
+            history_size: 0,
+            max_age_days: None,
+        }
+    }
+
+    pub fn with_character_classes(
+        mut self,
+        lowercase: bool,
+        uppercase: bool,
+        digit: bool,
+        symbol: bool,
+    ) -> Self {
+        self.require_lowercase = lowercase;
+        self.require_uppercase = uppercase;
+        self.require_digit = digit;
+        self.require_symbol = symbol;
+        self
+    }
+
+    pub fn with_forbidden_words(mut self, words: &[&str]) -> Self {
+        self.forbidden_words = words
+            .iter()
+            .map(|word| word.trim().to_lowercase())
+            .filter(|word| !word.is_empty())
+            .collect();
+        self
+    }
+
+    pub fn with_history_size(mut self, history_size: u64) -> Self {
+        self.history_size = history_size;
+        self
+    }
+
+    pub fn with_max_age_days(mut self, max_age_days: Option<u64>) -> Self {
+        self.max_age_days = max_age_days.filter(|days| *days > 0);
+        self
+    }
+
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        let forbidden_words = get_env_var("PASSWORD_FORBIDDEN_WORDS")
+            .map(|value| value.split(',').map(str::to_string).collect::<Vec<_>>())
+            .unwrap_or_else(|| default.forbidden_words.clone());
+        let forbidden_words: Vec<&str> = forbidden_words.iter().map(String::as_str).collect();
+
+        Self::new(env_parse("PASSWORD_MIN_LENGTH").unwrap_or(default.min_length))
+            .with_character_classes(
+                env_parse("PASSWORD_REQUIRE_LOWERCASE").unwrap_or(default.require_lowercase),
+                env_parse("PASSWORD_REQUIRE_UPPERCASE").unwrap_or(default.require_uppercase),
+                env_parse("PASSWORD_REQUIRE_DIGIT").unwrap_or(default.require_digit),
+                env_parse("PASSWORD_REQUIRE_SYMBOL").unwrap_or(default.require_symbol),
+            )
+            .with_forbidden_words(&forbidden_words)
+            .with_history_size(env_parse("PASSWORD_HISTORY_SIZE").unwrap_or(default.history_size))
+            .with_max_age_days(env_parse("PASSWORD_MAX_AGE_DAYS").or(default.max_age_days))
+    }
+
+    pub fn min_length(&self) -> usize {
+        self.min_length
+    }
+
+    /**
+     * Number of previous passwords a new one must differ from.
+     */
+    pub fn history_size(&self) -> u64 {
+        self.history_size
+    }
+
+    /**
+     * Days after which a password must be changed at the next login, None when it never expires.
+     */
+    pub fn max_age_days(&self) -> Option<u64> {
+        self.max_age_days
+    }
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        Self::new(DEFAULT_MIN_LENGTH)
+            .with_character_classes(true, true, true, false)
+            .with_forbidden_words(DEFAULT_FORBIDDEN_WORDS)
+            .with_history_size(DEFAULT_HISTORY_SIZE)
+    }
+}
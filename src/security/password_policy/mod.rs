@@ -0,0 +1,8 @@
+mod check;
+pub use check::personal_words;
+
+mod config;
+pub use config::PasswordPolicy;
+
+mod violation;
+pub use violation::PasswordViolation;
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/**
+ * One reason a password was refused, serialized with a stable `code` the front end can translate.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "code", rename_all = "snake_case")]
+pub enum PasswordViolation {
+    TooShort { min_length: usize },
+    MissingLowercase,
+    MissingUppercase,
+    MissingDigit,
+    MissingSymbol,
+    ContainsForbiddenWord,
+    ContainsPersonalInformation,
+    RecentlyUsed { history_size: u64 },
+}
+
+impl std::fmt::Display for PasswordViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PasswordViolation::TooShort { min_length } => {
+                write!(
+                    f,
+                    "Password must be at least {} characters long",
+                    min_length
+                )
+            }
+            PasswordViolation::MissingLowercase => {
+                write!(f, "Password must contain a lowercase letter")
+            }
+            PasswordViolation::MissingUppercase => {
+                write!(f, "Password must contain an uppercase letter")
+            }
+            PasswordViolation::MissingDigit => write!(f, "Password must contain a digit"),
+            PasswordViolation::MissingSymbol => write!(f, "Password must contain a symbol"),
+            PasswordViolation::ContainsForbiddenWord => {
+                write!(f, "Password contains a forbidden word")
+            }
+            PasswordViolation::ContainsPersonalInformation => {
+                write!(f, "Password must not contain your name or email")
+            }
+            PasswordViolation::RecentlyUsed { history_size } => write!(
+                f,
+                "Password must differ from the last {} passwords",
+                history_size
+            ),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::security::token::generate_token;
+
+/**
+ * Makes personal access tokens recognizable, e.g. by secret scanners.
+ */
+pub const PERSONAL_ACCESS_TOKEN_PREFIX: &str = "m360_pat_";
+
+pub fn generate_personal_access_token() -> String {
+    format!("{}{}", PERSONAL_ACCESS_TOKEN_PREFIX, generate_token())
+}
+
+/**
+ * Reads `Authorization: Token <personal access token>`, the scheme scripts use instead of
+ * `Bearer <jwt>`. Values without the token prefix are refused before any database lookup.
+ */
+pub fn parse_token_authorization(header: &str) -> Option<&str> {
+    let (scheme, token) = header.trim().split_once(' ')?;
+    if !scheme.eq_ignore_ascii_case("token") {
+        return None;
+    }
+    let token = token.trim();
+    if token.len() <= PERSONAL_ACCESS_TOKEN_PREFIX.len()
+        || !token.starts_with(PERSONAL_ACCESS_TOKEN_PREFIX)
+    {
+        return None;
+    }
+    Some(token)
+}
@@ -0,0 +1,10 @@
+mod authorization;
+pub use authorization::{
+    generate_personal_access_token, parse_token_authorization, PERSONAL_ACCESS_TOKEN_PREFIX,
+};
+
+mod scope;
+pub use scope::PersonalAccessTokenScope;
+
+mod token;
+pub use token::PersonalAccessToken;
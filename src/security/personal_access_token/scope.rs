@@ -0,0 +1,65 @@
+use actix_web::http::Method;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/**
+ * What a personal access token may do: `read` only allows safe methods, `write` any
+ * method, and `admin` is needed on top of them for the `/admin` routes.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PersonalAccessTokenScope {
+    Read,
+    Write,
+    Admin,
+}
+
+impl PersonalAccessTokenScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PersonalAccessTokenScope::Read => "read",
+            PersonalAccessTokenScope::Write => "write",
+            PersonalAccessTokenScope::Admin => "admin",
+        }
+    }
+
+    /**
+     * Unknown values are ignored, so a scope removed later simply stops granting anything.
+     */
+    pub fn parse_all(values: &[String]) -> Vec<PersonalAccessTokenScope> {
+        values
+            .iter()
+            .filter_map(|value| value.parse().ok())
+            .collect()
+    }
+
+    pub fn allows(scopes: &[PersonalAccessTokenScope], method: &Method, admin_route: bool) -> bool {
+        if admin_route && !scopes.contains(&PersonalAccessTokenScope::Admin) {
+            return false;
+        }
+        if scopes.contains(&PersonalAccessTokenScope::Write) {
+            return true;
+        }
+        let safe = matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS);
+        safe && scopes.contains(&PersonalAccessTokenScope::Read)
+    }
+}
+
+impl std::str::FromStr for PersonalAccessTokenScope {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "read" => Ok(PersonalAccessTokenScope::Read),
+            "write" => Ok(PersonalAccessTokenScope::Write),
+            "admin" => Ok(PersonalAccessTokenScope::Admin),
+            _ => Err(()),
+        }
+    }
+}
+
+impl std::fmt::Display for PersonalAccessTokenScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -0,0 +1,34 @@
+use crate::security::personal_access_token::PersonalAccessTokenScope;
+
+/**
+ * Stored in the request extensions, next to the `AuthenticatedUser`, when the caller
+ * authenticated with a personal access token rather than a session.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PersonalAccessToken {
+    id: i32,
+    user_id: u64,
+    scopes: Vec<PersonalAccessTokenScope>,
+}
+
+impl PersonalAccessToken {
+    pub fn new(id: i32, user_id: u64, scopes: Vec<PersonalAccessTokenScope>) -> Self {
+        Self {
+            id,
+            user_id,
+            scopes,
+        }
+    }
+
+    pub fn id(&self) -> i32 {
+        self.id
+    }
+
+    pub fn user_id(&self) -> u64 {
+        self.user_id
+    }
+
+    pub fn scopes(&self) -> &[PersonalAccessTokenScope] {
+        &self.scopes
+    }
+}
@@ -0,0 +1,34 @@
+use rand::fill;
+
+pub const RECOVERY_CODES_COUNT: usize = 10;
+
+// Sans 0/o, 1/l/i pour éviter les erreurs de recopie
+const ALPHABET: &[u8] = b"23456789abcdefghjkmnpqrstuvwxyz";
+const GROUP_LENGTH: usize = 5;
+
+fn random_char() -> char {
+    // Rejet des octets au-delà du dernier multiple de la taille de l'alphabet (pas de biais modulo)
+    let limit = 256 - (256 % ALPHABET.len());
+    loop {
+        let mut byte = [0u8; 1];
+        fill(&mut byte);
+        if (byte[0] as usize) < limit {
+            return ALPHABET[byte[0] as usize % ALPHABET.len()] as char;
+        }
+    }
+}
+
+fn generate_recovery_code() -> String {
+    let chars: String = (0..GROUP_LENGTH * 2).map(|_| random_char()).collect();
+    format!("{}-{}", &chars[..GROUP_LENGTH], &chars[GROUP_LENGTH..])
+}
+
+/**
+ * Generates a fresh set of one-time recovery codes formatted as `xxxxx-xxxxx`.
+ * They are shown to the user once; only `hash_recovery_code` digests are stored.
+ */
+pub fn generate_recovery_codes() -> Vec<String> {
+    (0..RECOVERY_CODES_COUNT)
+        .map(|_| generate_recovery_code())
+        .collect()
+}
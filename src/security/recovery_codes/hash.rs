@@ -0,0 +1,16 @@
+use crate::security::token::hash_token;
+
+fn normalize(code: &str) -> String {
+    code.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/**
+ * Hashes a recovery code for storage. Case, spaces and dashes are ignored
+ * so that `ABCDE-FGHJK` and `abcdefghjk` match the same row.
+ */
+pub fn hash_recovery_code(code: &str) -> String {
+    hash_token(&normalize(code))
+}
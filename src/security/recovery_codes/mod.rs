@@ -0,0 +1,5 @@
+mod generate;
+pub use generate::{generate_recovery_codes, RECOVERY_CODES_COUNT};
+
+mod hash;
+pub use hash::hash_recovery_code;
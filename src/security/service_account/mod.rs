@@ -0,0 +1,5 @@
+mod module;
+pub use module::AuthenticatedModule;
+
+mod scope;
+pub use scope::{grant_scopes, USERS_READ_SCOPE};
@@ -0,0 +1,55 @@
+use actix_web::HttpMessage;
+use actix_web::{dev::Payload, FromRequest, HttpRequest};
+use futures_util::future::{ready, Ready};
+
+/**
+ * Stored in the request extensions instead of an `AuthenticatedUser` when the caller is a
+ * module holding a client credentials token. Handlers that take it only serve modules.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthenticatedModule {
+    client_id: String,
+    module_id: i32,
+    scopes: Vec<String>,
+}
+
+impl AuthenticatedModule {
+    pub fn new(client_id: &str, module_id: i32, scopes: Vec<String>) -> Self {
+        Self {
+            client_id: client_id.to_string(),
+            module_id,
+            scopes,
+        }
+    }
+
+    pub fn client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    pub fn module_id(&self) -> i32 {
+        self.module_id
+    }
+
+    pub fn scopes(&self) -> &[String] {
+        &self.scopes
+    }
+
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes.iter().any(|s| s == scope)
+    }
+}
+
+impl FromRequest for AuthenticatedModule {
+    type Error = actix_web::Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        if let Some(module) = req.extensions().get::<AuthenticatedModule>() {
+            return ready(Ok(module.clone()));
+        }
+        // Un jeton utilisateur valide n'ouvre pas les routes réservées aux modules
+        ready(Err(actix_web::error::ErrorForbidden(
+            "Forbidden: Module token required.",
+        )))
+    }
+}
@@ -0,0 +1,21 @@
+/**
+ * Lets a module resolve user ids it holds into names and emails, in batches.
+ */
+pub const USERS_READ_SCOPE: &str = "users:read";
+
+/**
+ * Scopes granted for a client credentials request. Without a `scope` parameter the client
+ * gets everything it is allowed; asking for anything else fails the whole request.
+ */
+pub fn grant_scopes(requested: Option<&str>, allowed: &[String]) -> Option<Vec<String>> {
+    let mut scopes: Vec<String> = match requested {
+        Some(requested) => requested.split_whitespace().map(str::to_string).collect(),
+        None => allowed.to_vec(),
+    };
+    if scopes.is_empty() || scopes.iter().any(|scope| !allowed.contains(scope)) {
+        return None;
+    }
+    scopes.sort();
+    scopes.dedup();
+    Some(scopes)
+}
@@ -0,0 +1,30 @@
+use crate::security::session_policy::{SessionLimitAction, SessionPolicy};
+
+/**
+ * Decision taken for a new login, given the sessions the user already has.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionAdmission {
+    Allowed,
+    /// The oldest sessions have to be revoked first, this many of them.
+    EvictOldest(u64),
+    Refused,
+}
+
+impl SessionPolicy {
+    pub fn admit(&self, active_sessions: u64) -> SessionAdmission {
+        let max_sessions = match self.max_sessions() {
+            Some(max_sessions) => max_sessions as u64,
+            None => return SessionAdmission::Allowed,
+        };
+        if active_sessions < max_sessions {
+            return SessionAdmission::Allowed;
+        }
+        match self.limit_action() {
+            SessionLimitAction::EvictOldest => {
+                SessionAdmission::EvictOldest(active_sessions - max_sessions + 1)
+            }
+            SessionLimitAction::Refuse => SessionAdmission::Refused,
+        }
+    }
+}
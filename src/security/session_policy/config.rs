@@ -0,0 +1,142 @@
+use chrono::{DateTime, Duration, Utc};
+use mairie360_api_lib::env_manager::get_env_var;
+
+/**
+ * What happens to a login once the user already has `max_sessions` active sessions.
+ */
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SessionLimitAction {
+    #[default]
+    EvictOldest,
+    Refuse,
+}
+
+impl SessionLimitAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SessionLimitAction::EvictOldest => "evict_oldest",
+            SessionLimitAction::Refuse => "refuse",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim() {
+            "evict_oldest" => Some(SessionLimitAction::EvictOldest),
+            "refuse" => Some(SessionLimitAction::Refuse),
+            _ => None,
+        }
+    }
+}
+
+/**
+ * Limits applied to the sessions of a user.
+ * `max_sessions` caps the active sessions, `idle_timeout_minutes` expires a session that
+ * was not refreshed in time and `absolute_lifetime_minutes` bounds a login and all its
+ * refreshes; None means no limit. The instance-wide default is read from
+ * `SESSION_MAX_PER_USER`, `SESSION_LIMIT_ACTION` (`evict_oldest` or `refuse`),
+ * `SESSION_IDLE_TIMEOUT_MINUTES` and `SESSION_ABSOLUTE_LIFETIME_MINUTES`.
+ */
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionPolicy {
+    max_sessions: Option<u32>,
+    limit_action: SessionLimitAction,
+    idle_timeout_minutes: Option<u32>,
+    absolute_lifetime_minutes: Option<u32>,
+}
+
+fn env_limit(name: &str) -> Option<u32> {
+    get_env_var(name).and_then(|value| value.trim().parse::<u32>().ok())
+}
+
+// 0 n'a pas de sens pour une limite, on le traite comme une absence de limite
+fn non_zero(value: Option<u32>) -> Option<u32> {
+    value.filter(|value| *value > 0)
+}
+
+fn strictest(left: Option<u32>, right: Option<u32>) -> Option<u32> {
+    match (left, right) {
+        (Some(left), Some(right)) => Some(left.min(right)),
+        (left, right) => left.or(right),
+    }
+}
+
+impl SessionPolicy {
+    pub fn new(
+        max_sessions: Option<u32>,
+        limit_action: SessionLimitAction,
+        idle_timeout_minutes: Option<u32>,
+        absolute_lifetime_minutes: Option<u32>,
+    ) -> Self {
+        Self {
+            max_sessions: non_zero(max_sessions),
+            limit_action,
+            idle_timeout_minutes: non_zero(idle_timeout_minutes),
+            absolute_lifetime_minutes: non_zero(absolute_lifetime_minutes),
+        }
+    }
+
+    pub fn from_env() -> Self {
+        Self::new(
+            env_limit("SESSION_MAX_PER_USER"),
+            get_env_var("SESSION_LIMIT_ACTION")
+                .and_then(|value| SessionLimitAction::parse(&value))
+                .unwrap_or_default(),
+            env_limit("SESSION_IDLE_TIMEOUT_MINUTES"),
+            env_limit("SESSION_ABSOLUTE_LIFETIME_MINUTES"),
+        )
+    }
+
+    /**
+     * Policy of a user holding roles with the given policies: the smallest of each limit
+     * wins, and refusing wins over evicting. Users whose roles have no policy get `fallback`.
+     */
+    pub fn for_roles(policies: Vec<SessionPolicy>, fallback: SessionPolicy) -> Self {
+        policies
+            .into_iter()
+            .reduce(|left, right| left.merge(&right))
+            .unwrap_or(fallback)
+    }
+
+    fn merge(&self, other: &SessionPolicy) -> Self {
+        let limit_action = if self.limit_action == SessionLimitAction::Refuse
+            || other.limit_action == SessionLimitAction::Refuse
+        {
+            SessionLimitAction::Refuse
+        } else {
+            SessionLimitAction::EvictOldest
+        };
+        Self {
+            max_sessions: strictest(self.max_sessions, other.max_sessions),
+            limit_action,
+            idle_timeout_minutes: strictest(self.idle_timeout_minutes, other.idle_timeout_minutes),
+            absolute_lifetime_minutes: strictest(
+                self.absolute_lifetime_minutes,
+                other.absolute_lifetime_minutes,
+            ),
+        }
+    }
+
+    pub fn max_sessions(&self) -> Option<u32> {
+        self.max_sessions
+    }
+
+    pub fn limit_action(&self) -> SessionLimitAction {
+        self.limit_action
+    }
+
+    pub fn idle_timeout_minutes(&self) -> Option<u32> {
+        self.idle_timeout_minutes
+    }
+
+    pub fn absolute_lifetime_minutes(&self) -> Option<u32> {
+        self.absolute_lifetime_minutes
+    }
+
+    /**
+     * Date past which a session opened at `now`, and every session refreshed from it, ends.
+     */
+    pub fn absolute_expires_at(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.absolute_lifetime_minutes
+            .map(|minutes| now + Duration::minutes(minutes as i64))
+    }
+}
@@ -0,0 +1,5 @@
+mod admission;
+pub use admission::SessionAdmission;
+
+mod config;
+pub use config::{SessionLimitAction, SessionPolicy};
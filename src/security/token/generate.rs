@@ -0,0 +1,12 @@
+use base64::{engine::general_purpose, Engine as _};
+use rand::fill;
+
+/**
+ * Returns 32 bytes (256 bits) of secure randomness encoded as URL-safe base64,
+ * suitable for refresh tokens and one-time challenges.
+ */
+pub fn generate_token() -> String {
+    let mut buffer = [0u8; 32];
+    fill(&mut buffer);
+    general_purpose::URL_SAFE_NO_PAD.encode(buffer)
+}
@@ -0,0 +1,12 @@
+use sha2::{Digest, Sha256};
+
+/**
+ * SHA-256 digest of a high-entropy token, hex encoded.
+ * Only use it for random secrets: user-chosen values must go through `password::hash_password`.
+ */
+pub fn hash_token(token: &str) -> String {
+    Sha256::digest(token.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
@@ -0,0 +1,5 @@
+mod generate;
+pub use generate::generate_token;
+
+mod hash;
+pub use hash::hash_token;
@@ -0,0 +1,39 @@
+use mairie360_api_lib::env_manager::get_env_var;
+
+const DEFAULT_ISSUER: &str = "Mairie360";
+
+pub const TOTP_DIGITS: usize = 6;
+pub const TOTP_STEP: u64 = 30;
+// Nombre de pas tolérés avant/après le pas courant (décalage d'horloge du téléphone)
+pub const TOTP_SKEW: u64 = 1;
+
+/**
+ * TOTP settings shared by enrollment and verification.
+ * The issuer shown in authenticator apps is read from `MFA_ISSUER`.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TotpConfig {
+    issuer: String,
+}
+
+impl TotpConfig {
+    pub fn new(issuer: &str) -> Self {
+        Self {
+            issuer: issuer.to_string(),
+        }
+    }
+
+    pub fn from_env() -> Self {
+        Self::new(&get_env_var("MFA_ISSUER").unwrap_or_else(|| DEFAULT_ISSUER.to_string()))
+    }
+
+    pub fn issuer(&self) -> &str {
+        &self.issuer
+    }
+}
+
+impl Default for TotpConfig {
+    fn default() -> Self {
+        Self::new(DEFAULT_ISSUER)
+    }
+}
@@ -0,0 +1,20 @@
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TotpError {
+    InvalidSecret(String),
+    InvalidAccount(String),
+    ClockError,
+}
+
+impl Display for TotpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TotpError::InvalidSecret(e) => write!(f, "Invalid TOTP secret: {}", e),
+            TotpError::InvalidAccount(e) => write!(f, "Invalid TOTP account: {}", e),
+            TotpError::ClockError => write!(f, "System clock is before the UNIX epoch"),
+        }
+    }
+}
+
+impl std::error::Error for TotpError {}
@@ -0,0 +1,11 @@
+mod config;
+pub use config::TotpConfig;
+
+mod error;
+pub use error::TotpError;
+
+mod secret;
+pub use secret::{generate_totp_secret, totp_uri};
+
+mod verify;
+pub use verify::{current_time, is_totp_code, verify_totp_code};
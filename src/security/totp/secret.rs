@@ -0,0 +1,37 @@
+use rand::fill;
+use totp_rs::{Algorithm, Secret, TOTP};
+
+use super::config::{TOTP_DIGITS, TOTP_SKEW, TOTP_STEP};
+use super::{TotpConfig, TotpError};
+
+/**
+ * Generates a 160-bit TOTP secret (RFC 4226 recommended size), base32 encoded.
+ */
+pub fn generate_totp_secret() -> String {
+    let mut buffer = [0u8; 20];
+    fill(&mut buffer);
+    Secret::Raw(buffer.to_vec()).to_encoded().to_string()
+}
+
+pub(super) fn decode_secret(secret: &str) -> Result<Vec<u8>, TotpError> {
+    Secret::Encoded(secret.to_string())
+        .to_bytes()
+        .map_err(|e| TotpError::InvalidSecret(e.to_string()))
+}
+
+/**
+ * Builds the `otpauth://totp/...` URI that authenticator apps read from a QR code.
+ */
+pub fn totp_uri(secret: &str, account: &str, config: &TotpConfig) -> Result<String, TotpError> {
+    let totp = TOTP::new(
+        Algorithm::SHA1,
+        TOTP_DIGITS,
+        TOTP_SKEW as u8,
+        TOTP_STEP,
+        decode_secret(secret)?,
+        Some(config.issuer().to_string()),
+        account.to_string(),
+    )
+    .map_err(|e| TotpError::InvalidAccount(e.to_string()))?;
+    Ok(totp.get_url())
+}
@@ -0,0 +1,48 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+use subtle::ConstantTimeEq;
+use totp_rs::{Algorithm, TOTP};
+
+use super::config::{TOTP_DIGITS, TOTP_SKEW, TOTP_STEP};
+use super::secret::decode_secret;
+use super::TotpError;
+
+/**
+ * True when the input has the shape of a TOTP code (six digits).
+ */
+pub fn is_totp_code(code: &str) -> bool {
+    let code = code.trim();
+    code.len() == TOTP_DIGITS && code.chars().all(|c| c.is_ascii_digit())
+}
+
+/**
+ * Checks a code against the secret at `time` (seconds since the epoch).
+ * Returns the matched time step so callers can refuse to accept the same step twice.
+ */
+pub fn verify_totp_code(secret: &str, code: &str, time: u64) -> Result<Option<u64>, TotpError> {
+    let totp = TOTP::new_unchecked(
+        Algorithm::SHA1,
+        TOTP_DIGITS,
+        TOTP_SKEW as u8,
+        TOTP_STEP,
+        decode_secret(secret)?,
+        None,
+        String::new(),
+    );
+    let code = code.trim();
+    let current_step = time / TOTP_STEP;
+
+    for step in current_step.saturating_sub(TOTP_SKEW)..=current_step + TOTP_SKEW {
+        let expected = totp.generate(step * TOTP_STEP);
+        if bool::from(expected.as_bytes().ct_eq(code.as_bytes())) {
+            return Ok(Some(step));
+        }
+    }
+    Ok(None)
+}
+
+pub fn current_time() -> Result<u64, TotpError> {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .map_err(|_| TotpError::ClockError)
+}
@@ -0,0 +1,21 @@
+use ciborium::Value;
+
+use super::WebauthnError;
+
+/**
+ * Returns the `authData` of a CBOR attestation object.
+ * Attestation statements are not checked: registration options ask for `attestation: "none"`,
+ * so the authenticator model is not trusted for anything.
+ */
+pub(super) fn attestation_auth_data(attestation_object: &[u8]) -> Result<Vec<u8>, WebauthnError> {
+    let value: Value = ciborium::de::from_reader(attestation_object)
+        .map_err(|e| WebauthnError::InvalidAttestation(e.to_string()))?;
+    let map = value
+        .as_map()
+        .ok_or_else(|| WebauthnError::InvalidAttestation("not a CBOR map".to_string()))?;
+    map.iter()
+        .find(|(k, _)| k.as_text() == Some("authData"))
+        .and_then(|(_, v)| v.as_bytes())
+        .cloned()
+        .ok_or_else(|| WebauthnError::InvalidAttestation("missing authData".to_string()))
+}
@@ -0,0 +1,28 @@
+use sha2::{Digest, Sha256};
+
+use super::client_data::verify_client_data;
+use super::{AuthenticatorData, CosePublicKey, WebauthnConfig, WebauthnError};
+
+/**
+ * Verifies the response of `navigator.credentials.get()` (WebAuthn §7.2) against the stored
+ * COSE public key and returns the authenticator's new signature counter.
+ */
+pub fn verify_authentication(
+    config: &WebauthnConfig,
+    expected_challenge: &str,
+    client_data_json: &[u8],
+    authenticator_data: &[u8],
+    signature: &[u8],
+    public_key: &[u8],
+) -> Result<u32, WebauthnError> {
+    verify_client_data(client_data_json, "webauthn.get", expected_challenge, config)?;
+
+    let auth_data = AuthenticatorData::parse(authenticator_data)?;
+    auth_data.verify(config)?;
+
+    let mut signed = authenticator_data.to_vec();
+    signed.extend_from_slice(&Sha256::digest(client_data_json));
+    CosePublicKey::parse(public_key)?.verify(&signed, signature)?;
+
+    Ok(auth_data.sign_count())
+}
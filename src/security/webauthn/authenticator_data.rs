@@ -0,0 +1,105 @@
+use sha2::{Digest, Sha256};
+use std::io::Cursor;
+
+use super::{WebauthnConfig, WebauthnError};
+
+const FLAG_USER_PRESENT: u8 = 0x01;
+const FLAG_USER_VERIFIED: u8 = 0x04;
+const FLAG_ATTESTED_CREDENTIAL: u8 = 0x40;
+
+// rpIdHash (32) + flags (1) + signCount (4)
+const HEADER_LENGTH: usize = 37;
+const AAGUID_LENGTH: usize = 16;
+
+/**
+ * Binary authenticator data as described in WebAuthn §6.1.
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuthenticatorData {
+    rp_id_hash: Vec<u8>,
+    flags: u8,
+    sign_count: u32,
+    credential_id: Option<Vec<u8>>,
+    public_key: Option<Vec<u8>>,
+}
+
+fn invalid(message: &str) -> WebauthnError {
+    WebauthnError::InvalidAuthenticatorData(message.to_string())
+}
+
+impl AuthenticatorData {
+    pub fn parse(data: &[u8]) -> Result<Self, WebauthnError> {
+        if data.len() < HEADER_LENGTH {
+            return Err(invalid("too short"));
+        }
+        let flags = data[32];
+        let sign_count = u32::from_be_bytes([data[33], data[34], data[35], data[36]]);
+
+        let (credential_id, public_key) = if flags & FLAG_ATTESTED_CREDENTIAL != 0 {
+            let rest = &data[HEADER_LENGTH..];
+            if rest.len() < AAGUID_LENGTH + 2 {
+                return Err(invalid("truncated attested credential data"));
+            }
+            let id_length =
+                u16::from_be_bytes([rest[AAGUID_LENGTH], rest[AAGUID_LENGTH + 1]]) as usize;
+            let id_start = AAGUID_LENGTH + 2;
+            if rest.len() < id_start + id_length {
+                return Err(invalid("truncated credential id"));
+            }
+            let credential_id = rest[id_start..id_start + id_length].to_vec();
+
+            // La clé COSE n'a pas de longueur préfixée : on lit un item CBOR et on garde les octets consommés
+            let key_bytes = &rest[id_start + id_length..];
+            let mut cursor = Cursor::new(key_bytes);
+            let _: ciborium::Value = ciborium::de::from_reader(&mut cursor)
+                .map_err(|e| WebauthnError::InvalidPublicKey(e.to_string()))?;
+            let key_length = cursor.position() as usize;
+            (Some(credential_id), Some(key_bytes[..key_length].to_vec()))
+        } else {
+            (None, None)
+        };
+
+        Ok(Self {
+            rp_id_hash: data[..32].to_vec(),
+            flags,
+            sign_count,
+            credential_id,
+            public_key,
+        })
+    }
+
+    pub(super) fn verify(&self, config: &WebauthnConfig) -> Result<(), WebauthnError> {
+        let expected = Sha256::digest(config.rp_id().as_bytes());
+        if self.rp_id_hash[..] != expected[..] {
+            return Err(WebauthnError::RpIdMismatch);
+        }
+        if !self.user_present() {
+            return Err(WebauthnError::UserNotPresent);
+        }
+        // Une passkey remplace le mot de passe et le second facteur : un simple toucher ne suffit pas
+        if !self.user_verified() {
+            return Err(WebauthnError::UserNotVerified);
+        }
+        Ok(())
+    }
+
+    pub fn user_present(&self) -> bool {
+        self.flags & FLAG_USER_PRESENT != 0
+    }
+
+    pub fn user_verified(&self) -> bool {
+        self.flags & FLAG_USER_VERIFIED != 0
+    }
+
+    pub fn sign_count(&self) -> u32 {
+        self.sign_count
+    }
+
+    pub fn credential_id(&self) -> Option<&[u8]> {
+        self.credential_id.as_deref()
+    }
+
+    pub fn public_key(&self) -> Option<&[u8]> {
+        self.public_key.as_deref()
+    }
+}
@@ -0,0 +1,51 @@
+use base64::{engine::general_purpose, Engine as _};
+use serde::Deserialize;
+use subtle::ConstantTimeEq;
+
+use super::{WebauthnConfig, WebauthnError};
+
+#[derive(Deserialize)]
+struct CollectedClientData {
+    #[serde(rename = "type")]
+    ceremony: String,
+    challenge: String,
+    origin: String,
+}
+
+fn parse(client_data_json: &[u8]) -> Result<CollectedClientData, WebauthnError> {
+    serde_json::from_slice(client_data_json)
+        .map_err(|e| WebauthnError::InvalidClientData(e.to_string()))
+}
+
+/**
+ * Extracts the challenge the browser signed, so the matching server-side challenge can be looked up.
+ */
+pub fn client_data_challenge(client_data_json: &[u8]) -> Result<String, WebauthnError> {
+    Ok(parse(client_data_json)?.challenge)
+}
+
+pub(super) fn verify_client_data(
+    client_data_json: &[u8],
+    ceremony: &str,
+    expected_challenge: &str,
+    config: &WebauthnConfig,
+) -> Result<(), WebauthnError> {
+    let client_data = parse(client_data_json)?;
+    if client_data.ceremony != ceremony {
+        return Err(WebauthnError::TypeMismatch);
+    }
+    // Certains navigateurs ajoutent du padding base64, on compare les octets décodés
+    let received = general_purpose::URL_SAFE_NO_PAD
+        .decode(client_data.challenge.trim_end_matches('='))
+        .map_err(|e| WebauthnError::InvalidClientData(e.to_string()))?;
+    let expected = general_purpose::URL_SAFE_NO_PAD
+        .decode(expected_challenge)
+        .map_err(|e| WebauthnError::InvalidClientData(e.to_string()))?;
+    if !bool::from(received.ct_eq(&expected)) {
+        return Err(WebauthnError::ChallengeMismatch);
+    }
+    if client_data.origin != config.origin() {
+        return Err(WebauthnError::OriginMismatch);
+    }
+    Ok(())
+}
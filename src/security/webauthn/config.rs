@@ -0,0 +1,46 @@
+use mairie360_api_lib::env_manager::get_env_var;
+
+const DEFAULT_RP_ID: &str = "localhost";
+const DEFAULT_RP_NAME: &str = "Mairie360";
+const DEFAULT_ORIGIN: &str = "http://localhost:3000";
+
+/**
+ * Relying party settings, read from `WEBAUTHN_RP_ID` (the domain the passkeys are bound to),
+ * `WEBAUTHN_RP_NAME` and `WEBAUTHN_ORIGIN` (the exact origin of the front-end, scheme included).
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebauthnConfig {
+    rp_id: String,
+    rp_name: String,
+    origin: String,
+}
+
+impl WebauthnConfig {
+    pub fn new(rp_id: &str, rp_name: &str, origin: &str) -> Self {
+        Self {
+            rp_id: rp_id.to_string(),
+            rp_name: rp_name.to_string(),
+            origin: origin.to_string(),
+        }
+    }
+
+    pub fn from_env() -> Self {
+        Self::new(
+            &get_env_var("WEBAUTHN_RP_ID").unwrap_or_else(|| DEFAULT_RP_ID.to_string()),
+            &get_env_var("WEBAUTHN_RP_NAME").unwrap_or_else(|| DEFAULT_RP_NAME.to_string()),
+            &get_env_var("WEBAUTHN_ORIGIN").unwrap_or_else(|| DEFAULT_ORIGIN.to_string()),
+        )
+    }
+
+    pub fn rp_id(&self) -> &str {
+        &self.rp_id
+    }
+
+    pub fn rp_name(&self) -> &str {
+        &self.rp_name
+    }
+
+    pub fn origin(&self) -> &str {
+        &self.origin
+    }
+}
@@ -0,0 +1,112 @@
+use ciborium::Value;
+
+use super::WebauthnError;
+
+pub const COSE_ALG_ES256: i64 = -7;
+pub const COSE_ALG_EDDSA: i64 = -8;
+
+const COSE_KEY_KTY: i64 = 1;
+const COSE_KEY_ALG: i64 = 3;
+const COSE_KEY_CRV: i64 = -1;
+const COSE_KEY_X: i64 = -2;
+const COSE_KEY_Y: i64 = -3;
+
+const COSE_KTY_OKP: i64 = 1;
+const COSE_KTY_EC2: i64 = 2;
+const COSE_CRV_P256: i64 = 1;
+const COSE_CRV_ED25519: i64 = 6;
+
+/**
+ * Credential public key decoded from its COSE_Key encoding (RFC 9053).
+ * Only ES256 and EdDSA are accepted, which covers platform and roaming passkeys.
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub enum CosePublicKey {
+    Es256 { x: Vec<u8>, y: Vec<u8> },
+    Ed25519 { x: Vec<u8> },
+}
+
+fn invalid(message: &str) -> WebauthnError {
+    WebauthnError::InvalidPublicKey(message.to_string())
+}
+
+fn get(map: &[(Value, Value)], key: i64) -> Option<&Value> {
+    map.iter()
+        .find(|(k, _)| k.as_integer().map(i128::from) == Some(key as i128))
+        .map(|(_, v)| v)
+}
+
+fn get_integer(map: &[(Value, Value)], key: i64) -> Result<i64, WebauthnError> {
+    get(map, key)
+        .and_then(|v| v.as_integer())
+        .and_then(|v| i64::try_from(v).ok())
+        .ok_or_else(|| invalid("missing integer parameter"))
+}
+
+fn get_bytes(map: &[(Value, Value)], key: i64, length: usize) -> Result<Vec<u8>, WebauthnError> {
+    match get(map, key).and_then(|v| v.as_bytes()) {
+        Some(bytes) if bytes.len() == length => Ok(bytes.clone()),
+        _ => Err(invalid("missing or malformed coordinate")),
+    }
+}
+
+impl CosePublicKey {
+    pub fn parse(bytes: &[u8]) -> Result<Self, WebauthnError> {
+        let value: Value = ciborium::de::from_reader(bytes).map_err(|e| invalid(&e.to_string()))?;
+        let map = value.as_map().ok_or_else(|| invalid("not a CBOR map"))?;
+
+        let alg = get_integer(map, COSE_KEY_ALG)?;
+        let kty = get_integer(map, COSE_KEY_KTY)?;
+        let crv = get_integer(map, COSE_KEY_CRV)?;
+        match (alg, kty, crv) {
+            (COSE_ALG_ES256, COSE_KTY_EC2, COSE_CRV_P256) => Ok(CosePublicKey::Es256 {
+                x: get_bytes(map, COSE_KEY_X, 32)?,
+                y: get_bytes(map, COSE_KEY_Y, 32)?,
+            }),
+            (COSE_ALG_EDDSA, COSE_KTY_OKP, COSE_CRV_ED25519) => Ok(CosePublicKey::Ed25519 {
+                x: get_bytes(map, COSE_KEY_X, 32)?,
+            }),
+            _ => Err(WebauthnError::UnsupportedAlgorithm(alg)),
+        }
+    }
+
+    pub fn algorithm(&self) -> i64 {
+        match self {
+            CosePublicKey::Es256 { .. } => COSE_ALG_ES256,
+            CosePublicKey::Ed25519 { .. } => COSE_ALG_EDDSA,
+        }
+    }
+
+    /**
+     * Verifies an assertion signature: DER-encoded ECDSA for ES256, raw 64 bytes for EdDSA.
+     */
+    pub fn verify(&self, message: &[u8], signature: &[u8]) -> Result<(), WebauthnError> {
+        match self {
+            CosePublicKey::Es256 { x, y } => {
+                use p256::ecdsa::signature::Verifier;
+                use p256::ecdsa::{Signature, VerifyingKey};
+
+                let mut point = Vec::with_capacity(65);
+                point.push(0x04);
+                point.extend_from_slice(x);
+                point.extend_from_slice(y);
+                let key =
+                    VerifyingKey::from_sec1_bytes(&point).map_err(|e| invalid(&e.to_string()))?;
+                let signature =
+                    Signature::from_der(signature).map_err(|_| WebauthnError::InvalidSignature)?;
+                key.verify(message, &signature)
+                    .map_err(|_| WebauthnError::InvalidSignature)
+            }
+            CosePublicKey::Ed25519 { x } => {
+                use ed25519_dalek::{Signature, VerifyingKey};
+
+                let bytes: [u8; 32] = x.as_slice().try_into().map_err(|_| invalid("bad length"))?;
+                let key = VerifyingKey::from_bytes(&bytes).map_err(|e| invalid(&e.to_string()))?;
+                let signature = Signature::from_slice(signature)
+                    .map_err(|_| WebauthnError::InvalidSignature)?;
+                key.verify_strict(message, &signature)
+                    .map_err(|_| WebauthnError::InvalidSignature)
+            }
+        }
+    }
+}
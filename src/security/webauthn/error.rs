@@ -0,0 +1,46 @@
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum WebauthnError {
+    ChallengeMismatch,
+    InvalidAttestation(String),
+    InvalidAuthenticatorData(String),
+    InvalidClientData(String),
+    InvalidPublicKey(String),
+    InvalidSignature,
+    MissingCredentialData,
+    OriginMismatch,
+    RpIdMismatch,
+    TypeMismatch,
+    UnsupportedAlgorithm(i64),
+    UserNotPresent,
+    UserNotVerified,
+}
+
+impl Display for WebauthnError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WebauthnError::ChallengeMismatch => write!(f, "Challenge does not match"),
+            WebauthnError::InvalidAttestation(e) => write!(f, "Invalid attestation object: {}", e),
+            WebauthnError::InvalidAuthenticatorData(e) => {
+                write!(f, "Invalid authenticator data: {}", e)
+            }
+            WebauthnError::InvalidClientData(e) => write!(f, "Invalid client data: {}", e),
+            WebauthnError::InvalidPublicKey(e) => write!(f, "Invalid public key: {}", e),
+            WebauthnError::InvalidSignature => write!(f, "Invalid signature"),
+            WebauthnError::MissingCredentialData => {
+                write!(f, "Authenticator data has no attested credential")
+            }
+            WebauthnError::OriginMismatch => write!(f, "Origin does not match"),
+            WebauthnError::RpIdMismatch => write!(f, "RP ID hash does not match"),
+            WebauthnError::TypeMismatch => write!(f, "Unexpected client data type"),
+            WebauthnError::UnsupportedAlgorithm(alg) => {
+                write!(f, "Unsupported COSE algorithm: {}", alg)
+            }
+            WebauthnError::UserNotPresent => write!(f, "User presence flag is not set"),
+            WebauthnError::UserNotVerified => write!(f, "User verification flag is not set"),
+        }
+    }
+}
+
+impl std::error::Error for WebauthnError {}
@@ -0,0 +1,22 @@
+mod attestation;
+
+mod authentication;
+pub use authentication::verify_authentication;
+
+mod authenticator_data;
+pub use authenticator_data::AuthenticatorData;
+
+mod client_data;
+pub use client_data::client_data_challenge;
+
+mod config;
+pub use config::WebauthnConfig;
+
+mod cose;
+pub use cose::{CosePublicKey, COSE_ALG_EDDSA, COSE_ALG_ES256};
+
+mod error;
+pub use error::WebauthnError;
+
+mod registration;
+pub use registration::{verify_registration, RegisteredCredential};
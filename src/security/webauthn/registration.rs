@@ -0,0 +1,67 @@
+use super::attestation::attestation_auth_data;
+use super::client_data::verify_client_data;
+use super::{AuthenticatorData, CosePublicKey, WebauthnConfig, WebauthnError};
+
+/**
+ * Credential extracted from a verified registration ceremony, ready to be stored.
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegisteredCredential {
+    credential_id: Vec<u8>,
+    public_key: Vec<u8>,
+    algorithm: i64,
+    sign_count: u32,
+}
+
+impl RegisteredCredential {
+    pub fn credential_id(&self) -> &[u8] {
+        &self.credential_id
+    }
+
+    pub fn public_key(&self) -> &[u8] {
+        &self.public_key
+    }
+
+    pub fn algorithm(&self) -> i64 {
+        self.algorithm
+    }
+
+    pub fn sign_count(&self) -> u32 {
+        self.sign_count
+    }
+}
+
+/**
+ * Verifies the response of `navigator.credentials.create()` (WebAuthn §7.1).
+ */
+pub fn verify_registration(
+    config: &WebauthnConfig,
+    expected_challenge: &str,
+    client_data_json: &[u8],
+    attestation_object: &[u8],
+) -> Result<RegisteredCredential, WebauthnError> {
+    verify_client_data(
+        client_data_json,
+        "webauthn.create",
+        expected_challenge,
+        config,
+    )?;
+
+    let auth_data = AuthenticatorData::parse(&attestation_auth_data(attestation_object)?)?;
+    auth_data.verify(config)?;
+
+    let credential_id = auth_data
+        .credential_id()
+        .ok_or(WebauthnError::MissingCredentialData)?;
+    let public_key = auth_data
+        .public_key()
+        .ok_or(WebauthnError::MissingCredentialData)?;
+    let algorithm = CosePublicKey::parse(public_key)?.algorithm();
+
+    Ok(RegisteredCredential {
+        credential_id: credential_id.to_vec(),
+        public_key: public_key.to_vec(),
+        algorithm,
+        sign_count: auth_data.sign_count(),
+    })
+}
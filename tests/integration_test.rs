@@ -1,2 +1,3 @@
 mod common; // Accès à ton pool
 mod queries;
+mod security;
@@ -0,0 +1,52 @@
+use crate::common::get_pool;
+use core_api::database::known_devices::{
+    is_new_device::{is_new_device_query, IsNewDeviceQueryView},
+    record_known_device::{record_known_device_query, RecordKnownDeviceQueryView},
+};
+use mairie360_api_lib::test_setup::queries_setup::get_shared_db;
+use serial_test::serial;
+
+#[tokio::test]
+#[serial]
+async fn test_is_new_device() {
+    let (_container, host) = get_shared_db().await;
+    let pool = get_pool(host.to_string()).await;
+    let ip = std::net::IpAddr::from([10, 0, 0, 1]);
+
+    record_known_device_query(
+        RecordKnownDeviceQueryView::new(1, ip, "known_device"),
+        pool.clone(),
+    )
+    .await
+    .unwrap();
+    // Une deuxième connexion du même appareil ne fait que le rafraîchir
+    record_known_device_query(
+        RecordKnownDeviceQueryView::new(1, ip, "known_device"),
+        pool.clone(),
+    )
+    .await
+    .unwrap();
+
+    let known = is_new_device_query(
+        IsNewDeviceQueryView::new(1, ip, "known_device"),
+        pool.clone(),
+    )
+    .await
+    .unwrap();
+    let other_device = is_new_device_query(
+        IsNewDeviceQueryView::new(1, ip, "unknown_device"),
+        pool.clone(),
+    )
+    .await
+    .unwrap();
+    let other_ip = is_new_device_query(
+        IsNewDeviceQueryView::new(1, std::net::IpAddr::from([10, 0, 0, 2]), "known_device"),
+        pool,
+    )
+    .await
+    .unwrap();
+
+    assert!(!known);
+    assert!(other_device);
+    assert!(other_ip);
+}
@@ -1,5 +1,6 @@
 mod auth;
 mod groups;
+mod known_devices;
 mod ressources;
 mod rights;
 mod roles;
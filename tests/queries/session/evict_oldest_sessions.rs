@@ -0,0 +1,47 @@
+use crate::common::get_pool;
+use core_api::database::sessions::{
+    count_sessions_by_user::{count_sessions_by_user_query, CountSessionsByUserQueryView},
+    create_session::{create_session_query, CreateSessionQueryView},
+    evict_oldest_sessions::{evict_oldest_sessions_query, EvictOldestSessionsQueryView},
+};
+use mairie360_api_lib::test_setup::queries_setup::get_shared_db;
+use serial_test::serial;
+
+#[tokio::test]
+#[serial]
+async fn test_evict_oldest_sessions() {
+    let (_container, host) = get_shared_db().await;
+    let pool = get_pool(host.to_string()).await;
+
+    for token in ["test_evict_oldest_first", "test_evict_oldest_second"] {
+        create_session_query(
+            CreateSessionQueryView::new(
+                1,
+                token,
+                "any_device",
+                std::net::IpAddr::from([0, 0, 0, 0]),
+            ),
+            pool.clone(),
+        )
+        .await
+        .unwrap();
+    }
+    let active_before = count_sessions_by_user_query(
+        CountSessionsByUserQueryView::new(1, true, None, None),
+        pool.clone(),
+    )
+    .await
+    .unwrap();
+
+    let evicted =
+        evict_oldest_sessions_query(EvictOldestSessionsQueryView::new(1, 1), pool.clone())
+            .await
+            .unwrap();
+    let active_after =
+        count_sessions_by_user_query(CountSessionsByUserQueryView::new(1, true, None, None), pool)
+            .await
+            .unwrap();
+
+    assert_eq!(evicted.len(), 1);
+    assert_eq!(active_after, active_before - 1);
+}
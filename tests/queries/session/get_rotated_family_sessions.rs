@@ -0,0 +1,64 @@
+use core_api::database::sessions::{
+    create_session::{create_session_query, CreateSessionQueryView},
+    get_rotated_family_sessions::{
+        get_rotated_family_sessions_query, GetRotatedFamilySessionsQueryView,
+    },
+    get_session_by_token::{get_session_by_token_query, GetSessionByTokenQueryView},
+    rotate_session::{rotate_session_query, RotateSessionQueryView},
+};
+use mairie360_api_lib::test_setup::queries_setup::get_shared_db;
+use serial_test::serial;
+
+use crate::common::get_pool;
+
+#[tokio::test]
+#[serial]
+async fn test_revoking_a_refreshed_session_reaches_its_previous_tokens() {
+    let (_container, host) = get_shared_db().await;
+    let pool = get_pool(host.to_string()).await;
+    let ip_address = std::net::IpAddr::from([0, 0, 0, 0]);
+
+    let _ = create_session_query(
+        CreateSessionQueryView::new(1, "test_rotated_family_sessions", "any_device", ip_address),
+        pool.clone(),
+    )
+    .await;
+    let first = get_session_by_token_query(
+        GetSessionByTokenQueryView::new("test_rotated_family_sessions".to_string()),
+        pool.clone(),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    let rotate_view = RotateSessionQueryView::new(
+        "test_rotated_family_sessions",
+        "test_rotated_family_sessions_next",
+        ip_address,
+    );
+    let refreshed = *rotate_view.get_new_session_id();
+    let user_id = rotate_session_query(rotate_view, pool.clone())
+        .await
+        .unwrap();
+    assert_eq!(user_id, Some(1));
+
+    // Le jeton d'accès émis avant le refresh porte encore l'id de la première session
+    let result = get_rotated_family_sessions_query(
+        GetRotatedFamilySessionsQueryView::new(&[refreshed], 3600),
+        pool.clone(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result, vec![*first.id()]);
+
+    // Au-delà de JWT_TIMEOUT ces jetons ont expiré d'eux-mêmes
+    let result = get_rotated_family_sessions_query(
+        GetRotatedFamilySessionsQueryView::new(&[refreshed], 0),
+        pool.clone(),
+    )
+    .await
+    .unwrap();
+
+    assert!(result.is_empty());
+}
@@ -0,0 +1,22 @@
+use chrono::{Duration, Utc};
+use core_api::database::sessions::get_session_family::GetSessionFamilyQueryResultView;
+use uuid::Uuid;
+
+#[test]
+fn test_recent_rotation_is_within_the_grace_period() {
+    let family = GetSessionFamilyQueryResultView::new(
+        1,
+        Uuid::new_v4(),
+        Some(Utc::now() - Duration::seconds(5)),
+    );
+
+    assert!(family.is_rotated_within(30));
+    assert!(!family.is_rotated_within(2));
+}
+
+#[test]
+fn test_active_session_is_never_within_the_grace_period() {
+    let family = GetSessionFamilyQueryResultView::new(1, Uuid::new_v4(), None);
+
+    assert!(!family.is_rotated_within(30));
+}
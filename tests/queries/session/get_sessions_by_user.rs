@@ -1,6 +1,10 @@
+use chrono::{Duration, Utc};
 use core_api::database::sessions::{
+    count_sessions_by_user::{count_sessions_by_user_query, CountSessionsByUserQueryView},
     create_session::{create_session_query, CreateSessionQueryView},
+    get_session_by_token::{get_session_by_token_query, GetSessionByTokenQueryView},
     get_sessions_by_user::{get_sessions_by_user_query, GetSessionsByUserQueryView},
+    revoke_session_by_id::{revoke_session_by_id_query, RevokeSessionByIdQueryView},
 };
 use mairie360_api_lib::{
     database::{queries::is_session_token_valid_query, query_views::IsSessionTokenValidQueryView},
@@ -81,3 +85,68 @@ async fn test_get_sessions_by_unknow_user() {
 
     assert!(result.is_empty());
 }
+
+#[tokio::test]
+#[serial]
+async fn test_get_sessions_by_user_filters_and_pages() {
+    let (_container, host) = get_shared_db().await;
+    let pool = get_pool(host.to_string()).await;
+
+    for token in ["test_sessions_filter_kept", "test_sessions_filter_revoked"] {
+        let _ = create_session_query(
+            CreateSessionQueryView::new(
+                1,
+                token,
+                "any_device",
+                std::net::IpAddr::from([0, 0, 0, 0]),
+            ),
+            pool.clone(),
+        )
+        .await;
+    }
+    let revoked = get_session_by_token_query(
+        GetSessionByTokenQueryView::new("test_sessions_filter_revoked".to_string()),
+        pool.clone(),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    revoke_session_by_id_query(
+        RevokeSessionByIdQueryView::new(1, *revoked.id()),
+        pool.clone(),
+    )
+    .await
+    .unwrap();
+
+    let all = count_sessions_by_user_query(
+        CountSessionsByUserQueryView::new(1, false, None, None),
+        pool.clone(),
+    )
+    .await
+    .unwrap();
+    let active = get_sessions_by_user_query(
+        GetSessionsByUserQueryView::new(1).active_only(true),
+        pool.clone(),
+    )
+    .await
+    .unwrap();
+    assert!(active.iter().all(|session| session.revoked_at().is_none()));
+    assert!((active.len() as u64) < all);
+
+    let page = get_sessions_by_user_query(
+        GetSessionsByUserQueryView::new(1).paginate(1, 1),
+        pool.clone(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(page.len(), 1);
+
+    let future = get_sessions_by_user_query(
+        GetSessionsByUserQueryView::new(1)
+            .created_between(Some(Utc::now() + Duration::hours(1)), None),
+        pool,
+    )
+    .await
+    .unwrap();
+    assert!(future.is_empty());
+}
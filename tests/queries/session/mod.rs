@@ -1,7 +1,11 @@
 pub mod create_session;
+pub mod evict_oldest_sessions;
+pub mod get_rotated_family_sessions;
 pub mod get_session_by_token;
+pub mod get_session_family;
 pub mod get_sessions;
 pub mod get_sessions_by_user;
+pub mod purge_sessions;
 pub mod revoke_previous_session;
 pub mod revoke_session;
 pub mod revoke_session_by_id;
@@ -0,0 +1,43 @@
+use crate::common::get_pool;
+use core_api::database::sessions::{
+    create_session::{create_session_query, CreateSessionQueryView},
+    expire_sessions::{expire_sessions_query, ExpireSessionsQueryView},
+    get_session_by_token::{get_session_by_token_query, GetSessionByTokenQueryView},
+    purge_sessions::{purge_sessions_query, PurgeSessionsQueryView},
+};
+use mairie360_api_lib::test_setup::queries_setup::get_shared_db;
+use serial_test::serial;
+
+#[tokio::test]
+#[serial]
+async fn test_janitor_keeps_live_sessions() {
+    let (_container, host) = get_shared_db().await;
+    let pool = get_pool(host.to_string()).await;
+
+    let _ = create_session_query(
+        CreateSessionQueryView::new(
+            1,
+            "test_janitor_keeps_live_sessions",
+            "any_device",
+            std::net::IpAddr::from([0, 0, 0, 0]),
+        ),
+        pool.clone(),
+    )
+    .await;
+
+    expire_sessions_query(ExpireSessionsQueryView::new(), pool.clone())
+        .await
+        .unwrap();
+    purge_sessions_query(PurgeSessionsQueryView::new(0), pool.clone())
+        .await
+        .unwrap();
+
+    let result = get_session_by_token_query(
+        GetSessionByTokenQueryView::new("test_janitor_keeps_live_sessions".to_string()),
+        pool,
+    )
+    .await
+    .unwrap();
+
+    assert!(result.is_some());
+}
@@ -18,7 +18,7 @@ async fn test_revoke_previous_session() {
         .await
         .unwrap();
 
-    let result: Result<(), DatabaseError> = revoke_previous_session_query(
+    let result: Result<Vec<uuid::Uuid>, DatabaseError> = revoke_previous_session_query(
         RevokePreviousSessionQueryView::new(
             1,
             std::net::IpAddr::V4(std::net::Ipv4Addr::new(0, 0, 0, 0)),
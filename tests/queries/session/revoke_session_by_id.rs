@@ -55,11 +55,18 @@ async fn test_revoke_session_with_id() {
 
     let session_id = session.id().clone();
 
-    let result: Result<(), DatabaseError> =
+    let result: Result<bool, DatabaseError> =
         revoke_session_by_id_query(RevokeSessionByIdQueryView::new(1, session_id), pool.clone())
             .await;
 
-    assert!(result.is_ok());
+    assert_eq!(result, Ok(true));
+
+    // Déjà révoquée : rien à faire
+    let result =
+        revoke_session_by_id_query(RevokeSessionByIdQueryView::new(1, session_id), pool.clone())
+            .await;
+
+    assert_eq!(result, Ok(false));
 
     let is_valid = is_session_token_valid_query(
         IsSessionTokenValidQueryView::new(
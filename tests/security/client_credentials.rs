@@ -0,0 +1,49 @@
+use base64::{engine::general_purpose, Engine as _};
+use core_api::security::client_credentials::{
+    parse_basic_authorization, verify_client_secret, ClientCredentials,
+};
+use core_api::security::token::hash_token;
+
+fn basic(value: &str) -> String {
+    format!("Basic {}", general_purpose::STANDARD.encode(value))
+}
+
+#[test]
+fn test_parse_basic_authorization() {
+    assert_eq!(
+        parse_basic_authorization(&basic("documents:s3cr3t")),
+        Some(ClientCredentials {
+            client_id: "documents".to_string(),
+            client_secret: "s3cr3t".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_parse_basic_authorization_keeps_colons_in_secret() {
+    let credentials = parse_basic_authorization(&basic("documents:a:b")).unwrap();
+    assert_eq!(credentials.client_secret, "a:b");
+}
+
+#[test]
+fn test_parse_basic_authorization_scheme_is_case_insensitive() {
+    let header = basic("documents:s3cr3t").replace("Basic", "basic");
+    assert!(parse_basic_authorization(&header).is_some());
+}
+
+#[test]
+fn test_parse_basic_authorization_rejects_malformed_values() {
+    assert!(parse_basic_authorization("Bearer abc.def.ghi").is_none());
+    assert!(parse_basic_authorization("Basic not-base64!").is_none());
+    assert!(parse_basic_authorization(&basic("no-separator")).is_none());
+    assert!(parse_basic_authorization(&basic(":secret")).is_none());
+    assert!(parse_basic_authorization(&basic("documents:")).is_none());
+}
+
+#[test]
+fn test_verify_client_secret() {
+    let stored = hash_token("s3cr3t");
+    assert!(verify_client_secret("s3cr3t", &stored));
+    assert!(!verify_client_secret("S3cr3t", &stored));
+    assert!(!verify_client_secret("s3cr3t", "s3cr3t"));
+}
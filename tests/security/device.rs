@@ -0,0 +1,89 @@
+use core_api::security::device::{DeviceDetails, DeviceType};
+
+const FIREFOX_WINDOWS: &str =
+    "Mozilla/5.0 (Windows NT 10.0; Win64; x64; rv:128.0) Gecko/20100101 Firefox/128.0";
+const EDGE_WINDOWS: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/126.0.0.0 Safari/537.36 Edg/126.0.2592.87";
+const SAFARI_IPHONE: &str = "Mozilla/5.0 (iPhone; CPU iPhone OS 17_5 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.5 Mobile/15E148 Safari/604.1";
+const CHROME_ANDROID: &str = "Mozilla/5.0 (Linux; Android 14; Pixel 7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/126.0.6478.122 Mobile Safari/537.36";
+const SAFARI_IPAD: &str = "Mozilla/5.0 (iPad; CPU OS 16_6 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/16.6 Mobile/15E148 Safari/604.1";
+const CHROME_MAC: &str = "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/126.0.0.0 Safari/537.36";
+
+#[test]
+fn test_firefox_on_windows() {
+    let device = DeviceDetails::parse(Some(FIREFOX_WINDOWS), "Poste accueil");
+
+    assert_eq!(device.browser(), Some("Firefox 128"));
+    assert_eq!(device.os(), Some("Windows"));
+    assert_eq!(device.device_type(), DeviceType::Desktop);
+    assert_eq!(device.app_version(), None);
+}
+
+#[test]
+fn test_chromium_browsers_are_not_reported_as_chrome() {
+    let device = DeviceDetails::parse(Some(EDGE_WINDOWS), "");
+
+    assert_eq!(device.browser(), Some("Edge 126"));
+}
+
+#[test]
+fn test_chrome_is_not_reported_as_safari() {
+    let device = DeviceDetails::parse(Some(CHROME_MAC), "");
+
+    assert_eq!(device.browser(), Some("Chrome 126"));
+    assert_eq!(device.os(), Some("macOS"));
+    assert_eq!(device.device_type(), DeviceType::Desktop);
+}
+
+#[test]
+fn test_mobile_devices() {
+    let iphone = DeviceDetails::parse(Some(SAFARI_IPHONE), "");
+    assert_eq!(iphone.browser(), Some("Safari 17"));
+    assert_eq!(iphone.os(), Some("iOS 17.5"));
+    assert_eq!(iphone.device_type(), DeviceType::Mobile);
+
+    let android = DeviceDetails::parse(Some(CHROME_ANDROID), "");
+    assert_eq!(android.browser(), Some("Chrome 126"));
+    assert_eq!(android.os(), Some("Android 14"));
+    assert_eq!(android.device_type(), DeviceType::Mobile);
+}
+
+#[test]
+fn test_tablet() {
+    let device = DeviceDetails::parse(Some(SAFARI_IPAD), "");
+
+    assert_eq!(device.os(), Some("iOS 16.6"));
+    assert_eq!(device.device_type(), DeviceType::Tablet);
+}
+
+#[test]
+fn test_native_app_reads_device_info() {
+    let device = DeviceDetails::parse(
+        Some("okhttp/4.12.0"),
+        "mairie360-mobile/2.4.0 (Pixel 7; Android 14; Mobile)",
+    );
+
+    assert_eq!(device.browser(), None);
+    assert_eq!(device.os(), Some("Android 14"));
+    assert_eq!(device.device_type(), DeviceType::Mobile);
+    assert_eq!(device.app_version(), Some("2.4.0"));
+}
+
+#[test]
+fn test_browser_user_agent_as_device_info_has_no_app_version() {
+    let device = DeviceDetails::parse(None, FIREFOX_WINDOWS);
+
+    assert_eq!(device.browser(), Some("Firefox 128"));
+    assert_eq!(device.app_version(), None);
+}
+
+#[test]
+fn test_bots_and_unknown_clients() {
+    assert_eq!(
+        DeviceDetails::parse(Some("curl/8.5.0"), "").device_type(),
+        DeviceType::Bot
+    );
+
+    let unknown = DeviceDetails::parse(None, "");
+    assert_eq!(unknown, DeviceDetails::default());
+    assert_eq!(unknown.device_type().as_str(), "unknown");
+}
@@ -0,0 +1,107 @@
+use core_api::security::email_token::{EmailTokenError, EmailTokenSigner};
+
+const SECRET: &[u8] = b"0123456789abcdef0123456789abcdef";
+const NOW: i64 = 1_700_000_000;
+
+fn signer() -> EmailTokenSigner {
+    EmailTokenSigner::new(SECRET).unwrap()
+}
+
+#[test]
+fn test_token_round_trip() {
+    let token = signer().sign("email_verification", 42, "alice@example.com", NOW + 60);
+
+    assert_eq!(EmailTokenSigner::user_id(&token), Ok(42));
+    assert_eq!(
+        signer().verify("email_verification", &token, "alice@example.com", NOW),
+        Ok(42)
+    );
+}
+
+#[test]
+fn test_email_comparison_ignores_case() {
+    let token = signer().sign("email_verification", 42, "Alice@Example.com", NOW + 60);
+
+    assert_eq!(
+        signer().verify("email_verification", &token, "alice@example.com", NOW),
+        Ok(42)
+    );
+}
+
+#[test]
+fn test_expired_token_is_rejected() {
+    let token = signer().sign("email_verification", 42, "alice@example.com", NOW);
+
+    assert_eq!(
+        signer().verify("email_verification", &token, "alice@example.com", NOW),
+        Err(EmailTokenError::Expired)
+    );
+}
+
+#[test]
+fn test_token_for_another_purpose_or_address_is_rejected() {
+    let token = signer().sign("email_verification", 42, "alice@example.com", NOW + 60);
+
+    assert_eq!(
+        signer().verify("magic_link", &token, "alice@example.com", NOW),
+        Err(EmailTokenError::InvalidSignature)
+    );
+    assert_eq!(
+        signer().verify("email_verification", &token, "bob@example.com", NOW),
+        Err(EmailTokenError::InvalidSignature)
+    );
+}
+
+#[test]
+fn test_tampered_token_is_rejected() {
+    let token = signer().sign("email_verification", 42, "alice@example.com", NOW + 60);
+    let forged = token.replacen("42.", "43.", 1);
+    let extended = token.replacen(&(NOW + 60).to_string(), &(NOW + 3600).to_string(), 1);
+
+    assert_eq!(
+        signer().verify("email_verification", &forged, "alice@example.com", NOW),
+        Err(EmailTokenError::InvalidSignature)
+    );
+    assert_eq!(
+        signer().verify("email_verification", &extended, "alice@example.com", NOW),
+        Err(EmailTokenError::InvalidSignature)
+    );
+}
+
+#[test]
+fn test_token_signed_with_another_secret_is_rejected() {
+    let other = EmailTokenSigner::new(b"fedcba9876543210fedcba9876543210").unwrap();
+    let token = other.sign("email_verification", 42, "alice@example.com", NOW + 60);
+
+    assert_eq!(
+        signer().verify("email_verification", &token, "alice@example.com", NOW),
+        Err(EmailTokenError::InvalidSignature)
+    );
+}
+
+#[test]
+fn test_malformed_tokens_are_rejected() {
+    for token in [
+        "",
+        "42",
+        "42.abc.00",
+        "42.1700000060",
+        "42.1700000060.zz",
+        "a.b.c.d",
+    ] {
+        assert_eq!(
+            signer().verify("email_verification", token, "alice@example.com", NOW),
+            Err(EmailTokenError::Malformed),
+            "{}",
+            token
+        );
+    }
+}
+
+#[test]
+fn test_short_secret_is_refused() {
+    assert!(matches!(
+        EmailTokenSigner::new(b"too short"),
+        Err(EmailTokenError::Configuration(_))
+    ));
+}
@@ -0,0 +1,258 @@
+use actix_web::{web, App, HttpResponse, HttpServer};
+use chrono::{DateTime, TimeZone, Utc};
+use core_api::security::federation::{
+    exchange_code, fetch_jwks, fetch_provider_metadata, verify_id_token, FederationConfig,
+    FederationError,
+};
+use core_api::security::jwt::{encode_jwt, JwtAlgorithm, JwtKey, KeyStore};
+use core_api::security::oidc::code_challenge;
+use ed25519_dalek::pkcs8::{spki::der::pem::LineEnding, EncodePrivateKey};
+use jsonwebtoken::{encode, EncodingKey, Header};
+use serde_json::{json, Value};
+use std::net::TcpListener;
+
+const CLIENT_ID: &str = "core";
+const NONCE: &str = "nonce-1";
+const CODE: &str = "good-code";
+
+fn now() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 6, 1, 12, 0, 0).unwrap()
+}
+
+fn key_store() -> KeyStore {
+    let pem = ed25519_dalek::SigningKey::from_bytes(&[7; 32])
+        .to_pkcs8_pem(LineEnding::LF)
+        .unwrap()
+        .to_string();
+    let key = JwtKey::from_pem("idp-1", JwtAlgorithm::EdDSA, &pem, None, None).unwrap();
+    KeyStore::new(vec![key]).unwrap()
+}
+
+fn config(issuer: &str) -> FederationConfig {
+    FederationConfig::new(
+        issuer,
+        CLIENT_ID,
+        Some("secret"),
+        "https://app.mairie360.fr/federated/callback",
+    )
+}
+
+fn id_token_claims(issuer: &str, at: DateTime<Utc>) -> Value {
+    let iat = at.timestamp();
+    json!({
+        "iss": issuer,
+        "aud": CLIENT_ID,
+        "sub": "agent-42",
+        "iat": iat,
+        "exp": iat + 300,
+        "nonce": NONCE,
+        "email": "agent@mairie.fr",
+        "email_verified": true,
+    })
+}
+
+fn sign(store: &KeyStore, claims: &Value) -> String {
+    encode_jwt(store, claims, now()).unwrap()
+}
+
+/**
+ * Minimal identity provider: discovery, JWKS and a token endpoint that only accepts `CODE`
+ * with a PKCE verifier.
+ */
+fn start_issuer(id_token: impl Fn(&str) -> String) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let issuer = format!("http://{}", listener.local_addr().unwrap());
+    let discovery = json!({
+        "issuer": issuer,
+        "authorization_endpoint": format!("{}/authorize", issuer),
+        "token_endpoint": format!("{}/token", issuer),
+        "jwks_uri": format!("{}/jwks", issuer),
+    });
+    let jwks = serde_json::to_value(key_store().jwks(now())).unwrap();
+    let id_token = id_token(&issuer);
+
+    let server = HttpServer::new(move || {
+        let discovery = discovery.clone();
+        let jwks = jwks.clone();
+        let id_token = id_token.clone();
+        App::new()
+            .route(
+                "/.well-known/openid-configuration",
+                web::get().to(move || {
+                    let discovery = discovery.clone();
+                    async move { HttpResponse::Ok().json(discovery) }
+                }),
+            )
+            .route(
+                "/jwks",
+                web::get().to(move || {
+                    let jwks = jwks.clone();
+                    async move { HttpResponse::Ok().json(jwks) }
+                }),
+            )
+            .route(
+                "/token",
+                web::post().to(move |form: web::Form<Vec<(String, String)>>| {
+                    let id_token = id_token.clone();
+                    async move {
+                        let field = |name: &str| {
+                            form.iter()
+                                .find(|(key, _)| key == name)
+                                .map(|(_, value)| value.clone())
+                        };
+                        if field("code").as_deref() != Some(CODE)
+                            || field("client_id").as_deref() != Some(CLIENT_ID)
+                            || field("code_verifier").is_none()
+                        {
+                            return HttpResponse::BadRequest()
+                                .json(json!({"error": "invalid_grant"}));
+                        }
+                        HttpResponse::Ok().json(json!({"access_token": "at", "id_token": id_token}))
+                    }
+                }),
+            )
+    })
+    .workers(1)
+    .listen(listener)
+    .unwrap()
+    .run();
+    actix_web::rt::spawn(server);
+    issuer
+}
+
+#[actix_web::test]
+async fn test_authorization_url_carries_state_nonce_and_pkce() {
+    let issuer = start_issuer(|_| String::new());
+    let metadata = fetch_provider_metadata(&issuer).await.unwrap();
+    let url = metadata
+        .authorization_url(&config(&issuer), "state-1", NONCE, &code_challenge("v"))
+        .unwrap();
+
+    assert!(url.starts_with(&format!("{}/authorize?", issuer)));
+    assert!(url.contains("response_type=code"));
+    assert!(url.contains("client_id=core"));
+    assert!(url.contains("state=state-1"));
+    assert!(url.contains("nonce=nonce-1"));
+    assert!(url.contains("code_challenge_method=S256"));
+    assert!(url.contains("scope=openid+email+profile"));
+}
+
+#[actix_web::test]
+async fn test_discovery_for_another_issuer_is_refused() {
+    let issuer = start_issuer(|_| String::new());
+    // Même serveur, mais le document annonce un autre émetteur que celui configuré
+    let result = fetch_provider_metadata(&issuer.replace("127.0.0.1", "localhost")).await;
+    assert!(matches!(result, Err(FederationError::Provider(_))));
+}
+
+#[actix_web::test]
+async fn test_code_exchange_yields_verified_identity() {
+    let issuer = start_issuer(|issuer| sign(&key_store(), &id_token_claims(issuer, now())));
+    let config = config(&issuer);
+    let metadata = fetch_provider_metadata(&issuer).await.unwrap();
+
+    let id_token = exchange_code(&metadata, &config, CODE, "verifier")
+        .await
+        .unwrap();
+    let jwks = fetch_jwks(&metadata).await.unwrap();
+    let identity = verify_id_token(&id_token, &jwks, &config, NONCE, now()).unwrap();
+
+    assert_eq!(identity.subject(), "agent-42");
+    assert_eq!(identity.verified_email(), Some("agent@mairie.fr"));
+}
+
+#[actix_web::test]
+async fn test_rejected_code_is_reported() {
+    let issuer = start_issuer(|_| String::new());
+    let config = config(&issuer);
+    let metadata = fetch_provider_metadata(&issuer).await.unwrap();
+
+    let result = exchange_code(&metadata, &config, "stolen-code", "verifier").await;
+    assert_eq!(result, Err(FederationError::CodeRejected(400)));
+}
+
+fn verify(claims: &Value) -> Result<Option<String>, FederationError> {
+    let issuer = "https://idp.region.fr";
+    let store = key_store();
+    verify_id_token(
+        &sign(&store, claims),
+        &store.jwks(now()),
+        &config(issuer),
+        NONCE,
+        now(),
+    )
+    .map(|identity| identity.verified_email().map(str::to_string))
+}
+
+#[test]
+fn test_id_token_checks() {
+    let issuer = "https://idp.region.fr";
+    let valid = id_token_claims(issuer, now());
+    assert_eq!(verify(&valid), Ok(Some("agent@mairie.fr".to_string())));
+
+    let mut other_nonce = valid.clone();
+    other_nonce["nonce"] = json!("replayed");
+    assert_eq!(verify(&other_nonce), Err(FederationError::NonceMismatch));
+
+    let expired = id_token_claims(issuer, now() - chrono::Duration::minutes(10));
+    assert_eq!(verify(&expired), Err(FederationError::ExpiredIdToken));
+
+    let mut other_audience = valid.clone();
+    other_audience["aud"] = json!("another-app");
+    assert!(matches!(
+        verify(&other_audience),
+        Err(FederationError::InvalidIdToken(_))
+    ));
+
+    let other_issuer = id_token_claims("https://evil.example", now());
+    assert!(matches!(
+        verify(&other_issuer),
+        Err(FederationError::InvalidIdToken(_))
+    ));
+}
+
+#[test]
+fn test_unverified_email_is_not_trusted() {
+    let mut claims = id_token_claims("https://idp.region.fr", now());
+    claims["email_verified"] = json!(false);
+    assert_eq!(verify(&claims), Ok(None));
+
+    claims["email_verified"] = json!("true");
+    assert_eq!(verify(&claims), Ok(Some("agent@mairie.fr".to_string())));
+}
+
+#[test]
+fn test_symmetric_and_unknown_keys_are_refused() {
+    let issuer = "https://idp.region.fr";
+    let jwks = key_store().jwks(now());
+    let claims = id_token_claims(issuer, now());
+
+    let hs256 = encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(b"core"),
+    )
+    .unwrap();
+    assert!(matches!(
+        verify_id_token(&hs256, &jwks, &config(issuer), NONCE, now()),
+        Err(FederationError::InvalidIdToken(_))
+    ));
+
+    let pem = ed25519_dalek::SigningKey::from_bytes(&[8; 32])
+        .to_pkcs8_pem(LineEnding::LF)
+        .unwrap()
+        .to_string();
+    let other = KeyStore::new(vec![JwtKey::from_pem(
+        "idp-2",
+        JwtAlgorithm::EdDSA,
+        &pem,
+        None,
+        None,
+    )
+    .unwrap()])
+    .unwrap();
+    assert_eq!(
+        verify_id_token(&sign(&other, &claims), &jwks, &config(issuer), NONCE, now()),
+        Err(FederationError::UnknownKey("idp-2".to_string()))
+    );
+}
@@ -0,0 +1,273 @@
+use base64::{engine::general_purpose, Engine as _};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use core_api::security::jwt::{
+    decode_jwt, encode_jwt, Claims, JwtAlgorithm, JwtError, JwtKey, KeyStore,
+};
+use ed25519_dalek::pkcs8::{spki::der::pem::LineEnding, EncodePrivateKey};
+use jsonwebtoken::decode_header;
+use jsonwebtoken::jwk::AlgorithmParameters;
+
+const RS256_PEM: &str = include_str!("keys/rs256.pem");
+
+fn now() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 6, 1, 12, 0, 0).unwrap()
+}
+
+fn ed25519_pem(seed: u8) -> String {
+    ed25519_dalek::SigningKey::from_bytes(&[seed; 32])
+        .to_pkcs8_pem(LineEnding::LF)
+        .unwrap()
+        .to_string()
+}
+
+fn ed25519_key(
+    kid: &str,
+    seed: u8,
+    not_before: Option<DateTime<Utc>>,
+    retire_at: Option<DateTime<Utc>>,
+) -> JwtKey {
+    JwtKey::from_pem(
+        kid,
+        JwtAlgorithm::EdDSA,
+        &ed25519_pem(seed),
+        not_before,
+        retire_at,
+    )
+    .unwrap()
+}
+
+fn claims(at: DateTime<Utc>) -> Claims {
+    let issued_at = at.timestamp() as u64;
+    Claims::new("42", issued_at, issued_at + 900)
+}
+
+#[test]
+fn test_eddsa_token_round_trip() {
+    let store = KeyStore::new(vec![ed25519_key("ed-1", 1, None, None)]).unwrap();
+
+    let token = encode_jwt(&store, &claims(now()), now()).unwrap();
+    let header = decode_header(&token).unwrap();
+    assert_eq!(header.kid.as_deref(), Some("ed-1"));
+    assert_eq!(header.alg, jsonwebtoken::Algorithm::EdDSA);
+
+    let decoded = decode_jwt(&store, &token, now()).unwrap();
+    assert_eq!(decoded, claims(now()));
+    assert_eq!(decoded.get_user_id(), "42");
+}
+
+#[test]
+fn test_session_id_round_trip() {
+    let store = KeyStore::new(vec![ed25519_key("ed-1", 1, None, None)]).unwrap();
+    let sid = "5f0c6a34-7c1e-4d3b-9a51-0b8f2f7e6d21";
+
+    let token = encode_jwt(&store, &claims(now()).with_session_id(sid), now()).unwrap();
+    let decoded = decode_jwt(&store, &token, now()).unwrap();
+    assert_eq!(decoded.get_session_id(), Some(sid));
+
+    let token = encode_jwt(&store, &claims(now()), now()).unwrap();
+    let decoded = decode_jwt(&store, &token, now()).unwrap();
+    assert_eq!(decoded.get_session_id(), None);
+}
+
+#[test]
+fn test_module_claims_round_trip() {
+    let store = KeyStore::new(vec![ed25519_key("ed-1", 1, None, None)]).unwrap();
+    let issued_at = now().timestamp() as u64;
+    let scopes = vec!["users:read".to_string(), "users:write".to_string()];
+    let module_claims = Claims::for_module("agenda", 3, &scopes, issued_at, issued_at + 300);
+
+    let token = encode_jwt(&store, &module_claims, now()).unwrap();
+    let decoded = decode_jwt(&store, &token, now()).unwrap();
+    assert!(decoded.is_module_token());
+    assert_eq!(decoded.get_client_id(), Some("agenda"));
+    assert_eq!(decoded.get_module_id(), Some(3));
+    assert_eq!(decoded.get_scope(), Some("users:read users:write"));
+    assert!(decoded.get_user_id().parse::<u64>().is_err());
+
+    let token = encode_jwt(&store, &claims(now()), now()).unwrap();
+    let decoded = decode_jwt(&store, &token, now()).unwrap();
+    assert!(!decoded.is_module_token());
+    assert_eq!(decoded.get_scope(), None);
+}
+
+#[test]
+fn test_impersonation_claims_round_trip() {
+    let store = KeyStore::new(vec![ed25519_key("ed-1", 1, None, None)]).unwrap();
+    let sid = "5f0c6a34-7c1e-4d3b-9a51-0b8f2f7e6d21";
+    let impersonation_claims = claims(now()).with_session_id(sid).with_actor("7");
+
+    let token = encode_jwt(&store, &impersonation_claims, now()).unwrap();
+    let decoded = decode_jwt(&store, &token, now()).unwrap();
+    assert_eq!(decoded.get_user_id(), "42");
+    assert_eq!(decoded.get_actor_id(), Some("7"));
+    assert_eq!(decoded.get_session_id(), Some(sid));
+    assert!(!decoded.is_module_token());
+
+    // Les jetons ordinaires ne portent pas de claim act
+    let token = encode_jwt(&store, &claims(now()), now()).unwrap();
+    let decoded = decode_jwt(&store, &token, now()).unwrap();
+    assert_eq!(decoded.get_actor_id(), None);
+    let payload = general_purpose::URL_SAFE_NO_PAD
+        .decode(token.split('.').nth(1).unwrap())
+        .unwrap();
+    assert!(!String::from_utf8(payload).unwrap().contains("\"act\""));
+}
+
+#[test]
+fn test_rs256_token_round_trip() {
+    let key = JwtKey::from_pem("rsa-1", JwtAlgorithm::RS256, RS256_PEM, None, None).unwrap();
+    let store = KeyStore::new(vec![key]).unwrap();
+
+    let token = encode_jwt(&store, &claims(now()), now()).unwrap();
+    assert_eq!(
+        decode_header(&token).unwrap().alg,
+        jsonwebtoken::Algorithm::RS256
+    );
+    assert!(decode_jwt(&store, &token, now()).is_ok());
+}
+
+#[test]
+fn test_jwks_exposes_public_parameters_only() {
+    let rsa = JwtKey::from_pem("rsa-1", JwtAlgorithm::RS256, RS256_PEM, None, None).unwrap();
+    let store = KeyStore::new(vec![ed25519_key("ed-1", 1, None, None), rsa]).unwrap();
+
+    let jwks = store.jwks(now());
+    assert_eq!(jwks.keys.len(), 2);
+    match &jwks.find("ed-1").unwrap().algorithm {
+        AlgorithmParameters::OctetKeyPair(params) => assert_eq!(params.x.len(), 43),
+        _ => panic!("expected an OKP key"),
+    }
+    match &jwks.find("rsa-1").unwrap().algorithm {
+        AlgorithmParameters::RSA(params) => assert_eq!(params.e, "AQAB"),
+        _ => panic!("expected an RSA key"),
+    }
+
+    let json = serde_json::to_string(&jwks).unwrap();
+    assert!(!json.contains("\"d\""));
+}
+
+#[test]
+fn test_token_verifies_with_published_jwk() {
+    let store = KeyStore::new(vec![ed25519_key("ed-1", 1, None, None)]).unwrap();
+    let token = encode_jwt(&store, &claims(Utc::now()), Utc::now()).unwrap();
+
+    let jwk = store.jwks(now()).find("ed-1").unwrap().clone();
+    let key = jsonwebtoken::DecodingKey::from_jwk(&jwk).unwrap();
+    let validation = jsonwebtoken::Validation::new(jsonwebtoken::Algorithm::EdDSA);
+    assert!(jsonwebtoken::decode::<Claims>(&token, &key, &validation).is_ok());
+}
+
+#[test]
+fn test_scheduled_rotation_switches_signing_key() {
+    let rotation = now() + Duration::days(7);
+    let store = KeyStore::new(vec![
+        ed25519_key("old", 1, Some(now() - Duration::days(30)), None),
+        ed25519_key("new", 2, Some(rotation), None),
+    ])
+    .unwrap();
+
+    assert_eq!(store.signing_key(now()).unwrap().kid(), "old");
+    assert_eq!(store.signing_key(rotation).unwrap().kid(), "new");
+    // La clé à venir est publiée avant d'être utilisée
+    assert!(store.jwks(now()).find("new").is_some());
+
+    let before = encode_jwt(&store, &claims(rotation), rotation - Duration::seconds(1)).unwrap();
+    assert_eq!(decode_header(&before).unwrap().kid.as_deref(), Some("old"));
+    assert!(decode_jwt(&store, &before, rotation + Duration::seconds(60)).is_ok());
+}
+
+#[test]
+fn test_retired_key_is_rejected_and_unpublished() {
+    let retire_at = now() + Duration::hours(1);
+    let store = KeyStore::new(vec![
+        ed25519_key("old", 1, None, Some(retire_at)),
+        ed25519_key("new", 2, Some(now() + Duration::days(1)), None),
+    ])
+    .unwrap();
+
+    let token = encode_jwt(&store, &claims(retire_at), now()).unwrap();
+    assert_eq!(
+        decode_jwt(&store, &token, retire_at),
+        Err(JwtError::UnknownKey("old".to_string()))
+    );
+    assert!(store.jwks(retire_at).find("old").is_none());
+    assert_eq!(
+        store.signing_key(retire_at).err(),
+        Some(JwtError::NoSigningKey)
+    );
+}
+
+#[test]
+fn test_expired_token_is_rejected() {
+    let store = KeyStore::new(vec![ed25519_key("ed-1", 1, None, None)]).unwrap();
+    let token = encode_jwt(&store, &claims(now()), now()).unwrap();
+
+    assert_eq!(
+        decode_jwt(&store, &token, now() + Duration::seconds(900)),
+        Err(JwtError::ExpiredToken)
+    );
+}
+
+#[test]
+fn test_token_signed_by_unknown_key_is_rejected() {
+    let issuer = KeyStore::new(vec![ed25519_key("ed-1", 1, None, None)]).unwrap();
+    let verifier = KeyStore::new(vec![ed25519_key("ed-1", 2, None, None)]).unwrap();
+    let token = encode_jwt(&issuer, &claims(now()), now()).unwrap();
+
+    assert!(matches!(
+        decode_jwt(&verifier, &token, now()),
+        Err(JwtError::InvalidToken(_))
+    ));
+}
+
+#[test]
+fn test_hs256_token_is_rejected() {
+    let store = KeyStore::new(vec![ed25519_key("ed-1", 1, None, None)]).unwrap();
+    let mut header = jsonwebtoken::Header::new(jsonwebtoken::Algorithm::HS256);
+    header.kid = Some("ed-1".to_string());
+    let token = jsonwebtoken::encode(
+        &header,
+        &claims(now()),
+        &jsonwebtoken::EncodingKey::from_secret(b"secret"),
+    )
+    .unwrap();
+
+    assert!(decode_jwt(&store, &token, now()).is_err());
+}
+
+#[test]
+fn test_duplicate_kid_is_refused() {
+    let result = KeyStore::new(vec![
+        ed25519_key("ed-1", 1, None, None),
+        ed25519_key("ed-1", 2, None, None),
+    ]);
+    assert!(matches!(result, Err(JwtError::Configuration(_))));
+}
+
+#[test]
+fn test_invalid_pem_is_refused() {
+    let result = JwtKey::from_pem("ed-1", JwtAlgorithm::EdDSA, RS256_PEM, None, None);
+    assert!(matches!(result, Err(JwtError::InvalidKey(_))));
+}
+
+#[test]
+fn test_key_store_from_manifest() {
+    let dir = std::env::temp_dir().join(format!("core_api_jwt_keys_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("ed.pem"), ed25519_pem(3)).unwrap();
+    std::fs::write(dir.join("rsa.pem"), RS256_PEM).unwrap();
+    std::fs::write(
+        dir.join("keys.json"),
+        r#"{"keys": [
+            {"kid": "ed", "algorithm": "EdDSA", "private_key": "ed.pem", "not_before": "2026-01-01T00:00:00Z"},
+            {"kid": "rsa", "algorithm": "RS256", "private_key": "rsa.pem", "not_before": null, "retire_at": "2026-03-01T00:00:00Z"}
+        ]}"#,
+    )
+    .unwrap();
+
+    let store = KeyStore::from_manifest(&dir.join("keys.json")).unwrap();
+    std::fs::remove_dir_all(&dir).ok();
+
+    assert_eq!(store.signing_key(now()).unwrap().kid(), "ed");
+    assert_eq!(store.jwks(now()).keys.len(), 1);
+}
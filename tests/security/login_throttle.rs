@@ -0,0 +1,64 @@
+use core_api::endpoints::v1::auth::login::throttle::ThrottleSubject;
+use core_api::security::login_throttle::LoginThrottleConfig;
+
+#[test]
+fn test_first_failure_has_no_delay() {
+    let config = LoginThrottleConfig::new(5, 20, 900, 1);
+
+    assert_eq!(config.backoff_seconds(0), 0);
+    assert_eq!(config.backoff_seconds(1), 0);
+}
+
+#[test]
+fn test_delay_doubles_with_each_failure() {
+    let config = LoginThrottleConfig::new(10, 20, 900, 2);
+
+    assert_eq!(config.backoff_seconds(2), 2);
+    assert_eq!(config.backoff_seconds(3), 4);
+    assert_eq!(config.backoff_seconds(4), 8);
+    assert_eq!(config.backoff_seconds(5), 16);
+}
+
+#[test]
+fn test_delay_is_capped_by_lockout_window() {
+    let config = LoginThrottleConfig::new(100, 100, 60, 1);
+
+    assert_eq!(config.backoff_seconds(8), 60);
+    assert_eq!(config.backoff_seconds(u64::MAX), 60);
+}
+
+#[test]
+fn test_failure_limits_are_at_least_one() {
+    let config = LoginThrottleConfig::new(0, 0, 900, 1);
+
+    assert_eq!(config.max_account_failures(), 1);
+    assert_eq!(config.max_ip_failures(), 1);
+}
+
+#[test]
+fn test_default_policy() {
+    let config = LoginThrottleConfig::default();
+
+    assert_eq!(config.max_account_failures(), 5);
+    assert_eq!(config.max_ip_failures(), 20);
+    assert_eq!(config.lockout_seconds(), 900);
+}
+
+#[test]
+fn test_second_factor_budget_is_kept_per_account() {
+    // Deux challenges du même utilisateur partagent les mêmes compteurs
+    let subject = ThrottleSubject::SecondFactor(42);
+
+    assert_eq!(subject.key("failures"), "42/mfa_failures");
+    assert_eq!(subject.key("locked"), "42/mfa_locked");
+}
+
+#[test]
+fn test_password_login_does_not_clear_second_factor_budget() {
+    for suffix in ["failures", "delay", "locked"] {
+        assert_ne!(
+            ThrottleSubject::Account(42).key(suffix),
+            ThrottleSubject::SecondFactor(42).key(suffix)
+        );
+    }
+}
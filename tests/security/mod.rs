@@ -0,0 +1,15 @@
+mod client_credentials;
+mod device;
+mod email_token;
+mod federation;
+mod jwt;
+mod login_throttle;
+mod oidc;
+mod password;
+mod password_policy;
+mod personal_access_token;
+mod recovery_codes;
+mod service_account;
+mod session_policy;
+mod totp;
+mod webauthn;
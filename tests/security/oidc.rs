@@ -0,0 +1,123 @@
+use core_api::security::jwt::Claims;
+use core_api::security::oidc::{
+    authorization_redirect, is_registered_redirect_uri, parse_oidc_scopes, verify_code_challenge,
+    IdTokenClaims, UserClaims,
+};
+
+// RFC 7636, appendix B
+const VERIFIER: &str = "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk";
+const CHALLENGE: &str = "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM";
+
+fn scopes(scopes: &[&str]) -> Vec<String> {
+    scopes.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn test_pkce_challenge() {
+    assert!(verify_code_challenge(VERIFIER, CHALLENGE));
+    assert!(!verify_code_challenge(VERIFIER, VERIFIER));
+    assert!(!verify_code_challenge(
+        "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXK",
+        CHALLENGE
+    ));
+}
+
+#[test]
+fn test_pkce_verifier_format() {
+    assert!(!verify_code_challenge("too-short", CHALLENGE));
+    assert!(!verify_code_challenge(&"a".repeat(129), CHALLENGE));
+    assert!(!verify_code_challenge(
+        "dBjftJeZ4CVP+mB92K27uhbUJU1p1r/wW1gFWFOEjXk",
+        CHALLENGE
+    ));
+}
+
+#[test]
+fn test_scopes_require_openid() {
+    assert_eq!(parse_oidc_scopes("profile email"), None);
+    assert_eq!(
+        parse_oidc_scopes("email openid calendar email"),
+        Some(scopes(&["email", "openid"]))
+    );
+}
+
+#[test]
+fn test_redirect_uri_is_matched_exactly() {
+    let registered = scopes(&["https://agenda.mairie360.fr/callback"]);
+    assert!(is_registered_redirect_uri(
+        "https://agenda.mairie360.fr/callback",
+        &registered
+    ));
+    assert!(!is_registered_redirect_uri(
+        "https://agenda.mairie360.fr/callback/",
+        &registered
+    ));
+    assert!(!is_registered_redirect_uri(
+        "https://agenda.mairie360.fr/callback?next=https://evil.example",
+        &registered
+    ));
+}
+
+#[test]
+fn test_authorization_redirect_keeps_query_and_encodes_state() {
+    let url = authorization_redirect(
+        "https://agenda.mairie360.fr/callback?tenant=lyon",
+        &[("code", "abc")],
+        Some("a b&c"),
+    )
+    .unwrap();
+    assert_eq!(
+        url,
+        "https://agenda.mairie360.fr/callback?tenant=lyon&code=abc&state=a+b%26c"
+    );
+    assert_eq!(authorization_redirect("not a url", &[], None), None);
+}
+
+#[test]
+fn test_user_claims_follow_scopes() {
+    let claims = UserClaims::new(
+        42,
+        &scopes(&["openid"]),
+        "Jeanne",
+        "Martin",
+        "jeanne@mairie360.fr",
+        Some("0600000000"),
+    );
+    assert_eq!(claims.sub(), "42");
+    assert_eq!(claims.name(), None);
+    assert_eq!(claims.email(), None);
+
+    let claims = UserClaims::new(
+        42,
+        &scopes(&["email", "openid", "profile"]),
+        "Jeanne",
+        "Martin",
+        "jeanne@mairie360.fr",
+        Some("0600000000"),
+    );
+    assert_eq!(claims.name(), Some("Jeanne Martin"));
+    assert_eq!(claims.email(), Some("jeanne@mairie360.fr"));
+    assert_eq!(claims.phone_number(), None);
+}
+
+#[test]
+fn test_id_token_claims_are_flat() {
+    let user = UserClaims::new(42, &scopes(&["email", "openid"]), "J", "M", "j@m.fr", None);
+    let claims = IdTokenClaims::new("https://core.mairie360.fr", "agenda", user, 100, 1000)
+        .with_nonce(Some("n-0S6_WzA2Mj"));
+    let json = serde_json::to_value(&claims).unwrap();
+    assert_eq!(json["iss"], "https://core.mairie360.fr");
+    assert_eq!(json["aud"], "agenda");
+    assert_eq!(json["sub"], "42");
+    assert_eq!(json["email"], "j@m.fr");
+    assert_eq!(json["nonce"], "n-0S6_WzA2Mj");
+    assert!(json.get("sid").is_none());
+}
+
+#[test]
+fn test_client_access_token_is_not_a_module_token() {
+    let claims = Claims::new("42", 100, 1000).with_client("agenda", &scopes(&["openid"]));
+    assert!(!claims.is_module_token());
+    assert_eq!(claims.get_client_id(), Some("agenda"));
+    assert_eq!(claims.get_user_id(), "42");
+}
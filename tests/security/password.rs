@@ -0,0 +1,77 @@
+use core_api::security::password::{
+    hash_password, verify_password, PasswordHasherConfig, PasswordVerification,
+};
+
+fn test_config() -> PasswordHasherConfig {
+    PasswordHasherConfig::new(1024, 1, 1)
+}
+
+#[tokio::test]
+async fn test_hash_password_produces_argon2id_phc_string() {
+    let hash = hash_password("password123", test_config()).await.unwrap();
+
+    assert!(hash.starts_with("$argon2id$"));
+    assert_ne!(hash, "password123");
+}
+
+#[tokio::test]
+async fn test_hash_password_uses_random_salt() {
+    let first = hash_password("password123", test_config()).await.unwrap();
+    let second = hash_password("password123", test_config()).await.unwrap();
+
+    assert_ne!(first, second);
+}
+
+#[tokio::test]
+async fn test_verify_password_success() {
+    let hash = hash_password("password123", test_config()).await.unwrap();
+
+    let result = verify_password("password123", &hash, test_config())
+        .await
+        .unwrap();
+
+    assert_eq!(result, PasswordVerification::Valid);
+}
+
+#[tokio::test]
+async fn test_verify_password_wrong_password() {
+    let hash = hash_password("password123", test_config()).await.unwrap();
+
+    let result = verify_password("wrong_pass", &hash, test_config())
+        .await
+        .unwrap();
+
+    assert_eq!(result, PasswordVerification::Invalid);
+}
+
+#[tokio::test]
+async fn test_verify_password_outdated_params_needs_rehash() {
+    let hash = hash_password("password123", test_config()).await.unwrap();
+
+    let result = verify_password("password123", &hash, PasswordHasherConfig::new(2048, 1, 1))
+        .await
+        .unwrap();
+
+    assert_eq!(result, PasswordVerification::ValidNeedsRehash);
+}
+
+#[tokio::test]
+async fn test_verify_legacy_plaintext_needs_rehash() {
+    // CHAR(60) pads legacy values with spaces
+    let stored = format!("{:<60}", "password123");
+
+    let result = verify_password("password123", &stored, test_config())
+        .await
+        .unwrap();
+
+    assert_eq!(result, PasswordVerification::ValidNeedsRehash);
+}
+
+#[tokio::test]
+async fn test_verify_legacy_plaintext_wrong_password() {
+    let result = verify_password("wrong_pass", "password123", test_config())
+        .await
+        .unwrap();
+
+    assert_eq!(result, PasswordVerification::Invalid);
+}
@@ -0,0 +1,105 @@
+use core_api::security::password_policy::{personal_words, PasswordPolicy, PasswordViolation};
+
+fn strict_policy() -> PasswordPolicy {
+    PasswordPolicy::new(12)
+        .with_character_classes(true, true, true, true)
+        .with_forbidden_words(&["mairie360", "azerty"])
+}
+
+#[test]
+fn test_strong_password_is_accepted() {
+    let violations = strict_policy().check("Correct-Horse-42", &[]);
+
+    assert!(violations.is_empty());
+}
+
+#[test]
+fn test_every_broken_rule_is_reported() {
+    let violations = strict_policy().check("short", &[]);
+
+    assert_eq!(
+        violations,
+        vec![
+            PasswordViolation::TooShort { min_length: 12 },
+            PasswordViolation::MissingUppercase,
+            PasswordViolation::MissingDigit,
+            PasswordViolation::MissingSymbol,
+        ]
+    );
+}
+
+#[test]
+fn test_length_counts_characters_not_bytes() {
+    let policy = PasswordPolicy::new(8);
+
+    assert!(policy.check("éléphant", &[]).is_empty());
+    assert_eq!(
+        policy.check("éléph", &[]),
+        vec![PasswordViolation::TooShort { min_length: 8 }]
+    );
+}
+
+#[test]
+fn test_forbidden_words_are_case_insensitive() {
+    let violations = strict_policy().check("Welcome@MAIRIE360!", &[]);
+
+    assert!(violations.contains(&PasswordViolation::ContainsForbiddenWord));
+}
+
+#[test]
+fn test_personal_information_is_refused() {
+    let words = personal_words("Alice", "Martin", "a.martin+work@example.com");
+    let violations = strict_policy().check("Alice-2024-Secure!", &words);
+
+    assert_eq!(
+        violations,
+        vec![PasswordViolation::ContainsPersonalInformation]
+    );
+}
+
+#[test]
+fn test_personal_words_skip_short_parts() {
+    let words = personal_words("Al", "Martin", "a.martin+work@example.com");
+
+    assert_eq!(words, vec!["martin", "martin", "work"]);
+}
+
+#[test]
+fn test_violations_serialize_with_a_code() {
+    let json = serde_json::to_value(vec![
+        PasswordViolation::TooShort { min_length: 12 },
+        PasswordViolation::RecentlyUsed { history_size: 5 },
+    ])
+    .unwrap();
+
+    assert_eq!(
+        json,
+        serde_json::json!([
+            { "code": "too_short", "min_length": 12 },
+            { "code": "recently_used", "history_size": 5 },
+        ])
+    );
+}
+
+#[test]
+fn test_default_policy() {
+    let policy = PasswordPolicy::default();
+
+    assert_eq!(policy.min_length(), 12);
+    assert_eq!(policy.history_size(), 5);
+    assert_eq!(policy.max_age_days(), None);
+    assert_eq!(
+        policy.check("password1234", &[]),
+        vec![
+            PasswordViolation::MissingUppercase,
+            PasswordViolation::ContainsForbiddenWord,
+        ]
+    );
+}
+
+#[test]
+fn test_zero_max_age_disables_expiry() {
+    let policy = PasswordPolicy::new(8).with_max_age_days(Some(0));
+
+    assert_eq!(policy.max_age_days(), None);
+}
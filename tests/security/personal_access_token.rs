@@ -0,0 +1,114 @@
+use actix_web::http::Method;
+use core_api::security::personal_access_token::{
+    generate_personal_access_token, parse_token_authorization, PersonalAccessTokenScope,
+    PERSONAL_ACCESS_TOKEN_PREFIX,
+};
+
+#[test]
+fn test_generated_token_is_accepted_by_the_token_scheme() {
+    let token = generate_personal_access_token();
+    let header = format!("Token {}", token);
+
+    assert!(token.starts_with(PERSONAL_ACCESS_TOKEN_PREFIX));
+    assert_eq!(parse_token_authorization(&header), Some(token.as_str()));
+}
+
+#[test]
+fn test_generated_tokens_are_unique() {
+    assert_ne!(
+        generate_personal_access_token(),
+        generate_personal_access_token()
+    );
+}
+
+#[test]
+fn test_other_schemes_are_ignored() {
+    let token = generate_personal_access_token();
+
+    assert_eq!(
+        parse_token_authorization(&format!("Bearer {}", token)),
+        None
+    );
+    assert_eq!(parse_token_authorization(&token), None);
+}
+
+#[test]
+fn test_token_without_prefix_is_ignored() {
+    assert_eq!(parse_token_authorization("Token abcdef"), None);
+    assert_eq!(
+        parse_token_authorization(&format!("Token {}", PERSONAL_ACCESS_TOKEN_PREFIX)),
+        None
+    );
+}
+
+#[test]
+fn test_read_scope_only_allows_safe_methods() {
+    let scopes = [PersonalAccessTokenScope::Read];
+
+    assert!(PersonalAccessTokenScope::allows(
+        &scopes,
+        &Method::GET,
+        false
+    ));
+    assert!(!PersonalAccessTokenScope::allows(
+        &scopes,
+        &Method::POST,
+        false
+    ));
+    assert!(!PersonalAccessTokenScope::allows(
+        &scopes,
+        &Method::DELETE,
+        false
+    ));
+}
+
+#[test]
+fn test_write_scope_allows_every_method() {
+    let scopes = [PersonalAccessTokenScope::Write];
+
+    assert!(PersonalAccessTokenScope::allows(
+        &scopes,
+        &Method::GET,
+        false
+    ));
+    assert!(PersonalAccessTokenScope::allows(
+        &scopes,
+        &Method::PATCH,
+        false
+    ));
+}
+
+#[test]
+fn test_admin_routes_need_the_admin_scope() {
+    let write = [PersonalAccessTokenScope::Write];
+    let admin_read = [
+        PersonalAccessTokenScope::Admin,
+        PersonalAccessTokenScope::Read,
+    ];
+
+    assert!(!PersonalAccessTokenScope::allows(
+        &write,
+        &Method::GET,
+        true
+    ));
+    assert!(PersonalAccessTokenScope::allows(
+        &admin_read,
+        &Method::GET,
+        true
+    ));
+    assert!(!PersonalAccessTokenScope::allows(
+        &admin_read,
+        &Method::POST,
+        true
+    ));
+}
+
+#[test]
+fn test_unknown_stored_scopes_are_dropped() {
+    let stored = vec!["read".to_string(), "delete_everything".to_string()];
+
+    assert_eq!(
+        PersonalAccessTokenScope::parse_all(&stored),
+        vec![PersonalAccessTokenScope::Read]
+    );
+}
@@ -0,0 +1,44 @@
+use core_api::security::recovery_codes::{
+    generate_recovery_codes, hash_recovery_code, RECOVERY_CODES_COUNT,
+};
+use std::collections::HashSet;
+
+#[test]
+fn test_generate_recovery_codes_count_and_format() {
+    let codes = generate_recovery_codes();
+
+    assert_eq!(codes.len(), RECOVERY_CODES_COUNT);
+    for code in &codes {
+        assert_eq!(code.len(), 11);
+        assert_eq!(code.chars().nth(5), Some('-'));
+    }
+}
+
+#[test]
+fn test_generate_recovery_codes_are_unique() {
+    let codes = generate_recovery_codes();
+    let unique: HashSet<&String> = codes.iter().collect();
+
+    assert_eq!(unique.len(), codes.len());
+}
+
+#[test]
+fn test_hash_recovery_code_is_not_plaintext() {
+    let code = &generate_recovery_codes()[0];
+    let hash = hash_recovery_code(code);
+
+    assert_eq!(hash.len(), 64);
+    assert!(!hash.contains(code.as_str()));
+}
+
+#[test]
+fn test_hash_recovery_code_ignores_case_and_separators() {
+    assert_eq!(
+        hash_recovery_code("abcde-fghjk"),
+        hash_recovery_code(" ABCDE FGHJK ")
+    );
+    assert_ne!(
+        hash_recovery_code("abcde-fghjk"),
+        hash_recovery_code("abcde-fghjm")
+    );
+}
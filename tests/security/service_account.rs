@@ -0,0 +1,38 @@
+use core_api::security::service_account::{grant_scopes, AuthenticatedModule, USERS_READ_SCOPE};
+
+fn allowed() -> Vec<String> {
+    vec![USERS_READ_SCOPE.to_string(), "groups:read".to_string()]
+}
+
+#[test]
+fn test_missing_scope_grants_everything_allowed() {
+    let granted = grant_scopes(None, &allowed()).unwrap();
+    assert_eq!(granted, vec!["groups:read", "users:read"]);
+}
+
+#[test]
+fn test_requested_scopes_are_narrowed() {
+    let granted = grant_scopes(Some("users:read  users:read"), &allowed()).unwrap();
+    assert_eq!(granted, vec!["users:read"]);
+}
+
+#[test]
+fn test_unknown_scope_fails_the_request() {
+    assert_eq!(
+        grant_scopes(Some("users:read users:write"), &allowed()),
+        None
+    );
+}
+
+#[test]
+fn test_no_scope_is_refused() {
+    assert_eq!(grant_scopes(Some(" "), &allowed()), None);
+    assert_eq!(grant_scopes(None, &[]), None);
+}
+
+#[test]
+fn test_module_scopes() {
+    let module = AuthenticatedModule::new("agenda", 3, vec![USERS_READ_SCOPE.to_string()]);
+    assert!(module.has_scope(USERS_READ_SCOPE));
+    assert!(!module.has_scope("groups:read"));
+}
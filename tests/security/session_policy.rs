@@ -0,0 +1,78 @@
+use chrono::{Duration, Utc};
+use core_api::security::session_policy::{SessionAdmission, SessionLimitAction, SessionPolicy};
+
+#[test]
+fn test_no_limit_always_admits() {
+    let policy = SessionPolicy::default();
+
+    assert_eq!(policy.admit(0), SessionAdmission::Allowed);
+    assert_eq!(policy.admit(1_000), SessionAdmission::Allowed);
+}
+
+#[test]
+fn test_limit_evicts_oldest_sessions() {
+    let policy = SessionPolicy::new(Some(3), SessionLimitAction::EvictOldest, None, None);
+
+    assert_eq!(policy.admit(2), SessionAdmission::Allowed);
+    assert_eq!(policy.admit(3), SessionAdmission::EvictOldest(1));
+    assert_eq!(policy.admit(5), SessionAdmission::EvictOldest(3));
+}
+
+#[test]
+fn test_limit_refuses_new_logins() {
+    let policy = SessionPolicy::new(Some(1), SessionLimitAction::Refuse, None, None);
+
+    assert_eq!(policy.admit(0), SessionAdmission::Allowed);
+    assert_eq!(policy.admit(1), SessionAdmission::Refused);
+}
+
+#[test]
+fn test_zero_means_no_limit() {
+    let policy = SessionPolicy::new(Some(0), SessionLimitAction::Refuse, Some(0), Some(0));
+
+    assert_eq!(policy.max_sessions(), None);
+    assert_eq!(policy.idle_timeout_minutes(), None);
+    assert_eq!(policy.absolute_expires_at(Utc::now()), None);
+}
+
+#[test]
+fn test_roles_combine_to_the_strictest_policy() {
+    let staff = SessionPolicy::new(Some(5), SessionLimitAction::EvictOldest, Some(60), None);
+    let kiosk = SessionPolicy::new(Some(1), SessionLimitAction::Refuse, Some(10), Some(120));
+
+    let policy = SessionPolicy::for_roles(vec![staff, kiosk], SessionPolicy::default());
+
+    assert_eq!(policy.max_sessions(), Some(1));
+    assert_eq!(policy.limit_action(), SessionLimitAction::Refuse);
+    assert_eq!(policy.idle_timeout_minutes(), Some(10));
+    assert_eq!(policy.absolute_lifetime_minutes(), Some(120));
+}
+
+#[test]
+fn test_users_without_role_policy_get_the_fallback() {
+    let fallback = SessionPolicy::new(Some(10), SessionLimitAction::EvictOldest, None, None);
+
+    assert_eq!(
+        SessionPolicy::for_roles(Vec::new(), fallback.clone()),
+        fallback
+    );
+}
+
+#[test]
+fn test_absolute_lifetime_starts_at_login() {
+    let now = Utc::now();
+    let policy = SessionPolicy::new(None, SessionLimitAction::EvictOldest, None, Some(30));
+
+    assert_eq!(
+        policy.absolute_expires_at(now),
+        Some(now + Duration::minutes(30))
+    );
+}
+
+#[test]
+fn test_limit_action_round_trip() {
+    for action in [SessionLimitAction::EvictOldest, SessionLimitAction::Refuse] {
+        assert_eq!(SessionLimitAction::parse(action.as_str()), Some(action));
+    }
+    assert_eq!(SessionLimitAction::parse("kick"), None);
+}
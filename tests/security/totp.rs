@@ -0,0 +1,73 @@
+use core_api::security::totp::{
+    generate_totp_secret, is_totp_code, totp_uri, verify_totp_code, TotpConfig,
+};
+use totp_rs::{Algorithm, Secret, TOTP};
+
+const TIME: u64 = 1_700_000_000;
+
+fn code_at(secret: &str, time: u64) -> String {
+    let bytes = Secret::Encoded(secret.to_string()).to_bytes().unwrap();
+    TOTP::new_unchecked(Algorithm::SHA1, 6, 1, 30, bytes, None, String::new()).generate(time)
+}
+
+#[test]
+fn test_generate_totp_secret_is_base32_160_bits() {
+    let secret = generate_totp_secret();
+    let bytes = Secret::Encoded(secret.clone()).to_bytes().unwrap();
+
+    assert_eq!(bytes.len(), 20);
+    assert_ne!(secret, generate_totp_secret());
+}
+
+#[test]
+fn test_totp_uri_contains_issuer_and_account() {
+    let secret = generate_totp_secret();
+    let uri = totp_uri(&secret, "alice@example.com", &TotpConfig::new("Mairie360")).unwrap();
+
+    assert!(uri.starts_with("otpauth://totp/"));
+    assert!(uri.contains("issuer=Mairie360"));
+    assert!(uri.contains(&format!("secret={}", secret)));
+}
+
+#[test]
+fn test_verify_totp_code_current_step() {
+    let secret = generate_totp_secret();
+    let code = code_at(&secret, TIME);
+
+    let step = verify_totp_code(&secret, &code, TIME).unwrap();
+
+    assert_eq!(step, Some(TIME / 30));
+}
+
+#[test]
+fn test_verify_totp_code_accepts_clock_skew() {
+    let secret = generate_totp_secret();
+    let code = code_at(&secret, TIME - 30);
+
+    let step = verify_totp_code(&secret, &code, TIME).unwrap();
+
+    assert_eq!(step, Some(TIME / 30 - 1));
+}
+
+#[test]
+fn test_verify_totp_code_rejects_old_code() {
+    let secret = generate_totp_secret();
+    let code = code_at(&secret, TIME - 120);
+
+    let step = verify_totp_code(&secret, &code, TIME).unwrap();
+
+    assert_eq!(step, None);
+}
+
+#[test]
+fn test_verify_totp_code_invalid_secret() {
+    assert!(verify_totp_code("not base32!", "123456", TIME).is_err());
+}
+
+#[test]
+fn test_is_totp_code() {
+    assert!(is_totp_code("123456"));
+    assert!(is_totp_code(" 123456 "));
+    assert!(!is_totp_code("12345"));
+    assert!(!is_totp_code("abcde-fghjk"));
+}
@@ -0,0 +1,300 @@
+use base64::{engine::general_purpose, Engine as _};
+use ciborium::Value;
+use core_api::security::webauthn::{
+    client_data_challenge, verify_authentication, verify_registration, CosePublicKey,
+    WebauthnConfig, WebauthnError, COSE_ALG_EDDSA, COSE_ALG_ES256,
+};
+use sha2::{Digest, Sha256};
+
+const CHALLENGE: &str = "dGVzdC1jaGFsbGVuZ2UtMzItYnl0ZXMtbG9uZy4uLi4";
+const CREDENTIAL_ID: &[u8] = b"credential-id-0001";
+
+fn config() -> WebauthnConfig {
+    WebauthnConfig::new("mairie360.fr", "Mairie360", "https://app.mairie360.fr")
+}
+
+fn client_data(ceremony: &str, challenge: &str, origin: &str) -> Vec<u8> {
+    format!(
+        r#"{{"type":"{}","challenge":"{}","origin":"{}","crossOrigin":false}}"#,
+        ceremony, challenge, origin
+    )
+    .into_bytes()
+}
+
+fn cbor(value: Value) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    ciborium::ser::into_writer(&value, &mut bytes).unwrap();
+    bytes
+}
+
+fn int(value: i64) -> Value {
+    Value::Integer(value.into())
+}
+
+fn es256_key() -> p256::ecdsa::SigningKey {
+    p256::ecdsa::SigningKey::from_slice(&[7u8; 32]).unwrap()
+}
+
+fn es256_cose_key() -> Vec<u8> {
+    let point = es256_key().verifying_key().to_encoded_point(false);
+    cbor(Value::Map(vec![
+        (int(1), int(2)),
+        (int(3), int(COSE_ALG_ES256)),
+        (int(-1), int(1)),
+        (int(-2), Value::Bytes(point.x().unwrap().to_vec())),
+        (int(-3), Value::Bytes(point.y().unwrap().to_vec())),
+    ]))
+}
+
+fn ed25519_key() -> ed25519_dalek::SigningKey {
+    ed25519_dalek::SigningKey::from_bytes(&[9u8; 32])
+}
+
+fn ed25519_cose_key() -> Vec<u8> {
+    cbor(Value::Map(vec![
+        (int(1), int(1)),
+        (int(3), int(COSE_ALG_EDDSA)),
+        (int(-1), int(6)),
+        (
+            int(-2),
+            Value::Bytes(ed25519_key().verifying_key().to_bytes().to_vec()),
+        ),
+    ]))
+}
+
+fn authenticator_data(rp_id: &str, flags: u8, sign_count: u32, cose_key: Option<&[u8]>) -> Vec<u8> {
+    let mut data = Sha256::digest(rp_id.as_bytes()).to_vec();
+    data.push(flags);
+    data.extend_from_slice(&sign_count.to_be_bytes());
+    if let Some(key) = cose_key {
+        data.extend_from_slice(&[0u8; 16]);
+        data.extend_from_slice(&(CREDENTIAL_ID.len() as u16).to_be_bytes());
+        data.extend_from_slice(CREDENTIAL_ID);
+        data.extend_from_slice(key);
+    }
+    data
+}
+
+fn attestation_object(auth_data: Vec<u8>) -> Vec<u8> {
+    cbor(Value::Map(vec![
+        (
+            Value::Text("fmt".to_string()),
+            Value::Text("none".to_string()),
+        ),
+        (Value::Text("attStmt".to_string()), Value::Map(vec![])),
+        (Value::Text("authData".to_string()), Value::Bytes(auth_data)),
+    ]))
+}
+
+fn signed_payload(auth_data: &[u8], client_data: &[u8]) -> Vec<u8> {
+    let mut payload = auth_data.to_vec();
+    payload.extend_from_slice(&Sha256::digest(client_data));
+    payload
+}
+
+fn es256_sign(payload: &[u8]) -> Vec<u8> {
+    use p256::ecdsa::signature::Signer;
+    let signature: p256::ecdsa::Signature = es256_key().sign(payload);
+    signature.to_der().as_bytes().to_vec()
+}
+
+#[test]
+fn test_verify_registration_es256() {
+    let client_data = client_data("webauthn.create", CHALLENGE, "https://app.mairie360.fr");
+    let attestation = attestation_object(authenticator_data(
+        "mairie360.fr",
+        0x45,
+        0,
+        Some(&es256_cose_key()),
+    ));
+
+    let credential = verify_registration(&config(), CHALLENGE, &client_data, &attestation).unwrap();
+
+    assert_eq!(credential.credential_id(), CREDENTIAL_ID);
+    assert_eq!(credential.public_key(), es256_cose_key().as_slice());
+    assert_eq!(credential.algorithm(), COSE_ALG_ES256);
+}
+
+#[test]
+fn test_verify_registration_rejects_wrong_origin() {
+    let client_data = client_data("webauthn.create", CHALLENGE, "https://evil.example");
+    let attestation = attestation_object(authenticator_data(
+        "mairie360.fr",
+        0x45,
+        0,
+        Some(&es256_cose_key()),
+    ));
+
+    let result = verify_registration(&config(), CHALLENGE, &client_data, &attestation);
+
+    assert_eq!(result, Err(WebauthnError::OriginMismatch));
+}
+
+#[test]
+fn test_verify_registration_rejects_wrong_challenge() {
+    let client_data = client_data("webauthn.create", "b3RoZXI", "https://app.mairie360.fr");
+    let attestation = attestation_object(authenticator_data(
+        "mairie360.fr",
+        0x45,
+        0,
+        Some(&es256_cose_key()),
+    ));
+
+    let result = verify_registration(&config(), CHALLENGE, &client_data, &attestation);
+
+    assert_eq!(result, Err(WebauthnError::ChallengeMismatch));
+}
+
+#[test]
+fn test_verify_registration_rejects_wrong_rp_id() {
+    let client_data = client_data("webauthn.create", CHALLENGE, "https://app.mairie360.fr");
+    let attestation = attestation_object(authenticator_data(
+        "evil.example",
+        0x45,
+        0,
+        Some(&es256_cose_key()),
+    ));
+
+    let result = verify_registration(&config(), CHALLENGE, &client_data, &attestation);
+
+    assert_eq!(result, Err(WebauthnError::RpIdMismatch));
+}
+
+#[test]
+fn test_verify_registration_requires_user_presence() {
+    let client_data = client_data("webauthn.create", CHALLENGE, "https://app.mairie360.fr");
+    let attestation = attestation_object(authenticator_data(
+        "mairie360.fr",
+        0x40,
+        0,
+        Some(&es256_cose_key()),
+    ));
+
+    let result = verify_registration(&config(), CHALLENGE, &client_data, &attestation);
+
+    assert_eq!(result, Err(WebauthnError::UserNotPresent));
+}
+
+#[test]
+fn test_verify_authentication_es256() {
+    let client_data = client_data("webauthn.get", CHALLENGE, "https://app.mairie360.fr");
+    let auth_data = authenticator_data("mairie360.fr", 0x05, 42, None);
+    let signature = es256_sign(&signed_payload(&auth_data, &client_data));
+
+    let sign_count = verify_authentication(
+        &config(),
+        CHALLENGE,
+        &client_data,
+        &auth_data,
+        &signature,
+        &es256_cose_key(),
+    )
+    .unwrap();
+
+    assert_eq!(sign_count, 42);
+}
+
+#[test]
+fn test_verify_authentication_ed25519() {
+    use ed25519_dalek::Signer;
+
+    let client_data = client_data("webauthn.get", CHALLENGE, "https://app.mairie360.fr");
+    let auth_data = authenticator_data("mairie360.fr", 0x05, 0, None);
+    let signature = ed25519_key()
+        .sign(&signed_payload(&auth_data, &client_data))
+        .to_bytes();
+
+    let result = verify_authentication(
+        &config(),
+        CHALLENGE,
+        &client_data,
+        &auth_data,
+        &signature,
+        &ed25519_cose_key(),
+    );
+
+    assert_eq!(result, Ok(0));
+}
+
+#[test]
+fn test_verify_authentication_requires_user_verification() {
+    let client_data = client_data("webauthn.get", CHALLENGE, "https://app.mairie360.fr");
+    let auth_data = authenticator_data("mairie360.fr", 0x01, 42, None);
+    let signature = es256_sign(&signed_payload(&auth_data, &client_data));
+
+    let result = verify_authentication(
+        &config(),
+        CHALLENGE,
+        &client_data,
+        &auth_data,
+        &signature,
+        &es256_cose_key(),
+    );
+
+    assert_eq!(result, Err(WebauthnError::UserNotVerified));
+}
+
+#[test]
+fn test_verify_authentication_rejects_tampered_data() {
+    let client_data = client_data("webauthn.get", CHALLENGE, "https://app.mairie360.fr");
+    let auth_data = authenticator_data("mairie360.fr", 0x05, 42, None);
+    let signature = es256_sign(&signed_payload(&auth_data, &client_data));
+    let tampered = authenticator_data("mairie360.fr", 0x05, 43, None);
+
+    let result = verify_authentication(
+        &config(),
+        CHALLENGE,
+        &client_data,
+        &tampered,
+        &signature,
+        &es256_cose_key(),
+    );
+
+    assert_eq!(result, Err(WebauthnError::InvalidSignature));
+}
+
+#[test]
+fn test_verify_authentication_rejects_registration_client_data() {
+    let client_data = client_data("webauthn.create", CHALLENGE, "https://app.mairie360.fr");
+    let auth_data = authenticator_data("mairie360.fr", 0x05, 42, None);
+    let signature = es256_sign(&signed_payload(&auth_data, &client_data));
+
+    let result = verify_authentication(
+        &config(),
+        CHALLENGE,
+        &client_data,
+        &auth_data,
+        &signature,
+        &es256_cose_key(),
+    );
+
+    assert_eq!(result, Err(WebauthnError::TypeMismatch));
+}
+
+#[test]
+fn test_cose_public_key_rejects_unsupported_algorithm() {
+    let rs256 = cbor(Value::Map(vec![
+        (int(1), int(3)),
+        (int(3), int(-257)),
+        (int(-1), int(0)),
+    ]));
+
+    assert_eq!(
+        CosePublicKey::parse(&rs256),
+        Err(WebauthnError::UnsupportedAlgorithm(-257))
+    );
+}
+
+#[test]
+fn test_client_data_challenge() {
+    let client_data = client_data("webauthn.get", CHALLENGE, "https://app.mairie360.fr");
+
+    assert_eq!(client_data_challenge(&client_data).unwrap(), CHALLENGE);
+    assert_eq!(
+        general_purpose::URL_SAFE_NO_PAD
+            .decode(CHALLENGE)
+            .unwrap()
+            .len(),
+        32
+    );
+}